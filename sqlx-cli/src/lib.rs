@@ -31,8 +31,12 @@ hint: This command only works in the manifest directory of a Cargo package."#
 
     match opt.command {
         Command::Migrate(migrate) => match migrate.command {
-            MigrateCommand::Add { description } => migrate::add(&migrate.source, &description)?,
+            MigrateCommand::Add {
+                description,
+                reversible,
+            } => migrate::add(&migrate.source, &description, reversible)?,
             MigrateCommand::Run => migrate::run(&migrate.source, &database_url).await?,
+            MigrateCommand::Revert => migrate::revert(&migrate.source, &database_url).await?,
             MigrateCommand::Info => migrate::info(&migrate.source, &database_url).await?,
         },
 
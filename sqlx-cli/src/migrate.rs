@@ -1,22 +1,53 @@
 use anyhow::{bail, Context};
+use chrono::Utc;
 use console::style;
-use sqlx::migrate::{Migrate, MigrateError, Migrator};
+use sqlx::migrate::{Migrate, MigrateError, MigrationType, Migrator};
 use sqlx::{AnyConnection, Connection};
 use std::fs::{self, File};
 use std::io::Write;
-use std::path::Path;
-
-pub fn add(migration_source: &str, description: &str) -> anyhow::Result<()> {
-    use chrono::prelude::*;
-    use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+pub fn add(migration_source: &str, description: &str, reversible: bool) -> anyhow::Result<()> {
     fs::create_dir_all(migration_source).context("Unable to create migrations directory")?;
 
     let dt = Utc::now();
-    let mut file_name = dt.format("%Y%m%d%H%M%S").to_string();
-    file_name.push_str("_");
-    file_name.push_str(&description.replace(' ', "_"));
-    file_name.push_str(".sql");
+    let mut prefix = dt.format("%Y%m%d%H%M%S").to_string();
+    prefix.push_str("_");
+    prefix.push_str(&description.replace(' ', "_"));
+
+    if reversible {
+        create_file(
+            migration_source,
+            &prefix,
+            MigrationType::ReversibleUp,
+            b"-- Add up migration script here\n",
+        )?;
+        create_file(
+            migration_source,
+            &prefix,
+            MigrationType::ReversibleDown,
+            b"-- Add down migration script here\n",
+        )?;
+    } else {
+        create_file(
+            migration_source,
+            &prefix,
+            MigrationType::Simple,
+            b"-- Add migration script here\n",
+        )?;
+    }
+
+    Ok(())
+}
+
+fn create_file(
+    migration_source: &str,
+    prefix: &str,
+    migration_type: MigrationType,
+    contents: &[u8],
+) -> anyhow::Result<()> {
+    let mut file_name = prefix.to_owned();
+    file_name.push_str(migration_type.suffix());
 
     let mut path = PathBuf::new();
     path.push(migration_source);
@@ -26,7 +57,7 @@ pub fn add(migration_source: &str, description: &str) -> anyhow::Result<()> {
 
     let mut file = File::create(&path).context("Failed to create migration file")?;
 
-    file.write_all(b"-- Add migration script here\n")?;
+    file.write_all(contents)?;
 
     Ok(())
 }
@@ -39,7 +70,10 @@ pub async fn info(migration_source: &str, uri: &str) -> anyhow::Result<()> {
 
     let (version, _) = conn.version().await?.unwrap_or((0, false));
 
-    for migration in migrator.iter() {
+    for migration in migrator
+        .iter()
+        .filter(|m| !m.migration_type.is_down_migration())
+    {
         println!(
             "{}/{} {}",
             style(migration.version).cyan(),
@@ -59,6 +93,17 @@ pub async fn run(migration_source: &str, uri: &str) -> anyhow::Result<()> {
     let migrator = Migrator::new(Path::new(migration_source)).await?;
     let mut conn = AnyConnection::connect(uri).await?;
 
+    // another process may be migrating the same database, e.g. a service starting up
+    conn.lock().await?;
+
+    let res = apply_pending(&migrator, &mut conn).await;
+
+    conn.unlock().await?;
+
+    res
+}
+
+async fn apply_pending(migrator: &Migrator, conn: &mut AnyConnection) -> anyhow::Result<()> {
     conn.ensure_migrations_table().await?;
 
     let (version, dirty) = conn.version().await?.unwrap_or((0, false));
@@ -68,6 +113,11 @@ pub async fn run(migration_source: &str, uri: &str) -> anyhow::Result<()> {
     }
 
     for migration in migrator.iter() {
+        if migration.migration_type.is_down_migration() {
+            // skipping down migrations
+            continue;
+        }
+
         if migration.version > version {
             let elapsed = conn.apply(migration).await?;
 
@@ -85,3 +135,43 @@ pub async fn run(migration_source: &str, uri: &str) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+pub async fn revert(migration_source: &str, uri: &str) -> anyhow::Result<()> {
+    let migrator = Migrator::new(Path::new(migration_source)).await?;
+    let mut conn = AnyConnection::connect(uri).await?;
+
+    conn.ensure_migrations_table().await?;
+
+    let version = match conn.version().await? {
+        Some((version, _)) => version,
+        None => {
+            println!("No migrations available to revert");
+            return Ok(());
+        }
+    };
+
+    let migration = migrator
+        .iter()
+        .find(|m| m.version == version && !m.migration_type.is_down_migration())
+        .ok_or(MigrateError::VersionMissing(version))?;
+
+    // revert to the migration applied before the latest one; `undo` checks that the database
+    // is not dirty, that the applied migrations are unmodified and that this one is reversible
+    let previous = migrator
+        .iter()
+        .filter(|m| !m.migration_type.is_down_migration() && m.version < version)
+        .map(|m| m.version)
+        .max()
+        .unwrap_or(0);
+
+    migrator.undo(&mut conn, previous).await?;
+
+    println!(
+        "{}/{} {}",
+        style(migration.version).cyan(),
+        style("revert").green(),
+        migration.description,
+    );
+
+    Ok(())
+}
@@ -93,11 +93,20 @@ pub struct MigrateOpt {
 pub enum MigrateCommand {
     /// Create a new migration with the given description,
     /// and the current time as the version.
-    Add { description: String },
+    Add {
+        description: String,
+
+        /// Create a pair of up and down migration files so the migration can be reverted.
+        #[clap(short, long)]
+        reversible: bool,
+    },
 
     /// Run all pending migrations.
     Run,
 
+    /// Revert the latest migration with a down file.
+    Revert,
+
     /// List all available migrations.
     Info,
 }
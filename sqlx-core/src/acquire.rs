@@ -5,13 +5,57 @@ use crate::transaction::Transaction;
 use futures_core::future::BoxFuture;
 use std::ops::{Deref, DerefMut};
 
+/// A type that can provide a connection, or begin a transaction, to run several queries on.
+///
+/// Unlike an [`Executor`](crate::executor::Executor), which is consumed by a single query, an
+/// `Acquire` yields a connection that can be used for as many queries as needed. This lets
+/// a function be written once and called with whatever the caller has at hand.
+///
+/// Implemented for the following:
+///
+///  * [`&Pool`](super::pool::Pool), which acquires a connection from the pool
+///  * [`&mut PoolConnection`](super::pool::PoolConnection)
+///  * [`&mut Connection`](super::connection::Connection)
+///  * [`&mut Transaction`](super::transaction::Transaction), which runs the queries inside of
+///    the open transaction; [`begin`](Acquire::begin) creates a savepoint
+///
+/// ```rust,ignore
+/// async fn insert_user<'a, A>(conn: A, name: &str) -> sqlx::Result<i64>
+/// where
+///     A: Acquire<'a, Database = Postgres>,
+/// {
+///     let mut tx = conn.begin().await?;
+///
+///     let id: i64 = sqlx::query_scalar("INSERT INTO users (name) VALUES ($1) RETURNING id")
+///         .bind(name)
+///         .fetch_one(&mut tx)
+///         .await?;
+///
+///     sqlx::query("INSERT INTO audit_log (user_id) VALUES ($1)")
+///         .bind(id)
+///         .execute(&mut tx)
+///         .await?;
+///
+///     tx.commit().await?;
+///
+///     Ok(id)
+/// }
+///
+/// insert_user(&pool, "alice").await?;
+/// insert_user(&mut conn, "bob").await?;
+/// insert_user(&mut tx, "carol").await?;
+/// ```
 pub trait Acquire<'c> {
     type Database: Database;
 
     type Connection: Deref<Target = <Self::Database as Database>::Connection> + DerefMut;
 
+    /// Returns a connection to run queries on.
+    ///
+    /// Queries run on it through `&mut *conn`.
     fn acquire(self) -> BoxFuture<'c, Result<Self::Connection, Error>>;
 
+    /// Begins a new transaction, or a savepoint if called on a transaction.
     fn begin(self) -> BoxFuture<'c, Result<Transaction<'c, Self::Database>, Error>>;
 }
 
@@ -1,5 +1,5 @@
 use crate::any::Any;
-use crate::arguments::Arguments;
+use crate::arguments::{ArgumentCount, Arguments};
 use crate::encode::Encode;
 use crate::types::Type;
 
@@ -23,6 +23,12 @@ impl<'q> Arguments<'q> for AnyArguments<'q> {
     }
 }
 
+impl<'q> ArgumentCount<'q> for AnyArguments<'q> {
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+}
+
 pub struct AnyArgumentBuffer<'q>(pub(crate) AnyArgumentBufferKind<'q>);
 
 pub(crate) enum AnyArgumentBufferKind<'q> {
@@ -1,6 +1,6 @@
 use crate::any::connection::AnyConnectionKind;
 use crate::any::{Any, AnyColumn, AnyConnection, AnyDone, AnyRow, AnyStatement, AnyTypeInfo};
-use crate::database::Database;
+use crate::database::{Database, HasArguments, HasStatement};
 use crate::describe::Describe;
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
@@ -21,30 +21,31 @@ impl<'c> Executor<'c> for &'c mut AnyConnection {
         E: Execute<'q, Self::Database>,
     {
         let arguments = query.take_arguments();
+        let redacted = query.take_redacted_binds();
         let query = query.sql();
 
         match &mut self.0 {
             #[cfg(feature = "postgres")]
             AnyConnectionKind::Postgres(conn) => conn
-                .fetch_many((query, arguments.map(Into::into)))
+                .fetch_many(Forward::new(query, arguments, redacted))
                 .map_ok(|v| v.map_right(Into::into).map_left(Into::into))
                 .boxed(),
 
             #[cfg(feature = "mysql")]
             AnyConnectionKind::MySql(conn) => conn
-                .fetch_many((query, arguments.map(Into::into)))
+                .fetch_many(Forward::new(query, arguments, redacted))
                 .map_ok(|v| v.map_right(Into::into).map_left(Into::into))
                 .boxed(),
 
             #[cfg(feature = "sqlite")]
             AnyConnectionKind::Sqlite(conn) => conn
-                .fetch_many((query, arguments.map(Into::into)))
+                .fetch_many(Forward::new(query, arguments, redacted))
                 .map_ok(|v| v.map_right(Into::into).map_left(Into::into))
                 .boxed(),
 
             #[cfg(feature = "mssql")]
             AnyConnectionKind::Mssql(conn) => conn
-                .fetch_many((query, arguments.map(Into::into)))
+                .fetch_many(Forward::new(query, arguments, redacted))
                 .map_ok(|v| v.map_right(Into::into).map_left(Into::into))
                 .boxed(),
         }
@@ -59,31 +60,32 @@ impl<'c> Executor<'c> for &'c mut AnyConnection {
         E: Execute<'q, Self::Database>,
     {
         let arguments = query.take_arguments();
+        let redacted = query.take_redacted_binds();
         let query = query.sql();
 
         Box::pin(async move {
             Ok(match &mut self.0 {
                 #[cfg(feature = "postgres")]
                 AnyConnectionKind::Postgres(conn) => conn
-                    .fetch_optional((query, arguments.map(Into::into)))
+                    .fetch_optional(Forward::new(query, arguments, redacted))
                     .await?
                     .map(Into::into),
 
                 #[cfg(feature = "mysql")]
                 AnyConnectionKind::MySql(conn) => conn
-                    .fetch_optional((query, arguments.map(Into::into)))
+                    .fetch_optional(Forward::new(query, arguments, redacted))
                     .await?
                     .map(Into::into),
 
                 #[cfg(feature = "sqlite")]
                 AnyConnectionKind::Sqlite(conn) => conn
-                    .fetch_optional((query, arguments.map(Into::into)))
+                    .fetch_optional(Forward::new(query, arguments, redacted))
                     .await?
                     .map(Into::into),
 
                 #[cfg(feature = "mssql")]
                 AnyConnectionKind::Mssql(conn) => conn
-                    .fetch_optional((query, arguments.map(Into::into)))
+                    .fetch_optional(Forward::new(query, arguments, redacted))
                     .await?
                     .map(Into::into),
             })
@@ -141,6 +143,54 @@ impl<'c> Executor<'c> for &'c mut AnyConnection {
     }
 }
 
+// forwards a query to the connection of the underlying driver, along with
+// the bind values to redact in the statement log
+struct Forward<'q, DB: Database> {
+    sql: &'q str,
+    arguments: Option<<DB as HasArguments<'q>>::Arguments>,
+    redacted: Vec<usize>,
+}
+
+impl<'q, DB: Database> Forward<'q, DB> {
+    fn new<A>(sql: &'q str, arguments: Option<A>, redacted: Vec<usize>) -> Self
+    where
+        A: Into<<DB as HasArguments<'q>>::Arguments>,
+    {
+        Self {
+            sql,
+            arguments: arguments.map(Into::into),
+            redacted,
+        }
+    }
+}
+
+impl<'q, DB: Database> Execute<'q, DB> for Forward<'q, DB> {
+    #[inline]
+    fn sql(&self) -> &'q str {
+        self.sql
+    }
+
+    #[inline]
+    fn statement(&self) -> Option<&<DB as HasStatement<'q>>::Statement> {
+        None
+    }
+
+    #[inline]
+    fn take_arguments(&mut self) -> Option<<DB as HasArguments<'q>>::Arguments> {
+        self.arguments.take()
+    }
+
+    #[inline]
+    fn persistent(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn take_redacted_binds(&mut self) -> Vec<usize> {
+        std::mem::take(&mut self.redacted)
+    }
+}
+
 fn map_describe<DB: Database>(info: Describe<DB>) -> Describe<Any>
 where
     AnyTypeInfo: From<DB::TypeInfo>,
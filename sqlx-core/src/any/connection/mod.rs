@@ -1,7 +1,7 @@
 use futures_core::future::BoxFuture;
 
-use crate::any::{Any, AnyConnectOptions};
-use crate::connection::Connection;
+use crate::any::{Any, AnyConnectOptions, AnyKind};
+use crate::connection::{Connection, ConnectionStatistics, ServerVersion};
 use crate::error::Error;
 
 #[cfg(feature = "postgres")]
@@ -83,6 +83,39 @@ macro_rules! delegate_to_mut {
     };
 }
 
+impl AnyConnection {
+    /// The kind of database this is a connection to.
+    pub fn kind(&self) -> AnyKind {
+        match self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(_) => AnyKind::Postgres,
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(_) => AnyKind::MySql,
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(_) => AnyKind::Sqlite,
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(_) => AnyKind::Mssql,
+        }
+    }
+
+    /// Quote `ident` so it can be interpolated into SQL for this connection as an identifier.
+    ///
+    /// See [`AnyKind::quote_identifier`].
+    pub fn quote_identifier(&self, ident: &str) -> String {
+        self.kind().quote_identifier(ident)
+    }
+
+    /// Quote `value` so it can be interpolated into SQL for this connection as a string literal.
+    ///
+    /// See [`AnyKind::quote_literal`].
+    pub fn quote_literal(&self, value: &str) -> String {
+        self.kind().quote_literal(value)
+    }
+}
+
 impl Connection for AnyConnection {
     type Database = Any;
 
@@ -108,6 +141,10 @@ impl Connection for AnyConnection {
         delegate_to_mut!(self.ping())
     }
 
+    fn is_in_transaction(&self) -> bool {
+        delegate_to!(self.is_in_transaction())
+    }
+
     fn begin(&mut self) -> BoxFuture<'_, Result<Transaction<'_, Self::Database>, Error>>
     where
         Self: Sized,
@@ -149,6 +186,14 @@ impl Connection for AnyConnection {
         }
     }
 
+    fn statistics(&self) -> ConnectionStatistics {
+        delegate_to!(self.statistics())
+    }
+
+    fn server_version(&self) -> Option<&ServerVersion> {
+        delegate_to!(self.server_version())
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         delegate_to_mut!(self.flush())
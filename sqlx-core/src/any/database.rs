@@ -23,6 +23,11 @@ impl Database for Any {
     type TypeInfo = AnyTypeInfo;
 
     type Value = AnyValue;
+
+    const NAME: &'static str = "other_sql";
+
+    // the lowest limit of the supported databases, as the database is only known at runtime
+    const MAX_BIND_PARAMETERS: usize = 2100;
 }
 
 impl<'r> HasValueRef<'r> for Any {
@@ -16,8 +16,8 @@ use crate::sqlite::Sqlite;
 // Implements Encode for any T where T supports Encode for any database that has support currently
 // compiled into SQLx
 macro_rules! impl_any_encode {
-    ($ty:ty) => {
-        impl<'q> crate::encode::Encode<'q, crate::any::Any> for $ty
+    (<$($T:ident),*> $ty:ty) => {
+        impl<'q, $($T: 'q),*> crate::encode::Encode<'q, crate::any::Any> for $ty
         where
             $ty: crate::any::AnyEncode<'q>,
         {
@@ -46,6 +46,10 @@ macro_rules! impl_any_encode {
             }
         }
     };
+
+    ($ty:ty) => {
+        impl_any_encode!(<> $ty);
+    };
 }
 
 // FIXME: Find a nice way to auto-generate the below or petition Rust to add support for #[cfg]
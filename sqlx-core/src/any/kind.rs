@@ -1,7 +1,8 @@
+use crate::common::redact_url;
 use crate::error::Error;
 use std::str::FromStr;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AnyKind {
     #[cfg(feature = "postgres")]
     Postgres,
@@ -16,6 +17,46 @@ pub enum AnyKind {
     Mssql,
 }
 
+impl AnyKind {
+    /// Quote `ident` so it can be interpolated into SQL for this database as an identifier.
+    ///
+    /// Each database has a `quote_identifier` function of its own, which this calls.
+    pub fn quote_identifier(self, ident: &str) -> String {
+        match self {
+            #[cfg(feature = "postgres")]
+            AnyKind::Postgres => crate::postgres::Postgres::quote_identifier(ident),
+
+            #[cfg(feature = "mysql")]
+            AnyKind::MySql => crate::mysql::MySql::quote_identifier(ident),
+
+            #[cfg(feature = "sqlite")]
+            AnyKind::Sqlite => crate::sqlite::Sqlite::quote_identifier(ident),
+
+            #[cfg(feature = "mssql")]
+            AnyKind::Mssql => crate::mssql::Mssql::quote_identifier(ident),
+        }
+    }
+
+    /// Quote `value` so it can be interpolated into SQL for this database as a string literal.
+    ///
+    /// Each database has a `quote_literal` function of its own, which this calls.
+    pub fn quote_literal(self, value: &str) -> String {
+        match self {
+            #[cfg(feature = "postgres")]
+            AnyKind::Postgres => crate::postgres::Postgres::quote_literal(value),
+
+            #[cfg(feature = "mysql")]
+            AnyKind::MySql => crate::mysql::MySql::quote_literal(value),
+
+            #[cfg(feature = "sqlite")]
+            AnyKind::Sqlite => crate::sqlite::Sqlite::quote_literal(value),
+
+            #[cfg(feature = "mssql")]
+            AnyKind::Mssql => crate::mssql::Mssql::quote_literal(value),
+        }
+    }
+}
+
 impl FromStr for AnyKind {
     type Err = Error;
 
@@ -61,7 +102,17 @@ impl FromStr for AnyKind {
                 Err(Error::Configuration("database URL has the scheme of a MSSQL database but the `mssql` feature is not enabled".into()))
             }
 
-            _ => Err(Error::Configuration(format!("unrecognized database url: {:?}", uri).into()))
+            _ => Err(Error::Configuration(format!("unrecognized database url: {:?}", redact_url(uri)).into()))
         }
     }
 }
+
+#[cfg(all(feature = "postgres", feature = "mysql"))]
+#[test]
+fn it_quotes_for_the_kind_of_database() {
+    assert_eq!(AnyKind::Postgres.quote_identifier("users"), r#""users""#);
+    assert_eq!(AnyKind::MySql.quote_identifier("users"), "`users`");
+
+    assert_eq!(AnyKind::Postgres.quote_literal(r"a\'b"), r"E'a\\''b'");
+    assert_eq!(AnyKind::MySql.quote_literal(r"a\'b"), r"'a\\''b'");
+}
@@ -61,6 +61,24 @@ impl MigrateDatabase for Any {
             }
         })
     }
+
+    fn force_drop_database(uri: &str) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            match AnyKind::from_str(uri)? {
+                #[cfg(feature = "postgres")]
+                AnyKind::Postgres => crate::postgres::Postgres::force_drop_database(uri).await,
+
+                #[cfg(feature = "sqlite")]
+                AnyKind::Sqlite => crate::sqlite::Sqlite::force_drop_database(uri).await,
+
+                #[cfg(feature = "mysql")]
+                AnyKind::MySql => crate::mysql::MySql::force_drop_database(uri).await,
+
+                #[cfg(feature = "mssql")]
+                AnyKind::Mssql => unimplemented!(),
+            }
+        })
+    }
 }
 
 impl Migrate for AnyConnection {
@@ -171,4 +189,26 @@ impl Migrate for AnyConnection {
             }
         }
     }
+
+    fn revert<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.revert(migration),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn.revert(migration),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.revert(migration),
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(_conn) => {
+                let _ = migration;
+                unimplemented!()
+            }
+        }
+    }
 }
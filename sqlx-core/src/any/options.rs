@@ -172,4 +172,29 @@ impl ConnectOptions for AnyConnectOptions {
         };
         self
     }
+
+    fn log_binds(&mut self, enabled: bool) -> &mut Self {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectOptionsKind::Postgres(o) => {
+                o.log_binds(enabled);
+            }
+
+            #[cfg(feature = "mysql")]
+            AnyConnectOptionsKind::MySql(o) => {
+                o.log_binds(enabled);
+            }
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectOptionsKind::Sqlite(o) => {
+                o.log_binds(enabled);
+            }
+
+            #[cfg(feature = "mssql")]
+            AnyConnectOptionsKind::Mssql(o) => {
+                o.log_binds(enabled);
+            }
+        };
+        self
+    }
 }
@@ -43,6 +43,8 @@ impl_any_encode!(f64);
 impl_any_encode!(&'q str);
 impl_any_encode!(String);
 
+impl_any_encode!(<T> crate::types::Null<T>);
+
 // Decode
 
 impl_any_decode!(bool);
@@ -5,6 +5,30 @@ use crate::encode::Encode;
 use crate::types::Type;
 
 /// A tuple of arguments to be sent to the database.
+///
+/// Arguments can be accumulated one at a time for a query that is built at runtime, and then
+/// passed to [`query_with`](crate::query::query_with) or its siblings. The built-in drivers also
+/// implement [`ArgumentCount`] to number the placeholders as they go:
+///
+/// ```rust,ignore
+/// use sqlx::{ArgumentCount, Arguments};
+/// use sqlx::postgres::PgArguments;
+///
+/// let mut sql = String::from("SELECT * FROM users WHERE true");
+/// let mut arguments = PgArguments::default();
+///
+/// if let Some(name) = name {
+///     arguments.add(name);
+///     sql += &format!(" AND name = ${}", arguments.len());
+/// }
+///
+/// if let Some(min_age) = min_age {
+///     arguments.add(min_age);
+///     sql += &format!(" AND age >= ${}", arguments.len());
+/// }
+///
+/// let users = sqlx::query_with(&sql, arguments).fetch_all(&pool).await?;
+/// ```
 pub trait Arguments<'q>: Send + Sized + Default {
     type Database: Database;
 
@@ -16,6 +40,31 @@ pub trait Arguments<'q>: Send + Sized + Default {
     fn add<T>(&mut self, value: T)
     where
         T: 'q + Send + Encode<'q, Self::Database> + Type<Self::Database>;
+
+    /// Returns the encoded arguments as a key for caching query results, or `None` if the
+    /// arguments cannot be compared this way.
+    #[doc(hidden)]
+    fn cache_key(&self) -> Option<Vec<u8>> {
+        None
+    }
+
+    /// Returns each argument formatted for the statement log, or an empty list if the
+    /// arguments cannot be formatted.
+    #[doc(hidden)]
+    fn format_for_log(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// [`Arguments`] that know how many values have been added to them.
+pub trait ArgumentCount<'q>: Arguments<'q> {
+    /// Returns the number of arguments added so far.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if no arguments have been added.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 pub trait IntoArguments<'q, DB: HasArguments<'q>>: Sized + Send {
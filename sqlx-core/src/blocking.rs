@@ -0,0 +1,220 @@
+//! Synchronous wrappers around connections and pools.
+//!
+//! These run each operation to completion on an internal runtime, so command-line tools, build
+//! scripts, and migration runners can use SQLx without setting up an executor themselves.
+//!
+//! ```rust,ignore
+//! use sqlx::blocking::BlockingPool;
+//! use sqlx::postgres::Postgres;
+//!
+//! let pool = BlockingPool::<Postgres>::connect("postgres://localhost/app")?;
+//!
+//! pool.execute(sqlx::query("DELETE FROM sessions WHERE expires_at < now()"))?;
+//!
+//! let names: Vec<(String,)> = pool.run(|pool| {
+//!     sqlx::query_as("SELECT name FROM users").fetch_all(pool)
+//! })?;
+//! ```
+//!
+//! None of these may be called from within an asynchronous context; with Tokio this panics.
+//!
+//! With the `r2d2` feature enabled, [`r2d2::ConnectionManager`] lets an existing `r2d2` pool
+//! hand out [`BlockingConnection`]s.
+
+use std::future::Future;
+
+use crate::connection::Connection;
+use crate::database::Database;
+use crate::error::Error;
+use crate::executor::{Execute, Executor};
+use crate::pool::Pool;
+
+#[cfg(feature = "r2d2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "r2d2")))]
+pub mod r2d2;
+
+/// Run a future to completion on the internal runtime, blocking the current thread.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    sqlx_rt::block_on(future)
+}
+
+/// A single database connection with a synchronous interface.
+pub struct BlockingConnection<DB: Database> {
+    inner: DB::Connection,
+}
+
+impl<DB: Database> BlockingConnection<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+{
+    /// Establish a new database connection.
+    ///
+    /// See [`Connection::connect`].
+    pub fn connect(url: &str) -> Result<Self, Error> {
+        Ok(Self {
+            inner: block_on(DB::Connection::connect(url))?,
+        })
+    }
+
+    /// Wrap an already established connection.
+    pub fn from_connection(inner: DB::Connection) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the underlying asynchronous connection.
+    pub fn into_inner(self) -> DB::Connection {
+        self.inner
+    }
+
+    /// Run an asynchronous operation on the connection, blocking until it completes.
+    ///
+    /// This is the escape hatch for anything not covered by the other methods, such as
+    /// [`query_as`](crate::query_as::query_as) or transactions.
+    pub fn run<'c, F, Fut>(&'c mut self, f: F) -> Fut::Output
+    where
+        F: FnOnce(&'c mut DB::Connection) -> Fut,
+        Fut: Future,
+    {
+        block_on(f(&mut self.inner))
+    }
+
+    /// Execute the query and return the total number of rows affected.
+    pub fn execute<'q, E>(&mut self, query: E) -> Result<DB::Done, Error>
+    where
+        E: 'q + Execute<'q, DB>,
+    {
+        block_on(self.inner.execute(query))
+    }
+
+    /// Execute the query and return all the generated results, collected into a [`Vec`].
+    pub fn fetch_all<'q, E>(&mut self, query: E) -> Result<Vec<DB::Row>, Error>
+    where
+        E: 'q + Execute<'q, DB>,
+    {
+        block_on(self.inner.fetch_all(query))
+    }
+
+    /// Execute the query and returns exactly one row.
+    pub fn fetch_one<'q, E>(&mut self, query: E) -> Result<DB::Row, Error>
+    where
+        E: 'q + Execute<'q, DB>,
+    {
+        block_on(self.inner.fetch_one(query))
+    }
+
+    /// Execute the query and returns at most one row.
+    pub fn fetch_optional<'q, E>(&mut self, query: E) -> Result<Option<DB::Row>, Error>
+    where
+        E: 'q + Execute<'q, DB>,
+    {
+        block_on(self.inner.fetch_optional(query))
+    }
+
+    /// Explicitly close this database connection.
+    ///
+    /// See [`Connection::close`].
+    pub fn close(self) -> Result<(), Error> {
+        block_on(self.inner.close())
+    }
+}
+
+/// A connection pool with a synchronous interface.
+///
+/// Cloning is cheap; clones refer to the same pool.
+pub struct BlockingPool<DB: Database> {
+    inner: Pool<DB>,
+}
+
+impl<DB: Database> BlockingPool<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+{
+    /// Creates a new connection pool with a default pool configuration and the given
+    /// connection URI; and, immediately establishes one connection.
+    ///
+    /// See [`Pool::connect`].
+    pub fn connect(url: &str) -> Result<Self, Error> {
+        Ok(Self {
+            inner: block_on(Pool::connect(url))?,
+        })
+    }
+
+    /// Wrap an existing pool, such as one built from [`PoolOptions`](crate::pool::PoolOptions).
+    ///
+    /// The pool should have been created by [`block_on`] so its background tasks run on the
+    /// internal runtime.
+    pub fn from_pool(inner: Pool<DB>) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the underlying asynchronous pool.
+    pub fn into_inner(self) -> Pool<DB> {
+        self.inner
+    }
+
+    /// Run an asynchronous operation against the pool, blocking until it completes.
+    pub fn run<'p, F, Fut>(&'p self, f: F) -> Fut::Output
+    where
+        F: FnOnce(&'p Pool<DB>) -> Fut,
+        Fut: Future,
+    {
+        block_on(f(&self.inner))
+    }
+
+    /// Retrieves a connection from the pool, wrapped for synchronous use.
+    ///
+    /// The connection is not returned to the pool when dropped; use [`Pool::acquire`] through
+    /// [`run`](BlockingPool::run) to borrow one instead.
+    pub fn detach(&self) -> Result<BlockingConnection<DB>, Error> {
+        let conn = block_on(self.inner.acquire())?;
+
+        Ok(BlockingConnection::from_connection(conn.release()))
+    }
+
+    /// Execute the query and return the total number of rows affected.
+    pub fn execute<'q, E>(&self, query: E) -> Result<DB::Done, Error>
+    where
+        E: 'q + Execute<'q, DB>,
+    {
+        block_on(self.inner.execute(query))
+    }
+
+    /// Execute the query and return all the generated results, collected into a [`Vec`].
+    pub fn fetch_all<'q, E>(&self, query: E) -> Result<Vec<DB::Row>, Error>
+    where
+        E: 'q + Execute<'q, DB>,
+    {
+        block_on(self.inner.fetch_all(query))
+    }
+
+    /// Execute the query and returns exactly one row.
+    pub fn fetch_one<'q, E>(&self, query: E) -> Result<DB::Row, Error>
+    where
+        E: 'q + Execute<'q, DB>,
+    {
+        block_on(self.inner.fetch_one(query))
+    }
+
+    /// Execute the query and returns at most one row.
+    pub fn fetch_optional<'q, E>(&self, query: E) -> Result<Option<DB::Row>, Error>
+    where
+        E: 'q + Execute<'q, DB>,
+    {
+        block_on(self.inner.fetch_optional(query))
+    }
+
+    /// Close the pool, waiting until all connections are closed.
+    ///
+    /// See [`Pool::close`].
+    pub fn close(&self) {
+        block_on(self.inner.close())
+    }
+}
+
+impl<DB: Database> Clone for BlockingPool<DB> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
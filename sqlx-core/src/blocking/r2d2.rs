@@ -0,0 +1,81 @@
+use std::fmt::{self, Debug, Formatter};
+
+use super::{block_on, BlockingConnection};
+use crate::connection::{ConnectOptions, Connection};
+use crate::database::Database;
+use crate::error::Error;
+
+pub use r2d2_ as r2d2;
+
+/// An [`r2d2::ManageConnection`] that opens [`BlockingConnection`]s.
+///
+/// This lets code that already manages its connections with `r2d2` move over to SQLx without
+/// changing how its pools are configured:
+///
+/// ```rust,ignore
+/// use sqlx::blocking::r2d2::{r2d2, ConnectionManager};
+/// use sqlx::postgres::Postgres;
+///
+/// let manager = ConnectionManager::<Postgres>::from_url("postgres://localhost/app")?;
+///
+/// let pool = r2d2::Pool::builder()
+///     .max_size(8)
+///     .connection_timeout(std::time::Duration::from_secs(5))
+///     .build(manager)?;
+///
+/// let mut conn = pool.get()?;
+///
+/// conn.execute("DELETE FROM sessions WHERE expires_at < now()")?;
+/// ```
+///
+/// Connections are opened and checked on the internal runtime, like the rest of
+/// [`blocking`](crate::blocking).
+pub struct ConnectionManager<DB: Database> {
+    options: <DB::Connection as Connection>::Options,
+}
+
+impl<DB: Database> ConnectionManager<DB> {
+    /// Create a manager that opens connections with the given options.
+    pub fn new(options: <DB::Connection as Connection>::Options) -> Self {
+        Self { options }
+    }
+
+    /// Create a manager that opens connections to the given URL.
+    pub fn from_url(url: &str) -> Result<Self, Error> {
+        Ok(Self::new(url.parse()?))
+    }
+
+    /// Returns the options used to open new connections.
+    pub fn options(&self) -> &<DB::Connection as Connection>::Options {
+        &self.options
+    }
+}
+
+impl<DB: Database> Debug for ConnectionManager<DB> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectionManager")
+            .field("options", &self.options)
+            .finish()
+    }
+}
+
+impl<DB: Database> r2d2::ManageConnection for ConnectionManager<DB> {
+    type Connection = BlockingConnection<DB>;
+    type Error = Error;
+
+    fn connect(&self) -> Result<Self::Connection, Self::Error> {
+        let inner = block_on(self.options.connect())?;
+
+        Ok(BlockingConnection { inner })
+    }
+
+    fn is_valid(&self, conn: &mut Self::Connection) -> Result<(), Self::Error> {
+        block_on(conn.inner.ping())
+    }
+
+    fn has_broken(&self, _conn: &mut Self::Connection) -> bool {
+        // there is no way to tell without a round-trip; `is_valid` catches broken connections
+        // when they are next checked out
+        false
+    }
+}
@@ -1,5 +1,7 @@
 use crate::database::Database;
-use crate::error::Error;
+use crate::error::{mismatched_types, Error};
+use crate::type_info::TypeInfo;
+use crate::types::Type;
 use std::fmt::Debug;
 
 pub trait Column: private_column::Sealed + 'static + Send + Sync + Debug {
@@ -19,6 +21,36 @@ pub trait Column: private_column::Sealed + 'static + Send + Sync + Debug {
 
     /// Gets the type information for the column.
     fn type_info(&self) -> &<Self::Database as Database>::TypeInfo;
+
+    /// Checks that values of this column can be decoded as `T`, returning the same error as
+    /// [`Row::try_get`](crate::row::Row::try_get) if they cannot.
+    ///
+    /// Together with [`Executor::prepare`](crate::executor::Executor::prepare), this validates
+    /// the columns of a query against the Rust types they will be decoded into before any rows
+    /// are fetched.
+    ///
+    /// ```rust,ignore
+    /// let statement = conn.prepare("SELECT id, name FROM users").await?;
+    ///
+    /// statement.column(0).check_type::<i64>()?;
+    /// statement.column(1).check_type::<String>()?;
+    /// ```
+    fn check_type<T>(&self) -> Result<(), Error>
+    where
+        T: Type<Self::Database>,
+    {
+        let ty = self.type_info();
+
+        // the type of an expression is not always known before the query is executed
+        if ty.is_null() || T::compatible(ty) {
+            return Ok(());
+        }
+
+        Err(Error::ColumnDecode {
+            index: format!("{:?}", self.name()),
+            source: mismatched_types::<Self::Database, T>(ty),
+        })
+    }
 }
 
 // Prevent users from implementing the `Row` trait.
@@ -0,0 +1,159 @@
+//! Tag outgoing SQL with structured comments.
+//!
+//! Once a commenter is installed, SQL has a comment in the [sqlcommenter] format appended
+//! before it is sent to the server, preceded by the [`StatementId`] of the statement in a
+//! `/* sqlx:id=<hex> */` comment. The tags and the id then show up in slow query logs,
+//! `pg_stat_activity`, `pg_stat_statements`, and the MySQL process list, where they can be
+//! correlated with application traces and the `sqlx::query` logs and spans.
+//!
+//! Without a commenter, or when it returns no tags, the SQL is sent unchanged.
+//!
+//! ```rust,ignore
+//! sqlx::comment::set_commenter(|| {
+//!     vec![
+//!         ("application".to_owned(), "billing".to_owned()),
+//!         ("route".to_owned(), "/invoices/:id".to_owned()),
+//!     ]
+//! });
+//!
+//! // sent as: SELECT * FROM invoices WHERE id = $1 /* sqlx:id=... */
+//! //          /*application='billing',route='%2Finvoices%2F%3Aid'*/
+//! sqlx::query("SELECT * FROM invoices WHERE id = $1").bind(id).fetch_one(&pool).await?;
+//! ```
+//!
+//! Prepared statements are cached by their SQL text without the comment, so commenting does not
+//! stop them from being reused. Such a statement carries the tags of the execution that
+//! prepared it; tags that change on every execution, such as a trace id, are only accurate for
+//! statements that are not cached, e.g. with `.persistent(false)`.
+//!
+//! Statements that already contain a comment are never touched, as the specification requires.
+//!
+//! For SQLite, the comments are visible to `sqlite3_sql()` and trace callbacks. For MSSQL, only
+//! queries without arguments are commented: queries with arguments are run with
+//! `sp_executesql`, and SQL Server caches their plans by the exact text.
+//!
+//! [sqlcommenter]: https://google.github.io/sqlcommenter/spec/
+
+use std::borrow::Cow;
+
+use once_cell::sync::OnceCell;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+use crate::statement::StatementId;
+
+type Commenter = Box<dyn Fn() -> Vec<(String, String)> + Send + Sync>;
+
+static COMMENTER: OnceCell<Commenter> = OnceCell::new();
+
+// the unreserved characters of RFC 3986 are left as-is
+const ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Install the process-wide commenter.
+///
+/// The closure is called for every statement and returns the key-value pairs to attach, which
+/// are sent along with the id of the statement; returning an empty list leaves the statement
+/// untouched. A commenter can only be installed once; returns `false` if one was already set.
+pub fn set_commenter<F>(commenter: F) -> bool
+where
+    F: Fn() -> Vec<(String, String)> + Send + Sync + 'static,
+{
+    COMMENTER.set(Box::new(commenter)).is_ok()
+}
+
+pub(crate) fn apply(sql: &str) -> Cow<'_, str> {
+    match COMMENTER.get() {
+        Some(commenter) => append(sql, StatementId::of(sql), commenter()),
+        None => Cow::Borrowed(sql),
+    }
+}
+
+fn append(sql: &str, id: StatementId, mut tags: Vec<(String, String)>) -> Cow<'_, str> {
+    // the specification forbids touching a statement that already carries a comment
+    if tags.is_empty() || sql.contains("/*") {
+        return Cow::Borrowed(sql);
+    }
+
+    tags.sort();
+
+    let sql = sql.trim_end();
+
+    // a comment after a trailing `--` comment would be part of it, so it goes on a new line
+    // instead; the `;` may be part of the line comment as well and is left where it is
+    let ends_in_line_comment = sql
+        .rsplit('\n')
+        .next()
+        .map_or(false, |line| line.contains("--"));
+
+    let (body, terminator) = match sql.strip_suffix(';') {
+        Some(body) if !ends_in_line_comment => (body.trim_end(), ";"),
+        _ => (sql, ""),
+    };
+
+    let mut commented = String::with_capacity(body.len() + 64);
+
+    commented.push_str(body);
+    commented.push(if ends_in_line_comment { '\n' } else { ' ' });
+    commented.push_str("/* sqlx:id=");
+    commented.push_str(&id.to_string());
+    commented.push_str(" */ /*");
+
+    for (index, (key, value)) in tags.iter().enumerate() {
+        if index > 0 {
+            commented.push(',');
+        }
+
+        commented.extend(utf8_percent_encode(key, ENCODE_SET));
+        commented.push_str("='");
+        commented.extend(utf8_percent_encode(value, ENCODE_SET));
+        commented.push('\'');
+    }
+
+    commented.push_str("*/");
+    commented.push_str(terminator);
+
+    Cow::Owned(commented)
+}
+
+#[test]
+fn it_appends_the_id_and_sorted_encoded_tags() {
+    let tags = vec![
+        ("route".to_owned(), "/users/:id".to_owned()),
+        ("application".to_owned(), "it's".to_owned()),
+    ];
+
+    assert_eq!(
+        append("SELECT 1;", StatementId::of("SELECT 1;"), tags),
+        "SELECT 1 /* sqlx:id=72d121e7c77fa18b */ \
+         /*application='it%27s',route='%2Fusers%2F%3Aid'*/;"
+    );
+}
+
+#[test]
+fn it_leaves_commented_statements_untagged() {
+    let tags = vec![("application".to_owned(), "billing".to_owned())];
+    let id = StatementId::of("SELECT 1");
+
+    assert_eq!(
+        append("SELECT 1 /* hint */", id, tags),
+        "SELECT 1 /* hint */"
+    );
+    assert_eq!(append("SELECT 1", id, Vec::new()), "SELECT 1");
+}
+
+#[test]
+fn it_puts_the_comment_after_a_line_comment_on_a_new_line() {
+    let tags = vec![("application".to_owned(), "billing".to_owned())];
+    let id = StatementId::of("SELECT 1 -- one;");
+
+    assert_eq!(
+        append("SELECT 1 -- one;", id, tags),
+        format!(
+            "SELECT 1 -- one;\n/* sqlx:id={} */ /*application='billing'*/",
+            id
+        )
+    );
+}
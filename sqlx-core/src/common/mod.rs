@@ -1,3 +1,7 @@
+mod password_provider;
+mod secret;
 mod statement_cache;
 
+pub(crate) use password_provider::PasswordProvider;
+pub(crate) use secret::{redact_url, Secret};
 pub(crate) use statement_cache::StatementCache;
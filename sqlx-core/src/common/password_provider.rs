@@ -0,0 +1,35 @@
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+
+use futures_core::future::BoxFuture;
+
+use crate::common::Secret;
+use crate::error::{BoxDynError, Error};
+
+/// A callback held in connect options that fetches the password for each new connection.
+#[derive(Clone)]
+pub(crate) struct PasswordProvider(
+    Arc<dyn Fn() -> BoxFuture<'static, Result<String, BoxDynError>> + Send + Sync>,
+);
+
+impl PasswordProvider {
+    pub(crate) fn new<F>(provider: F) -> Self
+    where
+        F: Fn() -> BoxFuture<'static, Result<String, BoxDynError>> + 'static + Send + Sync,
+    {
+        Self(Arc::new(provider))
+    }
+
+    pub(crate) async fn password(&self) -> Result<Secret, Error> {
+        (self.0)()
+            .await
+            .map(Secret::from)
+            .map_err(Error::Configuration)
+    }
+}
+
+impl Debug for PasswordProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("PasswordProvider")
+    }
+}
@@ -1,14 +1,25 @@
 use crate::database::{Database, HasStatementCache};
 use crate::error::Error;
+use crate::statement::StatementId;
 use crate::transaction::Transaction;
 use futures_core::future::BoxFuture;
 use futures_core::Future;
 use log::LevelFilter;
-use std::fmt::Debug;
+use std::fmt::{self, Debug, Display, Formatter};
 use std::str::FromStr;
 use std::time::Duration;
 
 /// Represents a single database connection.
+///
+/// A connection runs one operation at a time: every operation borrows it mutably, so two
+/// queries can never be in flight on the same connection at once. Use a
+/// [`Pool`](crate::pool::Pool) to run queries concurrently.
+///
+/// An operation may be cancelled by dropping its future (e.g. with a timeout). Any response
+/// still owed by the server is read and discarded before the next operation starts. The one
+/// exception is a cancellation in the middle of sending a message to the server; the
+/// connection is then out of sync and every later operation on it returns an error instead
+/// (a pool closes such a connection when it is released).
 pub trait Connection: Send {
     type Database: Database;
 
@@ -22,8 +33,22 @@ pub trait Connection: Send {
     fn close(self) -> BoxFuture<'static, Result<(), Error>>;
 
     /// Checks if a connection to the database is still valid.
+    ///
+    /// This is a single round trip that does not run a query, which makes it suitable for
+    /// health checks. It is what the pool uses for
+    /// [`test_before_acquire`](crate::pool::PoolOptions::test_before_acquire).
     fn ping(&mut self) -> BoxFuture<'_, Result<(), Error>>;
 
+    /// Returns `true` if the connection is inside a transaction, whether it was started with
+    /// [`begin`](Connection::begin) or by executing `BEGIN` directly.
+    ///
+    /// This is the status most recently reported by the database, so it does not yet reflect
+    /// statements that are still being executed. Returns `false` for drivers that do not
+    /// track the transaction status.
+    fn is_in_transaction(&self) -> bool {
+        false
+    }
+
     /// Begin a new transaction or establish a savepoint within the active transaction.
     ///
     /// Returns a [`Transaction`] for controlling and tracking the new transaction.
@@ -81,6 +106,20 @@ pub trait Connection: Send {
         Box::pin(async move { Ok(()) })
     }
 
+    /// Counters tracked over the lifetime of this connection.
+    ///
+    /// Useful to check how effective the statement cache is for a given workload.
+    fn statistics(&self) -> ConnectionStatistics {
+        ConnectionStatistics::default()
+    }
+
+    /// The version of the database server, as reported when the connection was established.
+    ///
+    /// Returns `None` if the server did not report a version.
+    fn server_version(&self) -> Option<&ServerVersion> {
+        None
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>>;
 
@@ -106,7 +145,134 @@ pub trait Connection: Send {
     where
         Self: Sized,
     {
-        options.connect()
+        let connect = options.connect();
+
+        #[cfg(feature = "tracing")]
+        {
+            Box::pin(tracing::Instrument::instrument(
+                connect,
+                tracing::info_span!(
+                    target: "sqlx::connect",
+                    "sqlx::connect",
+                    db.system = <Self::Database as Database>::NAME,
+                ),
+            ))
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        {
+            connect
+        }
+    }
+}
+
+/// Counters tracked over the lifetime of a connection.
+///
+/// Returned by [`Connection::statistics`]. Counters that do not apply to a driver, such as
+/// the bytes transferred by SQLite, remain at zero.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ConnectionStatistics {
+    /// The number of statements sent for execution.
+    pub statements_executed: u64,
+
+    /// The number of rows returned by the database.
+    pub rows_fetched: u64,
+
+    /// The number of bytes written to the socket.
+    pub bytes_sent: u64,
+
+    /// The number of bytes read from the socket.
+    pub bytes_received: u64,
+
+    /// The number of statements that were found in the statement cache.
+    pub statement_cache_hits: u64,
+
+    /// The number of statements that had to be prepared because they were not in the
+    /// statement cache.
+    pub statement_cache_misses: u64,
+
+    /// The [id](crate::statement::StatementId) of the statement most recently sent for
+    /// execution.
+    pub last_statement_id: Option<StatementId>,
+}
+
+/// The version of a database server.
+///
+/// Returned by [`Connection::server_version`]. Useful to only use SQL that the server supports.
+///
+/// ```rust,ignore
+/// let returning = match conn.server_version() {
+///     Some(version) if version.flavor == ServerFlavor::MariaDb => version.at_least(10, 5),
+///     _ => false,
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ServerVersion {
+    /// The database the server implements.
+    pub flavor: ServerFlavor,
+
+    /// The major version, e.g. `13` for PostgreSQL 13.2.
+    pub major: u32,
+
+    /// The minor version, e.g. `2` for PostgreSQL 13.2.
+    pub minor: u32,
+
+    /// The patch version; zero if the server does not use one.
+    pub patch: u32,
+
+    /// The version exactly as reported by the server, which can include details such as the
+    /// platform or a pre-release tag.
+    pub raw: String,
+}
+
+/// The database implemented by a server.
+///
+/// Servers that speak the protocol of another database, such as CockroachDB, are told apart
+/// so that their differences in SQL can be accounted for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServerFlavor {
+    Postgres,
+    CockroachDb,
+    MySql,
+    MariaDb,
+    Sqlite,
+    Mssql,
+}
+
+impl ServerVersion {
+    /// Parses the leading `MAJOR.MINOR.PATCH` of `raw`; missing or unparsable components are
+    /// taken to be zero.
+    pub(crate) fn parse(flavor: ServerFlavor, raw: &str) -> Self {
+        let version = raw.trim_start_matches('v');
+        let end = version
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or_else(|| version.len());
+
+        let mut parts = version[..end]
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0));
+
+        Self {
+            flavor,
+            major: parts.next().unwrap_or(0),
+            minor: parts.next().unwrap_or(0),
+            patch: parts.next().unwrap_or(0),
+            raw: raw.to_owned(),
+        }
+    }
+
+    /// Returns `true` if this version is `major.minor` or newer.
+    pub fn at_least(&self, major: u32, minor: u32) -> bool {
+        (self.major, self.minor) >= (major, minor)
+    }
+}
+
+impl Display for ServerVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
     }
 }
 
@@ -115,6 +281,7 @@ pub(crate) struct LogSettings {
     pub(crate) statements_level: LevelFilter,
     pub(crate) slow_statements_level: LevelFilter,
     pub(crate) slow_statements_duration: Duration,
+    pub(crate) binds: bool,
 }
 
 impl Default for LogSettings {
@@ -123,6 +290,7 @@ impl Default for LogSettings {
             statements_level: LevelFilter::Info,
             slow_statements_level: LevelFilter::Warn,
             slow_statements_duration: Duration::from_secs(1),
+            binds: false,
         }
     }
 }
@@ -135,6 +303,18 @@ impl LogSettings {
         self.slow_statements_level = level;
         self.slow_statements_duration = duration;
     }
+    pub(crate) fn log_binds(&mut self, enabled: bool) {
+        self.binds = enabled;
+    }
+
+    // bind values are only formatted if they were asked for and a statement could be logged
+    pub(crate) fn binds_enabled(&self) -> bool {
+        let enabled = |level: LevelFilter| {
+            level != LevelFilter::Off && level <= log::STATIC_MAX_LEVEL && level <= log::max_level()
+        };
+
+        self.binds && (enabled(self.statements_level) || enabled(self.slow_statements_level))
+    }
 }
 
 pub trait ConnectOptions: 'static + Send + Sync + FromStr<Err = Error> + Debug {
@@ -145,10 +325,49 @@ pub trait ConnectOptions: 'static + Send + Sync + FromStr<Err = Error> + Debug {
     where
         Self::Connection: Sized;
 
-    /// Log executed statements with the specified `level`
+    /// Log executed statements with the specified `level`.
+    ///
+    /// Each statement is logged to the `sqlx::query` target once it has finished, along with
+    /// the number of rows it returned, the number of rows it affected and how long it took.
+    /// Pass [`LevelFilter::Off`] to disable logging. Defaults to `Info`.
     fn log_statements(&mut self, level: LevelFilter) -> &mut Self;
 
     /// Log executed statements with a duration above the specified `duration`
     /// at the specified `level`.
+    ///
+    /// Defaults to `Warn` for statements that take longer than one second.
     fn log_slow_statements(&mut self, level: LevelFilter, duration: Duration) -> &mut Self;
+
+    /// Include the values bound to a statement when it is logged. Defaults to `false`.
+    ///
+    /// Bind values often hold personal data or secrets, so they are only logged when enabled
+    /// here. Values of common types, such as numbers and strings, are shown as they would be by
+    /// `Debug`; other values are only described by their SQL type. Values passed to
+    /// [`Query::bind_sensitive`](crate::query::Query::bind_sensitive) are always logged as
+    /// `[REDACTED]`.
+    ///
+    /// This has no effect on drivers that cannot log bind values.
+    fn log_binds(&mut self, enabled: bool) -> &mut Self {
+        let _ = enabled;
+        self
+    }
+}
+
+#[test]
+fn test_parse_server_version() {
+    let v = ServerVersion::parse(ServerFlavor::Postgres, "13.2 (Debian 13.2-1.pgdg100+1)");
+    assert_eq!((v.major, v.minor, v.patch), (13, 2, 0));
+
+    let v = ServerVersion::parse(ServerFlavor::Postgres, "14beta1");
+    assert_eq!((v.major, v.minor, v.patch), (14, 0, 0));
+
+    let v = ServerVersion::parse(ServerFlavor::MySql, "8.0.23-0ubuntu0.20.04.1");
+    assert_eq!((v.major, v.minor, v.patch), (8, 0, 23));
+
+    let v = ServerVersion::parse(ServerFlavor::CockroachDb, "v20.2.7");
+    assert_eq!((v.major, v.minor, v.patch), (20, 2, 7));
+
+    assert!(v.at_least(20, 2));
+    assert!(v.at_least(19, 9));
+    assert!(!v.at_least(20, 3));
 }
@@ -57,11 +57,13 @@
 //!
 
 use std::fmt::Debug;
+use std::slice::Chunks;
 
 use crate::arguments::Arguments;
 use crate::column::Column;
 use crate::connection::Connection;
 use crate::done::Done;
+use crate::error::Error;
 use crate::row::Row;
 use crate::statement::Statement;
 use crate::transaction::TransactionManager;
@@ -102,6 +104,70 @@ pub trait Database:
     /// The concrete type used to hold an owned copy of the not-yet-decoded value that was
     /// received from the database.
     type Value: Value<Database = Self> + 'static;
+
+    /// The identifier of this database in the OpenTelemetry semantic conventions (`db.system`),
+    /// e.g., `postgresql`.
+    ///
+    /// Defaults to `other_sql`, the identifier for databases the conventions do not list.
+    const NAME: &'static str = "other_sql";
+
+    /// The maximum number of bind parameters in a single statement.
+    ///
+    /// Defaults to 999, the lowest limit among commonly used databases.
+    const MAX_BIND_PARAMETERS: usize = 999;
+
+    /// Split `items` into the largest chunks that can each be written by one statement, given
+    /// the number of parameters bound for every item.
+    ///
+    /// ```rust,ignore
+    /// for chunk in MySql::bind_chunks(&users, 2)? {
+    ///     let values = vec!["(?, ?)"; chunk.len()].join(", ");
+    ///     let sql = format!("INSERT INTO users (id, name) VALUES {}", values);
+    ///
+    ///     let mut query = sqlx::query(&sql);
+    ///
+    ///     for user in chunk {
+    ///         query = query.bind(user.id).bind(&user.name);
+    ///     }
+    ///
+    ///     query.execute(&mut conn).await?;
+    /// }
+    /// ```
+    ///
+    /// Returns [`Error::Encode`] if `binds_per_item` is zero or greater than
+    /// [`MAX_BIND_PARAMETERS`](Database::MAX_BIND_PARAMETERS).
+    fn bind_chunks<T>(items: &[T], binds_per_item: usize) -> Result<Chunks<'_, T>, Error> {
+        if binds_per_item == 0 || binds_per_item > Self::MAX_BIND_PARAMETERS {
+            return Err(Error::Encode(
+                format!(
+                    "cannot bind {} parameters per item; the limit is {}",
+                    binds_per_item,
+                    Self::MAX_BIND_PARAMETERS
+                )
+                .into(),
+            ));
+        }
+
+        Ok(items.chunks(Self::MAX_BIND_PARAMETERS / binds_per_item))
+    }
+}
+
+// wrap `value` in `open` and `close`, doubling every `close` inside of it
+pub(crate) fn quote(value: &str, open: char, close: char) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+
+    quoted.push(open);
+
+    for c in value.chars() {
+        if c == close {
+            quoted.push(c);
+        }
+
+        quoted.push(c);
+    }
+
+    quoted.push(close);
+    quoted
 }
 
 /// Associate [`Database`] with a [`ValueRef`](crate::value::ValueRef) of a generic lifetime.
@@ -1,5 +1,8 @@
 //! Provides [`Decode`](trait.Decode.html) for decoding values from the database.
 
+use std::borrow::Cow;
+use std::sync::Arc;
+
 use crate::database::{Database, HasValueRef};
 use crate::error::BoxDynError;
 use crate::value::ValueRef;
@@ -76,3 +79,49 @@ where
         }
     }
 }
+
+// strings can be decoded without copying into a `Cow`, or into a shared or boxed `str`
+impl<'r, DB> Decode<'r, DB> for Cow<'r, str>
+where
+    DB: Database,
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        <&'r str as Decode<'r, DB>>::decode(value).map(Cow::Borrowed)
+    }
+}
+
+impl<'r, DB> Decode<'r, DB> for Box<str>
+where
+    DB: Database,
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        <&'r str as Decode<'r, DB>>::decode(value).map(Box::from)
+    }
+}
+
+impl<'r, DB> Decode<'r, DB> for Arc<str>
+where
+    DB: Database,
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        <&'r str as Decode<'r, DB>>::decode(value).map(Arc::from)
+    }
+}
+
+// checks that a binary value is exactly as wide as the type it is decoded into so that a
+// truncated or corrupt value is returned as an error instead of panicking in `byteorder`
+pub(crate) fn check_width(buf: &[u8], width: usize) -> Result<&[u8], BoxDynError> {
+    if buf.len() == width {
+        Ok(buf)
+    } else {
+        Err(format!(
+            "expected a value of {} bytes but received {} bytes",
+            width,
+            buf.len()
+        )
+        .into())
+    }
+}
@@ -8,6 +8,9 @@ use std::convert::identity;
 ///
 /// The query macros (e.g., `query!`, `query_as!`, etc.) use the information here to validate
 /// output and parameter types; and, generate an anonymous record.
+///
+/// How much is known depends on the database. Nullability in particular is inferred on
+/// a best-effort basis and is `None` wherever the driver could not determine it.
 #[derive(Debug)]
 #[cfg_attr(feature = "offline", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
@@ -17,7 +20,6 @@ use std::convert::identity;
         deserialize = "DB::TypeInfo: serde::de::DeserializeOwned, DB::Column: serde::de::DeserializeOwned",
     ))
 )]
-#[doc(hidden)]
 pub struct Describe<DB: Database> {
     pub(crate) columns: Vec<DB::Column>,
     pub(crate) parameters: Option<Either<Vec<DB::TypeInfo>, usize>>,
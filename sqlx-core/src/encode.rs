@@ -117,3 +117,28 @@ macro_rules! impl_encode_for_option {
         }
     };
 }
+
+// `Null<T>` is always a `NULL` of the type of `T`; not for `Any`, where the `NULL` must still
+// be added to the arguments of the database in use
+#[allow(unused_macros)]
+macro_rules! impl_encode_for_null {
+    ($DB:ident) => {
+        impl<'q, T> crate::encode::Encode<'q, $DB> for crate::types::Null<T>
+        where
+            T: crate::types::Type<$DB>,
+        {
+            #[inline]
+            fn produces(&self) -> Option<<$DB as crate::database::Database>::TypeInfo> {
+                Some(T::type_info())
+            }
+
+            #[inline]
+            fn encode_by_ref(
+                &self,
+                _buf: &mut <$DB as crate::database::HasArguments<'q>>::ArgumentBuffer,
+            ) -> crate::encode::IsNull {
+                crate::encode::IsNull::Yes
+            }
+        }
+    };
+}
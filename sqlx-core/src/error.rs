@@ -78,6 +78,10 @@ pub enum Error {
     #[error("error occurred while decoding: {0}")]
     Decode(#[source] BoxDynError),
 
+    /// Error occurred while encoding a value.
+    #[error("error occurred while encoding a value: {0}")]
+    Encode(#[source] BoxDynError),
+
     /// A [`Pool::acquire`] timed out due to connections not becoming available or
     /// because another task encountered too many errors while trying to open a new connection.
     ///
@@ -21,7 +21,27 @@ use std::fmt::Debug;
 ///  * [`&Pool`](super::pool::Pool)
 ///  * [`&mut PoolConnection`](super::pool::PoolConnection)
 ///  * [`&mut Connection`](super::connection::Connection)
+///  * [`&mut Transaction`](super::transaction::Transaction)
 ///
+/// A function that runs a single query can take any of them:
+///
+/// ```rust,ignore
+/// async fn count_users<'c, E>(executor: E) -> sqlx::Result<i64>
+/// where
+///     E: Executor<'c, Database = Postgres>,
+/// {
+///     sqlx::query_scalar("SELECT count(*) FROM users")
+///         .fetch_one(executor)
+///         .await
+/// }
+///
+/// count_users(&pool).await?;
+/// count_users(&mut conn).await?;
+/// count_users(&mut tx).await?;
+/// ```
+///
+/// An `Executor` is consumed by each query, so a function that runs several should take an
+/// [`Acquire`](crate::acquire::Acquire) instead.
 pub trait Executor<'c>: Send + Debug + Sized {
     type Database: Database;
 
@@ -34,7 +54,17 @@ pub trait Executor<'c>: Send + Debug + Sized {
         'c: 'e,
         E: Execute<'q, Self::Database>,
     {
-        self.execute_many(query).try_collect().boxed()
+        // filter [fetch_many] directly rather than going through [execute_many] so only the
+        // driver's stream and the returned future are allocated
+        self.fetch_many(query)
+            .try_filter_map(|step| async move {
+                Ok(match step {
+                    Either::Left(done) => Some(done),
+                    Either::Right(_) => None,
+                })
+            })
+            .try_collect()
+            .boxed()
     }
 
     /// Execute multiple queries and return the rows affected from each query, in a stream.
@@ -100,7 +130,15 @@ pub trait Executor<'c>: Send + Debug + Sized {
         'c: 'e,
         E: Execute<'q, Self::Database>,
     {
-        self.fetch(query).try_collect().boxed()
+        self.fetch_many(query)
+            .try_filter_map(|step| async move {
+                Ok(match step {
+                    Either::Left(_) => None,
+                    Either::Right(row) => Some(row),
+                })
+            })
+            .try_collect()
+            .boxed()
     }
 
     /// Execute the query and returns exactly one row.
@@ -162,11 +200,21 @@ pub trait Executor<'c>: Send + Debug + Sized {
         'c: 'e;
 
     /// Describe the SQL query and return type information about its parameters
-    /// and results.
+    /// and results, without executing it.
     ///
     /// This is used by compile-time verification in the query macros to
-    /// power their type inference.
-    #[doc(hidden)]
+    /// power their type inference. It is equally useful to tools that generate code from
+    /// SQL or inspect queries at runtime.
+    ///
+    /// ```rust,ignore
+    /// let describe = conn.describe("SELECT id, name FROM users WHERE id = $1").await?;
+    ///
+    /// for (i, column) in describe.columns().iter().enumerate() {
+    ///     let nullable = describe.nullable(i);
+    ///
+    ///     println!("{}: {} (nullable: {:?})", column.name(), column.type_info(), nullable);
+    /// }
+    /// ```
     fn describe<'e, 'q: 'e>(
         self,
         sql: &'q str,
@@ -198,6 +246,13 @@ pub trait Execute<'q, DB: Database>: Send + Sized {
 
     /// Returns `true` if the statement should be cached.
     fn persistent(&self) -> bool;
+
+    /// Returns the bind values to redact in the statement log, as positions counted back from
+    /// the last argument.
+    #[doc(hidden)]
+    fn take_redacted_binds(&mut self) -> Vec<usize> {
+        Vec::new()
+    }
 }
 
 // NOTE: `Execute` is explicitly not implemented for String and &String to make it slightly more
@@ -1,14 +1,19 @@
 #![allow(dead_code)]
 
 use std::io;
+use std::mem;
 use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::task::{Context, Poll};
 
 use bytes::BytesMut;
-use sqlx_rt::{AsyncRead, AsyncReadExt, AsyncWrite};
+use futures_util::ready;
+use sqlx_rt::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 
 use crate::error::Error;
 use crate::io::write_and_flush::WriteAndFlush;
 use crate::io::{decode::Decode, encode::Encode};
+use crate::runtime;
 use std::io::Cursor;
 
 pub struct BufStream<S>
@@ -23,6 +28,14 @@ where
 
     // we read into the read buffer using 100% safe code
     rbuf: BytesMut,
+
+    // total bytes handed to and taken from the underlying stream
+    pub(crate) bytes_written: u64,
+    pub(crate) bytes_read: u64,
+
+    // set when a flush is abandoned before the whole buffer reached the stream; the peer
+    // has then seen a truncated message and nothing more can be sent or received
+    write_interrupted: bool,
 }
 
 impl<S> BufStream<S>
@@ -34,9 +47,18 @@ where
             stream,
             wbuf: Vec::with_capacity(512),
             rbuf: BytesMut::with_capacity(4096),
+            bytes_written: 0,
+            bytes_read: 0,
+            write_interrupted: false,
         }
     }
 
+    /// Returns `true` if a previous flush was abandoned part-way and the stream can no
+    /// longer be used.
+    pub fn is_interrupted(&self) -> bool {
+        self.write_interrupted
+    }
+
     pub fn write<'en, T>(&mut self, value: T)
     where
         T: Encode<'en, ()>,
@@ -52,10 +74,55 @@ where
     }
 
     pub fn flush(&mut self) -> WriteAndFlush<'_, S> {
+        self.bytes_written += self.wbuf.len() as u64;
+
         WriteAndFlush {
             stream: &mut self.stream,
             buf: Cursor::new(&mut self.wbuf),
+            interrupted: &mut self.write_interrupted,
+        }
+    }
+
+    // writes the buffer out without holding a future across polls, for use from `poll_*`
+    // methods; unlike `flush`, only the written prefix of the buffer is removed
+    pub fn poll_flush_buffer(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while !self.wbuf.is_empty() {
+            let written = ready!(Pin::new(&mut self.stream).poll_write(cx, &self.wbuf))?;
+
+            if written == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+
+            self.wbuf.drain(..written);
+            self.bytes_written += written as u64;
         }
+
+        Pin::new(&mut self.stream).poll_flush(cx)
+    }
+
+    // hands the stream to a task of its own that writes out the buffer before the stream is
+    // dropped, leaving `placeholder` in its place; returns `false`, and leaves the stream alone,
+    // if no task can be spawned from here
+    pub fn flush_in_background(&mut self, placeholder: S) -> bool
+    where
+        S: Send + 'static,
+    {
+        if !runtime::can_spawn() {
+            return false;
+        }
+
+        let mut stream = mem::replace(&mut self.stream, placeholder);
+        let buf = mem::take(&mut self.wbuf);
+
+        self.bytes_written += buf.len() as u64;
+
+        runtime::spawn(async move {
+            if stream.write_all(&buf).await.is_ok() {
+                let _ = stream.flush().await;
+            }
+        });
+
+        true
     }
 
     pub async fn read<'de, T>(&mut self, cnt: usize) -> Result<T, Error>
@@ -73,17 +140,74 @@ where
     }
 
     pub async fn read_raw(&mut self, cnt: usize) -> Result<BytesMut, Error> {
-        read_raw_into(&mut self.stream, &mut self.rbuf, cnt).await?;
-        let buf = self.rbuf.split_to(cnt);
+        self.fill(cnt).await?;
 
-        Ok(buf)
+        Ok(self.rbuf.split_to(cnt))
+    }
+
+    // reads a frame made of a fixed-size header followed by a body whose length is
+    // determined from the header; nothing is consumed until the whole frame has arrived,
+    // so the future can be dropped at any point without losing our place in the stream
+    pub async fn read_frame<F>(
+        &mut self,
+        header_len: usize,
+        body_len: F,
+    ) -> Result<(BytesMut, BytesMut), Error>
+    where
+        F: FnOnce(&[u8]) -> Result<usize, Error>,
+    {
+        self.fill(header_len).await?;
+
+        let body_len = body_len(&self.rbuf[..header_len])?;
+
+        self.fill(header_len + body_len).await?;
+
+        let header = self.rbuf.split_to(header_len);
+        let body = self.rbuf.split_to(body_len);
+
+        Ok((header, body))
     }
 
     pub async fn read_raw_into(&mut self, buf: &mut BytesMut, cnt: usize) -> Result<(), Error> {
-        read_raw_into(&mut self.stream, buf, cnt).await
+        if self.write_interrupted {
+            return Err(interrupted());
+        }
+
+        read_raw_into(&mut self.stream, buf, cnt, &mut self.bytes_read).await
+    }
+
+    // reads from the stream until the read buffer holds at least `cnt` bytes; bytes
+    // received by a call that is dropped stay in the buffer for the next one
+    async fn fill(&mut self, cnt: usize) -> Result<(), Error> {
+        if self.write_interrupted {
+            return Err(interrupted());
+        }
+
+        if self.rbuf.len() < cnt {
+            let needed = cnt - self.rbuf.len();
+
+            read_raw_into(
+                &mut self.stream,
+                &mut self.rbuf,
+                needed,
+                &mut self.bytes_read,
+            )
+            .await?;
+        }
+
+        Ok(())
     }
 }
 
+pub(crate) fn interrupted() -> Error {
+    io::Error::new(
+        io::ErrorKind::Other,
+        "a previous message was only partially sent, most likely because the operation \
+         was cancelled; the connection is out of sync with the server and must be closed",
+    )
+    .into()
+}
+
 impl<S> Deref for BufStream<S>
 where
     S: AsyncRead + AsyncWrite + Unpin,
@@ -108,17 +232,22 @@ async fn read_raw_into<S: AsyncRead + Unpin>(
     stream: &mut S,
     buf: &mut BytesMut,
     cnt: usize,
+    bytes_read: &mut u64,
 ) -> Result<(), Error> {
     let offset = buf.len();
 
     // zero-fills the space in the read buffer
     buf.resize(offset + cnt, 0);
 
-    let mut read = offset;
-    while (offset + cnt) > read {
+    // if we are dropped before the read completes, the zeroes past what was
+    // actually received must not be mistaken for data
+    let mut buf = Filled { buf, len: offset };
+
+    while (offset + cnt) > buf.len {
         // read in bytes from the stream into the read buffer starting
         // from the offset we last read from
-        let n = stream.read(&mut buf[read..]).await?;
+        let read = buf.len;
+        let n = stream.read(&mut buf.buf[read..]).await?;
 
         if n == 0 {
             // a zero read when we had space in the read buffer
@@ -129,8 +258,21 @@ async fn read_raw_into<S: AsyncRead + Unpin>(
             return Err(io::Error::from(io::ErrorKind::ConnectionAborted).into());
         }
 
-        read += n;
+        buf.len += n;
+        *bytes_read += n as u64;
     }
 
     Ok(())
 }
+
+// truncates the buffer to the bytes that were filled when dropped
+struct Filled<'a> {
+    buf: &'a mut BytesMut,
+    len: usize,
+}
+
+impl Drop for Filled<'_> {
+    fn drop(&mut self) {
+        self.buf.truncate(self.len);
+    }
+}
@@ -3,10 +3,15 @@ mod buf_mut;
 mod buf_stream;
 mod decode;
 mod encode;
+mod recycle;
 mod write_and_flush;
 
+#[cfg(feature = "protocol-trace")]
+pub(crate) mod trace;
+
 pub use buf::BufExt;
 pub use buf_mut::BufMutExt;
 pub use buf_stream::BufStream;
 pub use decode::Decode;
 pub use encode::Encode;
+pub(crate) use recycle::{recycle_buffer, take_buffer};
@@ -0,0 +1,66 @@
+use std::cell::RefCell;
+
+use super::write_and_flush::MAX_RETAINED_CAPACITY;
+
+// the most buffers kept around on each thread; a task rarely has more than a few sets of
+// arguments alive at once
+const MAX_RETAINED_BUFFERS: usize = 8;
+
+thread_local! {
+    static BUFFERS: RefCell<Vec<Vec<u8>>> = RefCell::new(Vec::new());
+}
+
+/// Takes an empty buffer given back by a previous [`recycle_buffer`] on this thread, so that
+/// encoding the arguments of a query does not need a fresh allocation each time.
+pub(crate) fn take_buffer() -> Vec<u8> {
+    BUFFERS
+        .try_with(|buffers| buffers.borrow_mut().pop())
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// Gives back a buffer to be reused by [`take_buffer`]. Buffers that never allocated or that
+/// grew beyond what a connection keeps for its own writes are freed instead.
+pub(crate) fn recycle_buffer(mut buf: Vec<u8>) {
+    if buf.capacity() == 0 || buf.capacity() > MAX_RETAINED_CAPACITY {
+        return;
+    }
+
+    buf.clear();
+
+    // the thread local is gone if this runs while the thread is shutting down
+    let _ = BUFFERS.try_with(|buffers| {
+        let mut buffers = buffers.borrow_mut();
+
+        if buffers.len() < MAX_RETAINED_BUFFERS {
+            buffers.push(buf);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_reuses_a_recycled_buffer() {
+        let mut buf = take_buffer();
+        buf.extend_from_slice(b"hello");
+
+        let ptr = buf.as_ptr();
+        recycle_buffer(buf);
+
+        let buf = take_buffer();
+
+        assert!(buf.is_empty());
+        assert_eq!(buf.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn it_frees_a_buffer_over_the_retained_capacity() {
+        recycle_buffer(Vec::with_capacity(MAX_RETAINED_CAPACITY + 1));
+
+        assert!(take_buffer().capacity() <= MAX_RETAINED_CAPACITY);
+    }
+}
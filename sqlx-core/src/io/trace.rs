@@ -0,0 +1,52 @@
+use std::str::from_utf8;
+
+// shared by the drivers to log every message that crosses the wire when the `protocol-trace`
+// feature is enabled; each driver summarizes its own messages
+
+const TARGET: &str = "sqlx::protocol";
+
+// the longest SQL excerpt included in a trace line
+const MAX_EXCERPT: usize = 256;
+
+fn enabled() -> bool {
+    log::Level::Trace <= log::STATIC_MAX_LEVEL && log::Level::Trace <= log::max_level()
+}
+
+pub(crate) fn frontend(summarize: impl FnOnce() -> String) {
+    if enabled() {
+        log::trace!(target: TARGET, "-> {}", summarize());
+    }
+}
+
+pub(crate) fn backend(summarize: impl FnOnce() -> String) {
+    if enabled() {
+        log::trace!(target: TARGET, "<- {}", summarize());
+    }
+}
+
+// an excerpt of the SQL in `buf`, cut short at a character boundary
+pub(crate) fn sql(buf: &[u8]) -> String {
+    let text = from_utf8(buf).unwrap_or("<invalid utf-8>");
+
+    if text.len() > MAX_EXCERPT {
+        let mut cut = MAX_EXCERPT;
+
+        while !text.is_char_boundary(cut) {
+            cut -= 1;
+        }
+
+        format!("sql: {:?}...", &text[..cut])
+    } else {
+        format!("sql: {:?}", text)
+    }
+}
+
+#[test]
+fn it_cuts_long_sql_at_a_char_boundary() {
+    let sql = "é".repeat(MAX_EXCERPT);
+
+    assert_eq!(
+        self::sql(sql.as_bytes()),
+        format!("sql: {:?}...", "é".repeat(MAX_EXCERPT / 2))
+    );
+}
@@ -1,4 +1,5 @@
 use crate::error::Error;
+use crate::io::buf_stream::interrupted;
 use futures_core::Future;
 use futures_util::ready;
 use sqlx_rt::AsyncWrite;
@@ -8,18 +9,27 @@ use std::task::{Context, Poll};
 
 // Atomic operation that writes the full buffer to the stream, flushes the stream, and then
 // clears the buffer (even if either of the two previous operations failed).
+//
+// If it is dropped before the whole buffer was written, the peer has received a partial
+// message (or none at all) and every later operation on the stream fails instead.
 pub struct WriteAndFlush<'a, S> {
     pub(super) stream: &'a mut S,
     pub(super) buf: Cursor<&'a mut Vec<u8>>,
+    pub(super) interrupted: &'a mut bool,
 }
 
 impl<S: AsyncWrite + Unpin> Future for WriteAndFlush<'_, S> {
     type Output = Result<(), Error>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if *self.interrupted {
+            return Poll::Ready(Err(interrupted()));
+        }
+
         let Self {
             ref mut stream,
             ref mut buf,
+            ..
         } = *self;
 
         loop {
@@ -37,9 +47,23 @@ impl<S: AsyncWrite + Unpin> Future for WriteAndFlush<'_, S> {
     }
 }
 
+// writes larger than this (e.g. a bulk insert) do not keep their buffer
+// allocated for the rest of the life of the connection
+pub(super) const MAX_RETAINED_CAPACITY: usize = 64 * 1024;
+
 impl<'a, S> Drop for WriteAndFlush<'a, S> {
     fn drop(&mut self) {
+        if (self.buf.position() as usize) < self.buf.get_ref().len() {
+            *self.interrupted = true;
+        }
+
+        let buf = self.buf.get_mut();
+
         // clear the buffer regardless of whether the flush succeeded or not
-        self.buf.get_mut().clear();
+        buf.clear();
+
+        if buf.capacity() > MAX_RETAINED_CAPACITY {
+            buf.shrink_to_fit();
+        }
     }
 }
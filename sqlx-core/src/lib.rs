@@ -17,6 +17,9 @@
 #[cfg(feature = "bigdecimal")]
 extern crate bigdecimal_ as bigdecimal;
 
+#[cfg(feature = "gssapi")]
+extern crate libgssapi_ as libgssapi;
+
 #[macro_use]
 mod ext;
 
@@ -55,6 +58,11 @@ pub mod column;
 #[macro_use]
 pub mod statement;
 
+#[macro_use]
+mod logger;
+
+pub mod blocking;
+pub mod comment;
 mod common;
 pub mod database;
 pub mod describe;
@@ -62,11 +70,13 @@ pub mod done;
 pub mod executor;
 pub mod from_row;
 mod io;
-mod logger;
 mod net;
 pub mod query_as;
+pub mod query_cache;
 pub mod query_scalar;
+pub mod reconnect;
 pub mod row;
+pub mod runtime;
 pub mod type_info;
 pub mod value;
 
@@ -100,6 +110,10 @@ pub mod mysql;
 #[cfg_attr(docsrs, doc(cfg(feature = "mssql")))]
 pub mod mssql;
 
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
+
 /// sqlx uses ahash for increased performance, at the cost of reduced DoS resistance.
 use ahash::AHashMap as HashMap;
 //type HashMap<K, V> = std::collections::HashMap<K, V, ahash::RandomState>;
@@ -1,30 +1,77 @@
+use crate::arguments::Arguments;
 use crate::connection::LogSettings;
+use crate::database::Database;
+use crate::statement::StatementId;
+use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+use std::fmt::Debug;
 use std::time::Instant;
 
+// the longest bind value included in a log line
+const MAX_BIND_LEN: usize = 64;
+
+pub(crate) const REDACTED: &str = "[REDACTED]";
+
 pub(crate) struct QueryLogger<'q> {
     sql: &'q str,
-    rows: usize,
+    id: StatementId,
+    binds: Vec<String>,
+    rows_returned: u64,
+    rows_affected: u64,
     start: Instant,
     settings: LogSettings,
+
+    // the span the statement is executed in, entered by `instrument_query` each time its
+    // results are polled, until the last row has been received (or the stream is dropped)
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
 }
 
 impl<'q> QueryLogger<'q> {
-    pub(crate) fn new(sql: &'q str, settings: LogSettings) -> Self {
+    pub(crate) fn new<DB: Database>(
+        sql: &'q str,
+        binds: Vec<String>,
+        settings: LogSettings,
+    ) -> Self {
+        let id = StatementId::of(sql);
+
         Self {
             sql,
-            rows: 0,
+            id,
+            binds,
+            rows_returned: 0,
+            rows_affected: 0,
             start: Instant::now(),
             settings,
+
+            // the logger is only created while the results are polled inside `instrument_query`
+            #[cfg(feature = "tracing")]
+            span: tracing::Span::current(),
         }
     }
 
+    pub(crate) fn statement_id(&self) -> StatementId {
+        self.id
+    }
+
     pub(crate) fn increment_rows(&mut self) {
-        self.rows += 1;
+        self.rows_returned += 1;
+    }
+
+    pub(crate) fn increase_rows_affected(&mut self, n: u64) {
+        self.rows_affected += n;
     }
 
     pub(crate) fn finish(&self) {
         let elapsed = self.start.elapsed();
 
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("rows_returned", &self.rows_returned);
+            self.span.record("rows_affected", &self.rows_affected);
+            self.span.record("elapsed", &tracing::field::debug(elapsed));
+        }
+
         let lvl = if elapsed >= self.settings.slow_statements_duration {
             self.settings.slow_statements_level
         } else {
@@ -49,13 +96,23 @@ impl<'q> QueryLogger<'q> {
                     String::new()
                 };
 
-                let rows = self.rows;
+                let binds = if self.binds.is_empty() {
+                    String::new()
+                } else {
+                    format!(", binds: [{}]", self.binds.join(", "))
+                };
 
                 log::logger().log(
                     &log::Record::builder()
                         .args(format_args!(
-                            "{}; rows: {}, elapsed: {:.3?}{}",
-                            summary, rows, elapsed, sql
+                            "{}; rows returned: {}, rows affected: {}, elapsed: {:.3?}, id: {}{}{}",
+                            summary,
+                            self.rows_returned,
+                            self.rows_affected,
+                            elapsed,
+                            self.id,
+                            binds,
+                            sql
                         ))
                         .level(lvl)
                         .module_path_static(Some("sqlx::query"))
@@ -73,6 +130,190 @@ impl<'q> Drop for QueryLogger<'q> {
     }
 }
 
+// wraps the execution of a statement in a span when the `tracing` feature is enabled; the
+// span is entered each time the results are polled, so that the `QueryLogger` created while
+// polling records its totals into it
+pub(crate) fn instrument_query<'e, DB, T>(sql: &str, results: BoxStream<'e, T>) -> BoxStream<'e, T>
+where
+    DB: Database,
+    T: 'e,
+{
+    #[cfg(feature = "tracing")]
+    {
+        Box::pin(InstrumentedStream {
+            inner: results,
+            span: query_span::<DB>(sql),
+        })
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = sql;
+        results
+    }
+}
+
+// like `instrument_query`, for the executor methods that only return the first result
+pub(crate) fn instrument_query_one<'e, DB, T>(
+    sql: &str,
+    result: BoxFuture<'e, T>,
+) -> BoxFuture<'e, T>
+where
+    DB: Database,
+    T: 'e,
+{
+    #[cfg(feature = "tracing")]
+    {
+        Box::pin(tracing::Instrument::instrument(
+            result,
+            query_span::<DB>(sql),
+        ))
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = sql;
+        result
+    }
+}
+
+// https://github.com/open-telemetry/opentelemetry-specification/blob/master/specification/trace/semantic_conventions/database.md
+#[cfg(feature = "tracing")]
+fn query_span<DB: Database>(sql: &str) -> tracing::Span {
+    tracing::info_span!(
+        target: "sqlx::query",
+        "sqlx::query",
+        db.system = DB::NAME,
+        db.statement = sql,
+        db.statement_id = %StatementId::of(sql),
+        rows_returned = tracing::field::Empty,
+        rows_affected = tracing::field::Empty,
+        elapsed = tracing::field::Empty,
+    )
+}
+
+// `tracing::Instrument` only covers futures
+#[cfg(feature = "tracing")]
+struct InstrumentedStream<'e, T> {
+    inner: BoxStream<'e, T>,
+    span: tracing::Span,
+}
+
+#[cfg(feature = "tracing")]
+impl<T> futures_core::Stream for InstrumentedStream<'_, T> {
+    type Item = T;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<T>> {
+        let this = &mut *self;
+        let _entered = this.span.enter();
+
+        this.inner.as_mut().poll_next(cx)
+    }
+}
+
+// wraps the preparation of a statement in a span when the `tracing` feature is enabled
+pub(crate) fn instrument_prepare<'e, DB, T>(
+    sql: &str,
+    prepare: BoxFuture<'e, T>,
+) -> BoxFuture<'e, T>
+where
+    DB: Database,
+    T: 'e,
+{
+    #[cfg(feature = "tracing")]
+    {
+        Box::pin(tracing::Instrument::instrument(
+            prepare,
+            tracing::info_span!(
+                target: "sqlx::query",
+                "sqlx::prepare",
+                db.system = DB::NAME,
+                db.statement = sql,
+                db.statement_id = %StatementId::of(sql),
+            ),
+        ))
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = sql;
+        prepare
+    }
+}
+
+// formats the bind values of a statement for its log line, if the settings ask for them
+pub(crate) fn format_binds<'q, A>(
+    settings: &LogSettings,
+    arguments: Option<&A>,
+    redacted: &[usize],
+) -> Vec<String>
+where
+    A: Arguments<'q>,
+{
+    let arguments = match arguments {
+        Some(arguments) if settings.binds_enabled() => arguments,
+        _ => return Vec::new(),
+    };
+
+    let mut binds = arguments.format_for_log();
+
+    // positions are counted back from the last argument
+    for &position in redacted {
+        if let Some(bind) = binds.len().checked_sub(position + 1).map(|i| &mut binds[i]) {
+            *bind = REDACTED.to_owned();
+        }
+    }
+
+    binds
+}
+
+// formats a bind value, decoded back from the encoded arguments, by trying each of the given
+// types with the same SQL type name; any other value is only described by its SQL type
+macro_rules! format_bind_value {
+    ($DB:ty, $value:expr, [$($T:ty),* $(,)?]) => {{
+        use crate::type_info::TypeInfo as _;
+        use crate::value::ValueRef as _;
+
+        let value = $value;
+        let ty = value.type_info().into_owned();
+
+        if value.is_null() {
+            String::from("NULL")
+        }
+        $(
+            else if ty.name() == <$T as crate::types::Type<$DB>>::type_info().name() {
+                match <$T as crate::decode::Decode<'_, $DB>>::decode(value) {
+                    Ok(value) => crate::logger::format_bind(&value),
+                    Err(_) => format!("<{}>", ty.name()),
+                }
+            }
+        )*
+        else {
+            format!("<{}>", ty.name())
+        }
+    }};
+}
+
+pub(crate) fn format_bind(value: &dyn Debug) -> String {
+    let mut formatted = format!("{:?}", value);
+
+    if formatted.len() > MAX_BIND_LEN {
+        let mut cut = MAX_BIND_LEN;
+
+        while !formatted.is_char_boundary(cut) {
+            cut -= 1;
+        }
+
+        formatted.truncate(cut);
+        formatted.push('…');
+    }
+
+    formatted
+}
+
 fn parse_query_summary(sql: &str) -> String {
     // For now, just take the first 4 words
     sql.split_whitespace()
@@ -80,3 +321,13 @@ fn parse_query_summary(sql: &str) -> String {
         .collect::<Vec<&str>>()
         .join(" ")
 }
+
+#[test]
+fn it_truncates_long_binds() {
+    assert_eq!(format_bind(&"hello"), "\"hello\"");
+
+    let formatted = format_bind(&"x".repeat(100));
+
+    assert_eq!(formatted.chars().count(), MAX_BIND_LEN + 1);
+    assert!(formatted.ends_with('…'));
+}
@@ -15,6 +15,11 @@ pub enum MigrateError {
     #[error("migration {0} was previously applied but has been modified")]
     VersionMismatch(i64),
 
+    #[error(
+        "migration {0} was previously applied but cannot be reverted as it has no down migration"
+    )]
+    Irreversible(i64),
+
     // NOTE: this will only happen with a database that does not have transactional DDL (.e.g, MySQL or Oracle)
     #[error(
         "migration {0} is partially applied; fix and remove row from `_sqlx_migrations` table"
@@ -15,6 +15,12 @@ pub trait MigrateDatabase {
     // drop database in uri
     // uses a maintenance database depending on driver
     fn drop_database(uri: &str) -> BoxFuture<'_, Result<(), Error>>;
+
+    // drop database in uri, terminating any connections to it that are still open
+    // where the driver supports that; otherwise the same as `drop_database`
+    fn force_drop_database(uri: &str) -> BoxFuture<'_, Result<(), Error>> {
+        Self::drop_database(uri)
+    }
 }
 
 // 'e = Executor
@@ -50,4 +56,15 @@ pub trait Migrate {
         &'e mut self,
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>>;
+
+    // run the down SQL from a reversible migration in a DDL transaction
+    // delete the row of the reverted version from [_migrations] on completion
+    // returns the time taking to run the migration SQL
+    // drivers that cannot revert migrations report every migration as irreversible
+    fn revert<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
+        Box::pin(async move { Err(MigrateError::Irreversible(migration.version)) })
+    }
 }
@@ -0,0 +1,43 @@
+/// The kind of a [`Migration`](super::Migration), as determined by the suffix of its file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MigrationType {
+    /// A single `<VERSION>_<DESCRIPTION>.sql` file that cannot be reverted.
+    Simple,
+
+    /// The `<VERSION>_<DESCRIPTION>.up.sql` half of a reversible migration.
+    ReversibleUp,
+
+    /// The `<VERSION>_<DESCRIPTION>.down.sql` half of a reversible migration; undoes the
+    /// `.up.sql` file with the same version.
+    ReversibleDown,
+}
+
+impl MigrationType {
+    /// Determine the type of migration from the name of its file.
+    pub fn from_filename(filename: &str) -> Self {
+        if filename.ends_with(MigrationType::ReversibleUp.suffix()) {
+            MigrationType::ReversibleUp
+        } else if filename.ends_with(MigrationType::ReversibleDown.suffix()) {
+            MigrationType::ReversibleDown
+        } else {
+            MigrationType::Simple
+        }
+    }
+
+    pub fn is_reversible(&self) -> bool {
+        !matches!(self, MigrationType::Simple)
+    }
+
+    pub fn is_down_migration(&self) -> bool {
+        matches!(self, MigrationType::ReversibleDown)
+    }
+
+    /// The suffix of files containing this type of migration.
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            MigrationType::Simple => ".sql",
+            MigrationType::ReversibleUp => ".up.sql",
+            MigrationType::ReversibleDown => ".down.sql",
+        }
+    }
+}
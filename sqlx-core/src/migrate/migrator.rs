@@ -53,6 +53,19 @@ impl Migrator {
         // lock the database for exclusive access by the migrator
         conn.lock().await?;
 
+        let res = self.run_locked(&mut *conn).await;
+
+        // unlock the migrator to allow other migrators to run, even if we failed; a pooled
+        // connection would otherwise go back to the pool still holding the lock
+        let unlocked = conn.unlock().await;
+
+        res.and(unlocked)
+    }
+
+    async fn run_locked<C>(&self, conn: &mut C) -> Result<(), MigrateError>
+    where
+        C: Migrate + ?Sized,
+    {
         // creates [_migrations] table only if needed
         // eventually this will likely migrate previous versions of the table
         conn.ensure_migrations_table().await?;
@@ -64,6 +77,11 @@ impl Migrator {
         }
 
         for migration in self.iter() {
+            if migration.migration_type.is_down_migration() {
+                // down migrations are only ever run by `undo`
+                continue;
+            }
+
             if migration.version > version {
                 conn.apply(migration).await?;
             } else {
@@ -71,9 +89,76 @@ impl Migrator {
             }
         }
 
-        // unlock the migrator to allow other migrators to run
-        // but do nothing as we already migrated
-        conn.unlock().await?;
+        Ok(())
+    }
+
+    /// Revert applied migrations, newest first, until the database is at `target` version.
+    ///
+    /// Every applied migration is first validated against the current migration source and
+    /// nothing is reverted if one of them has been modified since it was applied. Only
+    /// reversible migrations (`<VERSION>_<DESCRIPTION>.up.sql` paired with
+    /// `<VERSION>_<DESCRIPTION>.down.sql`) can be reverted; if a migration newer than `target`
+    /// has no down migration, nothing is reverted and [`MigrateError::Irreversible`] is returned.
+    ///
+    /// Pass `0` as `target` to revert every migration.
+    pub async fn undo<'a, A>(&self, migrator: A, target: i64) -> Result<(), MigrateError>
+    where
+        A: Acquire<'a>,
+        <A::Connection as Deref>::Target: Migrate,
+    {
+        let mut conn = migrator.acquire().await?;
+
+        // lock the database for exclusive access by the migrator
+        conn.lock().await?;
+
+        let res = self.undo_locked(&mut *conn, target).await;
+
+        let unlocked = conn.unlock().await;
+
+        res.and(unlocked)
+    }
+
+    async fn undo_locked<C>(&self, conn: &mut C, target: i64) -> Result<(), MigrateError>
+    where
+        C: Migrate + ?Sized,
+    {
+        conn.ensure_migrations_table().await?;
+
+        let (version, dirty) = conn.version().await?.unwrap_or((0, false));
+
+        if dirty {
+            return Err(MigrateError::Dirty(version));
+        }
+
+        // refuse to revert anything if an applied migration no longer matches its source
+        for migration in self.iter() {
+            if !migration.migration_type.is_down_migration() && migration.version <= version {
+                conn.validate(migration).await?;
+            }
+        }
+
+        let applied = |m: &&Migration| m.version > target && m.version <= version;
+
+        for migration in self
+            .iter()
+            .filter(|m| !m.migration_type.is_down_migration())
+            .filter(applied)
+        {
+            if !self.iter().any(|down| {
+                down.version == migration.version && down.migration_type.is_down_migration()
+            }) {
+                return Err(MigrateError::Irreversible(migration.version));
+            }
+        }
+
+        for migration in self
+            .iter()
+            .rev()
+            .filter(|m| m.migration_type.is_down_migration())
+            .filter(applied)
+        {
+            conn.revert(migration).await?;
+        }
 
         Ok(())
     }
@@ -1,5 +1,5 @@
 use crate::error::BoxDynError;
-use crate::migrate::Migration;
+use crate::migrate::{Migration, MigrationType};
 use futures_core::future::BoxFuture;
 use futures_util::TryStreamExt;
 use sqlx_rt::fs;
@@ -35,23 +35,41 @@ impl<'s> MigrationSource<'s> for &'s Path {
 
                 let version: i64 = parts[0].parse()?;
 
-                // remove the `.sql` and replace `_` with ` `
+                let migration_type = MigrationType::from_filename(parts[1]);
+
+                // remove the `.sql` (or `.up.sql` / `.down.sql`) and replace `_` with ` `
                 let description = parts[1]
-                    .trim_end_matches(".sql")
+                    .trim_end_matches(migration_type.suffix())
                     .replace('_', " ")
                     .to_owned();
 
                 let sql = fs::read_to_string(&entry.path()).await?;
 
-                migrations.push(Migration::new(
+                migrations.push(Migration::with_type(
                     version,
                     Cow::Owned(description),
+                    migration_type,
                     Cow::Owned(sql),
                 ));
             }
 
-            // ensure that we are sorted by `VERSION ASC`
-            migrations.sort_by_key(|m| m.version);
+            // ensure that we are sorted by `VERSION ASC`, with a down migration after its up migration
+            migrations.sort_by_key(|m| (m.version, m.migration_type.is_down_migration()));
+
+            // two files with the same version would be applied in an unspecified order
+            // and only one of them could ever be recorded as applied
+            if let Some(pair) = migrations.windows(2).find(|pair| {
+                pair[0].version == pair[1].version
+                    && pair[0].migration_type.is_down_migration()
+                        == pair[1].migration_type.is_down_migration()
+            }) {
+                return Err(format!(
+                    "migration version {} is used by more than one file in {}",
+                    pair[0].version,
+                    self.display()
+                )
+                .into());
+            }
 
             Ok(migrations)
         })
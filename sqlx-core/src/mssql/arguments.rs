@@ -1,9 +1,12 @@
-use crate::arguments::Arguments;
+use crate::arguments::{ArgumentCount, Arguments};
 use crate::encode::Encode;
 use crate::mssql::database::Mssql;
 use crate::mssql::io::MssqlBufMutExt;
 use crate::mssql::protocol::rpc::StatusFlags;
+use crate::mssql::protocol::type_info::TypeInfo;
+use crate::mssql::{MssqlTypeInfo, MssqlValueRef};
 use crate::types::Type;
+use bytes::{Buf, Bytes};
 
 #[derive(Default)]
 pub struct MssqlArguments {
@@ -59,7 +62,8 @@ impl MssqlArguments {
         self.data.append(&mut arguments.data);
     }
 
-    pub(crate) fn add<'q, T>(&mut self, value: T)
+    /// Add the value to the end of the arguments.
+    pub fn add<'q, T>(&mut self, value: T)
     where
         T: Encode<'q, Mssql> + Type<Mssql>,
     {
@@ -105,8 +109,9 @@ impl MssqlArguments {
 impl<'q> Arguments<'q> for MssqlArguments {
     type Database = Mssql;
 
-    fn reserve(&mut self, _additional: usize, size: usize) {
-        self.data.reserve(size + 10); // est. 4 chars for name, 1 for status, 1 for TYPE_INFO
+    fn reserve(&mut self, additional: usize, size: usize) {
+        // est. 4 chars for name, 1 for status, 1 for TYPE_INFO (per argument)
+        self.data.reserve(size + additional * 10);
     }
 
     fn add<T>(&mut self, value: T)
@@ -115,4 +120,52 @@ impl<'q> Arguments<'q> for MssqlArguments {
     {
         self.add(value)
     }
+
+    fn cache_key(&self) -> Option<Vec<u8>> {
+        // each parameter is written with its type info
+        Some(self.data.clone())
+    }
+
+    fn format_for_log(&self) -> Vec<String> {
+        let mut buf = Bytes::copy_from_slice(&self.data);
+        let mut values = Vec::with_capacity(self.ordinal);
+
+        while buf.has_remaining() {
+            // skip [ParamName] (a length in UTF-16 code units) and [StatusFlags]
+            let skip = 2 + usize::from(buf[0]) * 2;
+
+            if buf.len() < skip {
+                return Vec::new();
+            }
+
+            buf.advance(skip);
+
+            let ty = match TypeInfo::get(&mut buf) {
+                Ok(ty) => ty,
+                Err(_) => return Vec::new(),
+            };
+
+            let data = ty.get_value(&mut buf);
+
+            let value = MssqlValueRef {
+                type_info: MssqlTypeInfo(ty),
+                data: data.as_deref(),
+                row: None,
+            };
+
+            values.push(format_bind_value!(
+                Mssql,
+                value,
+                [bool, i8, i16, i32, i64, f32, f64, String]
+            ));
+        }
+
+        values
+    }
+}
+
+impl<'q> ArgumentCount<'q> for MssqlArguments {
+    fn len(&self) -> usize {
+        self.ordinal
+    }
 }
@@ -1,4 +1,5 @@
 use crate::common::StatementCache;
+use crate::connection::{ConnectionStatistics, ServerFlavor, ServerVersion};
 use crate::error::Error;
 use crate::io::Decode;
 use crate::mssql::connection::stream::MssqlStream;
@@ -57,14 +58,23 @@ impl MssqlConnection {
 
         stream.flush().await?;
 
+        let mut server_version = None;
+
         loop {
             // NOTE: we should receive an [Error] message if something goes wrong, otherwise,
             //       all messages are mostly informational (ENVCHANGE, INFO, LOGINACK)
 
             match stream.recv_message().await? {
-                Message::LoginAck(_) => {
+                Message::LoginAck(ack) => {
                     // indicates that the login was successful
-                    // no action is needed, we are just going to keep waiting till we hit <Done>
+                    // we are just going to keep waiting till we hit <Done>
+
+                    let version = ack.program_version;
+
+                    server_version = Some(ServerVersion::parse(
+                        ServerFlavor::Mssql,
+                        &format!("{}.{}.{}", version.major, version.minor, version.build),
+                    ));
                 }
 
                 Message::Done(_) => {
@@ -81,7 +91,9 @@ impl MssqlConnection {
 
         Ok(Self {
             stream,
+            server_version,
             cache_statement: StatementCache::new(1024),
+            statistics: ConnectionStatistics::default(),
             log_settings: options.log_settings.clone(),
         })
     }
@@ -1,7 +1,8 @@
+use crate::comment;
 use crate::describe::Describe;
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
-use crate::logger::QueryLogger;
+use crate::logger::{format_binds, instrument_query, QueryLogger};
 use crate::mssql::connection::prepare::prepare;
 use crate::mssql::protocol::col_meta_data::Flags;
 use crate::mssql::protocol::done::Status;
@@ -23,12 +24,13 @@ impl MssqlConnection {
     async fn run(&mut self, query: &str, arguments: Option<MssqlArguments>) -> Result<(), Error> {
         self.stream.wait_until_ready().await?;
         self.stream.pending_done_count += 1;
+        self.statistics.statements_executed += 1;
 
         if let Some(mut arguments) = arguments {
             let proc = Either::Right(Procedure::ExecuteSql);
             let mut proc_args = MssqlArguments::default();
 
-            // SQL
+            // SQL; left without a comment as SQL Server caches the plan by the exact text
             proc_args.add_unnamed(query);
 
             if !arguments.data.is_empty() {
@@ -54,7 +56,7 @@ impl MssqlConnection {
                 PacketType::SqlBatch,
                 SqlBatch {
                     transaction_descriptor: self.stream.transaction_descriptor,
-                    sql: query,
+                    sql: &comment::apply(query),
                 },
             );
         }
@@ -78,54 +80,66 @@ impl<'c> Executor<'c> for &'c mut MssqlConnection {
     {
         let sql = query.sql();
         let arguments = query.take_arguments();
-        let mut logger = QueryLogger::new(sql, self.log_settings.clone());
+        let redacted = query.take_redacted_binds();
+        let binds = format_binds(&self.log_settings, arguments.as_ref(), &redacted);
 
-        Box::pin(try_stream! {
-            self.run(sql, arguments).await?;
+        instrument_query::<Mssql, _>(
+            sql,
+            Box::pin(try_stream! {
+                let mut logger = QueryLogger::new::<Mssql>(sql, binds, self.log_settings.clone());
 
-            loop {
-                let message = self.stream.recv_message().await?;
+                self.statistics.last_statement_id = Some(logger.statement_id());
+                self.run(sql, arguments).await?;
 
-                match message {
-                    Message::Row(row) => {
-                        let columns = Arc::clone(&self.stream.columns);
-                        let column_names = Arc::clone(&self.stream.column_names);
+                loop {
+                    let message = self.stream.recv_message().await?;
 
-                        logger.increment_rows();
+                    match message {
+                        Message::Row(row) => {
+                            let columns = Arc::clone(&self.stream.columns);
+                            let column_names = Arc::clone(&self.stream.column_names);
 
-                        r#yield!(Either::Right(MssqlRow { row, column_names, columns }));
-                    }
+                            logger.increment_rows();
+                            self.statistics.rows_fetched += 1;
 
-                    Message::Done(done) | Message::DoneProc(done) => {
-                        if !done.status.contains(Status::DONE_MORE) {
-                            self.stream.handle_done(&done);
+                            r#yield!(Either::Right(MssqlRow { row, column_names, columns }));
                         }
 
-                        if done.status.contains(Status::DONE_COUNT) {
-                            r#yield!(Either::Left(MssqlDone {
-                                rows_affected: done.affected_rows,
-                            }));
-                        }
+                        Message::Done(done) | Message::DoneProc(done) => {
+                            if !done.status.contains(Status::DONE_MORE) {
+                                self.stream.handle_done(&done);
+                            }
+
+                            if done.status.contains(Status::DONE_COUNT) {
+                                logger.increase_rows_affected(done.affected_rows);
+
+                                r#yield!(Either::Left(MssqlDone {
+                                    rows_affected: done.affected_rows,
+                                }));
+                            }
 
-                        if !done.status.contains(Status::DONE_MORE) {
-                            break;
+                            if !done.status.contains(Status::DONE_MORE) {
+                                break;
+                            }
                         }
-                    }
 
-                    Message::DoneInProc(done) => {
-                        if done.status.contains(Status::DONE_COUNT) {
-                            r#yield!(Either::Left(MssqlDone {
-                                rows_affected: done.affected_rows,
-                            }));
+                        Message::DoneInProc(done) => {
+                            if done.status.contains(Status::DONE_COUNT) {
+                                logger.increase_rows_affected(done.affected_rows);
+
+                                r#yield!(Either::Left(MssqlDone {
+                                    rows_affected: done.affected_rows,
+                                }));
+                            }
                         }
-                    }
 
-                    _ => {}
+                        _ => {}
+                    }
                 }
-            }
 
-            Ok(())
-        })
+                Ok(())
+            }),
+        )
     }
 
     fn fetch_optional<'e, 'q: 'e, E: 'q>(
@@ -157,14 +171,17 @@ impl<'c> Executor<'c> for &'c mut MssqlConnection {
     where
         'c: 'e,
     {
-        Box::pin(async move {
-            let metadata = prepare(self, sql).await?;
-
-            Ok(MssqlStatement {
-                sql: Cow::Borrowed(sql),
-                metadata,
-            })
-        })
+        crate::logger::instrument_prepare::<Mssql, _>(
+            sql,
+            Box::pin(async move {
+                let metadata = prepare(self, sql).await?;
+
+                Ok(MssqlStatement {
+                    sql: Cow::Borrowed(sql),
+                    metadata,
+                })
+            }),
+        )
     }
 
     fn describe<'e, 'q: 'e>(
@@ -1,5 +1,5 @@
 use crate::common::StatementCache;
-use crate::connection::{Connection, LogSettings};
+use crate::connection::{Connection, ConnectionStatistics, LogSettings, ServerVersion};
 use crate::error::Error;
 use crate::executor::Executor;
 use crate::mssql::connection::stream::MssqlStream;
@@ -9,7 +9,6 @@ use crate::transaction::Transaction;
 use futures_core::future::BoxFuture;
 use futures_util::{future::ready, FutureExt, TryFutureExt};
 use std::fmt::{self, Debug, Formatter};
-use std::net::Shutdown;
 use std::sync::Arc;
 
 mod establish;
@@ -19,7 +18,9 @@ mod stream;
 
 pub struct MssqlConnection {
     pub(crate) stream: MssqlStream,
+    server_version: Option<ServerVersion>,
     pub(crate) cache_statement: StatementCache<Arc<MssqlStatementMetadata>>,
+    statistics: ConnectionStatistics,
     log_settings: LogSettings,
 }
 
@@ -36,7 +37,7 @@ impl Connection for MssqlConnection {
 
     fn close(self) -> BoxFuture<'static, Result<(), Error>> {
         // NOTE: there does not seem to be a clean shutdown packet to send to MSSQL
-        ready(self.stream.shutdown(Shutdown::Both).map_err(Into::into)).boxed()
+        ready(self.stream.shutdown().map_err(Into::into)).boxed()
     }
 
     fn ping(&mut self) -> BoxFuture<'_, Result<(), Error>> {
@@ -44,6 +45,10 @@ impl Connection for MssqlConnection {
         self.execute("/* SQLx ping */").map_ok(|_| ()).boxed()
     }
 
+    fn is_in_transaction(&self) -> bool {
+        self.stream.transaction_descriptor != 0
+    }
+
     fn begin(&mut self) -> BoxFuture<'_, Result<Transaction<'_, Self::Database>, Error>>
     where
         Self: Sized,
@@ -51,6 +56,18 @@ impl Connection for MssqlConnection {
         Transaction::begin(self)
     }
 
+    fn statistics(&self) -> ConnectionStatistics {
+        ConnectionStatistics {
+            bytes_sent: self.stream.bytes_written,
+            bytes_received: self.stream.bytes_read,
+            ..self.statistics
+        }
+    }
+
+    fn server_version(&self) -> Option<&ServerVersion> {
+        self.server_version.as_ref()
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         self.stream.wait_until_ready().boxed()
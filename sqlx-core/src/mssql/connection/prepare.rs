@@ -86,7 +86,8 @@ pub(crate) async fn prepare(
 
             Message::ReturnValue(rv) => {
                 id = <i32 as Decode<Mssql>>::decode(MssqlValueRef {
-                    data: rv.value.as_ref(),
+                    data: rv.value.as_deref(),
+                    row: None,
                     type_info: MssqlTypeInfo(rv.type_info),
                 })
                 .ok();
@@ -133,7 +134,7 @@ pub(crate) async fn prepare(
 
     let metadata = Arc::new(MssqlStatementMetadata {
         columns: conn.stream.columns.as_ref().clone(),
-        column_names: conn.stream.column_names.as_ref().clone(),
+        column_names: Arc::clone(&conn.stream.column_names),
     });
 
     conn.cache_statement.insert(sql, metadata.clone());
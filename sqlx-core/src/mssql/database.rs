@@ -1,4 +1,4 @@
-use crate::database::{Database, HasArguments, HasStatement, HasValueRef};
+use crate::database::{quote, Database, HasArguments, HasStatement, HasValueRef};
 use crate::mssql::{
     MssqlArguments, MssqlColumn, MssqlConnection, MssqlDone, MssqlRow, MssqlStatement,
     MssqlTransactionManager, MssqlTypeInfo, MssqlValue, MssqlValueRef,
@@ -22,6 +22,25 @@ impl Database for Mssql {
     type TypeInfo = MssqlTypeInfo;
 
     type Value = MssqlValue;
+
+    const NAME: &'static str = "mssql";
+
+    const MAX_BIND_PARAMETERS: usize = 2100;
+}
+
+impl Mssql {
+    /// Quote `ident` so it can be interpolated into SQL as an identifier, such as a table or
+    /// column name; it is wrapped with square brackets.
+    pub fn quote_identifier(ident: &str) -> String {
+        quote(ident, '[', ']')
+    }
+
+    /// Quote `value` so it can be interpolated into SQL as a string literal; it is written
+    /// as a national (`N'...'`) literal.
+    pub fn quote_literal(value: &str) -> String {
+        // a national (`NVARCHAR`) literal so that any character survives
+        format!("N{}", quote(value, '\'', '\''))
+    }
 }
 
 impl<'r> HasValueRef<'r> for Mssql {
@@ -43,3 +62,11 @@ impl HasArguments<'_> for Mssql {
 
     type ArgumentBuffer = Vec<u8>;
 }
+
+#[test]
+fn it_quotes_identifiers_and_literals() {
+    assert_eq!(Mssql::quote_identifier("users"), "[users]");
+    assert_eq!(Mssql::quote_identifier("my [table]"), "[my [table]]]");
+
+    assert_eq!(Mssql::quote_literal("it's"), "N'it''s'");
+}
@@ -24,4 +24,9 @@ impl ConnectOptions for MssqlConnectOptions {
         self.log_settings.log_slow_statements(level, duration);
         self
     }
+
+    fn log_binds(&mut self, enabled: bool) -> &mut Self {
+        self.log_settings.log_binds(enabled);
+        self
+    }
 }
@@ -1,4 +1,8 @@
+use std::time::Duration;
+
+use crate::common::Secret;
 use crate::connection::LogSettings;
+use crate::net::{Socks5Proxy, TcpSettings};
 
 mod connect;
 mod parse;
@@ -9,8 +13,9 @@ pub struct MssqlConnectOptions {
     pub(crate) port: u16,
     pub(crate) username: String,
     pub(crate) database: String,
-    pub(crate) password: Option<String>,
+    pub(crate) password: Option<Secret>,
     pub(crate) log_settings: LogSettings,
+    pub(crate) tcp_settings: TcpSettings,
 }
 
 impl Default for MssqlConnectOptions {
@@ -28,6 +33,7 @@ impl MssqlConnectOptions {
             username: String::from("sa"),
             password: None,
             log_settings: Default::default(),
+            tcp_settings: Default::default(),
         }
     }
 
@@ -47,7 +53,7 @@ impl MssqlConnectOptions {
     }
 
     pub fn password(mut self, password: &str) -> Self {
-        self.password = Some(password.to_owned());
+        self.password = Some(password.into());
         self
     }
 
@@ -55,4 +61,72 @@ impl MssqlConnectOptions {
         self.database = database.to_owned();
         self
     }
+
+    /// Sets whether `TCP_NODELAY` is set on the socket, disabling Nagle's algorithm.
+    ///
+    /// Enabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::mssql::MssqlConnectOptions;
+    /// let options = MssqlConnectOptions::new()
+    ///     .tcp_nodelay(false);
+    /// ```
+    pub fn tcp_nodelay(mut self, nodelay: bool) -> Self {
+        self.tcp_settings.nodelay = nodelay;
+        self
+    }
+
+    /// Enables TCP keepalive on the socket, sending the first probe after the connection
+    /// has been idle for `interval`.
+    ///
+    /// Disabled by default. Only supported by the Tokio and Actix runtimes.
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_settings.keepalive = Some(interval);
+        self
+    }
+
+    /// Sets the size of the socket send buffer (`SO_SNDBUF`) in bytes.
+    ///
+    /// Defaults to the operating system setting. Only supported by the Tokio and Actix runtimes.
+    pub fn tcp_send_buffer_size(mut self, size: usize) -> Self {
+        self.tcp_settings.send_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets the size of the socket receive buffer (`SO_RCVBUF`) in bytes.
+    ///
+    /// Defaults to the operating system setting. Only supported by the Tokio and Actix runtimes.
+    pub fn tcp_recv_buffer_size(mut self, size: usize) -> Self {
+        self.tcp_settings.recv_buffer_size = Some(size);
+        self
+    }
+
+    /// Connects to the server through a SOCKS5 proxy.
+    ///
+    /// The host name of the server is resolved by the proxy. To reach a server behind an SSH
+    /// bastion host, open a dynamic port forward with `ssh -D 1080 <bastion>` and use
+    /// `localhost:1080` as the proxy.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::mssql::MssqlConnectOptions;
+    /// let options = MssqlConnectOptions::new()
+    ///     .host("db.internal")
+    ///     .socks5_proxy("localhost", 1080);
+    /// ```
+    pub fn socks5_proxy(mut self, host: &str, port: u16) -> Self {
+        self.tcp_settings.socks5_proxy = Some(Socks5Proxy::new(host, port));
+        self
+    }
+
+    /// Sets the username and password to authenticate with the SOCKS5 proxy.
+    ///
+    /// Can be called before or after `socks5_proxy`; has no effect if no proxy is set.
+    pub fn socks5_proxy_credentials(mut self, username: &str, password: &str) -> Self {
+        self.tcp_settings.socks5_credentials = Some((username.to_owned(), password.into()));
+        self
+    }
 }
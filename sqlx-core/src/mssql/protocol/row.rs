@@ -1,13 +1,20 @@
+use std::ops::Range;
+
 use bytes::Bytes;
 
 use crate::error::Error;
 use crate::io::BufExt;
-use crate::mssql::{MssqlColumn, MssqlTypeInfo};
+use crate::mssql::MssqlColumn;
 
+/// A row of data from the database.
 #[derive(Debug)]
 pub(crate) struct Row {
-    pub(crate) column_types: Vec<MssqlTypeInfo>,
-    pub(crate) values: Vec<Option<Bytes>>,
+    pub(crate) storage: Bytes,
+
+    /// Ranges into the stored row data.
+    /// This uses `u32` instead of usize to reduce the size of this type. A row is bounded by
+    /// the size of the message it was received in.
+    pub(crate) values: Vec<Option<Range<u32>>>,
 }
 
 impl Row {
@@ -16,8 +23,9 @@ impl Row {
         nullable: bool,
         columns: &[MssqlColumn],
     ) -> Result<Self, Error> {
+        // the row shares the packet it was received in; values are never copied out of it
+        let mut storage = buf.clone();
         let mut values = Vec::with_capacity(columns.len());
-        let mut column_types = Vec::with_capacity(columns.len());
 
         let nulls = if nullable {
             buf.get_bytes((columns.len() + 7) / 8)
@@ -26,19 +34,56 @@ impl Row {
         };
 
         for (i, column) in columns.iter().enumerate() {
-            column_types.push(column.type_info.clone());
-
             if !(column.type_info.0.is_null() || (nullable && (nulls[i / 8] & (1 << (i % 8))) != 0))
             {
-                values.push(column.type_info.0.get_value(buf));
+                values.push(column.type_info.0.get_value(buf).map(|value| {
+                    let end = (storage.len() - buf.len()) as u32;
+
+                    (end - value.len() as u32)..end
+                }));
             } else {
                 values.push(None);
             }
         }
 
-        Ok(Self {
-            values,
-            column_types,
-        })
+        storage.truncate(storage.len() - buf.len());
+
+        Ok(Self { storage, values })
+    }
+
+    #[inline]
+    pub(crate) fn value(&self, index: usize) -> Option<&'_ [u8]> {
+        self.values[index]
+            .as_ref()
+            .map(|col| &self.storage[(col.start as usize)..(col.end as usize)])
     }
 }
+
+#[test]
+fn test_get_nbc_row() {
+    use crate::ext::ustr::UStr;
+    use crate::mssql::protocol::col_meta_data::Flags;
+    use crate::mssql::protocol::type_info::{DataType, TypeInfo};
+    use crate::mssql::MssqlTypeInfo;
+
+    let columns: Vec<MssqlColumn> = (0..3)
+        .map(|ordinal| MssqlColumn {
+            ordinal,
+            name: UStr::Static("v"),
+            type_info: MssqlTypeInfo(TypeInfo::new(DataType::IntN, 4)),
+            flags: Flags::NULLABLE,
+        })
+        .collect();
+
+    // the second column is NULL; the trailing byte belongs to the next message
+    let mut buf = Bytes::from_static(b"\x02\x04\x01\x00\x00\x00\x04\x02\x00\x00\x00\xfd");
+
+    let row = Row::get(&mut buf, true, &columns).unwrap();
+
+    assert_eq!(row.value(0), Some(&[1_u8, 0, 0, 0][..]));
+    assert_eq!(row.value(1), None);
+    assert_eq!(row.value(2), Some(&[2_u8, 0, 0, 0][..]));
+
+    assert_eq!(row.storage.len(), 11);
+    assert_eq!(&buf[..], b"\xfd");
+}
@@ -28,8 +28,9 @@ impl Row for MssqlRow {
     {
         let index = index.index(self)?;
         let value = MssqlValueRef {
-            data: self.row.values[index].as_ref(),
-            type_info: self.row.column_types[index].clone(),
+            data: self.row.value(index),
+            row: Some(&self.row.storage),
+            type_info: self.columns[index].type_info.clone(),
         };
 
         Ok(value)
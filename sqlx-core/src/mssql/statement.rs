@@ -17,7 +17,7 @@ pub struct MssqlStatement<'q> {
 #[derive(Debug, Default, Clone)]
 pub(crate) struct MssqlStatementMetadata {
     pub(crate) columns: Vec<MssqlColumn>,
-    pub(crate) column_names: HashMap<UStr, usize>,
+    pub(crate) column_names: Arc<HashMap<UStr, usize>>,
 }
 
 impl<'q> Statement<'q> for MssqlStatement<'q> {
@@ -67,7 +67,7 @@ impl<'q> From<MssqlStatement<'q>> for crate::any::AnyStatement<'q> {
                 .iter()
                 .map(|col| col.clone().into())
                 .collect(),
-            column_names: std::sync::Arc::new(statement.metadata.column_names.clone()),
+            column_names: Arc::clone(&statement.metadata.column_names),
             parameters: None,
             sql: statement.sql,
         }
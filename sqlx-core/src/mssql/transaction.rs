@@ -7,7 +7,7 @@ use crate::executor::Executor;
 use crate::mssql::protocol::packet::PacketType;
 use crate::mssql::protocol::sql_batch::SqlBatch;
 use crate::mssql::{Mssql, MssqlConnection};
-use crate::transaction::TransactionManager;
+use crate::transaction::{SavepointCommand, TransactionManager};
 
 /// Implementation of [`TransactionManager`] for MSSQL.
 pub struct MssqlTransactionManager;
@@ -91,4 +91,24 @@ impl TransactionManager for MssqlTransactionManager {
             conn.stream.transaction_depth = depth - 1;
         }
     }
+
+    fn savepoint<'c>(
+        conn: &'c mut MssqlConnection,
+        command: SavepointCommand,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            let query = match command {
+                SavepointCommand::Create => format!("SAVE TRAN {}", name),
+                SavepointCommand::RollbackTo => format!("ROLLBACK TRAN {}", name),
+
+                // savepoints are not released in MSSQL
+                SavepointCommand::Release => return Ok(()),
+            };
+
+            conn.execute(&*query).await?;
+
+            Ok(())
+        })
+    }
 }
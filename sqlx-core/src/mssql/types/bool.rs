@@ -1,4 +1,4 @@
-use crate::decode::Decode;
+use crate::decode::{check_width, Decode};
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::mssql::protocol::type_info::{DataType, TypeInfo};
@@ -25,6 +25,6 @@ impl Encode<'_, Mssql> for bool {
 
 impl Decode<'_, Mssql> for bool {
     fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
-        Ok(value.as_bytes()?[0] == 1)
+        Ok(check_width(value.as_bytes()?, 1)?[0] == 1)
     }
 }
@@ -1,6 +1,6 @@
 use byteorder::{ByteOrder, LittleEndian};
 
-use crate::decode::Decode;
+use crate::decode::{check_width, Decode};
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::mssql::protocol::type_info::{DataType, TypeInfo};
@@ -27,7 +27,7 @@ impl Encode<'_, Mssql> for f32 {
 
 impl Decode<'_, Mssql> for f32 {
     fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
-        Ok(LittleEndian::read_f32(value.as_bytes()?))
+        Ok(LittleEndian::read_f32(check_width(value.as_bytes()?, 4)?))
     }
 }
 
@@ -51,6 +51,6 @@ impl Encode<'_, Mssql> for f64 {
 
 impl Decode<'_, Mssql> for f64 {
     fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
-        Ok(LittleEndian::read_f64(value.as_bytes()?))
+        Ok(LittleEndian::read_f64(check_width(value.as_bytes()?, 8)?))
     }
 }
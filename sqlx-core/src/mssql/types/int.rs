@@ -1,6 +1,6 @@
 use byteorder::{ByteOrder, LittleEndian};
 
-use crate::decode::Decode;
+use crate::decode::{check_width, Decode};
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::mssql::protocol::type_info::{DataType, TypeInfo};
@@ -27,7 +27,7 @@ impl Encode<'_, Mssql> for i8 {
 
 impl Decode<'_, Mssql> for i8 {
     fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
-        Ok(value.as_bytes()?[0] as i8)
+        Ok(check_width(value.as_bytes()?, 1)?[0] as i8)
     }
 }
 
@@ -51,7 +51,7 @@ impl Encode<'_, Mssql> for i16 {
 
 impl Decode<'_, Mssql> for i16 {
     fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
-        Ok(LittleEndian::read_i16(value.as_bytes()?))
+        Ok(LittleEndian::read_i16(check_width(value.as_bytes()?, 2)?))
     }
 }
 
@@ -75,7 +75,7 @@ impl Encode<'_, Mssql> for i32 {
 
 impl Decode<'_, Mssql> for i32 {
     fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
-        Ok(LittleEndian::read_i32(value.as_bytes()?))
+        Ok(LittleEndian::read_i32(check_width(value.as_bytes()?, 4)?))
     }
 }
 
@@ -99,6 +99,6 @@ impl Encode<'_, Mssql> for i64 {
 
 impl Decode<'_, Mssql> for i64 {
     fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
-        Ok(LittleEndian::read_i64(value.as_bytes()?))
+        Ok(LittleEndian::read_i64(check_width(value.as_bytes()?, 8)?))
     }
 }
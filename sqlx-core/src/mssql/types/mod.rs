@@ -1,6 +1,7 @@
 use crate::encode::{Encode, IsNull};
 use crate::mssql::protocol::type_info::{DataType, TypeInfo};
 use crate::mssql::{Mssql, MssqlTypeInfo};
+use crate::types::Null;
 
 mod bool;
 mod float;
@@ -37,3 +38,14 @@ impl<'q, T: 'q + Encode<'q, Mssql>> Encode<'q, Mssql> for Option<T> {
         self.as_ref().map_or(0, Encode::size_hint)
     }
 }
+
+// MSSQL has no typed NULLs, so this is sent like `None`
+impl<'q, T> Encode<'q, Mssql> for Null<T> {
+    fn encode_by_ref(&self, _buf: &mut Vec<u8>) -> IsNull {
+        IsNull::Yes
+    }
+
+    fn produces(&self) -> Option<MssqlTypeInfo> {
+        Some(MssqlTypeInfo(TypeInfo::new(DataType::Null, 0)))
+    }
+}
@@ -1,3 +1,6 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
@@ -70,6 +73,36 @@ impl Encode<'_, Mssql> for String {
     }
 }
 
+impl Encode<'_, Mssql> for Cow<'_, str> {
+    fn produces(&self) -> Option<MssqlTypeInfo> {
+        <&str as Encode<Mssql>>::produces(&&**self)
+    }
+
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        <&str as Encode<Mssql>>::encode_by_ref(&&**self, buf)
+    }
+}
+
+impl Encode<'_, Mssql> for Box<str> {
+    fn produces(&self) -> Option<MssqlTypeInfo> {
+        <&str as Encode<Mssql>>::produces(&&**self)
+    }
+
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        <&str as Encode<Mssql>>::encode_by_ref(&&**self, buf)
+    }
+}
+
+impl Encode<'_, Mssql> for Arc<str> {
+    fn produces(&self) -> Option<MssqlTypeInfo> {
+        <&str as Encode<Mssql>>::produces(&&**self)
+    }
+
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        <&str as Encode<Mssql>>::encode_by_ref(&&**self, buf)
+    }
+}
+
 impl Decode<'_, Mssql> for String {
     fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
         Ok(value
@@ -8,7 +8,8 @@ use std::borrow::Cow;
 #[derive(Clone)]
 pub struct MssqlValueRef<'r> {
     pub(crate) type_info: MssqlTypeInfo,
-    pub(crate) data: Option<&'r Bytes>,
+    pub(crate) data: Option<&'r [u8]>,
+    pub(crate) row: Option<&'r Bytes>,
 }
 
 impl<'r> MssqlValueRef<'r> {
@@ -24,8 +25,16 @@ impl ValueRef<'_> for MssqlValueRef<'_> {
     type Database = Mssql;
 
     fn to_owned(&self) -> MssqlValue {
+        let data = match (self.row, self.data) {
+            (Some(row), Some(data)) => Some(row.slice_ref(data)),
+
+            (None, Some(data)) => Some(Bytes::copy_from_slice(data)),
+
+            _ => None,
+        };
+
         MssqlValue {
-            data: self.data.cloned(),
+            data,
             type_info: self.type_info.clone(),
         }
     }
@@ -62,7 +71,8 @@ impl Value for MssqlValue {
 
     fn as_ref(&self) -> MssqlValueRef<'_> {
         MssqlValueRef {
-            data: self.data.as_ref(),
+            data: self.data.as_deref(),
+            row: None,
             type_info: self.type_info.clone(),
         }
     }
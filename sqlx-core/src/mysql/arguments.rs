@@ -1,18 +1,39 @@
-use crate::arguments::Arguments;
+use crate::arguments::{ArgumentCount, Arguments};
 use crate::encode::{Encode, IsNull};
-use crate::mysql::{MySql, MySqlTypeInfo};
+use crate::io::{recycle_buffer, take_buffer};
+use crate::mysql::protocol::text::ColumnType;
+use crate::mysql::{MySql, MySqlTypeInfo, MySqlValueFormat, MySqlValueRef};
+use crate::type_info::TypeInfo;
 use crate::types::Type;
 
 /// Implementation of [`Arguments`] for MySQL.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct MySqlArguments {
     pub(crate) values: Vec<u8>,
     pub(crate) types: Vec<MySqlTypeInfo>,
     pub(crate) null_bitmap: Vec<u8>,
 }
 
+impl Default for MySqlArguments {
+    fn default() -> Self {
+        Self {
+            // reuse the allocation of arguments that were already sent
+            values: take_buffer(),
+            types: Vec::new(),
+            null_bitmap: Vec::new(),
+        }
+    }
+}
+
+impl Drop for MySqlArguments {
+    fn drop(&mut self) {
+        recycle_buffer(std::mem::take(&mut self.values));
+    }
+}
+
 impl MySqlArguments {
-    pub(crate) fn add<'q, T>(&mut self, value: T)
+    /// Add the value to the end of the arguments.
+    pub fn add<'q, T>(&mut self, value: T)
     where
         T: Encode<'q, MySql> + Type<MySql>,
     {
@@ -21,6 +42,7 @@ impl MySqlArguments {
 
         self.types.push(ty);
         self.null_bitmap.resize((index / 8) + 1, 0);
+        self.values.reserve(value.size_hint());
 
         if let IsNull::Yes = value.encode(&mut self.values) {
             self.null_bitmap[index / 8] |= (1 << (index % 8)) as u8;
@@ -42,4 +64,101 @@ impl<'q> Arguments<'q> for MySqlArguments {
     {
         self.add(value)
     }
+
+    fn cache_key(&self) -> Option<Vec<u8>> {
+        let mut key =
+            Vec::with_capacity(self.values.len() + self.null_bitmap.len() + self.types.len() * 8);
+
+        for ty in &self.types {
+            key.extend_from_slice(ty.name().as_bytes());
+            key.push(0);
+        }
+
+        key.extend_from_slice(&self.null_bitmap);
+        key.extend_from_slice(&self.values);
+
+        Some(key)
+    }
+
+    fn format_for_log(&self) -> Vec<String> {
+        let mut buf: &[u8] = &self.values;
+        let mut values = Vec::with_capacity(self.types.len());
+
+        for (index, ty) in self.types.iter().enumerate() {
+            let value = if self.null_bitmap[index / 8] & (1 << (index % 8)) as u8 != 0 {
+                None
+            } else {
+                let (header, len) = match binary_value_len(buf, ty.r#type) {
+                    Some(len) => len,
+                    None => return Vec::new(),
+                };
+
+                let value = &buf[header..(header + len)];
+                buf = &buf[(header + len)..];
+
+                Some(value)
+            };
+
+            let value = MySqlValueRef {
+                value,
+                row: None,
+                type_info: ty.clone(),
+                format: MySqlValueFormat::Binary,
+            };
+
+            values.push(format_bind_value!(
+                MySql,
+                value,
+                [bool, i8, i16, i32, i64, u8, u16, u32, u64, f32, f64, String]
+            ));
+        }
+
+        values
+    }
+}
+
+// returns the length of the header and of the binary encoded value at the start of `buf`
+// https://dev.mysql.com/doc/internals/en/binary-protocol-value.html
+fn binary_value_len(buf: &[u8], ty: ColumnType) -> Option<(usize, usize)> {
+    let (header, len) = match ty {
+        ColumnType::LongLong | ColumnType::Double => (0, 8),
+        ColumnType::Long | ColumnType::Int24 | ColumnType::Float => (0, 4),
+        ColumnType::Short | ColumnType::Year => (0, 2),
+        ColumnType::Tiny => (0, 1),
+
+        // the length byte is part of the value, as it is needed to decode it
+        ColumnType::Time | ColumnType::Timestamp | ColumnType::Date | ColumnType::Datetime => {
+            (0, usize::from(*buf.first()?) + 1)
+        }
+
+        ColumnType::Null => (0, 0),
+
+        // everything else is a length-encoded string
+        _ => match *buf.first()? {
+            0xfc if buf.len() >= 3 => (3, usize::from(u16::from_le_bytes([buf[1], buf[2]]))),
+            0xfd if buf.len() >= 4 => (4, u32::from_le_bytes([buf[1], buf[2], buf[3], 0]) as usize),
+            0xfe if buf.len() >= 9 => {
+                let mut len = [0; 8];
+                len.copy_from_slice(&buf[1..9]);
+
+                (9, u64::from_le_bytes(len) as usize)
+            }
+
+            0xfc | 0xfd | 0xfe | 0xfb | 0xff => return None,
+
+            len => (1, usize::from(len)),
+        },
+    };
+
+    if buf.len() < header + len {
+        return None;
+    }
+
+    Some((header, len))
+}
+
+impl<'q> ArgumentCount<'q> for MySqlArguments {
+    fn len(&self) -> usize {
+        self.types.len()
+    }
 }
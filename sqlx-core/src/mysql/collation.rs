@@ -658,6 +658,23 @@ impl Collation {
     }
 }
 
+// Returns `true` if text in the collation with the given ID is valid UTF-8 as sent by the server.
+//
+// Columns report the collation of the text as it is sent, which is normally the collation of
+// the connection. This includes the collations of MySQL 8 and MariaDB that do not fit
+// into a `Collation`.
+pub(crate) fn is_utf8_compatible(collation_id: u16) -> bool {
+    matches!(
+        collation_id,
+        // ascii
+        11 | 65
+        // utf8 (utf8mb3)
+        | 33 | 76 | 83 | 192..=215 | 223 | 576..=578
+        // utf8mb4
+        | 45 | 46 | 224..=247 | 255..=323 | 608..=610
+    )
+}
+
 // Handshake packet have only 1 byte for collation_id.
 // So we can't use collations with ID > 255.
 impl FromStr for Collation {
@@ -136,13 +136,23 @@ async fn encrypt_rsa<'s>(
         return Ok(to_asciz(password));
     }
 
-    // client sends a public key request
-    stream.write_packet(&[public_key_request_id][..]);
-    stream.flush().await?;
+    let pkey = match &stream.server_public_key {
+        // the public key was given in the options, so the server is not asked for it
+        Some(key) => parse_rsa_pub_key(key).map_err(|_| {
+            Error::Configuration("invalid server public key; expected a PEM encoded RSA key".into())
+        })?,
 
-    // server sends a public key response
-    let packet = stream.recv_packet().await?;
-    let rsa_pub_key = &packet[1..];
+        None => {
+            // client sends a public key request
+            stream.write_packet(&[public_key_request_id][..]);
+            stream.flush().await?;
+
+            // server sends a public key response
+            let packet = stream.recv_packet().await?;
+
+            parse_rsa_pub_key(&packet[1..])?
+        }
+    };
 
     // xor the password with the given nonce
     let mut pass = to_asciz(password);
@@ -155,7 +165,6 @@ async fn encrypt_rsa<'s>(
     xor_eq(&mut pass, &*nonce);
 
     // client sends an RSA encrypted password
-    let pkey = parse_rsa_pub_key(rsa_pub_key)?;
     let padding = PaddingScheme::new_oaep::<sha1::Sha1>();
     pkey.encrypt(&mut thread_rng(), padding, &pass[..])
         .map_err(Error::protocol)
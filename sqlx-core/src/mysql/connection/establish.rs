@@ -1,6 +1,7 @@
 use bytes::Bytes;
 
 use crate::common::StatementCache;
+use crate::connection::{ConnectionStatistics, ServerFlavor, ServerVersion};
 use crate::error::Error;
 use crate::mysql::connection::{tls, MySqlStream, MAX_PACKET_SIZE};
 use crate::mysql::protocol::connect::{
@@ -22,34 +23,7 @@ impl MySqlConnection {
         let mut plugin = handshake.auth_plugin;
         let mut nonce = handshake.auth_plugin_data;
 
-        // FIXME: server version parse is a bit ugly
-        // expecting MAJOR.MINOR.PATCH
-
-        let mut server_version = handshake.server_version.split('.');
-
-        let server_version_major: u16 = server_version
-            .next()
-            .unwrap_or_default()
-            .parse()
-            .unwrap_or(0);
-
-        let server_version_minor: u16 = server_version
-            .next()
-            .unwrap_or_default()
-            .parse()
-            .unwrap_or(0);
-
-        let server_version_patch: u16 = server_version
-            .next()
-            .unwrap_or_default()
-            .parse()
-            .unwrap_or(0);
-
-        stream.server_version = (
-            server_version_major,
-            server_version_minor,
-            server_version_patch,
-        );
+        stream.server_version = Some(parse_server_version(&handshake.server_version));
 
         stream.capabilities &= handshake.server_capabilities;
         stream.capabilities |= Capabilities::PROTOCOL_41;
@@ -83,7 +57,7 @@ impl MySqlConnection {
             let packet = stream.recv_packet().await?;
             match packet[0] {
                 0x00 => {
-                    let _ok = packet.ok()?;
+                    stream.status = packet.ok()?.status;
 
                     break;
                 }
@@ -129,7 +103,36 @@ impl MySqlConnection {
             stream,
             transaction_depth: 0,
             cache_statement: StatementCache::new(options.statement_cache_capacity),
+            statistics: ConnectionStatistics::default(),
             log_settings: options.log_settings.clone(),
+            close_on_drop: options.close_on_drop,
         })
     }
 }
+
+fn parse_server_version(version: &str) -> ServerVersion {
+    if version.contains("MariaDB") {
+        // MariaDB before 11.0 prefixes its version with `5.5.5-` so that old replication
+        // clients do not mistake it for MySQL 10, e.g. `5.5.5-10.5.8-MariaDB-1:10.5.8+maria~focal`
+        let mut server_version = ServerVersion::parse(
+            ServerFlavor::MariaDb,
+            version.strip_prefix("5.5.5-").unwrap_or(version),
+        );
+        server_version.raw = version.to_owned();
+
+        return server_version;
+    }
+
+    ServerVersion::parse(ServerFlavor::MySql, version)
+}
+
+#[test]
+fn test_parse_server_version() {
+    let version = parse_server_version("8.0.23");
+    assert_eq!(version.flavor, ServerFlavor::MySql);
+    assert_eq!((version.major, version.minor, version.patch), (8, 0, 23));
+
+    let version = parse_server_version("5.5.5-10.5.8-MariaDB-1:10.5.8+maria~focal");
+    assert_eq!(version.flavor, ServerFlavor::MariaDb);
+    assert_eq!((version.major, version.minor, version.patch), (10, 5, 8));
+}
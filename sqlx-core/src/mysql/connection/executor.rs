@@ -1,9 +1,11 @@
 use super::MySqlStream;
+use crate::comment;
+use crate::connection::ServerFlavor;
 use crate::describe::Describe;
-use crate::error::Error;
+use crate::error::{DatabaseError, Error};
 use crate::executor::{Execute, Executor};
 use crate::ext::ustr::UStr;
-use crate::logger::QueryLogger;
+use crate::logger::{format_binds, instrument_query, instrument_query_one, QueryLogger};
 use crate::mysql::connection::stream::Busy;
 use crate::mysql::io::MySqlBufExt;
 use crate::mysql::protocol::response::Status;
@@ -13,8 +15,8 @@ use crate::mysql::protocol::statement::{
 use crate::mysql::protocol::text::{ColumnDefinition, ColumnFlags, Query, TextRow};
 use crate::mysql::statement::{MySqlStatement, MySqlStatementMetadata};
 use crate::mysql::{
-    MySql, MySqlArguments, MySqlColumn, MySqlConnection, MySqlDone, MySqlRow, MySqlTypeInfo,
-    MySqlValueFormat,
+    MySql, MySqlArguments, MySqlColumn, MySqlConnection, MySqlDatabaseError, MySqlDone, MySqlRow,
+    MySqlTypeInfo, MySqlValueFormat,
 };
 use crate::HashMap;
 use either::Either;
@@ -31,16 +33,27 @@ impl MySqlConnection {
         persistent: bool,
     ) -> Result<(u32, MySqlStatementMetadata), Error> {
         if let Some(statement) = self.cache_statement.get_mut(sql) {
+            self.statistics.statement_cache_hits += 1;
+
             // <MySqlStatementMetadata> is internally reference-counted
             return Ok((*statement).clone());
         }
 
+        self.statistics.statement_cache_misses += 1;
+
         // https://dev.mysql.com/doc/internals/en/com-stmt-prepare.html
         // https://dev.mysql.com/doc/internals/en/com-stmt-prepare-response.html#packet-COM_STMT_PREPARE_OK
 
-        self.stream.send_packet(Prepare { query: sql }).await?;
+        // the statement is cached by the SQL without the comment, so that it is reused
+        self.stream.busy = Busy::Prepare;
+        self.stream
+            .send_packet(Prepare {
+                query: &comment::apply(sql),
+            })
+            .await?;
 
         let ok: PrepareOk = self.stream.recv().await?;
+        self.stream.prepared(&ok);
 
         // the parameter definitions are very unreliable so we skip over them
         // as we have little use
@@ -65,6 +78,8 @@ impl MySqlConnection {
             Default::default()
         };
 
+        self.stream.busy = Busy::NotBusy;
+
         let id = ok.statement_id;
         let metadata = MySqlStatementMetadata {
             parameters: ok.params as usize,
@@ -75,32 +90,51 @@ impl MySqlConnection {
         if persistent && self.cache_statement.is_enabled() {
             // in case of the cache being full, close the least recently used statement
             if let Some((id, _)) = self.cache_statement.insert(sql, (id, metadata.clone())) {
-                self.stream.send_packet(StmtClose { statement: id }).await?;
+                // COM_STMT_CLOSE has no response so there is nothing to wait for;
+                // the packet goes out in the same write as the next command
+                self.stream.sequence_id = 0;
+                self.stream.write_packet(StmtClose { statement: id });
             }
         }
 
         Ok((id, metadata))
     }
 
+    // closes a statement that was not stored in the cache along with the next command, so that
+    // statements do not pile up on the server when caching is disabled or bypassed
+    fn close_if_uncached(&mut self, sql: &str, statement: u32) {
+        if !self.cache_statement.contains_key(sql) {
+            // COM_STMT_CLOSE has no response so there is nothing to wait for
+            self.stream.sequence_id = 0;
+            self.stream.write_packet(StmtClose { statement });
+        }
+    }
+
     #[allow(clippy::needless_lifetimes)]
     async fn run<'e, 'c: 'e, 'q: 'e>(
         &'c mut self,
         sql: &'q str,
         arguments: Option<MySqlArguments>,
+        binds: Vec<String>,
         persistent: bool,
     ) -> Result<impl Stream<Item = Result<Either<MySqlDone, MySqlRow>, Error>> + 'e, Error> {
-        let mut logger = QueryLogger::new(sql, self.log_settings.clone());
+        let mut logger = QueryLogger::new::<MySql>(sql, binds, self.log_settings.clone());
 
         self.stream.wait_until_ready().await?;
-        self.stream.busy = Busy::Result;
+        self.statistics.statements_executed += 1;
+        self.statistics.last_statement_id = Some(logger.statement_id());
 
-        Ok(Box::pin(try_stream! {
+        Ok(try_stream! {
             // make a slot for the shared column data
             // as long as a reference to a row is not held past one iteration, this enables us
             // to re-use this memory freely between result sets
             let mut columns = Arc::new(Vec::new());
 
-            let (mut column_names, format, mut needs_metadata) = if let Some(arguments) = arguments {
+            // a statement taken from the cache can go stale when the schema changes; it is
+            // prepared again and retried once if the server asks for that
+            let mut can_reprepare = persistent && arguments.is_some();
+
+            let (mut column_names, format, mut needs_metadata) = if let Some(arguments) = &arguments {
                 let (id, metadata) = self.get_or_prepare(
                     sql,
                     persistent,
@@ -108,17 +142,21 @@ impl MySqlConnection {
                 .await?;
 
                 // https://dev.mysql.com/doc/internals/en/com-stmt-execute.html
+                self.stream.busy = Busy::Result;
                 self.stream
                     .send_packet(StatementExecute {
                         statement: id,
-                        arguments: &arguments,
+                        arguments,
                     })
                     .await?;
 
+                self.close_if_uncached(sql, id);
+
                 (metadata.column_names, MySqlValueFormat::Binary, false)
             } else {
                 // https://dev.mysql.com/doc/internals/en/com-query.html
-                self.stream.send_packet(Query(sql)).await?;
+                self.stream.busy = Busy::Result;
+                self.stream.send_packet(Query(&comment::apply(sql))).await?;
 
                 (Arc::default(), MySqlValueFormat::Text, true)
             };
@@ -126,18 +164,54 @@ impl MySqlConnection {
             loop {
                 // query response is a meta-packet which may be one of:
                 //  Ok, Err, ResultSet, or (unhandled) LocalInfileRequest
-                let mut packet = self.stream.recv_packet().await?;
+                let mut packet = match self.stream.recv_packet().await {
+                    Ok(packet) => packet,
+
+                    Err(Error::Database(error)) if can_reprepare && is_need_reprepare(&*error) => {
+                        can_reprepare = false;
+
+                        let arguments = arguments
+                            .as_ref()
+                            .expect("BUG: re-preparing a statement without arguments");
+
+                        if let Some((id, _)) = self.cache_statement.remove(sql) {
+                            // sent along with the PREPARE below
+                            self.stream.sequence_id = 0;
+                            self.stream.write_packet(StmtClose { statement: id });
+                        }
+
+                        let (id, metadata) = self.get_or_prepare(sql, persistent).await?;
+                        column_names = metadata.column_names;
+
+                        self.stream.busy = Busy::Result;
+                        self.stream
+                            .send_packet(StatementExecute {
+                                statement: id,
+                                arguments,
+                            })
+                            .await?;
+
+                        continue;
+                    }
+
+                    Err(error) => return Err(error),
+                };
+
+                can_reprepare = false;
 
                 if packet[0] == 0x00 || packet[0] == 0xff {
                     // first packet in a query response is OK or ERR
                     // this indicates either a successful query with no rows at all or a failed query
                     let ok = packet.ok()?;
+                    self.stream.status = ok.status;
 
                     let done = MySqlDone {
                         rows_affected: ok.affected_rows,
                         last_insert_id: ok.last_insert_id,
                     };
 
+                    logger.increase_rows_affected(done.rows_affected);
+
                     r#yield!(Either::Left(done));
 
                     if ok.status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
@@ -170,6 +244,7 @@ impl MySqlConnection {
 
                     if packet[0] == 0xfe && packet.len() < 9 {
                         let eof = packet.eof(self.stream.capabilities)?;
+                        self.stream.status = eof.status;
 
                         r#yield!(Either::Left(MySqlDone {
                             rows_affected: 0,
@@ -199,11 +274,12 @@ impl MySqlConnection {
                     });
 
                     logger.increment_rows();
+                    self.statistics.rows_fetched += 1;
 
                     r#yield!(v);
                 }
             }
-        }))
+        })
     }
 }
 
@@ -220,39 +296,54 @@ impl<'c> Executor<'c> for &'c mut MySqlConnection {
     {
         let sql = query.sql();
         let arguments = query.take_arguments();
+        let redacted = query.take_redacted_binds();
+        let binds = format_binds(&self.log_settings, arguments.as_ref(), &redacted);
         let persistent = query.persistent();
 
-        Box::pin(try_stream! {
-            let s = self.run(sql, arguments, persistent).await?;
-            pin_mut!(s);
+        instrument_query::<MySql, _>(
+            sql,
+            Box::pin(try_stream! {
+                let s = self.run(sql, arguments, binds, persistent).await?;
+                pin_mut!(s);
 
-            while let Some(v) = s.try_next().await? {
-                r#yield!(v);
-            }
+                while let Some(v) = s.try_next().await? {
+                    r#yield!(v);
+                }
 
-            Ok(())
-        })
+                Ok(())
+            }),
+        )
     }
 
     fn fetch_optional<'e, 'q: 'e, E: 'q>(
         self,
-        query: E,
+        mut query: E,
     ) -> BoxFuture<'e, Result<Option<MySqlRow>, Error>>
     where
         'c: 'e,
         E: Execute<'q, Self::Database>,
     {
-        let mut s = self.fetch_many(query);
+        let sql = query.sql();
+        let arguments = query.take_arguments();
+        let redacted = query.take_redacted_binds();
+        let binds = format_binds(&self.log_settings, arguments.as_ref(), &redacted);
+        let persistent = query.persistent();
 
-        Box::pin(async move {
-            while let Some(v) = s.try_next().await? {
-                if let Either::Right(r) = v {
-                    return Ok(Some(r));
+        instrument_query_one::<MySql, _>(
+            sql,
+            Box::pin(async move {
+                let s = self.run(sql, arguments, binds, persistent).await?;
+                pin_mut!(s);
+
+                while let Some(v) = s.try_next().await? {
+                    if let Either::Right(r) = v {
+                        return Ok(Some(r));
+                    }
                 }
-            }
 
-            Ok(None)
-        })
+                Ok(None)
+            }),
+        )
     }
 
     fn prepare_with<'e, 'q: 'e>(
@@ -263,17 +354,21 @@ impl<'c> Executor<'c> for &'c mut MySqlConnection {
     where
         'c: 'e,
     {
-        Box::pin(async move {
-            self.stream.wait_until_ready().await?;
-
-            let (_, metadata) = self.get_or_prepare(sql, true).await?;
-
-            Ok(MySqlStatement {
-                sql: Cow::Borrowed(sql),
-                // metadata has internal Arcs for expensive data structures
-                metadata: metadata.clone(),
-            })
-        })
+        crate::logger::instrument_prepare::<MySql, _>(
+            sql,
+            Box::pin(async move {
+                self.stream.wait_until_ready().await?;
+
+                let (id, metadata) = self.get_or_prepare(sql, true).await?;
+                self.close_if_uncached(sql, id);
+
+                Ok(MySqlStatement {
+                    sql: Cow::Borrowed(sql),
+                    // metadata has internal Arcs for expensive data structures
+                    metadata: metadata.clone(),
+                })
+            }),
+        )
     }
 
     #[doc(hidden)]
@@ -284,9 +379,25 @@ impl<'c> Executor<'c> for &'c mut MySqlConnection {
         Box::pin(async move {
             self.stream.wait_until_ready().await?;
 
-            let (_, metadata) = self.get_or_prepare(sql, false).await?;
+            let (id, metadata) = self.get_or_prepare(sql, false).await?;
+            self.close_if_uncached(sql, id);
 
-            let columns = (&*metadata.columns).clone();
+            let mut columns = (&*metadata.columns).clone();
+
+            // MariaDB 10.5+ supports `INSERT .. RETURNING` (and `REPLACE` and `DELETE`), but
+            // does not always describe the returned columns when the statement is prepared;
+            // they are the same as those of selecting the `RETURNING` list from the table
+            if columns.is_empty() && self.supports_returning() {
+                if let Some(select) = returning_as_select(sql) {
+                    // the list may use expressions that are only valid in the original
+                    // statement, in which case the columns remain undescribed
+                    if let Ok((id, returning)) = self.get_or_prepare(&select, false).await {
+                        self.close_if_uncached(&select, id);
+
+                        columns = (&*returning.columns).clone();
+                    }
+                }
+            }
 
             let nullable = columns
                 .iter()
@@ -368,3 +479,89 @@ async fn recv_result_metadata(
 
     Ok(column_names)
 }
+
+impl MySqlConnection {
+    // only MariaDB has a `RETURNING` clause
+    fn supports_returning(&self) -> bool {
+        self.stream
+            .server_version
+            .as_ref()
+            .map_or(false, |version| {
+                version.flavor == ServerFlavor::MariaDb && version.at_least(10, 5)
+            })
+    }
+}
+
+// rewrites `INSERT INTO table .. RETURNING list` (or `REPLACE` or `DELETE FROM`) to
+// `SELECT list FROM table`; returns `None` for any other statement
+fn returning_as_select(sql: &str) -> Option<String> {
+    let upper = sql.to_ascii_uppercase();
+    let mut words = upper.split_whitespace();
+
+    let target = match words.next()? {
+        "INSERT" | "REPLACE" => "INTO",
+        "DELETE" => "FROM",
+        _ => return None,
+    };
+
+    // skip modifiers such as `IGNORE` or `LOW_PRIORITY`
+    if !words.any(|word| word == target) {
+        return None;
+    }
+
+    // the positions of words in `upper` are also their positions in `sql`
+    let table_word = words.next()?;
+    let table_start = table_word.as_ptr() as usize - upper.as_ptr() as usize;
+    let table_len = table_word.find('(').unwrap_or_else(|| table_word.len());
+    let table = &sql[table_start..table_start + table_len];
+
+    let returning = upper
+        .rmatch_indices("RETURNING")
+        .map(|(pos, _)| pos)
+        .find(|&pos| {
+            let before = upper[..pos].chars().next_back();
+            let after = upper[pos + 9..].chars().next();
+
+            before.map_or(false, char::is_whitespace) && after.map_or(false, char::is_whitespace)
+        })?;
+
+    let list = sql[returning + 9..].trim().trim_end_matches(';').trim_end();
+
+    if table.is_empty() || list.is_empty() {
+        return None;
+    }
+
+    Some(format!("SELECT {} FROM {}", list, table))
+}
+
+// ER_NEED_REPREPARE; returned when the server could not transparently prepare a statement
+// again after the tables it uses changed
+fn is_need_reprepare(error: &dyn DatabaseError) -> bool {
+    error
+        .try_downcast_ref::<MySqlDatabaseError>()
+        .map_or(false, |error| error.number() == 1615)
+}
+
+#[test]
+fn test_returning_as_select() {
+    assert_eq!(
+        returning_as_select("INSERT INTO tweet (text) VALUES (?) RETURNING id, text").as_deref(),
+        Some("SELECT id, text FROM tweet")
+    );
+
+    assert_eq!(
+        returning_as_select("insert ignore into `tweet`(text) values (?)\nreturning *;").as_deref(),
+        Some("SELECT * FROM `tweet`")
+    );
+
+    assert_eq!(
+        returning_as_select("DELETE FROM tweet WHERE id = ? RETURNING text").as_deref(),
+        Some("SELECT text FROM tweet")
+    );
+
+    assert_eq!(
+        returning_as_select("INSERT INTO tweet (text) VALUES ('returning')"),
+        None
+    );
+    assert_eq!(returning_as_select("SELECT 1 AS returning_count"), None);
+}
@@ -1,20 +1,26 @@
 use crate::common::StatementCache;
-use crate::connection::{Connection, LogSettings};
+use crate::connection::{Connection, ConnectionStatistics, LogSettings, ServerVersion};
 use crate::error::Error;
+use crate::mysql::protocol::response::Status;
 use crate::mysql::protocol::statement::StmtClose;
 use crate::mysql::protocol::text::{Ping, Quit};
 use crate::mysql::statement::MySqlStatementMetadata;
 use crate::mysql::{MySql, MySqlConnectOptions};
+use crate::net::MaybeTlsStream;
 use crate::transaction::Transaction;
 use futures_core::future::BoxFuture;
+use futures_util::task::noop_waker_ref;
 use futures_util::FutureExt;
 use std::fmt::{self, Debug, Formatter};
+use std::task::Context;
 
 mod auth;
 mod establish;
 mod executor;
 mod stream;
 mod tls;
+#[cfg(feature = "protocol-trace")]
+mod trace;
 
 pub(crate) use stream::{Busy, MySqlStream};
 
@@ -33,7 +39,13 @@ pub struct MySqlConnection {
     // cache by query string to the statement id and metadata
     cache_statement: StatementCache<(u32, MySqlStatementMetadata)>,
 
+    // counters reported by [Connection::statistics]
+    statistics: ConnectionStatistics,
+
     log_settings: LogSettings,
+
+    // whether to send COM_QUIT when dropped without being closed
+    close_on_drop: bool,
 }
 
 impl Debug for MySqlConnection {
@@ -42,6 +54,24 @@ impl Debug for MySqlConnection {
     }
 }
 
+impl Drop for MySqlConnection {
+    fn drop(&mut self) {
+        // after an interrupted write the server would read the packet as part of another one
+        if self.close_on_drop && !self.stream.is_interrupted() {
+            self.stream.sequence_id = 0;
+            self.stream.write_packet(Quit);
+
+            // without a runtime to spawn on, the packet is only written out as far as the
+            // socket accepts it without waiting
+            if !self.stream.flush_in_background(MaybeTlsStream::Upgrading) {
+                let _ = self
+                    .stream
+                    .poll_flush_buffer(&mut Context::from_waker(noop_waker_ref()));
+            }
+        }
+    }
+}
+
 impl Connection for MySqlConnection {
     type Database = MySql;
 
@@ -49,6 +79,8 @@ impl Connection for MySqlConnection {
 
     fn close(mut self) -> BoxFuture<'static, Result<(), Error>> {
         Box::pin(async move {
+            self.close_on_drop = false;
+
             self.stream.send_packet(Quit).await?;
             self.stream.shutdown()?;
 
@@ -66,6 +98,18 @@ impl Connection for MySqlConnection {
         })
     }
 
+    fn statistics(&self) -> ConnectionStatistics {
+        ConnectionStatistics {
+            bytes_sent: self.stream.bytes_written,
+            bytes_received: self.stream.bytes_read,
+            ..self.statistics
+        }
+    }
+
+    fn server_version(&self) -> Option<&ServerVersion> {
+        self.stream.server_version.as_ref()
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         self.stream.wait_until_ready().boxed()
@@ -77,21 +121,29 @@ impl Connection for MySqlConnection {
 
     fn clear_cached_statements(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
+            self.stream.wait_until_ready().await?;
+
+            // COM_STMT_CLOSE has no response so every close can go out in a single write
             while let Some((statement_id, _)) = self.cache_statement.remove_lru() {
-                self.stream
-                    .send_packet(StmtClose {
-                        statement: statement_id,
-                    })
-                    .await?;
+                self.stream.sequence_id = 0;
+                self.stream.write_packet(StmtClose {
+                    statement: statement_id,
+                });
             }
 
+            self.stream.flush().await?;
+
             Ok(())
         })
     }
 
     #[doc(hidden)]
     fn should_flush(&self) -> bool {
-        !self.stream.wbuf.is_empty()
+        !self.stream.wbuf.is_empty() || self.stream.is_interrupted()
+    }
+
+    fn is_in_transaction(&self) -> bool {
+        self.stream.status.contains(Status::SERVER_STATUS_IN_TRANS)
     }
 
     fn begin(&mut self) -> BoxFuture<'_, Result<Transaction<'_, Self::Database>, Error>>
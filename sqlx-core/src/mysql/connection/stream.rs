@@ -1,22 +1,31 @@
 use std::ops::{Deref, DerefMut};
 
 use bytes::{Buf, Bytes};
+use sqlx_rt::fs;
 
+use crate::connection::ServerVersion;
 use crate::error::Error;
 use crate::io::{BufStream, Decode, Encode};
 use crate::mysql::collation::{CharSet, Collation};
 use crate::mysql::io::MySqlBufExt;
 use crate::mysql::protocol::response::{EofPacket, ErrPacket, OkPacket, Status};
+use crate::mysql::protocol::statement::{PrepareOk, StmtClose};
 use crate::mysql::protocol::{Capabilities, Packet};
 use crate::mysql::{MySqlConnectOptions, MySqlDatabaseError};
 use crate::net::{MaybeTlsStream, Socket};
 
 pub struct MySqlStream {
     stream: BufStream<MaybeTlsStream<Socket>>,
-    pub(crate) server_version: (u16, u16, u16),
-    pub(super) capabilities: Capabilities,
+    pub(crate) server_version: Option<ServerVersion>,
+    pub(crate) capabilities: Capabilities,
+
+    // RSA public key of the server given in the options, used instead of requesting it
+    pub(super) server_public_key: Option<Vec<u8>>,
     pub(crate) sequence_id: u8,
     pub(crate) busy: Busy,
+
+    // server status flags from the most recent OK or EOF packet
+    pub(crate) status: Status,
     pub(crate) charset: CharSet,
     pub(crate) collation: Collation,
 }
@@ -25,6 +34,12 @@ pub struct MySqlStream {
 pub(crate) enum Busy {
     NotBusy,
 
+    // waiting for the response to a COM_STMT_PREPARE
+    Prepare,
+
+    // waiting for the parameter and column definitions of a prepared statement
+    PrepareMetadata { statement: u32, remaining: usize },
+
     // waiting for a result set
     Result,
 
@@ -42,9 +57,14 @@ impl MySqlStream {
             .transpose()?
             .unwrap_or_else(|| charset.default_collation());
 
+        let server_public_key = match &options.server_public_key {
+            Some(path) => Some(fs::read(path).await?),
+            None => None,
+        };
+
         let socket = match options.socket {
             Some(ref path) => Socket::connect_uds(path).await?,
-            None => Socket::connect_tcp(&options.host, options.port).await?,
+            None => Socket::connect_tcp(&options.host, options.port, &options.tcp_settings).await?,
         };
 
         let mut capabilities = Capabilities::PROTOCOL_41
@@ -66,8 +86,10 @@ impl MySqlStream {
 
         Ok(Self {
             busy: Busy::NotBusy,
+            status: Status::empty(),
             capabilities,
-            server_version: (0, 0, 0),
+            server_public_key,
+            server_version: None,
             sequence_id: 0,
             collation,
             charset,
@@ -81,11 +103,40 @@ impl MySqlStream {
         }
 
         while self.busy != Busy::NotBusy {
+            while self.busy == Busy::Prepare {
+                match self.recv::<PrepareOk>().await {
+                    Ok(ok) => self.prepared(&ok),
+
+                    // the failure belongs to an operation that was cancelled
+                    Err(Error::Database(_)) => {}
+
+                    Err(error) => return Err(error),
+                }
+            }
+
+            while let Busy::PrepareMetadata {
+                statement,
+                remaining,
+            } = self.busy
+            {
+                if remaining > 0 {
+                    self.recv_packet().await?;
+                    continue;
+                }
+
+                // the statement never made it into the cache, so it would be left open on the
+                // server; COM_STMT_CLOSE goes out with the next command
+                self.sequence_id = 0;
+                self.write_packet(StmtClose { statement });
+                self.busy = Busy::NotBusy;
+            }
+
             while self.busy == Busy::Row {
                 let packet = self.recv_packet().await?;
 
                 if packet[0] == 0xfe && packet.len() < 9 {
                     let eof = packet.eof(self.capabilities)?;
+                    self.status = eof.status;
 
                     self.busy = if eof.status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
                         Busy::Result
@@ -100,6 +151,7 @@ impl MySqlStream {
 
                 if packet[0] == 0x00 || packet[0] == 0xff {
                     let ok = packet.ok()?;
+                    self.status = ok.status;
 
                     if !ok.status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
                         self.busy = Busy::NotBusy;
@@ -127,8 +179,17 @@ impl MySqlStream {
     where
         T: Encode<'en, Capabilities>,
     {
+        #[cfg(feature = "protocol-trace")]
+        let offset = self.stream.wbuf.len();
+
         self.stream
             .write_with(Packet(payload), (self.capabilities, &mut self.sequence_id));
+
+        #[cfg(feature = "protocol-trace")]
+        super::trace::frontend(
+            self.stream.wbuf[offset + 3],
+            &self.stream.wbuf[offset + 4..],
+        );
     }
 
     // receive the next packet from the database server
@@ -137,14 +198,22 @@ impl MySqlStream {
         // https://dev.mysql.com/doc/dev/mysql-server/8.0.12/page_protocol_basic_packets.html
         // https://mariadb.com/kb/en/library/0-packet/#standard-packet
 
-        let mut header: Bytes = self.stream.read(4).await?;
+        let (header, payload) = self
+            .stream
+            .read_frame(4, |mut header| Ok(header.get_uint_le(3) as usize))
+            .await?;
 
-        let packet_size = header.get_uint_le(3) as usize;
-        let sequence_id = header.get_u8();
+        let sequence_id = header[3];
+        let payload = payload.freeze();
 
         self.sequence_id = sequence_id.wrapping_add(1);
 
-        let payload: Bytes = self.stream.read(packet_size).await?;
+        if let Busy::PrepareMetadata { remaining, .. } = &mut self.busy {
+            *remaining = remaining.saturating_sub(1);
+        }
+
+        #[cfg(feature = "protocol-trace")]
+        super::trace::backend(sequence_id, &payload);
 
         // TODO: packet compression
         // TODO: packet joining
@@ -170,7 +239,29 @@ impl MySqlStream {
     }
 
     pub(crate) async fn recv_ok(&mut self) -> Result<OkPacket, Error> {
-        self.recv_packet().await?.ok()
+        let ok = self.recv_packet().await?.ok()?;
+        self.status = ok.status;
+
+        Ok(ok)
+    }
+
+    // called with the COM_STMT_PREPARE_OK packet, which is followed by a definition of each
+    // parameter and column and, unless deprecated, an EOF packet after each of those lists
+    pub(crate) fn prepared(&mut self, ok: &PrepareOk) {
+        let eof = !self.capabilities.contains(Capabilities::DEPRECATE_EOF) as usize;
+
+        let mut remaining = 0;
+
+        for &n in &[ok.params, ok.columns] {
+            if n > 0 {
+                remaining += n as usize + eof;
+            }
+        }
+
+        self.busy = Busy::PrepareMetadata {
+            statement: ok.statement_id,
+            remaining,
+        };
     }
 
     pub(crate) async fn maybe_recv_eof(&mut self) -> Result<Option<EofPacket>, Error> {
@@ -8,7 +8,7 @@ pub(super) async fn maybe_upgrade(
     stream: &mut MySqlStream,
     options: &MySqlConnectOptions,
 ) -> Result<(), Error> {
-    // https://www.postgresql.org/docs/12/libpq-ssl.html#LIBPQ-SSL-SSLMODE-STATEMENTS
+    // https://dev.mysql.com/doc/refman/8.0/en/connection-options.html#option_general_ssl-mode
     match options.ssl_mode {
         MySqlSslMode::Disabled => {}
 
@@ -47,9 +47,12 @@ async fn upgrade(stream: &mut MySqlStream, options: &MySqlConnectOptions) -> Res
     );
     let accept_invalid_host_names = !matches!(options.ssl_mode, MySqlSslMode::VerifyIdentity);
 
+    // the name to send with SNI and to verify the certificate against
+    let server_name = options.ssl_server_name.as_deref().unwrap_or(&options.host);
+
     stream
         .upgrade(
-            &options.host,
+            server_name,
             accept_invalid_certs,
             accept_invalid_host_names,
             options.ssl_ca.as_deref(),
@@ -0,0 +1,69 @@
+use crate::io::trace::{self, sql};
+
+// logs every packet that crosses the wire when the `protocol-trace` feature is enabled
+// https://dev.mysql.com/doc/internals/en/text-protocol.html
+
+pub(super) fn frontend(sequence_id: u8, payload: &[u8]) {
+    trace::frontend(|| summarize_frontend(sequence_id, payload));
+}
+
+pub(super) fn backend(sequence_id: u8, payload: &[u8]) {
+    trace::backend(|| summarize_backend(sequence_id, payload));
+}
+
+fn summarize_frontend(sequence_id: u8, payload: &[u8]) -> String {
+    let len = payload.len();
+
+    // commands always start a new sequence; anything later in a sequence is part of
+    // the handshake or authentication exchange and may carry credentials
+    if sequence_id != 0 || payload.is_empty() {
+        return format!(
+            "Packet {{ seq: {}, len: {}, payload: <redacted> }}",
+            sequence_id, len
+        );
+    }
+
+    match payload[0] {
+        0x01 => format!("ComQuit {{ len: {} }}", len),
+        0x02 => format!("ComInitDb {{ len: {} }}", len),
+        0x03 => format!("ComQuery {{ len: {}, {} }}", len, sql(&payload[1..])),
+        0x0e => format!("ComPing {{ len: {} }}", len),
+        0x16 => format!("ComStmtPrepare {{ len: {}, {} }}", len, sql(&payload[1..])),
+        0x17 => format!("ComStmtExecute {{ len: {}, payload: <redacted> }}", len),
+        0x19 => format!("ComStmtClose {{ len: {} }}", len),
+        0x1f => format!("ComResetConnection {{ len: {} }}", len),
+
+        other => format!("Command(0x{:02x}) {{ len: {} }}", other, len),
+    }
+}
+
+fn summarize_backend(sequence_id: u8, payload: &[u8]) -> String {
+    let kind = match payload.first() {
+        Some(0x00) => "Ok",
+        Some(0xff) => "Err",
+        Some(0xfe) if payload.len() < 9 => "Eof",
+        _ => "Packet",
+    };
+
+    format!(
+        "{} {{ seq: {}, len: {} }}",
+        kind,
+        sequence_id,
+        payload.len()
+    )
+}
+
+#[test]
+fn it_redacts_statement_parameters() {
+    let summary = summarize_frontend(0, b"\x17\x01\0\0\0\0\x01\0\0\0hunter2");
+
+    assert!(summary.starts_with("ComStmtExecute"));
+    assert!(!summary.contains("hunter2"));
+}
+
+#[test]
+fn it_redacts_handshake_response() {
+    let summary = summarize_frontend(1, b"\x03root\0hunter2");
+
+    assert!(!summary.contains("hunter2"));
+}
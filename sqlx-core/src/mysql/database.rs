@@ -1,4 +1,6 @@
-use crate::database::{Database, HasArguments, HasStatement, HasStatementCache, HasValueRef};
+use crate::database::{
+    quote, Database, HasArguments, HasStatement, HasStatementCache, HasValueRef,
+};
 use crate::mysql::value::{MySqlValue, MySqlValueRef};
 use crate::mysql::{
     MySqlArguments, MySqlColumn, MySqlConnection, MySqlDone, MySqlRow, MySqlStatement,
@@ -23,6 +25,26 @@ impl Database for MySql {
     type TypeInfo = MySqlTypeInfo;
 
     type Value = MySqlValue;
+
+    const NAME: &'static str = "mysql";
+
+    // the number of parameters is sent as a 16-bit integer
+    const MAX_BIND_PARAMETERS: usize = 65535;
+}
+
+impl MySql {
+    /// Quote `ident` so it can be interpolated into SQL as an identifier, such as a table or
+    /// column name; it is wrapped with backticks.
+    pub fn quote_identifier(ident: &str) -> String {
+        quote(ident, '`', '`')
+    }
+
+    /// Quote `value` so it can be interpolated into SQL as a string literal; it is wrapped
+    /// with single quotes, escaping backslashes.
+    pub fn quote_literal(value: &str) -> String {
+        // backslashes are escape characters unless the `NO_BACKSLASH_ESCAPES` SQL mode is set
+        quote(&value.replace('\\', r"\\"), '\'', '\'')
+    }
 }
 
 impl<'r> HasValueRef<'r> for MySql {
@@ -46,3 +68,12 @@ impl<'q> HasStatement<'q> for MySql {
 }
 
 impl HasStatementCache for MySql {}
+
+#[test]
+fn it_quotes_identifiers_and_literals() {
+    assert_eq!(MySql::quote_identifier("users"), "`users`");
+    assert_eq!(MySql::quote_identifier("my `table`"), "`my ``table```");
+
+    assert_eq!(MySql::quote_literal("it's"), "'it''s'");
+    assert_eq!(MySql::quote_literal(r"C:\dir\"), r"'C:\\dir\\'");
+}
@@ -2,6 +2,14 @@ use crate::done::Done;
 use crate::mysql::MySql;
 use std::iter::{Extend, IntoIterator};
 
+/// The result of executing a query with MySQL, as returned by
+/// [`Executor::execute`](crate::executor::Executor::execute).
+///
+/// As MySQL has no `RETURNING` clause, the value generated for an `AUTO_INCREMENT` column by an
+/// `INSERT` is available from [`last_insert_id`](MySqlDone::last_insert_id). MariaDB 10.5 and
+/// later also support `INSERT .. RETURNING`, which returns rows like a `SELECT`; check for
+/// [`ServerFlavor::MariaDb`](crate::connection::ServerFlavor::MariaDb) with
+/// [`Connection::server_version`](crate::connection::Connection::server_version) to use it.
 #[derive(Debug, Default)]
 pub struct MySqlDone {
     pub(super) rows_affected: u64,
@@ -9,6 +17,10 @@ pub struct MySqlDone {
 }
 
 impl MySqlDone {
+    /// Returns the value generated for an `AUTO_INCREMENT` column by the last `INSERT`.
+    ///
+    /// If a single `INSERT` statement inserts multiple rows, this is the value generated for
+    /// the first row. It is `0` if the statement did not generate a value.
     pub fn last_insert_id(&self) -> u64 {
         self.last_insert_id
     }
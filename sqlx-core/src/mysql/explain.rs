@@ -0,0 +1,42 @@
+use crate::error::Error;
+use crate::executor::Executor;
+use crate::mysql::{MySql, MySqlArguments, MySqlRow};
+use crate::query::Query;
+use crate::row::Row;
+
+impl<'q> Query<'q, MySql, MySqlArguments> {
+    /// Returns the rows of `EXPLAIN` for this query, one per table that it accesses.
+    ///
+    /// Columns are looked up by name, e.g. `table`, `type`, `key` and `rows`; see the
+    /// [MySQL manual](https://dev.mysql.com/doc/refman/8.0/en/explain-output.html).
+    ///
+    /// ```rust,ignore
+    /// let plan = sqlx::query("SELECT * FROM users WHERE id = ?")
+    ///     .bind(42_i64)
+    ///     .explain(&pool)
+    ///     .await?;
+    ///
+    /// let key: Option<String> = plan[0].try_get("key")?;
+    /// ```
+    pub async fn explain<'c, E>(self, executor: E) -> Result<Vec<MySqlRow>, Error>
+    where
+        E: Executor<'c, Database = MySql>,
+    {
+        let sql = format!("EXPLAIN {}", self.sql_str());
+
+        executor.fetch_all(self.with_sql(&sql)).await
+    }
+
+    /// Runs this query with `EXPLAIN ANALYZE` and returns the resulting plan tree as text.
+    ///
+    /// This requires MySQL 8.0.18 or later, and **executes** the query.
+    pub async fn explain_analyze<'c, E>(self, executor: E) -> Result<String, Error>
+    where
+        E: Executor<'c, Database = MySql>,
+    {
+        let sql = format!("EXPLAIN ANALYZE {}", self.sql_str());
+        let row = executor.fetch_one(self.with_sql(&sql)).await?;
+
+        row.try_get(0)
+    }
+}
@@ -38,7 +38,10 @@ impl MigrateDatabase for MySql {
             let mut conn = options.connect().await?;
 
             let _ = conn
-                .execute(&*format!("CREATE DATABASE `{}`", database))
+                .execute(&*format!(
+                    "CREATE DATABASE {}",
+                    MySql::quote_identifier(&database)
+                ))
                 .await?;
 
             Ok(())
@@ -67,7 +70,10 @@ impl MigrateDatabase for MySql {
             let mut conn = options.connect().await?;
 
             let _ = conn
-                .execute(&*format!("DROP DATABASE IF EXISTS `{}`", database,))
+                .execute(&*format!(
+                    "DROP DATABASE IF EXISTS {}",
+                    MySql::quote_identifier(&database)
+                ))
                 .await?;
 
             Ok(())
@@ -201,6 +207,39 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
             Ok(elapsed)
         })
     }
+
+    fn revert<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
+        Box::pin(async move {
+            let start = Instant::now();
+
+            // MySQL cannot roll back DDL so, like `apply`, a failure leaves the version
+            // marked as dirty rather than pretending it was never touched
+            let res = self.execute(&*migration.sql).await;
+
+            let elapsed = start.elapsed();
+
+            if res.is_ok() {
+                // language=MySQL
+                let _ = query("DELETE FROM _sqlx_migrations WHERE version = ?")
+                    .bind(migration.version)
+                    .execute(&mut *self)
+                    .await?;
+            } else {
+                // language=MySQL
+                let _ = query("UPDATE _sqlx_migrations SET success = FALSE WHERE version = ?")
+                    .bind(migration.version)
+                    .execute(&mut *self)
+                    .await?;
+            }
+
+            res?;
+
+            Ok(elapsed)
+        })
+    }
 }
 
 async fn current_database(conn: &mut MySqlConnection) -> Result<String, MigrateError> {
@@ -7,9 +7,14 @@ mod connection;
 mod database;
 mod done;
 mod error;
+mod explain;
 mod io;
 mod options;
+#[cfg(not(feature = "unstable-protocol"))]
 mod protocol;
+#[cfg(feature = "unstable-protocol")]
+pub mod protocol;
+mod replication;
 mod row;
 mod statement;
 mod transaction;
@@ -27,6 +32,7 @@ pub use database::MySql;
 pub use done::MySqlDone;
 pub use error::MySqlDatabaseError;
 pub use options::{MySqlConnectOptions, MySqlSslMode};
+pub use replication::{BinlogEvent, BinlogRow, BinlogTable, BinlogValue, MySqlReplication};
 pub use row::MySqlRow;
 pub use statement::MySqlStatement;
 pub use transaction::MySqlTransactionManager;
@@ -51,3 +57,4 @@ impl_into_maybe_pool!(MySql, MySqlConnection);
 
 // required because some databases have a different handling of NULL
 impl_encode_for_option!(MySql);
+impl_encode_for_null!(MySql);
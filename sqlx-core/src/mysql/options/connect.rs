@@ -4,6 +4,7 @@ use crate::executor::Executor;
 use crate::mysql::{MySqlConnectOptions, MySqlConnection};
 use futures_core::future::BoxFuture;
 use log::LevelFilter;
+use std::borrow::Cow;
 use std::time::Duration;
 
 impl ConnectOptions for MySqlConnectOptions {
@@ -14,7 +15,13 @@ impl ConnectOptions for MySqlConnectOptions {
         Self::Connection: Sized,
     {
         Box::pin(async move {
-            let mut conn = MySqlConnection::establish(self).await?;
+            let mut options = Cow::Borrowed(self);
+
+            if let Some(provider) = &self.password_provider {
+                options.to_mut().password = Some(provider.password().await?);
+            }
+
+            let mut conn = MySqlConnection::establish(&options).await?;
 
             // After the connection is established, we initialize by configuring a few
             // connection parameters
@@ -42,8 +49,18 @@ impl ConnectOptions for MySqlConnectOptions {
             // https://mathiasbynens.be/notes/mysql-utf8mb4
 
             let mut options = String::new();
-            options.push_str(r#"SET sql_mode=(SELECT CONCAT(@@sql_mode, ',PIPES_AS_CONCAT,NO_ENGINE_SUBSTITUTION')),"#);
-            options.push_str(r#"time_zone='+00:00',"#);
+
+            match &self.sql_mode {
+                Some(sql_mode) => {
+                    options.push_str(&format!("SET sql_mode={},", quote(sql_mode)));
+                }
+
+                None => {
+                    options.push_str(r#"SET sql_mode=(SELECT CONCAT(@@sql_mode, ',PIPES_AS_CONCAT,NO_ENGINE_SUBSTITUTION')),"#);
+                }
+            }
+
+            options.push_str(&format!("time_zone={},", quote(&self.time_zone)));
             options.push_str(&format!(
                 r#"NAMES {} COLLATE {};"#,
                 conn.stream.charset.as_str(),
@@ -65,4 +82,14 @@ impl ConnectOptions for MySqlConnectOptions {
         self.log_settings.log_slow_statements(level, duration);
         self
     }
+
+    fn log_binds(&mut self, enabled: bool) -> &mut Self {
+        self.log_settings.log_binds(enabled);
+        self
+    }
+}
+
+// quotes a value given in the options as a string literal
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
 }
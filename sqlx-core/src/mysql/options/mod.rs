@@ -1,10 +1,15 @@
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 mod connect;
 mod parse;
 mod ssl_mode;
 
+use crate::common::{PasswordProvider, Secret};
 use crate::connection::LogSettings;
+use crate::error::BoxDynError;
+use crate::net::{Socks5Proxy, TcpSettings};
+use futures_core::future::BoxFuture;
 pub use ssl_mode::MySqlSslMode;
 
 /// Options and flags which can be used to configure a MySQL connection.
@@ -24,8 +29,12 @@ pub use ssl_mode::MySqlSslMode;
 /// |---------|-------|-----------|
 /// | `ssl-mode` | `PREFERRED` | Determines whether or with what priority a secure SSL TCP/IP connection will be negotiated. See [`MySqlSslMode`]. |
 /// | `ssl-ca` | `None` | Sets the name of a file containing a list of trusted SSL Certificate Authorities. |
+/// | `ssl-server-name` | `None` | The host name sent with SNI and verified with `VERIFY_IDENTITY`, instead of the host connected to. See [`ssl_server_name`](MySqlConnectOptions::ssl_server_name). |
 /// | `statement-cache-capacity` | `100` | The maximum number of prepared statements stored in the cache. Set to `0` to disable. |
+/// | `server-public-key-path` | `None` | Path to the RSA public key of the server. See [`server_public_key_path`](MySqlConnectOptions::server_public_key_path). |
 /// | `socket` | `None` | Path to the unix domain socket, which will be used instead of TCP if set. |
+/// | `sql-mode` | `None` | The SQL mode of the session, replacing the server default. See [`sql_mode`](MySqlConnectOptions::sql_mode). |
+/// | `time-zone` | `+00:00` | The time zone of the session. |
 ///
 /// # Example
 ///
@@ -59,13 +68,20 @@ pub struct MySqlConnectOptions {
     pub(crate) port: u16,
     pub(crate) socket: Option<PathBuf>,
     pub(crate) username: String,
-    pub(crate) password: Option<String>,
+    pub(crate) password: Option<Secret>,
+    pub(crate) password_provider: Option<PasswordProvider>,
     pub(crate) database: Option<String>,
     pub(crate) ssl_mode: MySqlSslMode,
     pub(crate) ssl_ca: Option<PathBuf>,
+    pub(crate) ssl_server_name: Option<String>,
+    pub(crate) server_public_key: Option<PathBuf>,
     pub(crate) statement_cache_capacity: usize,
     pub(crate) charset: String,
     pub(crate) collation: Option<String>,
+    pub(crate) sql_mode: Option<String>,
+    pub(crate) time_zone: String,
+    pub(crate) close_on_drop: bool,
+    pub(crate) tcp_settings: TcpSettings,
     pub(crate) log_settings: LogSettings,
 }
 
@@ -84,12 +100,19 @@ impl MySqlConnectOptions {
             socket: None,
             username: String::from("root"),
             password: None,
+            password_provider: None,
             database: None,
             charset: String::from("utf8mb4"),
             collation: None,
+            sql_mode: None,
+            time_zone: String::from("+00:00"),
             ssl_mode: MySqlSslMode::Preferred,
             ssl_ca: None,
+            ssl_server_name: None,
+            server_public_key: None,
             statement_cache_capacity: 100,
+            close_on_drop: true,
+            tcp_settings: Default::default(),
             log_settings: Default::default(),
         }
     }
@@ -128,7 +151,34 @@ impl MySqlConnectOptions {
 
     /// Sets the password to connect with.
     pub fn password(mut self, password: &str) -> Self {
-        self.password = Some(password.to_owned());
+        self.password = Some(password.into());
+        self
+    }
+
+    /// Sets a callback that fetches the password for every new connection.
+    ///
+    /// This is for short-lived credentials, such as AWS RDS IAM authentication tokens or
+    /// passwords leased from Vault, that would expire if they were set once with
+    /// [`password`](MySqlConnectOptions::password). The callback is invoked each time a connection
+    /// is opened, e.g. by a pool, and takes precedence over `password`. An error from the
+    /// callback fails the connection attempt.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use sqlx_core::mysql::MySqlConnectOptions;
+    /// let options = MySqlConnectOptions::new()
+    ///     .username("app")
+    ///     .password_provider(|| Box::pin(async move {
+    ///         let token = fetch_rds_auth_token().await?;
+    ///         Ok(token)
+    ///     }));
+    /// ```
+    pub fn password_provider<F>(mut self, provider: F) -> Self
+    where
+        F: Fn() -> BoxFuture<'static, Result<String, BoxDynError>> + 'static + Send + Sync,
+    {
+        self.password_provider = Some(PasswordProvider::new(provider));
         self
     }
 
@@ -171,21 +221,161 @@ impl MySqlConnectOptions {
         self
     }
 
+    /// Sets the host name that is sent to the server with Server Name Indication (SNI) and that
+    /// the certificate of the server must be issued for with
+    /// [`VerifyIdentity`](MySqlSslMode::VerifyIdentity).
+    ///
+    /// Defaults to the host connected to. Set this when the server is reached under a name that
+    /// is not in its certificate, such as through a tunnel to `localhost` or by IP address.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::mysql::{MySqlSslMode, MySqlConnectOptions};
+    /// let options = MySqlConnectOptions::new()
+    ///     .host("127.0.0.1")
+    ///     .port(13306)
+    ///     .ssl_mode(MySqlSslMode::VerifyIdentity)
+    ///     .ssl_server_name("db.example.com");
+    /// ```
+    pub fn ssl_server_name(mut self, name: &str) -> Self {
+        self.ssl_server_name = Some(name.to_owned());
+        self
+    }
+
+    /// Sets the name of a file containing the RSA public key of the server, in PEM format.
+    ///
+    /// Without TLS, the `caching_sha2_password` and `sha256_password` authentication plugins
+    /// encrypt the password with this key. If it is not set, the key is requested from the
+    /// server, which costs a round trip and trusts whichever key is sent back.
+    ///
+    /// The key of the server is usually found at `public_key.pem` in its data directory.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::mysql::MySqlConnectOptions;
+    /// let options = MySqlConnectOptions::new()
+    ///     .server_public_key_path("path/to/public_key.pem");
+    /// ```
+    pub fn server_public_key_path(mut self, file_name: impl AsRef<Path>) -> Self {
+        self.server_public_key = Some(file_name.as_ref().to_owned());
+        self
+    }
+
     /// Sets the capacity of the connection's statement cache in a number of stored
     /// distinct statements. Caching is handled using LRU, meaning when the
     /// amount of queries hits the defined limit, the oldest statement will get
     /// dropped.
     ///
-    /// The default cache capacity is 100 statements.
+    /// The default cache capacity is 100 statements. Set to `0` to disable caching, which keeps
+    /// the memory used for prepared statements on the server from growing when many distinct
+    /// queries are executed; every statement is then prepared again for each execution and
+    /// closed afterwards.
     pub fn statement_cache_capacity(mut self, capacity: usize) -> Self {
         self.statement_cache_capacity = capacity;
         self
     }
 
+    /// Sets whether a connection that is dropped without calling
+    /// [`close`](crate::connection::Connection::close) still sends `COM_QUIT` to the server.
+    ///
+    /// This lets the server tell a deliberate disconnect apart from a lost connection, so it
+    /// does not log an error for every dropped connection. The message is sent from a task of
+    /// its own when the connection is dropped inside of a runtime, and otherwise only written
+    /// as far as the socket accepts it without waiting; it is not guaranteed to be sent either
+    /// way. Enabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::mysql::MySqlConnectOptions;
+    /// let options = MySqlConnectOptions::new()
+    ///     .close_on_drop(false);
+    /// ```
+    pub fn close_on_drop(mut self, close_on_drop: bool) -> Self {
+        self.close_on_drop = close_on_drop;
+        self
+    }
+
+    /// Sets whether `TCP_NODELAY` is set on the socket, disabling Nagle's algorithm.
+    ///
+    /// Enabled by default. Ignored for Unix domain socket communication.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::mysql::MySqlConnectOptions;
+    /// let options = MySqlConnectOptions::new()
+    ///     .tcp_nodelay(false);
+    /// ```
+    pub fn tcp_nodelay(mut self, nodelay: bool) -> Self {
+        self.tcp_settings.nodelay = nodelay;
+        self
+    }
+
+    /// Enables TCP keepalive on the socket, sending the first probe after the connection
+    /// has been idle for `interval`.
+    ///
+    /// Disabled by default. Only supported by the Tokio and Actix runtimes.
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_settings.keepalive = Some(interval);
+        self
+    }
+
+    /// Sets the size of the socket send buffer (`SO_SNDBUF`) in bytes.
+    ///
+    /// Defaults to the operating system setting. Only supported by the Tokio and Actix runtimes.
+    pub fn tcp_send_buffer_size(mut self, size: usize) -> Self {
+        self.tcp_settings.send_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets the size of the socket receive buffer (`SO_RCVBUF`) in bytes.
+    ///
+    /// Defaults to the operating system setting. Only supported by the Tokio and Actix runtimes.
+    pub fn tcp_recv_buffer_size(mut self, size: usize) -> Self {
+        self.tcp_settings.recv_buffer_size = Some(size);
+        self
+    }
+
+    /// Connects to the server through a SOCKS5 proxy.
+    ///
+    /// The host name of the server is resolved by the proxy. To reach a server behind an SSH
+    /// bastion host, open a dynamic port forward with `ssh -D 1080 <bastion>` and use
+    /// `localhost:1080` as the proxy.
+    ///
+    /// Ignored for Unix domain socket communication.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::mysql::MySqlConnectOptions;
+    /// let options = MySqlConnectOptions::new()
+    ///     .host("db.internal")
+    ///     .socks5_proxy("localhost", 1080);
+    /// ```
+    pub fn socks5_proxy(mut self, host: &str, port: u16) -> Self {
+        self.tcp_settings.socks5_proxy = Some(Socks5Proxy::new(host, port));
+        self
+    }
+
+    /// Sets the username and password to authenticate with the SOCKS5 proxy.
+    ///
+    /// Can be called before or after `socks5_proxy`; has no effect if no proxy is set.
+    pub fn socks5_proxy_credentials(mut self, username: &str, password: &str) -> Self {
+        self.tcp_settings.socks5_credentials = Some((username.to_owned(), password.into()));
+        self
+    }
+
     /// Sets the character set for the connection.
     ///
     /// The default character set is `utf8mb4`. This is supported from MySQL 5.5.3.
     /// If you need to connect to an older version, we recommend you to change this to `utf8`.
+    ///
+    /// Text is sent by the server in this character set, whatever the character set of the
+    /// column is. Decoding text into `String` or `&str` is only supported for `utf8mb4`, `utf8`
+    /// and `ascii`.
     pub fn charset(mut self, charset: &str) -> Self {
         self.charset = charset.to_owned();
         self
@@ -199,4 +389,32 @@ impl MySqlConnectOptions {
         self.collation = Some(collation.to_owned());
         self
     }
+
+    /// Sets the SQL mode of every session, replacing the server default.
+    ///
+    /// By default, `PIPES_AS_CONCAT` and `NO_ENGINE_SUBSTITUTION` are added to the SQL mode of
+    /// the server. The query macros rely on `PIPES_AS_CONCAT`, so it should be included if they
+    /// are used with `||`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::mysql::MySqlConnectOptions;
+    /// let options = MySqlConnectOptions::new()
+    ///     .sql_mode("STRICT_TRANS_TABLES,PIPES_AS_CONCAT,NO_ENGINE_SUBSTITUTION");
+    /// ```
+    pub fn sql_mode(mut self, sql_mode: &str) -> Self {
+        self.sql_mode = Some(sql_mode.to_owned());
+        self
+    }
+
+    /// Sets the time zone of every session, such as `+02:00` or, if the time zone tables of the
+    /// server are populated, `Europe/Berlin`.
+    ///
+    /// Defaults to UTC (`+00:00`). `TIMESTAMP` values are converted to this time zone before
+    /// they are sent, but are always decoded as if they were in UTC.
+    pub fn time_zone(mut self, time_zone: &str) -> Self {
+        self.time_zone = time_zone.to_owned();
+        self
+    }
 }
@@ -38,7 +38,11 @@ impl FromStr for MySqlConnectOptions {
 
         let path = url.path().trim_start_matches('/');
         if !path.is_empty() {
-            options = options.database(path);
+            options = options.database(
+                &*percent_decode_str(path)
+                    .decode_utf8()
+                    .map_err(Error::config)?,
+            );
         }
 
         for (key, value) in url.query_pairs().into_iter() {
@@ -51,6 +55,14 @@ impl FromStr for MySqlConnectOptions {
                     options = options.ssl_ca(&*value);
                 }
 
+                "ssl-server-name" => {
+                    options = options.ssl_server_name(&*value);
+                }
+
+                "server-public-key-path" => {
+                    options = options.server_public_key_path(&*value);
+                }
+
                 "charset" => {
                     options = options.charset(&*value);
                 }
@@ -68,6 +80,14 @@ impl FromStr for MySqlConnectOptions {
                     options = options.socket(&*value);
                 }
 
+                "sql-mode" => {
+                    options = options.sql_mode(&*value);
+                }
+
+                "time-zone" => {
+                    options = options.time_zone(&*value);
+                }
+
                 _ => {}
             }
         }
@@ -84,6 +104,14 @@ fn it_parses_username_with_at_sign_correctly() {
     assert_eq!("user@hostname", &opts.username);
 }
 
+#[test]
+fn it_parses_database_percent_encoded() {
+    let uri = "mysql://username@hostname/my%20database";
+    let opts = MySqlConnectOptions::from_str(uri).unwrap();
+
+    assert_eq!(Some("my database"), opts.database.as_deref());
+}
+
 #[test]
 fn it_parses_password_with_non_ascii_chars_correctly() {
     let uri = "mysql://username:p@ssw0rd@hostname:5432/database";
@@ -91,3 +119,44 @@ fn it_parses_password_with_non_ascii_chars_correctly() {
 
     assert_eq!(Some("p@ssw0rd".into()), opts.password);
 }
+
+#[test]
+fn it_parses_session_settings() {
+    let url = "mysql://root@localhost/db?sql-mode=ANSI&time-zone=%2B02:00";
+    let opts = MySqlConnectOptions::from_str(url).unwrap();
+
+    assert_eq!(opts.sql_mode.as_deref(), Some("ANSI"));
+    assert_eq!(opts.time_zone, "+02:00");
+}
+
+#[test]
+fn it_parses_server_public_key_path() {
+    let url = "mysql://root@localhost/db?server-public-key-path=/var/lib/mysql/public_key.pem";
+    let opts = MySqlConnectOptions::from_str(url).unwrap();
+
+    assert_eq!(
+        opts.server_public_key.as_deref(),
+        Some(std::path::Path::new("/var/lib/mysql/public_key.pem"))
+    );
+}
+
+#[test]
+fn it_parses_ssl_options() {
+    let url = "mysql://root@127.0.0.1/db?ssl-mode=VERIFY_IDENTITY&ssl-server-name=db.example.com";
+    let opts = MySqlConnectOptions::from_str(url).unwrap();
+
+    assert!(matches!(
+        opts.ssl_mode,
+        crate::mysql::MySqlSslMode::VerifyIdentity
+    ));
+    assert_eq!(opts.ssl_server_name.as_deref(), Some("db.example.com"));
+
+    let url = "mysql://root@localhost/db?ssl-mode=verify_ca";
+    let opts = MySqlConnectOptions::from_str(url).unwrap();
+
+    assert!(matches!(
+        opts.ssl_mode,
+        crate::mysql::MySqlSslMode::VerifyCa
+    ));
+    assert!(MySqlConnectOptions::from_str("mysql://localhost/?ssl-mode=verify").is_err());
+}
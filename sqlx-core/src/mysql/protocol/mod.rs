@@ -1,12 +1,71 @@
+//! The packets of the MySQL client/server protocol and how they are framed.
+//!
+//! With the `unstable-protocol` feature, the generic response packets and packet framing are
+//! exposed so that tests, fuzzers and other tooling can exercise the decoders without a
+//! server. Anything public in this module may change in any release.
+
 pub(crate) mod auth;
 mod capabilities;
 pub(crate) mod connect;
 mod packet;
-pub(crate) mod response;
+pub(crate) mod replication;
+pub mod response;
 mod row;
 pub(crate) mod statement;
 pub(crate) mod text;
 
-pub(crate) use capabilities::Capabilities;
-pub(crate) use packet::Packet;
+pub use capabilities::Capabilities;
+pub use packet::Packet;
 pub(crate) use row::Row;
+
+#[cfg(feature = "unstable-protocol")]
+pub use crate::io::{Decode, Encode};
+
+/// Splits the next packet off the front of `buf`, exactly as the connection frames the data
+/// it reads from the server, and returns its sequence id and payload.
+///
+/// Returns `None`, leaving `buf` untouched, if it does not yet hold a complete packet.
+#[cfg(feature = "unstable-protocol")]
+pub fn split_packet(buf: &mut bytes::Bytes) -> Option<(u8, bytes::Bytes)> {
+    use bytes::Buf;
+
+    if buf.len() < 4 {
+        return None;
+    }
+
+    let len = (&buf[..3]).get_uint_le(3) as usize;
+
+    if buf.len() - 4 < len {
+        return None;
+    }
+
+    let sequence_id = buf[3];
+
+    let mut payload = buf.split_to(4 + len);
+    payload.advance(4);
+
+    Some((sequence_id, payload))
+}
+
+#[cfg(all(test, feature = "unstable-protocol"))]
+mod tests {
+    use bytes::Bytes;
+
+    use super::response::OkPacket;
+    use super::{split_packet, Decode};
+
+    #[test]
+    fn it_splits_packets() {
+        let mut buf = Bytes::from_static(b"\x07\0\0\x02\0\0\0\x02\0\0\0\x01\0\0\x03");
+
+        let (sequence_id, payload) = split_packet(&mut buf).unwrap();
+        assert_eq!(sequence_id, 2);
+
+        let ok = OkPacket::decode(payload).unwrap();
+        assert_eq!(ok.affected_rows, 0);
+
+        // the next packet is incomplete
+        assert!(split_packet(&mut buf).is_none());
+        assert_eq!(buf.len(), 4);
+    }
+}
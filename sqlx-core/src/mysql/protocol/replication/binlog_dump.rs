@@ -0,0 +1,130 @@
+use crate::error::Error;
+use crate::io::Encode;
+use crate::mysql::protocol::Capabilities;
+
+// https://dev.mysql.com/doc/internals/en/com-binlog-dump.html
+
+#[derive(Debug)]
+pub(crate) struct BinlogDump<'a> {
+    pub(crate) server_id: u32,
+    pub(crate) file: &'a str,
+    pub(crate) position: u32,
+}
+
+impl Encode<'_, Capabilities> for BinlogDump<'_> {
+    fn encode_with(&self, buf: &mut Vec<u8>, _: Capabilities) {
+        buf.push(0x12); // COM_BINLOG_DUMP
+        buf.extend(&self.position.to_le_bytes());
+        buf.extend(&0_u16.to_le_bytes()); // flags; 0 blocks for new events
+        buf.extend(&self.server_id.to_le_bytes());
+        buf.extend(self.file.as_bytes());
+    }
+}
+
+// https://dev.mysql.com/doc/internals/en/com-binlog-dump-gtid.html
+
+const BINLOG_THROUGH_GTID: u16 = 0x04;
+
+#[derive(Debug)]
+pub(crate) struct BinlogDumpGtid {
+    pub(crate) server_id: u32,
+    pub(crate) gtid_set: GtidSet,
+}
+
+impl Encode<'_, Capabilities> for BinlogDumpGtid {
+    fn encode_with(&self, buf: &mut Vec<u8>, _: Capabilities) {
+        buf.push(0x1e); // COM_BINLOG_DUMP_GTID
+        buf.extend(&BINLOG_THROUGH_GTID.to_le_bytes());
+        buf.extend(&self.server_id.to_le_bytes());
+        buf.extend(&0_u32.to_le_bytes()); // binlog file name length; the server picks the file
+        buf.extend(&4_u64.to_le_bytes()); // binlog position; the first event after the header
+
+        let offset = buf.len();
+        buf.extend(&0_u32.to_le_bytes()); // reserve space for the length of the GTID set
+
+        self.gtid_set.encode(buf);
+
+        let len = (buf.len() - offset - 4) as u32;
+        buf[offset..offset + 4].copy_from_slice(&len.to_le_bytes());
+    }
+}
+
+/// A set of transactions, such as `3E11FA47-71CA-11E1-9E33-C80AA9429562:1-5:11`, in the binary
+/// encoding used by replication.
+#[derive(Debug, Default)]
+pub(crate) struct GtidSet {
+    // server UUID and the intervals of transaction numbers, with exclusive ends
+    sids: Vec<([u8; 16], Vec<(i64, i64)>)>,
+}
+
+impl GtidSet {
+    pub(crate) fn parse(s: &str) -> Result<Self, Error> {
+        let mut sids = Vec::new();
+
+        for sid in s.split(',').map(str::trim).filter(|sid| !sid.is_empty()) {
+            let mut parts = sid.split(':');
+
+            let uuid = parts.next().unwrap_or_default().replace('-', "");
+            let mut bytes = [0; 16];
+
+            hex::decode_to_slice(&uuid, &mut bytes).map_err(|_| {
+                Error::Configuration(format!("invalid server UUID in GTID set: {:?}", sid).into())
+            })?;
+
+            let mut intervals = Vec::new();
+
+            for interval in parts {
+                let mut bounds = interval.splitn(2, '-').map(str::parse::<i64>);
+
+                let (start, end) = match (bounds.next(), bounds.next()) {
+                    (Some(Ok(start)), None) => (start, start),
+                    (Some(Ok(start)), Some(Ok(end))) => (start, end),
+
+                    _ => {
+                        return Err(Error::Configuration(
+                            format!("invalid interval in GTID set: {:?}", interval).into(),
+                        ));
+                    }
+                };
+
+                intervals.push((start, end + 1));
+            }
+
+            sids.push((bytes, intervals));
+        }
+
+        Ok(Self { sids })
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend(&(self.sids.len() as u64).to_le_bytes());
+
+        for (sid, intervals) in &self.sids {
+            buf.extend(sid);
+            buf.extend(&(intervals.len() as u64).to_le_bytes());
+
+            for (start, end) in intervals {
+                buf.extend(&start.to_le_bytes());
+                buf.extend(&end.to_le_bytes());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_encode_gtid_set() {
+    let set = GtidSet::parse("3E11FA47-71CA-11E1-9E33-C80AA9429562:1-5:11").unwrap();
+
+    let mut buf = Vec::new();
+    set.encode(&mut buf);
+
+    assert_eq!(buf.len(), 8 + 16 + 8 + 2 * 16);
+    assert_eq!(&buf[8..10], b"\x3E\x11");
+    assert_eq!(&buf[32..40], &1_i64.to_le_bytes());
+    assert_eq!(&buf[40..48], &6_i64.to_le_bytes());
+    assert_eq!(&buf[48..56], &11_i64.to_le_bytes());
+    assert_eq!(&buf[56..64], &12_i64.to_le_bytes());
+
+    assert!(GtidSet::parse("").unwrap().sids.is_empty());
+    assert!(GtidSet::parse("not-a-uuid:1").is_err());
+}
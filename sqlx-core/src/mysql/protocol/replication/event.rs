@@ -0,0 +1,816 @@
+use std::fmt::Write;
+
+use bytes::{Buf, Bytes};
+
+use crate::error::Error;
+use crate::io::BufExt;
+use crate::mysql::io::MySqlBufExt;
+use crate::mysql::replication::{BinlogRow, BinlogTable, BinlogValue};
+
+// https://dev.mysql.com/doc/internals/en/binlog-event-type.html
+
+const QUERY_EVENT: u8 = 2;
+const ROTATE_EVENT: u8 = 4;
+const XID_EVENT: u8 = 16;
+const TABLE_MAP_EVENT: u8 = 19;
+const WRITE_ROWS_EVENT_V1: u8 = 23;
+const UPDATE_ROWS_EVENT_V1: u8 = 24;
+const DELETE_ROWS_EVENT_V1: u8 = 25;
+const WRITE_ROWS_EVENT: u8 = 30;
+const UPDATE_ROWS_EVENT: u8 = 31;
+const DELETE_ROWS_EVENT: u8 = 32;
+const GTID_EVENT: u8 = 33;
+const PARTIAL_UPDATE_ROWS_EVENT: u8 = 39;
+
+// column types that only appear in the binary log
+const TYPE_TIMESTAMP2: u8 = 0x11;
+const TYPE_DATETIME2: u8 = 0x12;
+const TYPE_TIME2: u8 = 0x13;
+
+// https://dev.mysql.com/doc/internals/en/binlog-event-header.html
+
+#[derive(Debug)]
+pub(crate) struct EventHeader {
+    pub(crate) event_type: u8,
+
+    // position of the next event in the binary log
+    pub(crate) log_pos: u32,
+}
+
+#[derive(Debug)]
+pub(crate) enum Event {
+    Rotate { file: String, position: u64 },
+    Query { schema: String, query: String },
+    Gtid(String),
+    Xid(u64),
+    TableMap(u64, BinlogTable),
+    Rows(RowsEvent),
+
+    // an event that is of no interest to the client, such as a heartbeat
+    Other,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum RowsKind {
+    Write,
+    Update,
+    Delete,
+}
+
+#[derive(Debug)]
+pub(crate) struct RowsEvent {
+    pub(crate) kind: RowsKind,
+    pub(crate) table_id: u64,
+
+    // version 2 of the rows events carries extra data after the flags
+    has_extra_data: bool,
+    body: Bytes,
+}
+
+impl Event {
+    // the buffer starts after the OK byte that precedes every event in the dump
+    pub(crate) fn decode(mut buf: Bytes) -> Result<(EventHeader, Self), Error> {
+        if buf.len() < 19 {
+            return Err(err_protocol!("binlog event is shorter than its header"));
+        }
+
+        let _timestamp = buf.get_u32_le();
+        let event_type = buf.get_u8();
+        let _server_id = buf.get_u32_le();
+        let _event_size = buf.get_u32_le();
+        let log_pos = buf.get_u32_le();
+        let _flags = buf.get_u16_le();
+
+        let header = EventHeader {
+            event_type,
+            log_pos,
+        };
+
+        let event = match event_type {
+            ROTATE_EVENT => {
+                ensure(&buf, 8)?;
+
+                let position = buf.get_u64_le();
+                let file = buf.get_str(buf.len())?;
+
+                Event::Rotate { file, position }
+            }
+
+            QUERY_EVENT => {
+                ensure(&buf, 13)?;
+
+                let _thread_id = buf.get_u32_le();
+                let _exec_time = buf.get_u32_le();
+                let schema_len = buf.get_u8();
+                let _error_code = buf.get_u16_le();
+                let status_len = buf.get_u16_le();
+
+                ensure(&buf, usize::from(status_len) + usize::from(schema_len) + 1)?;
+                buf.advance(usize::from(status_len));
+
+                let schema = buf.get_str(usize::from(schema_len))?;
+                buf.advance(1);
+
+                let query = buf.get_str(buf.len())?;
+
+                Event::Query { schema, query }
+            }
+
+            GTID_EVENT => {
+                ensure(&buf, 25)?;
+
+                let _flags = buf.get_u8();
+                let sid = buf.get_bytes(16);
+                let gno = buf.get_i64_le();
+
+                Event::Gtid(format_gtid(&sid, gno))
+            }
+
+            XID_EVENT => {
+                ensure(&buf, 8)?;
+
+                Event::Xid(buf.get_u64_le())
+            }
+
+            TABLE_MAP_EVENT => {
+                let (table_id, table) = decode_table_map(buf)?;
+
+                Event::TableMap(table_id, table)
+            }
+
+            WRITE_ROWS_EVENT_V1 | WRITE_ROWS_EVENT => {
+                decode_rows(RowsKind::Write, event_type, buf)?
+            }
+            UPDATE_ROWS_EVENT_V1 | UPDATE_ROWS_EVENT => {
+                decode_rows(RowsKind::Update, event_type, buf)?
+            }
+            DELETE_ROWS_EVENT_V1 | DELETE_ROWS_EVENT => {
+                decode_rows(RowsKind::Delete, event_type, buf)?
+            }
+
+            // skipping the event would silently lose the update of a row
+            PARTIAL_UPDATE_ROWS_EVENT => {
+                return Err(err_protocol!(
+                    "partial updates of JSON columns are not supported in the binary log; \
+                     set `binlog_row_value_options` to an empty string on the server"
+                ));
+            }
+
+            _ => Event::Other,
+        };
+
+        Ok((header, event))
+    }
+}
+
+// returns an error unless the event has at least `len` more bytes to read
+fn ensure(buf: &Bytes, len: usize) -> Result<(), Error> {
+    if buf.len() < len {
+        return Err(err_protocol!(
+            "binlog event ended after {} bytes while expecting {} more",
+            buf.len(),
+            len
+        ));
+    }
+
+    Ok(())
+}
+
+// reads a length-encoded integer, checking that all of it is there
+fn get_uint_lenenc(buf: &mut Bytes) -> Result<u64, Error> {
+    ensure(buf, 1)?;
+
+    let len = match buf[0] {
+        0xfc => 3,
+        0xfd => 4,
+        0xfe => 9,
+        _ => 1,
+    };
+
+    ensure(buf, len)?;
+
+    Ok(buf.get_uint_lenenc())
+}
+
+// reads an unsigned little-endian integer of a width taken from the table metadata
+fn get_uint_le(buf: &mut Bytes, len: usize) -> Result<u64, Error> {
+    if len == 0 || len > 8 {
+        return Err(err_protocol!(
+            "unexpected integer width of {} bytes in binlog row event",
+            len
+        ));
+    }
+
+    ensure(buf, len)?;
+
+    Ok(buf.get_uint_le(len))
+}
+
+fn format_gtid(sid: &[u8], gno: i64) -> String {
+    let sid = hex::encode(sid);
+
+    format!(
+        "{}-{}-{}-{}-{}:{}",
+        &sid[..8],
+        &sid[8..12],
+        &sid[12..16],
+        &sid[16..20],
+        &sid[20..],
+        gno
+    )
+}
+
+// https://dev.mysql.com/doc/internals/en/table-map-event.html
+
+fn decode_table_map(mut buf: Bytes) -> Result<(u64, BinlogTable), Error> {
+    ensure(&buf, 9)?;
+
+    let table_id = buf.get_uint_le(6);
+    let _flags = buf.get_u16_le();
+
+    let schema_len = buf.get_u8();
+    ensure(&buf, usize::from(schema_len) + 2)?;
+
+    let schema = buf.get_str(usize::from(schema_len))?;
+    buf.advance(1);
+
+    let name_len = buf.get_u8();
+    ensure(&buf, usize::from(name_len) + 1)?;
+
+    let name = buf.get_str(usize::from(name_len))?;
+    buf.advance(1);
+
+    let num_columns = get_uint_lenenc(&mut buf)? as usize;
+    ensure(&buf, num_columns)?;
+
+    let types = buf.get_bytes(num_columns);
+
+    let metadata_len = get_uint_lenenc(&mut buf)? as usize;
+    ensure(&buf, metadata_len)?;
+
+    let mut metadata = buf.split_to(metadata_len);
+    let mut columns = Vec::with_capacity(num_columns);
+
+    for &ty in &*types {
+        let meta = match ty {
+            // FLOAT, DOUBLE, BLOB, JSON, GEOMETRY and the fractional precision of temporal types
+            0x04 | 0x05 | 0xfc | 0xf5 | 0xff | TYPE_TIMESTAMP2 | TYPE_DATETIME2 | TYPE_TIME2 => {
+                ensure(&metadata, 1)?;
+                u16::from(metadata.get_u8())
+            }
+
+            // VARCHAR, BIT, NEWDECIMAL
+            0x0f | 0x10 | 0xf6 => {
+                ensure(&metadata, 2)?;
+                metadata.get_u16_le()
+            }
+
+            // STRING, ENUM, SET; the real type and length are stored big-endian
+            0xfe | 0xf7 | 0xf8 => {
+                ensure(&metadata, 2)?;
+                metadata.get_u16()
+            }
+
+            _ => 0,
+        };
+
+        columns.push((ty, meta));
+    }
+
+    Ok((
+        table_id,
+        BinlogTable {
+            schema,
+            name,
+            columns,
+        },
+    ))
+}
+
+// https://dev.mysql.com/doc/internals/en/rows-event.html
+
+fn decode_rows(kind: RowsKind, event_type: u8, mut buf: Bytes) -> Result<Event, Error> {
+    ensure(&buf, 6)?;
+
+    let table_id = buf.get_uint_le(6);
+
+    Ok(Event::Rows(RowsEvent {
+        kind,
+        table_id,
+        has_extra_data: event_type >= WRITE_ROWS_EVENT,
+        body: buf,
+    }))
+}
+
+impl RowsEvent {
+    // decodes the rows; an update yields the row before and after each change
+    pub(crate) fn decode_rows(
+        mut self,
+        table: &BinlogTable,
+    ) -> Result<Vec<(BinlogRow, Option<BinlogRow>)>, Error> {
+        let buf = &mut self.body;
+
+        ensure(buf, 2)?;
+        let _flags = buf.get_u16_le();
+
+        if self.has_extra_data {
+            // the length of the extra data includes the two bytes of the length itself
+            ensure(buf, 2)?;
+            let extra_len = usize::from(buf.get_u16_le().saturating_sub(2));
+
+            ensure(buf, extra_len)?;
+            buf.advance(extra_len);
+        }
+
+        let num_columns = get_uint_lenenc(buf)? as usize;
+
+        if num_columns != table.columns.len() {
+            return Err(err_protocol!(
+                "rows event has {} columns but table `{}`.`{}` has {}",
+                num_columns,
+                table.schema,
+                table.name,
+                table.columns.len()
+            ));
+        }
+
+        let bitmap_len = (num_columns + 7) / 8;
+
+        ensure(buf, bitmap_len)?;
+        let present = buf.get_bytes(bitmap_len);
+
+        let present_after = if self.kind == RowsKind::Update {
+            ensure(buf, bitmap_len)?;
+            Some(buf.get_bytes(bitmap_len))
+        } else {
+            None
+        };
+
+        let mut rows = Vec::new();
+
+        while buf.has_remaining() {
+            let row = decode_row(buf, table, &present)?;
+
+            let after = match &present_after {
+                Some(present_after) => Some(decode_row(buf, table, present_after)?),
+                None => None,
+            };
+
+            rows.push((row, after));
+        }
+
+        Ok(rows)
+    }
+}
+
+fn is_set(bitmap: &[u8], index: usize) -> bool {
+    bitmap[index / 8] & (1 << (index % 8)) != 0
+}
+
+fn decode_row(buf: &mut Bytes, table: &BinlogTable, present: &[u8]) -> Result<BinlogRow, Error> {
+    let num_present = (0..table.columns.len())
+        .filter(|&index| is_set(present, index))
+        .count();
+
+    // the NULL bitmap only has bits for the columns present in the row image
+    let nulls_len = (num_present + 7) / 8;
+    ensure(buf, nulls_len)?;
+
+    let nulls = buf.get_bytes(nulls_len);
+    let mut row = Vec::with_capacity(table.columns.len());
+    let mut null_index = 0;
+
+    for (index, &(ty, meta)) in table.columns.iter().enumerate() {
+        if !is_set(present, index) {
+            row.push(None);
+            continue;
+        }
+
+        let is_null = is_set(&nulls, null_index);
+        null_index += 1;
+
+        row.push(Some(if is_null {
+            BinlogValue::Null
+        } else {
+            decode_value(buf, ty, meta)?
+        }));
+    }
+
+    Ok(row)
+}
+
+fn decode_value(buf: &mut Bytes, ty: u8, meta: u16) -> Result<BinlogValue, Error> {
+    // the width of the types that are stored in a fixed number of bytes
+    let width = match ty {
+        0x01 | 0x0d => 1,
+        0x02 => 2,
+        0x09 | 0x0a | 0x0b => 3,
+        0x03 | 0x04 | 0x07 => 4,
+        0x05 | 0x08 | 0x0c => 8,
+        TYPE_TIMESTAMP2 => 4,
+        TYPE_DATETIME2 => 5,
+        _ => 0,
+    };
+
+    ensure(buf, width)?;
+
+    Ok(match ty {
+        // TINY, SHORT, LONG, LONGLONG
+        0x01 => BinlogValue::Int(i64::from(buf.get_i8())),
+        0x02 => BinlogValue::Int(i64::from(buf.get_i16_le())),
+        0x03 => BinlogValue::Int(i64::from(buf.get_i32_le())),
+        0x08 => BinlogValue::Int(buf.get_i64_le()),
+
+        // INT24
+        0x09 => {
+            let value = buf.get_uint_le(3) as i64;
+
+            BinlogValue::Int(if value & 0x80_0000 != 0 {
+                value - 0x100_0000
+            } else {
+                value
+            })
+        }
+
+        // FLOAT, DOUBLE
+        0x04 => BinlogValue::Float(buf.get_f32_le()),
+        0x05 => BinlogValue::Double(buf.get_f64_le()),
+
+        // NEWDECIMAL
+        0xf6 => BinlogValue::Decimal(decode_decimal(buf, meta as u8, (meta >> 8) as u8)?),
+
+        // YEAR
+        0x0d => {
+            let year = u16::from(buf.get_u8());
+
+            BinlogValue::Year(if year == 0 { 0 } else { 1900 + year })
+        }
+
+        // DATE
+        0x0a => {
+            let value = buf.get_uint_le(3);
+
+            BinlogValue::Date {
+                year: (value >> 9) as u16,
+                month: ((value >> 5) & 0x0f) as u8,
+                day: (value & 0x1f) as u8,
+            }
+        }
+
+        // TIME
+        0x0b => {
+            let value = buf.get_uint_le(3);
+
+            BinlogValue::Time {
+                negative: false,
+                hours: (value / 10000) as u32,
+                minutes: (value / 100 % 100) as u8,
+                seconds: (value % 100) as u8,
+                microseconds: 0,
+            }
+        }
+
+        TYPE_TIME2 => decode_time2(buf, meta as u8)?,
+
+        // DATETIME; packed as the decimal number YYYYMMDDhhmmss
+        0x0c => {
+            let value = buf.get_u64_le();
+            let date = value / 1_000_000;
+            let time = value % 1_000_000;
+
+            BinlogValue::DateTime {
+                year: (date / 10000) as u16,
+                month: (date / 100 % 100) as u8,
+                day: (date % 100) as u8,
+                hour: (time / 10000) as u8,
+                minute: (time / 100 % 100) as u8,
+                second: (time % 100) as u8,
+                microsecond: 0,
+            }
+        }
+
+        TYPE_DATETIME2 => {
+            let value = buf.get_uint(5) - 0x80_0000_0000;
+            let microsecond = decode_fraction(buf, meta as u8)?;
+
+            let ymd = value >> 17;
+            let ym = ymd >> 5;
+            let hms = value & 0x1_ffff;
+
+            BinlogValue::DateTime {
+                year: (ym / 13) as u16,
+                month: (ym % 13) as u8,
+                day: (ymd & 0x1f) as u8,
+                hour: (hms >> 12) as u8,
+                minute: ((hms >> 6) & 0x3f) as u8,
+                second: (hms & 0x3f) as u8,
+                microsecond,
+            }
+        }
+
+        // TIMESTAMP
+        0x07 => BinlogValue::Timestamp {
+            seconds: buf.get_u32_le(),
+            microseconds: 0,
+        },
+
+        TYPE_TIMESTAMP2 => BinlogValue::Timestamp {
+            seconds: buf.get_u32(),
+            microseconds: decode_fraction(buf, meta as u8)?,
+        },
+
+        // VARCHAR, VAR_STRING
+        0x0f | 0xfd => BinlogValue::Bytes(get_var_bytes(buf, meta)?),
+
+        // STRING, ENUM, SET
+        0xfe | 0xf7 | 0xf8 => decode_string(buf, ty, meta)?,
+
+        // BIT
+        0x10 => {
+            let bits = usize::from(meta >> 8) * 8 + usize::from(meta & 0xff);
+            let len = (bits + 7) / 8;
+
+            ensure(buf, len)?;
+            BinlogValue::Bit(buf.get_bytes(len).to_vec())
+        }
+
+        // BLOB, GEOMETRY, JSON; the metadata is the size of the length prefix
+        0xfc | 0xff | 0xf5 => {
+            let len = get_uint_le(buf, usize::from(meta))? as usize;
+
+            ensure(buf, len)?;
+            let bytes = buf.get_bytes(len).to_vec();
+
+            if ty == 0xf5 {
+                BinlogValue::Json(bytes)
+            } else {
+                BinlogValue::Bytes(bytes)
+            }
+        }
+
+        _ => {
+            return Err(err_protocol!(
+                "unsupported column type {:#04x} in binlog row event",
+                ty
+            ));
+        }
+    })
+}
+
+// reads a string prefixed by its length in one byte, or in two bytes if the column can hold
+// more than 255 bytes
+fn get_var_bytes(buf: &mut Bytes, max_len: u16) -> Result<Vec<u8>, Error> {
+    let len = if max_len < 256 {
+        ensure(buf, 1)?;
+        usize::from(buf.get_u8())
+    } else {
+        ensure(buf, 2)?;
+        usize::from(buf.get_u16_le())
+    };
+
+    ensure(buf, len)?;
+
+    Ok(buf.get_bytes(len).to_vec())
+}
+
+fn decode_string(buf: &mut Bytes, ty: u8, meta: u16) -> Result<BinlogValue, Error> {
+    let (real_type, len) = if ty != 0xfe || meta < 256 {
+        (ty, meta)
+    } else {
+        let byte0 = (meta >> 8) as u8;
+        let byte1 = meta & 0xff;
+
+        if byte0 & 0x30 != 0x30 {
+            // the length of CHAR columns longer than 255 bytes spills into the type byte
+            (
+                byte0 | 0x30,
+                byte1 | ((u16::from(byte0 & 0x30) ^ 0x30) << 4),
+            )
+        } else {
+            (byte0, byte1)
+        }
+    };
+
+    Ok(match real_type {
+        0xf7 => BinlogValue::Enum(get_uint_le(buf, usize::from(len))? as u16),
+        0xf8 => BinlogValue::Set(get_uint_le(buf, usize::from(len))?),
+
+        _ => BinlogValue::Bytes(get_var_bytes(buf, len)?),
+    })
+}
+
+// number of bytes used to store the fractional seconds of the given precision
+fn fraction_len(fsp: u8) -> Result<usize, Error> {
+    if fsp > 6 {
+        return Err(err_protocol!(
+            "unexpected fractional seconds precision of {} in binlog row event",
+            fsp
+        ));
+    }
+
+    Ok((usize::from(fsp) + 1) / 2)
+}
+
+// scale the stored fraction to microseconds
+fn fraction_to_micros(fraction: u64, len: usize) -> u32 {
+    match len {
+        1 => (fraction * 10000) as u32,
+        2 => (fraction * 100) as u32,
+        3 => fraction as u32,
+        _ => 0,
+    }
+}
+
+fn decode_fraction(buf: &mut Bytes, fsp: u8) -> Result<u32, Error> {
+    let len = fraction_len(fsp)?;
+
+    if len == 0 {
+        return Ok(0);
+    }
+
+    ensure(buf, len)?;
+
+    Ok(fraction_to_micros(buf.get_uint(len), len))
+}
+
+// https://dev.mysql.com/doc/internals/en/date-and-time-data-type-representation.html
+fn decode_time2(buf: &mut Bytes, fsp: u8) -> Result<BinlogValue, Error> {
+    let len = fraction_len(fsp)?;
+    ensure(buf, 3 + len)?;
+
+    // the integer and fractional parts are stored together as a single signed number that is
+    // offset to be positive
+    let packed = buf.get_uint(3 + len) as i64 - (0x80_0000_i64 << (8 * len));
+    let value = packed.abs() as u64;
+
+    let time = value >> (8 * len);
+    let fraction = value & ((1 << (8 * len)) - 1);
+
+    Ok(BinlogValue::Time {
+        negative: packed < 0,
+        hours: ((time >> 12) & 0x3ff) as u32,
+        minutes: ((time >> 6) & 0x3f) as u8,
+        seconds: (time & 0x3f) as u8,
+        microseconds: fraction_to_micros(fraction, len),
+    })
+}
+
+// https://dev.mysql.com/doc/internals/en/myisam-column-attributes.html
+// DECIMAL is stored as groups of nine digits in four bytes, big-endian, with the sign in the
+// highest bit of the first byte
+fn decode_decimal(buf: &mut Bytes, precision: u8, scale: u8) -> Result<String, Error> {
+    const DIGITS_PER_GROUP: usize = 9;
+    const BYTES_FOR_DIGITS: [usize; 10] = [0, 1, 1, 2, 2, 3, 3, 4, 4, 4];
+
+    let integral = usize::from(precision.saturating_sub(scale));
+    let scale = usize::from(scale);
+
+    let integral_groups = integral / DIGITS_PER_GROUP;
+    let integral_extra = BYTES_FOR_DIGITS[integral % DIGITS_PER_GROUP];
+    let fractional_groups = scale / DIGITS_PER_GROUP;
+    let fractional_digits = scale % DIGITS_PER_GROUP;
+    let fractional_extra = BYTES_FOR_DIGITS[fractional_digits];
+
+    let len = integral_groups * 4 + integral_extra + fractional_groups * 4 + fractional_extra;
+
+    if len == 0 {
+        return Err(err_protocol!(
+            "unexpected DECIMAL({}, {}) in binlog row event",
+            precision,
+            scale
+        ));
+    }
+
+    ensure(buf, len)?;
+    let mut bytes = buf.get_bytes(len).to_vec();
+
+    let negative = bytes[0] & 0x80 == 0;
+    bytes[0] ^= 0x80;
+
+    if negative {
+        for byte in &mut bytes {
+            *byte ^= 0xff;
+        }
+    }
+
+    let mut bytes = Bytes::from(bytes);
+    let mut integer = String::new();
+
+    if integral_extra > 0 {
+        let _ = write!(integer, "{}", bytes.get_uint(integral_extra));
+    }
+
+    for _ in 0..integral_groups {
+        let _ = write!(integer, "{:09}", bytes.get_u32());
+    }
+
+    let integer = integer.trim_start_matches('0');
+
+    let mut s = String::new();
+
+    if negative {
+        s.push('-');
+    }
+
+    s.push_str(if integer.is_empty() { "0" } else { integer });
+
+    if scale > 0 {
+        s.push('.');
+
+        for _ in 0..fractional_groups {
+            let _ = write!(s, "{:09}", bytes.get_u32());
+        }
+
+        if fractional_extra > 0 {
+            let value = bytes.get_uint(fractional_extra);
+            let _ = write!(s, "{:0width$}", value, width = fractional_digits);
+        }
+    }
+
+    Ok(s)
+}
+
+#[test]
+fn it_decodes_binary_decimals() {
+    let mut buf = Bytes::from_static(b"\x81\x0D\xFB\x38\xD2\x04\xD2");
+    assert_eq!(decode_decimal(&mut buf, 14, 4).unwrap(), "1234567890.1234");
+
+    let mut buf = Bytes::from_static(b"\x7E\xF2\x04\xC7\x2D\xFB\x2D");
+    assert_eq!(decode_decimal(&mut buf, 14, 4).unwrap(), "-1234567890.1234");
+
+    let mut buf = Bytes::from_static(b"\x80\x00\x00\x00\x00\x00\x01");
+    assert_eq!(decode_decimal(&mut buf, 14, 4).unwrap(), "0.0001");
+}
+
+#[test]
+fn it_decodes_temporal_values() {
+    // 2020-01-02 03:04:05 as DATETIME(0)
+    let ym = 2020 * 13 + 1;
+    let packed = (((ym << 5) | 2) << 17 | (3 << 12) | (4 << 6) | 5) + 0x80_0000_0000_u64;
+    let mut buf = Bytes::from(packed.to_be_bytes()[3..].to_vec());
+
+    assert_eq!(
+        decode_value(&mut buf, TYPE_DATETIME2, 0).unwrap(),
+        BinlogValue::DateTime {
+            year: 2020,
+            month: 1,
+            day: 2,
+            hour: 3,
+            minute: 4,
+            second: 5,
+            microsecond: 0,
+        }
+    );
+
+    // -00:00:01.5 as TIME(1)
+    let packed = ((0x80_0000_i64 << 8) - ((1 << 8) + 50)) as u64;
+    let mut buf = Bytes::from(packed.to_be_bytes()[4..].to_vec());
+
+    assert_eq!(
+        decode_value(&mut buf, TYPE_TIME2, 1).unwrap(),
+        BinlogValue::Time {
+            negative: true,
+            hours: 0,
+            minutes: 0,
+            seconds: 1,
+            microseconds: 500_000,
+        }
+    );
+}
+
+#[test]
+fn it_rejects_truncated_events() {
+    // a header without a body
+    let header = b"\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\0\0\0\0\0";
+    assert!(Event::decode(Bytes::from_static(header)).is_err());
+
+    let mut event = header.to_vec();
+    event[4] = TABLE_MAP_EVENT;
+    // table id, flags, schema `db` and the start of a table name that is cut short
+    event.extend_from_slice(b"\x01\0\0\0\0\0\0\0\x02db\0\x05ta");
+    assert!(Event::decode(Bytes::from(event)).is_err());
+
+    let mut event = header.to_vec();
+    event[4] = PARTIAL_UPDATE_ROWS_EVENT;
+    assert!(Event::decode(Bytes::from(event)).is_err());
+}
+
+#[test]
+fn it_rejects_truncated_values() {
+    let mut buf = Bytes::from_static(b"\x01\x02");
+    assert!(decode_value(&mut buf, 0x03, 0).is_err());
+
+    // VARCHAR announcing 5 bytes with only 2 following
+    let mut buf = Bytes::from_static(b"\x05ab");
+    assert!(decode_value(&mut buf, 0x0f, 20).is_err());
+
+    // BLOB with a length prefix wider than a u64
+    let mut buf = Bytes::from_static(b"\x01\x00");
+    assert!(decode_value(&mut buf, 0xfc, 9).is_err());
+
+    let mut buf = Bytes::from_static(b"\x81\x0D\xFB");
+    assert!(decode_decimal(&mut buf, 14, 4).is_err());
+
+    let mut buf = Bytes::from_static(b"\x80\x00\x00");
+    assert!(decode_value(&mut buf, TYPE_TIME2, 7).is_err());
+}
@@ -0,0 +1,7 @@
+mod binlog_dump;
+mod event;
+mod register_replica;
+
+pub(crate) use binlog_dump::{BinlogDump, BinlogDumpGtid, GtidSet};
+pub(crate) use event::{Event, RowsKind};
+pub(crate) use register_replica::RegisterReplica;
@@ -0,0 +1,28 @@
+use crate::io::Encode;
+use crate::mysql::protocol::Capabilities;
+
+// https://dev.mysql.com/doc/internals/en/com-register-slave.html
+
+#[derive(Debug)]
+pub(crate) struct RegisterReplica<'a> {
+    pub(crate) server_id: u32,
+    pub(crate) hostname: &'a str,
+}
+
+impl Encode<'_, Capabilities> for RegisterReplica<'_> {
+    fn encode_with(&self, buf: &mut Vec<u8>, _: Capabilities) {
+        buf.push(0x15); // COM_REGISTER_SLAVE
+        buf.extend(&self.server_id.to_le_bytes());
+
+        // the host name is shown by SHOW REPLICAS and is limited to a single byte of length
+        let hostname = &self.hostname.as_bytes()[..self.hostname.len().min(255)];
+        buf.push(hostname.len() as u8);
+        buf.extend(hostname);
+
+        buf.push(0); // user
+        buf.push(0); // password
+        buf.extend(&0_u16.to_le_bytes()); // port
+        buf.extend(&0_u32.to_le_bytes()); // replication rank; ignored
+        buf.extend(&0_u32.to_le_bytes()); // source id; filled in by the server
+    }
+}
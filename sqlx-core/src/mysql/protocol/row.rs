@@ -2,10 +2,18 @@ use std::ops::Range;
 
 use bytes::Bytes;
 
+/// A row of data from the database.
+///
+/// Column values are never copied out of the received packet; they are
+/// borrowed from `storage` when decoded.
 #[derive(Debug)]
 pub(crate) struct Row {
     pub(crate) storage: Bytes,
-    pub(crate) values: Vec<Option<Range<usize>>>,
+
+    /// Ranges into the stored row data.
+    /// This uses `u32` instead of usize to halve the per-row allocation for wide rows.
+    /// A row is bounded by the maximum packet size of 1 GiB.
+    pub(crate) values: Vec<Option<Range<u32>>>,
 }
 
 impl Row {
@@ -82,7 +82,7 @@ impl<'de> Decode<'de, &'de [MySqlColumn]> for BinaryRow {
 
             let offset = offset - buf.len();
 
-            values.push(Some(offset..(offset + size)));
+            values.push(Some((offset as u32)..((offset + size) as u32)));
 
             buf.advance(size);
         }
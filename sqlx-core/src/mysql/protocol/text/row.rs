@@ -25,7 +25,7 @@ impl<'de> Decode<'de, &'de [MySqlColumn]> for TextRow {
                 let size = buf.get_uint_lenenc() as usize;
                 let offset = offset - buf.len();
 
-                values.push(Some(offset..(offset + size)));
+                values.push(Some((offset as u32)..((offset + size) as u32)));
 
                 buf.advance(size);
             }
@@ -34,3 +34,27 @@ impl<'de> Decode<'de, &'de [MySqlColumn]> for TextRow {
         Ok(TextRow(Row { values, storage }))
     }
 }
+
+#[test]
+fn test_decode_text_row() {
+    use crate::ext::ustr::UStr;
+    use crate::mysql::protocol::text::ColumnType;
+    use crate::mysql::{MySqlColumn, MySqlTypeInfo};
+
+    let columns: Vec<MySqlColumn> = (0..3)
+        .map(|ordinal| MySqlColumn {
+            ordinal,
+            name: UStr::Static("v"),
+            type_info: MySqlTypeInfo::binary(ColumnType::VarString),
+            flags: None,
+        })
+        .collect();
+
+    let row = TextRow::decode_with(Bytes::from_static(b"\x011\xfb\x03abc"), &columns)
+        .unwrap()
+        .0;
+
+    assert_eq!(row.get(0), Some(&b"1"[..]));
+    assert_eq!(row.get(1), None);
+    assert_eq!(row.get(2), Some(&b"abc"[..]));
+}
@@ -0,0 +1,402 @@
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use bytes::Buf;
+use futures_core::stream::Stream;
+
+use crate::error::Error;
+use crate::executor::Executor;
+use crate::io::Decode;
+use crate::mysql::protocol::replication::{
+    BinlogDump, BinlogDumpGtid, Event, GtidSet, RegisterReplica, RowsKind,
+};
+use crate::mysql::protocol::response::ErrPacket;
+use crate::mysql::{MySqlConnection, MySqlDatabaseError};
+use crate::query_scalar::query_scalar;
+use crate::HashMap;
+
+/// A stream of changes from the binary log of a MySQL server.
+///
+/// The connection registers itself as a replica with the given server id, which must be unique
+/// among the replicas of the server, and receives the row events written to the binary log.
+/// This requires the `REPLICATION SLAVE` and `REPLICATION CLIENT` privileges and a server with
+/// `binlog_format = ROW`.
+///
+/// The connection is dedicated to replication once started and cannot be used for queries.
+///
+/// ```rust,ignore
+/// use sqlx::mysql::{BinlogEvent, MySqlConnection, MySqlReplication};
+///
+/// let conn = MySqlConnection::connect("mysql://replicator@localhost/").await?;
+///
+/// // an empty GTID set streams every transaction still in the binary log
+/// let mut replication = MySqlReplication::start_at_gtid(conn, 1001, "").await?;
+///
+/// loop {
+///     match replication.recv().await? {
+///         BinlogEvent::Insert { table, rows } => {
+///             println!("{} rows inserted into {}", rows.len(), table.name());
+///         }
+///
+///         BinlogEvent::Gtid(gtid) => println!("transaction {}", gtid),
+///         _ => {}
+///     }
+/// }
+/// ```
+pub struct MySqlReplication {
+    conn: MySqlConnection,
+
+    // whether each event ends in a CRC32 checksum
+    checksum: bool,
+
+    // tables by the id given to them in table map events
+    tables: HashMap<u64, Arc<BinlogTable>>,
+
+    // file and position of the next event in the binary log
+    file: String,
+    position: u64,
+}
+
+/// A change received from the binary log.
+#[derive(Debug)]
+pub enum BinlogEvent {
+    /// The server switched to another binary log file. Also sent first when streaming starts.
+    Rotate { file: String, position: u64 },
+
+    /// A transaction with the given global transaction identifier is starting.
+    Gtid(String),
+
+    /// A statement that is not logged as rows, such as DDL or `BEGIN`.
+    Query { schema: String, query: String },
+
+    /// Rows were inserted.
+    Insert {
+        table: Arc<BinlogTable>,
+        rows: Vec<BinlogRow>,
+    },
+
+    /// Rows were updated; each change holds the row before and after the update.
+    Update {
+        table: Arc<BinlogTable>,
+        rows: Vec<(BinlogRow, BinlogRow)>,
+    },
+
+    /// Rows were deleted.
+    Delete {
+        table: Arc<BinlogTable>,
+        rows: Vec<BinlogRow>,
+    },
+
+    /// A transaction was committed.
+    Commit { xid: u64 },
+}
+
+/// The values of a row, in the order of the columns of the table.
+///
+/// A column is `None` if it is not part of the row image, which happens when the server is
+/// configured with `binlog_row_image = MINIMAL`.
+pub type BinlogRow = Vec<Option<BinlogValue>>;
+
+/// A table that rows in the binary log belong to.
+#[derive(Debug)]
+pub struct BinlogTable {
+    pub(crate) schema: String,
+    pub(crate) name: String,
+
+    // column type and metadata as given by the table map event
+    pub(crate) columns: Vec<(u8, u16)>,
+}
+
+impl BinlogTable {
+    /// Returns the name of the schema (database) of the table.
+    pub fn schema(&self) -> &str {
+        &self.schema
+    }
+
+    /// Returns the name of the table.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the number of columns of the table.
+    pub fn num_columns(&self) -> usize {
+        self.columns.len()
+    }
+}
+
+/// A value of a column in the binary log.
+///
+/// The binary log does not record whether an integer column is unsigned, or the character set
+/// of a string; an `INT UNSIGNED` of `4294967295` is received as `Int(-1)`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BinlogValue {
+    Null,
+    Int(i64),
+    Float(f32),
+    Double(f64),
+
+    /// A `DECIMAL`, formatted as a string to not lose precision.
+    Decimal(String),
+
+    Year(u16),
+
+    Date {
+        year: u16,
+        month: u8,
+        day: u8,
+    },
+
+    Time {
+        negative: bool,
+        hours: u32,
+        minutes: u8,
+        seconds: u8,
+        microseconds: u32,
+    },
+
+    DateTime {
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        microsecond: u32,
+    },
+
+    /// A `TIMESTAMP`, as seconds since the Unix epoch.
+    Timestamp {
+        seconds: u32,
+        microseconds: u32,
+    },
+
+    /// A string or binary string, including `BLOB`, `TEXT` and `GEOMETRY`.
+    Bytes(Vec<u8>),
+
+    /// A `JSON` document in the binary format of MySQL.
+    Json(Vec<u8>),
+
+    Bit(Vec<u8>),
+
+    /// The 1-based index of the value of an `ENUM`.
+    Enum(u16),
+
+    /// The bitmap of the values of a `SET`.
+    Set(u64),
+}
+
+impl MySqlReplication {
+    /// Starts streaming after the transactions in the GTID set, such as
+    /// `3E11FA47-71CA-11E1-9E33-C80AA9429562:1-5`.
+    ///
+    /// An empty set starts with the oldest event still in the binary log. Requires a MySQL
+    /// server with `gtid_mode = ON`; not supported by MariaDB.
+    pub async fn start_at_gtid(
+        mut conn: MySqlConnection,
+        server_id: u32,
+        gtid_set: &str,
+    ) -> Result<Self, Error> {
+        let gtid_set = GtidSet::parse(gtid_set)?;
+        let checksum = register(&mut conn, server_id).await?;
+
+        conn.stream
+            .send_packet(BinlogDumpGtid {
+                server_id,
+                gtid_set,
+            })
+            .await?;
+
+        Ok(Self::new(conn, checksum, String::new(), 4))
+    }
+
+    /// Starts streaming at a position in a binary log file, such as one returned by
+    /// `SHOW MASTER STATUS` or [`position`].
+    ///
+    /// [`position`]: #method.position
+    pub async fn start_at_position(
+        mut conn: MySqlConnection,
+        server_id: u32,
+        file: &str,
+        position: u64,
+    ) -> Result<Self, Error> {
+        // COM_BINLOG_DUMP only has room for 4 bytes of position
+        let dump_position = u32::try_from(position).map_err(|_| {
+            Error::Configuration(format!("binlog position {} is out of range", position).into())
+        })?;
+
+        let checksum = register(&mut conn, server_id).await?;
+
+        conn.stream
+            .send_packet(BinlogDump {
+                server_id,
+                file,
+                position: dump_position,
+            })
+            .await?;
+
+        Ok(Self::new(conn, checksum, file.to_owned(), position))
+    }
+
+    fn new(conn: MySqlConnection, checksum: bool, file: String, position: u64) -> Self {
+        Self {
+            conn,
+            checksum,
+            tables: HashMap::default(),
+            file,
+            position,
+        }
+    }
+
+    /// Returns the binary log file and position after the last received event, from where
+    /// streaming can be resumed with [`start_at_position`].
+    ///
+    /// [`start_at_position`]: #method.start_at_position
+    pub fn position(&self) -> (&str, u64) {
+        (&self.file, self.position)
+    }
+
+    /// Receives the next event, waiting until the server writes one.
+    pub async fn recv(&mut self) -> Result<BinlogEvent, Error> {
+        loop {
+            let mut packet = self.conn.stream.recv_packet().await?.0;
+
+            if packet.is_empty() {
+                return Err(err_protocol!(
+                    "received an empty packet in the binary log stream"
+                ));
+            }
+
+            match packet[0] {
+                0x00 => packet.advance(1),
+                0xfe => return Err(err_protocol!("server ended the binary log stream")),
+
+                // such as when the requested position or GTID set is no longer in the binary log
+                0xff => {
+                    let error = ErrPacket::decode_with(packet, self.conn.stream.capabilities)?;
+
+                    return Err(MySqlDatabaseError(error).into());
+                }
+
+                tag => {
+                    return Err(err_protocol!(
+                        "expected a binary log event but received 0x{:02x}",
+                        tag
+                    ));
+                }
+            }
+
+            if self.checksum {
+                if packet.len() < 4 {
+                    return Err(err_protocol!("binlog event is shorter than its checksum"));
+                }
+
+                packet.truncate(packet.len() - 4);
+            }
+
+            let (header, event) = Event::decode(packet)?;
+
+            // artificial events, such as the rotate event sent first, have no position
+            if header.log_pos != 0 {
+                self.position = u64::from(header.log_pos);
+            }
+
+            return Ok(match event {
+                Event::Rotate { file, position } => {
+                    self.file = file.clone();
+                    self.position = position;
+
+                    BinlogEvent::Rotate { file, position }
+                }
+
+                Event::Gtid(gtid) => BinlogEvent::Gtid(gtid),
+                Event::Query { schema, query } => BinlogEvent::Query { schema, query },
+                Event::Xid(xid) => BinlogEvent::Commit { xid },
+
+                Event::TableMap(id, table) => {
+                    self.tables.insert(id, Arc::new(table));
+                    continue;
+                }
+
+                Event::Rows(rows) => {
+                    let table = self.tables.get(&rows.table_id).cloned().ok_or_else(|| {
+                        err_protocol!("received rows for unknown table id {}", rows.table_id)
+                    })?;
+
+                    let kind = rows.kind;
+                    let rows = rows.decode_rows(&table)?;
+
+                    match kind {
+                        RowsKind::Write => BinlogEvent::Insert {
+                            table,
+                            rows: rows.into_iter().map(|(row, _)| row).collect(),
+                        },
+
+                        RowsKind::Update => BinlogEvent::Update {
+                            table,
+                            rows: rows
+                                .into_iter()
+                                .map(|(before, after)| (before, after.unwrap_or_default()))
+                                .collect(),
+                        },
+
+                        RowsKind::Delete => BinlogEvent::Delete {
+                            table,
+                            rows: rows.into_iter().map(|(row, _)| row).collect(),
+                        },
+                    }
+                }
+
+                Event::Other => continue,
+            });
+        }
+    }
+
+    /// Consume this replication client, returning a `Stream` of events.
+    pub fn into_stream(mut self) -> impl Stream<Item = Result<BinlogEvent, Error>> + Unpin {
+        Box::pin(try_stream! {
+            loop {
+                r#yield!(self.recv().await?);
+            }
+        })
+    }
+}
+
+// negotiates checksums and registers the connection as a replica; returns whether the events
+// will carry checksums
+async fn register(conn: &mut MySqlConnection, server_id: u32) -> Result<bool, Error> {
+    // servers before MySQL 5.6 do not checksum events and do not have the variable
+    let checksum: Option<String> = match query_scalar("SELECT @@global.binlog_checksum")
+        .fetch_one(&mut *conn)
+        .await
+    {
+        Ok(checksum) => Some(checksum),
+        Err(Error::Database(_)) => None,
+        Err(error) => return Err(error),
+    };
+
+    let checksum = match checksum {
+        Some(checksum) => {
+            // tell the server that we understand checksums; otherwise it refuses to stream
+            conn.execute("SET @master_binlog_checksum = @@global.binlog_checksum")
+                .await?;
+
+            checksum.eq_ignore_ascii_case("CRC32")
+        }
+
+        None => false,
+    };
+
+    // ask MariaDB to send its GTID events
+    conn.execute("SET @mariadb_slave_capability = 4").await?;
+
+    conn.stream
+        .send_packet(RegisterReplica {
+            server_id,
+            hostname: &whoami::hostname(),
+        })
+        .await?;
+
+    conn.stream.recv_ok().await?;
+
+    Ok(checksum)
+}
@@ -0,0 +1,36 @@
+//! Helpers shared by the `chrono` and `time` integrations, which read the same binary format.
+
+use crate::error::BoxDynError;
+
+// checks the length prefix of a binary date or time value against the lengths the protocol
+// allows for it and against the bytes that actually follow it
+pub(super) fn check_len(buf: &[u8], allowed: &[u8]) -> Result<u8, BoxDynError> {
+    let len = *buf
+        .first()
+        .ok_or("expected a length prefix but received 0 bytes")?;
+
+    if !allowed.contains(&len) || buf.len() != 1 + usize::from(len) {
+        return Err(format!(
+            "unexpected binary value of {} bytes with a length prefix of {}",
+            buf.len(),
+            len
+        )
+        .into());
+    }
+
+    Ok(len)
+}
+
+// a binary value of the given type, as received in a row
+#[cfg(test)]
+pub(super) fn value(
+    ty: crate::mysql::protocol::text::ColumnType,
+    buf: &[u8],
+) -> crate::mysql::MySqlValueRef<'_> {
+    crate::mysql::MySqlValueRef {
+        value: Some(buf),
+        row: None,
+        type_info: crate::mysql::MySqlTypeInfo::binary(ty),
+        format: crate::mysql::MySqlValueFormat::Binary,
+    }
+}
@@ -32,6 +32,11 @@ impl Encode<'_, MySql> for &'_ [u8] {
 
         IsNull::No
     }
+
+    fn size_hint(&self) -> usize {
+        // the length-encoded prefix takes at most 9 bytes
+        self.len() + 9
+    }
 }
 
 impl<'r> Decode<'r, MySql> for &'r [u8] {
@@ -54,6 +59,10 @@ impl Encode<'_, MySql> for Vec<u8> {
     fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
         <&[u8] as Encode<MySql>>::encode(&**self, buf)
     }
+
+    fn size_hint(&self) -> usize {
+        <&[u8] as Encode<MySql>>::size_hint(&&**self)
+    }
 }
 
 impl Decode<'_, MySql> for Vec<u8> {
@@ -8,6 +8,7 @@ use crate::encode::{Encode, IsNull};
 use crate::error::{BoxDynError, UnexpectedNullError};
 use crate::mysql::protocol::text::ColumnType;
 use crate::mysql::type_info::MySqlTypeInfo;
+use crate::mysql::types::binary_time::check_len;
 use crate::mysql::{MySql, MySqlValueFormat, MySqlValueRef};
 use crate::types::Type;
 
@@ -76,6 +77,7 @@ impl<'r> Decode<'r, MySql> for NaiveTime {
                 let mut buf = value.as_bytes()?;
 
                 // data length, expecting 8 or 12 (fractional seconds)
+                check_len(buf, &[0, 8, 12])?;
                 let len = buf.get_u8();
 
                 // MySQL specifies that if all of hours, minutes, seconds, microseconds
@@ -93,7 +95,7 @@ impl<'r> Decode<'r, MySql> for NaiveTime {
                 // https://mariadb.com/kb/en/resultset-row/#timestamp-binary-encoding
                 buf.advance(4);
 
-                Ok(decode_time(len - 5, buf))
+                decode_time(len - 5, buf)
             }
 
             MySqlValueFormat::Text => {
@@ -128,7 +130,10 @@ impl<'r> Decode<'r, MySql> for NaiveDate {
     fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
         match value.format() {
             MySqlValueFormat::Binary => {
-                decode_date(&value.as_bytes()?[1..]).ok_or_else(|| UnexpectedNullError.into())
+                let buf = value.as_bytes()?;
+                check_len(buf, &[0, 4, 7, 11])?;
+
+                Ok(decode_date(&buf[1..])?.ok_or(UnexpectedNullError)?)
             }
 
             MySqlValueFormat::Text => {
@@ -187,11 +192,11 @@ impl<'r> Decode<'r, MySql> for NaiveDateTime {
             MySqlValueFormat::Binary => {
                 let buf = value.as_bytes()?;
 
-                let len = buf[0];
-                let date = decode_date(&buf[1..]).ok_or(UnexpectedNullError)?;
+                let len = check_len(buf, &[0, 4, 7, 11])?;
+                let date = decode_date(&buf[1..])?.ok_or(UnexpectedNullError)?;
 
                 let dt = if len > 4 {
-                    date.and_time(decode_time(len - 4, &buf[5..]))
+                    date.and_time(decode_time(len - 4, &buf[5..])?)
                 } else {
                     date.and_hms(0, 0, 0)
                 };
@@ -217,18 +222,23 @@ fn encode_date(date: &NaiveDate, buf: &mut Vec<u8>) {
     buf.push(date.day() as u8);
 }
 
-fn decode_date(mut buf: &[u8]) -> Option<NaiveDate> {
-    if buf.len() == 0 {
+fn decode_date(mut buf: &[u8]) -> Result<Option<NaiveDate>, BoxDynError> {
+    if buf.is_empty() {
         // MySQL specifies that if there are no bytes, this is all zeros
-        None
-    } else {
-        let year = buf.get_u16_le();
-        Some(NaiveDate::from_ymd(
-            year as i32,
-            buf[0] as u32,
-            buf[1] as u32,
-        ))
+        return Ok(None);
     }
+
+    let year = buf.get_u16_le();
+
+    NaiveDate::from_ymd_opt(year as i32, buf[0] as u32, buf[1] as u32)
+        .map(Some)
+        .ok_or_else(|| {
+            format!(
+                "NaiveDate out of range for MySQL: {}-{}-{}",
+                year, buf[0], buf[1]
+            )
+            .into()
+        })
 }
 
 fn encode_time(time: &NaiveTime, include_micros: bool, buf: &mut Vec<u8>) {
@@ -241,7 +251,7 @@ fn encode_time(time: &NaiveTime, include_micros: bool, buf: &mut Vec<u8>) {
     }
 }
 
-fn decode_time(len: u8, mut buf: &[u8]) -> NaiveTime {
+fn decode_time(len: u8, mut buf: &[u8]) -> Result<NaiveTime, BoxDynError> {
     let hour = buf.get_u8();
     let minute = buf.get_u8();
     let seconds = buf.get_u8();
@@ -253,5 +263,48 @@ fn decode_time(len: u8, mut buf: &[u8]) -> NaiveTime {
         0
     };
 
-    NaiveTime::from_hms_micro(hour as u32, minute as u32, seconds as u32, micros as u32)
+    NaiveTime::from_hms_micro_opt(hour as u32, minute as u32, seconds as u32, micros as u32)
+        .ok_or_else(|| {
+            format!(
+                "NaiveTime out of range for MySQL: {}:{}:{}.{}",
+                hour, minute, seconds, micros
+            )
+            .into()
+        })
+}
+
+#[test]
+fn it_rejects_truncated_binary_dates_and_times() {
+    use super::binary_time::value;
+
+    let date: Result<NaiveDate, _> = Decode::<MySql>::decode(value(ColumnType::Date, &[4, 0xe4]));
+    assert!(date.is_err());
+
+    let date: Result<NaiveDate, _> = Decode::<MySql>::decode(value(ColumnType::Date, &[]));
+    assert!(date.is_err());
+
+    let date: NaiveDate =
+        Decode::<MySql>::decode(value(ColumnType::Date, &[4, 0xe4, 0x07, 1, 2])).unwrap();
+    assert_eq!(date, NaiveDate::from_ymd(2020, 1, 2));
+
+    let dt: Result<NaiveDateTime, _> =
+        Decode::<MySql>::decode(value(ColumnType::Datetime, &[7, 0xe4, 0x07, 1, 2, 3]));
+    assert!(dt.is_err());
+
+    let time: Result<NaiveTime, _> =
+        Decode::<MySql>::decode(value(ColumnType::Time, &[12, 0, 0, 0, 0, 0, 1, 2, 3]));
+    assert!(time.is_err());
+}
+
+#[test]
+fn it_rejects_out_of_range_binary_dates_and_times() {
+    use super::binary_time::value;
+
+    let date: Result<NaiveDate, _> =
+        Decode::<MySql>::decode(value(ColumnType::Date, &[4, 0xe4, 0x07, 13, 2]));
+    assert!(date.is_err());
+
+    let time: Result<NaiveTime, _> =
+        Decode::<MySql>::decode(value(ColumnType::Time, &[8, 0, 0, 0, 0, 0, 25, 2, 3]));
+    assert!(time.is_err());
 }
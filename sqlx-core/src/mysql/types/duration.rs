@@ -0,0 +1,142 @@
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use byteorder::{ByteOrder, LittleEndian};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::mysql::protocol::text::ColumnType;
+use crate::mysql::{MySql, MySqlTypeInfo, MySqlValueFormat, MySqlValueRef};
+use crate::types::Type;
+
+impl Type<MySql> for Duration {
+    fn type_info() -> MySqlTypeInfo {
+        MySqlTypeInfo::binary(ColumnType::Time)
+    }
+}
+
+impl Encode<'_, MySql> for Duration {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        // https://dev.mysql.com/doc/internals/en/binary-protocol-value.html#packet-ProtocolBinary::MYSQL_TYPE_TIME
+        let secs = self.as_secs();
+        let micros = self.subsec_micros();
+
+        // data length; the microseconds are only sent if there are any
+        buf.push(if micros == 0 { 8 } else { 12 });
+
+        // is negative
+        buf.push(0);
+
+        // days : int<4>; MySQL rejects a TIME of more than 838 hours
+        buf.extend_from_slice(
+            &u32::try_from(secs / 86_400)
+                .unwrap_or(u32::MAX)
+                .to_le_bytes(),
+        );
+
+        buf.push(((secs % 86_400) / 3600) as u8);
+        buf.push(((secs % 3600) / 60) as u8);
+        buf.push((secs % 60) as u8);
+
+        if micros != 0 {
+            buf.extend_from_slice(&micros.to_le_bytes());
+        }
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        13
+    }
+}
+
+impl Decode<'_, MySql> for Duration {
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            MySqlValueFormat::Binary => {
+                let buf = value.as_bytes()?;
+
+                // a length of 0 means a TIME of zero and no further data is sent
+                if buf.is_empty() || buf[0] == 0 {
+                    return Ok(Duration::default());
+                }
+
+                let len = usize::from(buf[0]);
+
+                if (len != 8 && len != 12) || buf.len() != len + 1 {
+                    return Err(format!("invalid length {} of a TIME value", len).into());
+                }
+
+                if buf[1] != 0 {
+                    return Err("negative TIME cannot be decoded as `std::time::Duration`".into());
+                }
+
+                let days = u64::from(LittleEndian::read_u32(&buf[2..]));
+                let secs = days * 86_400
+                    + u64::from(buf[6]) * 3600
+                    + u64::from(buf[7]) * 60
+                    + u64::from(buf[8]);
+
+                let micros = if len == 12 {
+                    LittleEndian::read_u32(&buf[9..])
+                } else {
+                    0
+                };
+
+                Ok(Duration::new(secs, micros * 1000))
+            }
+
+            MySqlValueFormat::Text => decode_text(value.as_str()?),
+        }
+    }
+}
+
+// [-]HHH:MM:SS[.ffffff]
+fn decode_text(s: &str) -> Result<Duration, BoxDynError> {
+    if s.starts_with('-') {
+        return Err("negative TIME cannot be decoded as `std::time::Duration`".into());
+    }
+
+    let (time, fraction) = match s.find('.') {
+        Some(index) => (&s[..index], &s[index + 1..]),
+        None => (s, ""),
+    };
+
+    let parts = time
+        .split(':')
+        .map(str::parse::<u64>)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (hours, minutes, seconds) = match *parts {
+        [hours, minutes, seconds] => (hours, minutes, seconds),
+        _ => return Err(format!("invalid TIME value {:?}", s).into()),
+    };
+
+    let nanos = if fraction.is_empty() {
+        0
+    } else if fraction.len() <= 9 && fraction.bytes().all(|b| b.is_ascii_digit()) {
+        // right-pad to nanoseconds
+        format!("{:0<9}", fraction).parse::<u32>()?
+    } else {
+        return Err(format!("invalid TIME value {:?}", s).into());
+    };
+
+    Ok(Duration::new(hours * 3600 + minutes * 60 + seconds, nanos))
+}
+
+#[test]
+fn it_decodes_text_time_as_duration() {
+    assert_eq!(decode_text("00:00:00").unwrap(), Duration::default());
+    assert_eq!(
+        decode_text("838:59:59").unwrap(),
+        Duration::from_secs(3_020_399)
+    );
+    assert_eq!(
+        decode_text("01:02:03.5").unwrap(),
+        Duration::from_millis(3_723_500)
+    );
+
+    assert!(decode_text("-01:00:00").is_err());
+    assert!(decode_text("01:00").is_err());
+}
@@ -1,6 +1,6 @@
 use byteorder::{ByteOrder, LittleEndian};
 
-use crate::decode::Decode;
+use crate::decode::{check_width, Decode};
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::mysql::protocol::text::ColumnType;
@@ -58,7 +58,7 @@ impl Decode<'_, MySql> for f32 {
                     // We take and truncate to f32 as that's the same behavior as *in* MySQL
                     LittleEndian::read_f64(buf) as f32
                 } else {
-                    LittleEndian::read_f32(buf)
+                    LittleEndian::read_f32(check_width(buf, 4)?)
                 }
             }
 
@@ -70,7 +70,16 @@ impl Decode<'_, MySql> for f32 {
 impl Decode<'_, MySql> for f64 {
     fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
         Ok(match value.format() {
-            MySqlValueFormat::Binary => LittleEndian::read_f64(value.as_bytes()?),
+            MySqlValueFormat::Binary => {
+                let buf = value.as_bytes()?;
+
+                if buf.len() == 4 {
+                    // a FLOAT column is widened losslessly
+                    LittleEndian::read_f32(buf).into()
+                } else {
+                    LittleEndian::read_f64(check_width(buf, 8)?)
+                }
+            }
             MySqlValueFormat::Text => value.as_str()?.parse()?,
         })
     }
@@ -97,6 +97,15 @@ fn int_decode(value: MySqlValueRef<'_>) -> Result<i64, BoxDynError> {
         MySqlValueFormat::Text => value.as_str()?.parse()?,
         MySqlValueFormat::Binary => {
             let buf = value.as_bytes()?;
+
+            if buf.is_empty() || buf.len() > 8 {
+                return Err(format!(
+                    "expected an integer of 1 to 8 bytes but received {} bytes",
+                    buf.len()
+                )
+                .into());
+            }
+
             LittleEndian::read_int(buf, buf.len())
         }
     })
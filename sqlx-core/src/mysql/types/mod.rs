@@ -17,6 +17,7 @@
 //! | `f64`                                 | DOUBLE                                               |
 //! | `&str`, `String`                      | VARCHAR, CHAR, TEXT                                  |
 //! | `&[u8]`, `Vec<u8>`                    | VARBINARY, BINARY, BLOB                              |
+//! | `std::time::Duration`                 | TIME                                                 |
 //!
 //! ### [`chrono`](https://crates.io/crates/chrono)
 //!
@@ -80,6 +81,7 @@
 
 mod bool;
 mod bytes;
+mod duration;
 mod float;
 mod int;
 mod str;
@@ -91,6 +93,9 @@ mod bigdecimal;
 #[cfg(feature = "decimal")]
 mod decimal;
 
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod binary_time;
+
 #[cfg(feature = "chrono")]
 mod chrono;
 
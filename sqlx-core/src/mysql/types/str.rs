@@ -1,15 +1,16 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
+use crate::mysql::collation::is_utf8_compatible;
 use crate::mysql::io::MySqlBufMutExt;
 use crate::mysql::protocol::text::{ColumnFlags, ColumnType};
 use crate::mysql::{MySql, MySqlTypeInfo, MySqlValueRef};
 use crate::types::Type;
 
-const COLLATE_UTF8_GENERAL_CI: u16 = 33;
-const COLLATE_UTF8_UNICODE_CI: u16 = 192;
 const COLLATE_UTF8MB4_UNICODE_CI: u16 = 224;
-const COLLATE_UTF8MB4_BIN: u16 = 46;
 
 impl Type<MySql> for str {
     fn type_info() -> MySqlTypeInfo {
@@ -22,7 +23,7 @@ impl Type<MySql> for str {
     }
 
     fn compatible(ty: &MySqlTypeInfo) -> bool {
-        // TODO: Support more collations being returned from SQL?
+        // text in any other character set, including binary strings, may not be valid UTF-8
         matches!(
             ty.r#type,
             ColumnType::VarChar
@@ -33,13 +34,7 @@ impl Type<MySql> for str {
                 | ColumnType::String
                 | ColumnType::VarString
                 | ColumnType::Enum
-        ) && matches!(
-            ty.char_set,
-            COLLATE_UTF8MB4_UNICODE_CI
-                | COLLATE_UTF8_UNICODE_CI
-                | COLLATE_UTF8_GENERAL_CI
-                | COLLATE_UTF8MB4_BIN
-        )
+        ) && is_utf8_compatible(ty.char_set)
     }
 }
 
@@ -49,6 +44,11 @@ impl Encode<'_, MySql> for &'_ str {
 
         IsNull::No
     }
+
+    fn size_hint(&self) -> usize {
+        // the length-encoded prefix takes at most 9 bytes
+        self.len() + 9
+    }
 }
 
 impl<'r> Decode<'r, MySql> for &'r str {
@@ -71,6 +71,40 @@ impl Encode<'_, MySql> for String {
     fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
         <&str as Encode<MySql>>::encode(&**self, buf)
     }
+
+    fn size_hint(&self) -> usize {
+        <&str as Encode<MySql>>::size_hint(&&**self)
+    }
+}
+
+impl Encode<'_, MySql> for Cow<'_, str> {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        <&str as Encode<MySql>>::encode(&**self, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        <&str as Encode<MySql>>::size_hint(&&**self)
+    }
+}
+
+impl Encode<'_, MySql> for Box<str> {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        <&str as Encode<MySql>>::encode(&**self, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        <&str as Encode<MySql>>::size_hint(&&**self)
+    }
+}
+
+impl Encode<'_, MySql> for Arc<str> {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        <&str as Encode<MySql>>::encode(&**self, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        <&str as Encode<MySql>>::size_hint(&&**self)
+    }
 }
 
 impl Decode<'_, MySql> for String {
@@ -10,6 +10,7 @@ use crate::encode::{Encode, IsNull};
 use crate::error::{BoxDynError, UnexpectedNullError};
 use crate::mysql::protocol::text::ColumnType;
 use crate::mysql::type_info::MySqlTypeInfo;
+use crate::mysql::types::binary_time::check_len;
 use crate::mysql::{MySql, MySqlValueFormat, MySqlValueRef};
 use crate::types::Type;
 
@@ -81,6 +82,7 @@ impl<'r> Decode<'r, MySql> for Time {
                 let mut buf = value.as_bytes()?;
 
                 // data length, expecting 8 or 12 (fractional seconds)
+                check_len(buf, &[0, 8, 12])?;
                 let len = buf.get_u8();
 
                 // MySQL specifies that if all of hours, minutes, seconds, microseconds
@@ -92,7 +94,9 @@ impl<'r> Decode<'r, MySql> for Time {
 
                 // is negative : int<1>
                 let is_negative = buf.get_u8();
-                assert_eq!(is_negative, 0, "Negative dates/times are not supported");
+                if is_negative != 0 {
+                    return Err("negative times are not supported".into());
+                }
 
                 // "date on 4 bytes little-endian format" (?)
                 // https://mariadb.com/kb/en/resultset-row/#timestamp-binary-encoding
@@ -144,7 +148,10 @@ impl<'r> Decode<'r, MySql> for Date {
     fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
         match value.format() {
             MySqlValueFormat::Binary => {
-                Ok(decode_date(&value.as_bytes()?[1..])?.ok_or(UnexpectedNullError)?)
+                let buf = value.as_bytes()?;
+                check_len(buf, &[0, 4, 7, 11])?;
+
+                Ok(decode_date(&buf[1..])?.ok_or(UnexpectedNullError)?)
             }
             MySqlValueFormat::Text => {
                 let s = value.as_str()?;
@@ -196,7 +203,7 @@ impl<'r> Decode<'r, MySql> for PrimitiveDateTime {
         match value.format() {
             MySqlValueFormat::Binary => {
                 let buf = value.as_bytes()?;
-                let len = buf[0];
+                let len = check_len(buf, &[0, 4, 7, 11])?;
                 let date = decode_date(&buf[1..])?.ok_or(UnexpectedNullError)?;
 
                 let dt = if len > 4 {
@@ -281,3 +288,30 @@ fn decode_time(len: u8, mut buf: &[u8]) -> Result<Time, BoxDynError> {
     Time::try_from_hms_micro(hour, minute, seconds, micros as u32)
         .map_err(|e| format!("Time out of range for MySQL: {}", e).into())
 }
+
+#[test]
+fn it_rejects_truncated_binary_dates_and_times() {
+    use super::binary_time::value;
+
+    let date: Result<Date, _> = Decode::<MySql>::decode(value(ColumnType::Date, &[4, 0xe4]));
+    assert!(date.is_err());
+
+    let date: Result<Date, _> = Decode::<MySql>::decode(value(ColumnType::Date, &[]));
+    assert!(date.is_err());
+
+    let date: Date =
+        Decode::<MySql>::decode(value(ColumnType::Date, &[4, 0xe4, 0x07, 1, 2])).unwrap();
+    assert_eq!(date, Date::try_from_ymd(2020, 1, 2).unwrap());
+
+    let dt: Result<PrimitiveDateTime, _> =
+        Decode::<MySql>::decode(value(ColumnType::Datetime, &[7, 0xe4, 0x07, 1, 2, 3]));
+    assert!(dt.is_err());
+
+    let time: Result<Time, _> =
+        Decode::<MySql>::decode(value(ColumnType::Time, &[12, 0, 0, 0, 0, 0, 1, 2, 3]));
+    assert!(time.is_err());
+
+    let time: Result<Time, _> =
+        Decode::<MySql>::decode(value(ColumnType::Time, &[8, 1, 0, 0, 0, 0, 1, 2, 3]));
+    assert!(time.is_err());
+}
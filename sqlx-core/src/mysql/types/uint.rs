@@ -120,6 +120,15 @@ fn uint_decode(value: MySqlValueRef<'_>) -> Result<u64, BoxDynError> {
 
         MySqlValueFormat::Binary => {
             let buf = value.as_bytes()?;
+
+            if buf.is_empty() || buf.len() > 8 {
+                return Err(format!(
+                    "expected an integer of 1 to 8 bytes but received {} bytes",
+                    buf.len()
+                )
+                .into());
+            }
+
             LittleEndian::read_uint(buf, buf.len())
         }
     })
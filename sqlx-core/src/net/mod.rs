@@ -1,5 +1,7 @@
 mod socket;
+mod socks;
 mod tls;
 
-pub use socket::Socket;
+pub use socket::{Socket, TcpSettings};
+pub use socks::Socks5Proxy;
 pub use tls::MaybeTlsStream;
@@ -5,31 +5,146 @@ use std::net::Shutdown;
 use std::path::Path;
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use std::time::Duration;
 
+use futures_util::io::{AsyncRead as FuturesRead, AsyncWrite as FuturesWrite};
 use sqlx_rt::{AsyncRead, AsyncWrite, TcpStream};
 
+use crate::common::Secret;
+use crate::net::Socks5Proxy;
+use crate::runtime::{self, RuntimeStream};
+
+#[derive(Clone, Debug)]
+pub struct TcpSettings {
+    pub(crate) nodelay: bool,
+    pub(crate) keepalive: Option<Duration>,
+    pub(crate) send_buffer_size: Option<usize>,
+    pub(crate) recv_buffer_size: Option<usize>,
+    pub(crate) socks5_proxy: Option<Socks5Proxy>,
+    // kept apart from the proxy so that they can be set before it
+    pub(crate) socks5_credentials: Option<(String, Secret)>,
+}
+
+impl Default for TcpSettings {
+    fn default() -> Self {
+        TcpSettings {
+            // we flush once per round-trip; waiting on Nagle only adds latency
+            nodelay: true,
+            keepalive: None,
+            send_buffer_size: None,
+            recv_buffer_size: None,
+            socks5_proxy: None,
+            socks5_credentials: None,
+        }
+    }
+}
+
+impl TcpSettings {
+    fn apply(&self, stream: &TcpStream) -> io::Result<()> {
+        stream.set_nodelay(self.nodelay)?;
+
+        #[cfg(any(feature = "_rt-actix", feature = "_rt-tokio"))]
+        {
+            if self.keepalive.is_some() {
+                stream.set_keepalive(self.keepalive)?;
+            }
+
+            if let Some(size) = self.send_buffer_size {
+                stream.set_send_buffer_size(size)?;
+            }
+
+            if let Some(size) = self.recv_buffer_size {
+                stream.set_recv_buffer_size(size)?;
+            }
+        }
+
+        #[cfg(not(any(feature = "_rt-actix", feature = "_rt-tokio")))]
+        {
+            // async-std does not expose these socket options
+            if self.keepalive.is_some()
+                || self.send_buffer_size.is_some()
+                || self.recv_buffer_size.is_some()
+            {
+                log::warn!(
+                    "TCP keepalive and socket buffer sizes are not supported by this runtime \
+                     and will be ignored"
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub enum Socket {
     Tcp(TcpStream),
 
     #[cfg(unix)]
     Unix(sqlx_rt::UnixStream),
+
+    // opened by a custom [Runtime](crate::runtime::Runtime); implements the `futures-io` traits
+    // whichever runtime feature is enabled
+    Custom(Box<dyn RuntimeStream>),
 }
 
 impl Socket {
-    pub async fn connect_tcp(host: &str, port: u16) -> io::Result<Self> {
-        TcpStream::connect((host, port)).await.map(Socket::Tcp)
+    pub async fn connect_tcp(host: &str, port: u16, settings: &TcpSettings) -> io::Result<Self> {
+        match &settings.socks5_proxy {
+            Some(proxy) => {
+                let mut socket = Self::connect_direct(&proxy.host, proxy.port, settings).await?;
+
+                proxy
+                    .handshake(
+                        &mut socket,
+                        host,
+                        port,
+                        settings.socks5_credentials.as_ref(),
+                    )
+                    .await?;
+
+                Ok(socket)
+            }
+
+            None => Self::connect_direct(host, port, settings).await,
+        }
+    }
+
+    async fn connect_direct(host: &str, port: u16, settings: &TcpSettings) -> io::Result<Self> {
+        if let Some(runtime) = runtime::custom() {
+            return runtime.connect_tcp(host, port).await.map(Socket::Custom);
+        }
+
+        let stream = TcpStream::connect((host, port)).await?;
+
+        settings.apply(&stream)?;
+
+        Ok(Socket::Tcp(stream))
     }
 
     #[cfg(unix)]
     pub async fn connect_uds(path: impl AsRef<Path>) -> io::Result<Self> {
+        if let Some(runtime) = runtime::custom() {
+            return runtime
+                .connect_unix(path.as_ref())
+                .await
+                .map(Socket::Custom);
+        }
+
         sqlx_rt::UnixStream::connect(path.as_ref())
             .await
             .map(Socket::Unix)
     }
 
     #[cfg(not(unix))]
-    pub async fn connect_uds(_: impl AsRef<Path>) -> io::Result<Self> {
+    pub async fn connect_uds(path: impl AsRef<Path>) -> io::Result<Self> {
+        if let Some(runtime) = runtime::custom() {
+            return runtime
+                .connect_unix(path.as_ref())
+                .await
+                .map(Socket::Custom);
+        }
+
         Err(io::Error::new(
             io::ErrorKind::Other,
             "Unix domain sockets are not supported outside Unix platforms.",
@@ -42,6 +157,9 @@ impl Socket {
 
             #[cfg(unix)]
             Socket::Unix(s) => s.shutdown(Shutdown::Both),
+
+            // the stream is closed when dropped
+            Socket::Custom(_) => Ok(()),
         }
     }
 }
@@ -57,6 +175,8 @@ impl AsyncRead for Socket {
 
             #[cfg(unix)]
             Socket::Unix(s) => Pin::new(s).poll_read(cx, buf),
+
+            Socket::Custom(s) => FuturesRead::poll_read(Pin::new(s), cx, buf),
         }
     }
 
@@ -75,6 +195,22 @@ impl AsyncRead for Socket {
 
             #[cfg(unix)]
             Socket::Unix(s) => Pin::new(s).poll_read_buf(cx, buf),
+
+            Socket::Custom(s) => {
+                // `futures-io` cannot read into the uninitialized capacity of `buf`, so read
+                // through a buffer on the stack instead
+                let mut chunk = [0_u8; 8 * 1024];
+                let len = chunk.len().min(buf.remaining_mut());
+
+                match FuturesRead::poll_read(Pin::new(s), cx, &mut chunk[..len]) {
+                    Poll::Ready(Ok(n)) => {
+                        buf.put_slice(&chunk[..n]);
+                        Poll::Ready(Ok(n))
+                    }
+
+                    other => other,
+                }
+            }
         }
     }
 }
@@ -90,6 +226,8 @@ impl AsyncWrite for Socket {
 
             #[cfg(unix)]
             Socket::Unix(s) => Pin::new(s).poll_write(cx, buf),
+
+            Socket::Custom(s) => FuturesWrite::poll_write(Pin::new(s), cx, buf),
         }
     }
 
@@ -99,6 +237,8 @@ impl AsyncWrite for Socket {
 
             #[cfg(unix)]
             Socket::Unix(s) => Pin::new(s).poll_flush(cx),
+
+            Socket::Custom(s) => FuturesWrite::poll_flush(Pin::new(s), cx),
         }
     }
 
@@ -109,6 +249,8 @@ impl AsyncWrite for Socket {
 
             #[cfg(unix)]
             Socket::Unix(s) => Pin::new(s).poll_shutdown(cx),
+
+            Socket::Custom(s) => FuturesWrite::poll_close(Pin::new(s), cx),
         }
     }
 
@@ -119,6 +261,8 @@ impl AsyncWrite for Socket {
 
             #[cfg(unix)]
             Socket::Unix(s) => Pin::new(s).poll_close(cx),
+
+            Socket::Custom(s) => FuturesWrite::poll_close(Pin::new(s), cx),
         }
     }
 
@@ -137,6 +281,15 @@ impl AsyncWrite for Socket {
 
             #[cfg(unix)]
             Socket::Unix(s) => Pin::new(s).poll_write_buf(cx, buf),
+
+            Socket::Custom(s) => match FuturesWrite::poll_write(Pin::new(s), cx, buf.bytes()) {
+                Poll::Ready(Ok(n)) => {
+                    buf.advance(n);
+                    Poll::Ready(Ok(n))
+                }
+
+                other => other,
+            },
         }
     }
 }
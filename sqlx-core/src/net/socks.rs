@@ -0,0 +1,237 @@
+use std::io;
+use std::net::IpAddr;
+
+use sqlx_rt::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::common::Secret;
+
+// https://tools.ietf.org/html/rfc1928
+// https://tools.ietf.org/html/rfc1929
+
+const VERSION: u8 = 0x05;
+
+const METHOD_NONE: u8 = 0x00;
+const METHOD_PASSWORD: u8 = 0x02;
+const METHOD_UNACCEPTABLE: u8 = 0xFF;
+
+const COMMAND_CONNECT: u8 = 0x01;
+
+const ADDRESS_IPV4: u8 = 0x01;
+const ADDRESS_DOMAIN: u8 = 0x03;
+const ADDRESS_IPV6: u8 = 0x04;
+
+/// A SOCKS5 proxy that database connections are tunneled through.
+#[derive(Clone, Debug)]
+pub struct Socks5Proxy {
+    pub(crate) host: String,
+    pub(crate) port: u16,
+}
+
+impl Socks5Proxy {
+    pub(crate) fn new(host: &str, port: u16) -> Self {
+        Self {
+            host: host.to_owned(),
+            port,
+        }
+    }
+
+    /// Ask the proxy, over a stream that is connected to it, to connect to the database server.
+    ///
+    /// Host names are resolved by the proxy, so that names that are only known on the
+    /// far side of the proxy can be reached.
+    pub(crate) async fn handshake<S>(
+        &self,
+        stream: &mut S,
+        host: &str,
+        port: u16,
+        credentials: Option<&(String, Secret)>,
+    ) -> io::Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        let greeting: &[u8] = if credentials.is_some() {
+            &[VERSION, 2, METHOD_NONE, METHOD_PASSWORD]
+        } else {
+            &[VERSION, 1, METHOD_NONE]
+        };
+
+        stream.write_all(greeting).await?;
+
+        let method = read_method(stream).await?;
+
+        match (method, credentials) {
+            (METHOD_NONE, _) => {}
+
+            (METHOD_PASSWORD, Some((username, password))) => {
+                stream
+                    .write_all(&encode_credentials(username, password)?)
+                    .await?;
+
+                let mut reply = [0; 2];
+                stream.read_exact(&mut reply).await?;
+
+                if reply[1] != 0 {
+                    return Err(proxy_error("SOCKS5 proxy rejected the credentials"));
+                }
+            }
+
+            (METHOD_UNACCEPTABLE, _) => {
+                return Err(proxy_error(
+                    "SOCKS5 proxy did not accept any of the offered authentication methods",
+                ));
+            }
+
+            (method, _) => {
+                return Err(proxy_error(format!(
+                    "SOCKS5 proxy chose unsupported authentication method {:#04x}",
+                    method
+                )));
+            }
+        }
+
+        stream.write_all(&encode_connect(host, port)?).await?;
+
+        // VER, REP, RSV, ATYP
+        let mut reply = [0; 4];
+        stream.read_exact(&mut reply).await?;
+
+        if reply[0] != VERSION {
+            return Err(proxy_error(
+                "received an invalid reply from the SOCKS5 proxy",
+            ));
+        }
+
+        if reply[1] != 0 {
+            return Err(proxy_error(format!(
+                "SOCKS5 proxy failed to connect to {}:{}: {}",
+                host,
+                port,
+                reply_message(reply[1])
+            )));
+        }
+
+        // skip the bound address and port, which are of no use to us
+        let len = match reply[3] {
+            ADDRESS_IPV4 => 4,
+            ADDRESS_IPV6 => 16,
+            ADDRESS_DOMAIN => {
+                let mut len = [0; 1];
+                stream.read_exact(&mut len).await?;
+
+                usize::from(len[0])
+            }
+
+            _ => {
+                return Err(proxy_error(
+                    "received an invalid reply from the SOCKS5 proxy",
+                ))
+            }
+        };
+
+        let mut bound = vec![0; len + 2];
+        stream.read_exact(&mut bound).await?;
+
+        Ok(())
+    }
+}
+
+async fn read_method<S>(stream: &mut S) -> io::Result<u8>
+where
+    S: AsyncRead + Unpin,
+{
+    let mut reply = [0; 2];
+    stream.read_exact(&mut reply).await?;
+
+    if reply[0] != VERSION {
+        return Err(proxy_error(
+            "received an invalid reply from the SOCKS5 proxy",
+        ));
+    }
+
+    Ok(reply[1])
+}
+
+fn encode_credentials(username: &str, password: &str) -> io::Result<Vec<u8>> {
+    let mut buf = vec![0x01];
+
+    for field in &[username, password] {
+        if field.len() > 255 {
+            return Err(proxy_error(
+                "SOCKS5 proxy credentials are limited to 255 bytes",
+            ));
+        }
+
+        buf.push(field.len() as u8);
+        buf.extend_from_slice(field.as_bytes());
+    }
+
+    Ok(buf)
+}
+
+fn encode_connect(host: &str, port: u16) -> io::Result<Vec<u8>> {
+    let mut buf = vec![VERSION, COMMAND_CONNECT, 0];
+
+    match host.parse::<IpAddr>() {
+        Ok(IpAddr::V4(addr)) => {
+            buf.push(ADDRESS_IPV4);
+            buf.extend_from_slice(&addr.octets());
+        }
+
+        Ok(IpAddr::V6(addr)) => {
+            buf.push(ADDRESS_IPV6);
+            buf.extend_from_slice(&addr.octets());
+        }
+
+        Err(_) => {
+            if host.len() > 255 {
+                return Err(proxy_error(
+                    "host name is too long to be sent to a SOCKS5 proxy",
+                ));
+            }
+
+            buf.push(ADDRESS_DOMAIN);
+            buf.push(host.len() as u8);
+            buf.extend_from_slice(host.as_bytes());
+        }
+    }
+
+    buf.extend_from_slice(&port.to_be_bytes());
+
+    Ok(buf)
+}
+
+fn reply_message(code: u8) -> &'static str {
+    match code {
+        0x01 => "general failure",
+        0x02 => "connection not allowed by ruleset",
+        0x03 => "network unreachable",
+        0x04 => "host unreachable",
+        0x05 => "connection refused",
+        0x06 => "TTL expired",
+        0x07 => "command not supported",
+        0x08 => "address type not supported",
+        _ => "unknown error",
+    }
+}
+
+fn proxy_error(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, message.into())
+}
+
+#[test]
+fn it_encodes_connect_requests() {
+    assert_eq!(
+        encode_connect("10.0.0.1", 5432).unwrap(),
+        [5, 1, 0, 1, 10, 0, 0, 1, 0x15, 0x38]
+    );
+
+    assert_eq!(
+        encode_connect("db", 3306).unwrap(),
+        [5, 1, 0, 3, 2, b'd', b'b', 0x0C, 0xEA]
+    );
+
+    assert_eq!(
+        encode_credentials("user", "pw").unwrap(),
+        [1, 4, b'u', b's', b'e', b'r', 2, b'p', b'w']
+    );
+}
@@ -2,11 +2,12 @@ use super::inner::{DecrementSizeGuard, SharedPool};
 use crate::connection::Connection;
 use crate::database::Database;
 use crate::error::Error;
-use sqlx_rt::spawn;
+use crate::pool::CloseReason;
+use crate::runtime::spawn;
 use std::fmt::{self, Debug, Formatter};
 use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// A connection managed by a [`Pool`][crate::pool::Pool].
 ///
@@ -16,6 +17,17 @@ pub struct PoolConnection<DB: Database> {
     pub(crate) pool: Arc<SharedPool<DB>>,
 }
 
+/// Information about an idle connection, as passed to [`Pool::retain`][crate::pool::Pool::retain].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct PoolConnectionMetadata {
+    /// The time since the connection was opened.
+    pub age: Duration,
+
+    /// The time since the connection was last returned to the pool.
+    pub idle_for: Duration,
+}
+
 pub(super) struct Live<DB: Database> {
     pub(super) raw: DB::Connection,
     pub(super) created: Instant,
@@ -60,6 +72,16 @@ impl<DB: Database> PoolConnection<DB> {
     pub fn release(mut self) -> DB::Connection {
         self.live.take().expect("PoolConnection double-dropped").raw
     }
+
+    // closes the connection instead of returning it to the pool, without waiting on anything;
+    // for a connection left in a state that cannot be reset outside of a runtime
+    pub(crate) fn close_without_waiting(mut self) {
+        if let Some(live) = self.live.take() {
+            // the connection writes what it can of its close message when dropped, and the
+            // guard gives its slot in the pool back
+            drop(live.float(&self.pool));
+        }
+    }
 }
 
 /// Returns the connection to the [`Pool`][crate::pool::Pool] it was checked-out from.
@@ -78,6 +100,7 @@ impl<DB: Database> Drop for PoolConnection<DB> {
                         // we now consider the connection to be broken
                         // close the connection and drop from the pool
                         let _ = live.float(&pool).into_idle().close().await;
+                        pool.options.notify_close(CloseReason::Broken);
                     } else {
                         // after we have flushed successfully, release to the pool
                         pool.release(live.float(&pool));
@@ -107,6 +130,15 @@ impl<DB: Database> Live<DB> {
     }
 }
 
+impl<DB: Database> Idle<DB> {
+    pub fn metadata(&self) -> PoolConnectionMetadata {
+        PoolConnectionMetadata {
+            age: self.live.created.elapsed(),
+            idle_for: self.since.elapsed(),
+        }
+    }
+}
+
 impl<DB: Database> Deref for Idle<DB> {
     type Target = Live<DB>;
 
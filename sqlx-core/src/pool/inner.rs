@@ -1,17 +1,17 @@
-use super::connection::{Floating, Idle, Live};
-use crate::connection::ConnectOptions;
+use super::connection::{Floating, Idle, Live, PoolConnectionMetadata};
 use crate::connection::Connection;
 use crate::database::Database;
 use crate::error::Error;
-use crate::pool::{deadline_as_timeout, PoolOptions};
+use crate::pool::{deadline_as_timeout, CloseReason, PoolOptions};
+use crate::query_cache::ResultCache;
+use crate::runtime::{sleep, spawn, timeout};
 use crossbeam_queue::{ArrayQueue, SegQueue};
 use futures_core::task::{Poll, Waker};
 use futures_util::future;
-use sqlx_rt::{sleep, spawn, timeout};
 use std::cmp;
 use std::mem;
 use std::ptr;
-use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU8, Ordering};
 use std::sync::Arc;
 use std::task::Context;
 use std::time::Instant;
@@ -23,6 +23,7 @@ pub(crate) struct SharedPool<DB: Database> {
     pub(super) size: AtomicU32,
     is_closed: AtomicBool,
     pub(super) options: PoolOptions<DB>,
+    pub(crate) results: ResultCache<DB>,
 }
 
 impl<DB: Database> SharedPool<DB> {
@@ -47,16 +48,50 @@ impl<DB: Database> SharedPool<DB> {
 
         // ensure we wait until the pool is actually closed
         while self.size() > 0 {
-            let _ = self
-                .idle_conns
-                .pop()
-                .map(|idle| Floating::from_idle(idle, self));
+            if let Ok(idle) = self.idle_conns.pop() {
+                let _ = Floating::from_idle(idle, self);
+                self.options.notify_close(CloseReason::PoolClosed);
+            }
 
             // yield to avoid starving the executor
             sqlx_rt::yield_now().await;
         }
     }
 
+    /// Close every idle connection for which `keep` returns `false`.
+    ///
+    /// Connections that are checked out are not affected.
+    pub(super) async fn retain<F>(&self, mut keep: F)
+    where
+        F: FnMut(&PoolConnectionMetadata) -> bool,
+    {
+        // take every connection that is idle right now, so that connections we return to
+        // the queue are not visited twice
+        let idle = (0..self.idle_conns.len())
+            .filter_map(|_| self.pop_idle())
+            .collect::<Vec<_>>();
+
+        let mut prune = Vec::new();
+
+        for conn in idle {
+            if keep(&conn.metadata()) {
+                self.idle_conns
+                    .push(conn.into_leakable())
+                    .expect("BUG: connection queue overflow in retain()");
+
+                // a task may have started waiting while the connection was out of the queue
+                wake_one(&self.waiters);
+            } else {
+                prune.push(conn);
+            }
+        }
+
+        for conn in prune {
+            let _ = conn.close().await;
+            self.options.notify_close(CloseReason::Pruned);
+        }
+    }
+
     #[inline]
     pub(super) fn try_acquire(&self) -> Option<Floating<'_, Live<DB>>> {
         // don't cut in line
@@ -75,9 +110,19 @@ impl<DB: Database> SharedPool<DB> {
     }
 
     pub(super) fn release(&self, mut floating: Floating<'_, Live<DB>>) {
+        if floating.raw.is_in_transaction() {
+            // a transaction that was started by executing `BEGIN` directly and never ended
+            // would otherwise be inherited by the next user of the connection
+            log::warn!("closing a connection that was returned to the pool inside a transaction");
+            self.options.notify_close(CloseReason::InTransaction);
+
+            return;
+        }
+
         if let Some(test) = &self.options.after_release {
             if !test(&mut floating.raw) {
                 // drop the connection and do not return to the pool
+                self.options.notify_close(CloseReason::Rejected);
                 return;
             }
         }
@@ -86,9 +131,7 @@ impl<DB: Database> SharedPool<DB> {
             .push(floating.into_idle().into_leakable())
             .expect("BUG: connection queue overflow in release()");
 
-        if let Ok(waker) = self.waiters.pop() {
-            waker.wake();
-        }
+        wake_one(&self.waiters);
     }
 
     /// Try to atomically increment the pool size for a new connection.
@@ -123,19 +166,25 @@ impl<DB: Database> SharedPool<DB> {
             return Err(Error::PoolClosed);
         }
 
-        let mut waiter = None;
+        // cancels the waiter if this future is dropped or times out before it is woken
+        let mut guard = WaiterGuard {
+            waiters: &self.waiters,
+            waiter: None,
+        };
 
         timeout(
             deadline_as_timeout::<DB>(deadline)?,
             // `poll_fn` gets us easy access to a `Waker` that we can push to our queue
             future::poll_fn(|cx| -> Poll<()> {
-                let waiter = waiter.get_or_insert_with(|| {
+                let waiter = guard.waiter.get_or_insert_with(|| {
                     let waiter = Waiter::new(cx);
                     self.waiters.push(waiter.clone());
                     waiter
                 });
 
                 if waiter.is_woken() {
+                    // the wakeup is ours; there is nothing to cancel
+                    guard.waiter = None;
                     Poll::Ready(())
                 } else {
                     Poll::Pending
@@ -150,6 +199,8 @@ impl<DB: Database> SharedPool<DB> {
         options: PoolOptions<DB>,
         connect_options: <DB::Connection as Connection>::Options,
     ) -> Arc<Self> {
+        let results = ResultCache::new(options.result_cache_capacity);
+
         let pool = Self {
             connect_options,
             idle_conns: ArrayQueue::new(options.max_connections as usize),
@@ -157,6 +208,7 @@ impl<DB: Database> SharedPool<DB> {
             size: AtomicU32::new(0),
             is_closed: AtomicBool::new(false),
             options,
+            results,
         };
 
         let pool = Arc::new(pool);
@@ -166,10 +218,30 @@ impl<DB: Database> SharedPool<DB> {
         pool
     }
 
+    /// Acquire a connection, giving up with `Error::PoolTimedOut` once `deadline` has passed.
+    ///
+    /// The returned future may be dropped at any point. A connection that is being checked or
+    /// opened is then closed, its slot in the pool is released, and a wakeup that was meant
+    /// for this task is passed on to the next waiting task.
     #[allow(clippy::needless_lifetimes)]
-    pub(super) async fn acquire<'s>(&'s self) -> Result<Floating<'s, Live<DB>>, Error> {
-        let start = Instant::now();
-        let deadline = start + self.options.connect_timeout;
+    pub(super) async fn acquire<'s>(
+        &'s self,
+        deadline: Instant,
+    ) -> Result<Floating<'s, Live<DB>>, Error> {
+        let result = self.acquire_until(deadline).await;
+
+        if let Err(Error::PoolTimedOut) = result {
+            self.options.notify_acquire_timeout();
+        }
+
+        result
+    }
+
+    #[allow(clippy::needless_lifetimes)]
+    async fn acquire_until<'s>(
+        &'s self,
+        deadline: Instant,
+    ) -> Result<Floating<'s, Live<DB>>, Error> {
         let mut waited = !self.options.fair;
 
         // Unless the pool has been closed ...
@@ -216,14 +288,27 @@ impl<DB: Database> SharedPool<DB> {
 
         let timeout = super::deadline_as_timeout::<DB>(deadline)?;
 
+        let connect = DB::Connection::connect_with(&self.connect_options);
+
         // result here is `Result<Result<C, Error>, TimeoutError>`
-        match sqlx_rt::timeout(timeout, self.connect_options.connect()).await {
+        let result = crate::runtime::timeout(timeout, connect).await;
+
+        if let Ok(Err(error)) = &result {
+            self.options.notify_connect_error(error);
+        }
+
+        match result {
             // successfully established connection
             Ok(Ok(mut raw)) => {
                 if let Some(callback) = &self.options.after_connect {
-                    callback(&mut raw).await?;
+                    if let Err(error) = callback(&mut raw).await {
+                        self.options.notify_connect_error(&error);
+                        return Err(error);
+                    }
                 }
 
+                self.options.notify_connect();
+
                 Ok(Some(Floating::new_live(raw, guard)))
             }
 
@@ -241,7 +326,11 @@ impl<DB: Database> SharedPool<DB> {
             Ok(Err(e)) => Err(e),
 
             // timed out
-            Err(_) => Err(Error::PoolTimedOut),
+            Err(_) => {
+                let error = Error::PoolTimedOut;
+                self.options.notify_connect_error(&error);
+                Err(error)
+            }
         }
     }
 }
@@ -272,6 +361,7 @@ async fn check_conn<'s: 'p, 'p, DB: Database>(
         // we're closing the connection either way
         // close the connection but don't really care about the result
         let _ = conn.close().await;
+        options.notify_close(CloseReason::MaxLifetime);
         return None;
     } else if options.test_before_acquire {
         // Check that the connection is still live
@@ -281,17 +371,20 @@ async fn check_conn<'s: 'p, 'p, DB: Database>(
             // the error itself here isn't necessarily unexpected so WARN is too strong
             log::info!("ping on idle connection returned error: {}", e);
             // connection is broken so don't try to close nicely
+            options.notify_close(CloseReason::Broken);
             return None;
         }
     } else if let Some(test) = &options.before_acquire {
         match test(&mut conn.live.raw).await {
             Ok(false) => {
                 // connection was rejected by user-defined hook
+                options.notify_close(CloseReason::Rejected);
                 return None;
             }
 
             Err(error) => {
                 log::info!("in `before_acquire`: {}", error);
+                options.notify_close(CloseReason::Rejected);
                 return None;
             }
 
@@ -320,23 +413,30 @@ fn spawn_reaper<DB: Database>(pool: &Arc<SharedPool<DB>>) {
             // reap at most the current size minus the minimum idle
             let max_reaped = pool.size().saturating_sub(pool.options.min_connections);
 
-            // collect connections to reap
-            let (reap, keep) = (0..max_reaped)
-                // only connections waiting in the queue
+            // collect connections to reap, with the reason for reaping each
+            let mut reap = Vec::new();
+
+            // only connections waiting in the queue
+            let idle = (0..max_reaped)
                 .filter_map(|_| pool.pop_idle())
-                .partition::<Vec<_>, _>(|conn| {
-                    is_beyond_idle(conn, &pool.options) || is_beyond_lifetime(conn, &pool.options)
-                });
+                .collect::<Vec<_>>();
 
-            for conn in keep {
-                // return these connections to the pool first
-                pool.idle_conns
-                    .push(conn.into_leakable())
-                    .expect("BUG: connection queue overflow in spawn_reaper");
+            for conn in idle {
+                if is_beyond_lifetime(&conn, &pool.options) {
+                    reap.push((conn, CloseReason::MaxLifetime));
+                } else if is_beyond_idle(&conn, &pool.options) {
+                    reap.push((conn, CloseReason::IdleTimeout));
+                } else {
+                    // return these connections to the pool first
+                    pool.idle_conns
+                        .push(conn.into_leakable())
+                        .expect("BUG: connection queue overflow in spawn_reaper");
+                }
             }
 
-            for conn in reap {
+            for (conn, reason) in reap {
                 let _ = conn.close().await;
+                pool.options.notify_close(reason);
             }
 
             sleep(period).await;
@@ -378,31 +478,78 @@ impl Drop for DecrementSizeGuard<'_> {
         assert!(!self.dropped, "double-dropped!");
         self.dropped = true;
         self.size.fetch_sub(1, Ordering::SeqCst);
-        if let Ok(waker) = self.waiters.pop() {
-            waker.wake();
+        wake_one(self.waiters);
+    }
+}
+
+/// Wake the first waiter in the queue that is still waiting.
+fn wake_one(waiters: &SegQueue<Arc<Waiter>>) {
+    while let Ok(waiter) = waiters.pop() {
+        if waiter.wake() {
+            return;
         }
     }
 }
 
+const WAITING: u8 = 0;
+const WOKEN: u8 = 1;
+const CANCELLED: u8 = 2;
+
 struct Waiter {
-    woken: AtomicBool,
+    state: AtomicU8,
     waker: Waker,
 }
 
 impl Waiter {
     fn new(cx: &mut Context<'_>) -> Arc<Self> {
         Arc::new(Self {
-            woken: AtomicBool::new(false),
+            state: AtomicU8::new(WAITING),
             waker: cx.waker().clone(),
         })
     }
 
-    fn wake(&self) {
-        self.woken.store(true, Ordering::Release);
-        self.waker.wake_by_ref();
+    /// Returns `false` if the waiter was cancelled, in which case the wakeup should be
+    /// given to another waiter.
+    fn wake(&self) -> bool {
+        let woken = self
+            .state
+            .compare_exchange(WAITING, WOKEN, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok();
+
+        if woken {
+            self.waker.wake_by_ref();
+        }
+
+        woken
+    }
+
+    /// Returns `false` if the waiter was already woken.
+    fn cancel(&self) -> bool {
+        self.state
+            .compare_exchange(WAITING, CANCELLED, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
     }
 
     fn is_woken(&self) -> bool {
-        self.woken.load(Ordering::Acquire)
+        self.state.load(Ordering::Acquire) == WOKEN
+    }
+}
+
+/// Cancels a waiter that was never consumed, e.g. because the `acquire()` future waiting on it
+/// was dropped or timed out.
+struct WaiterGuard<'a> {
+    waiters: &'a SegQueue<Arc<Waiter>>,
+    waiter: Option<Arc<Waiter>>,
+}
+
+impl Drop for WaiterGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(waiter) = self.waiter.take() {
+            if !waiter.cancel() {
+                // we were woken but will never act on it; pass the wakeup on so that
+                // another task is not left waiting for a connection that is available
+                wake_one(self.waiters);
+            }
+        }
     }
 }
@@ -54,6 +54,11 @@
 //! [`Pool::acquire`][Pool::acquire] or
 //! [`Pool::begin`][Pool::begin].
 //!
+//! # Sharding
+//!
+//! A database that is partitioned over several servers can be accessed through a
+//! [`ShardedPool`], which holds one pool per shard and routes each key to the pool of its shard.
+//!
 
 use self::inner::SharedPool;
 use crate::connection::Connection;
@@ -74,10 +79,12 @@ mod maybe;
 mod connection;
 mod inner;
 mod options;
+mod sharded;
 
-pub use self::connection::PoolConnection;
+pub use self::connection::{PoolConnection, PoolConnectionMetadata};
 pub(crate) use self::maybe::MaybePoolConnection;
-pub use self::options::PoolOptions;
+pub use self::options::{CloseReason, PoolOptions};
+pub use self::sharded::{ShardId, ShardedPool};
 
 /// An asynchronous pool of SQLx database connections.
 ///
@@ -248,9 +255,56 @@ impl<DB: Database> Pool<DB> {
     /// Retrieves a connection from the pool.
     ///
     /// Waits for at most the configured connection timeout before returning an error.
+    ///
+    /// The returned future may be dropped at any point without leaking a connection or a slot
+    /// in the pool; see [`acquire_with_deadline`][Pool::acquire_with_deadline].
     pub fn acquire(&self) -> impl Future<Output = Result<PoolConnection<DB>, Error>> + 'static {
+        self.acquire_with_deadline(Instant::now() + self.0.options.connect_timeout)
+    }
+
+    /// Retrieves a connection from the pool, waiting until at most `deadline` before returning
+    /// [`Error::PoolTimedOut`].
+    ///
+    /// The configured connection timeout does not apply; a deadline past it may be given to
+    /// wait longer than the timeout, as may one earlier to bound the time spent on a request.
+    ///
+    /// # Cancellation
+    ///
+    /// The returned future may be dropped at any point, for example by a surrounding timeout,
+    /// without affecting the pool:
+    ///
+    /// * a connection that was being opened, or checked before being returned, is closed and
+    ///   its slot is released so that [`size`][Pool::size] stays accurate;
+    /// * if a connection had been made available to this call while it was waiting, it is
+    ///   handed on to the next task waiting in [`acquire`][Pool::acquire].
+    pub fn acquire_with_deadline(
+        &self,
+        deadline: Instant,
+    ) -> impl Future<Output = Result<PoolConnection<DB>, Error>> + 'static {
         let shared = self.0.clone();
-        async move { shared.acquire().await.map(|conn| conn.attach(&shared)) }
+        let acquire = async move {
+            shared
+                .acquire(deadline)
+                .await
+                .map(|conn| conn.attach(&shared))
+        };
+
+        #[cfg(feature = "tracing")]
+        {
+            tracing::Instrument::instrument(
+                acquire,
+                tracing::info_span!(
+                    target: "sqlx::pool",
+                    "sqlx::pool::acquire",
+                    db.system = DB::NAME,
+                ),
+            )
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        {
+            acquire
+        }
     }
 
     /// Attempts to retrieve a connection from the pool if there is one available.
@@ -285,6 +339,31 @@ impl<DB: Database> Pool<DB> {
         self.0.close().await;
     }
 
+    /// Closes all connections that are currently idle in the pool.
+    ///
+    /// Connections that are checked out are not affected and return to the pool as usual.
+    /// Useful to shed connections ahead of a planned database failover, or during periods of
+    /// low traffic, without waiting for [`PoolOptions::idle_timeout`]. New connections are
+    /// opened on demand by [`acquire`][Pool::acquire].
+    pub async fn close_idle(&self) {
+        self.0.retain(|_| false).await;
+    }
+
+    /// Closes the idle connections for which `keep` returns `false`.
+    ///
+    /// ```rust,ignore
+    /// use std::time::Duration;
+    ///
+    /// // close connections that were opened more than an hour ago
+    /// pool.retain(|meta| meta.age < Duration::from_secs(60 * 60)).await;
+    /// ```
+    pub async fn retain<F>(&self, keep: F)
+    where
+        F: FnMut(&PoolConnectionMetadata) -> bool,
+    {
+        self.0.retain(keep).await;
+    }
+
     /// Returns `true` if [`.close()`][Pool::close] has been called on the pool, `false` otherwise.
     pub fn is_closed(&self) -> bool {
         self.0.is_closed()
@@ -303,6 +382,18 @@ impl<DB: Database> Pool<DB> {
     pub fn num_idle(&self) -> usize {
         self.0.num_idle()
     }
+
+    /// Drops all cached results of the query with the given SQL.
+    ///
+    /// See [`Query::cached`](crate::query::Query::cached).
+    pub fn invalidate_cached(&self, sql: &str) {
+        self.0.results.invalidate(sql);
+    }
+
+    /// Drops all query results cached in this pool.
+    pub fn clear_cached(&self) {
+        self.0.results.clear();
+    }
 }
 
 /// Returns a new [Pool] tied to the same shared connection pool.
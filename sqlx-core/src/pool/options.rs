@@ -3,8 +3,8 @@ use crate::database::Database;
 use crate::error::Error;
 use crate::pool::inner::SharedPool;
 use crate::pool::Pool;
+use crate::runtime::spawn;
 use futures_core::future::BoxFuture;
-use sqlx_rt::spawn;
 use std::fmt::{self, Debug, Formatter};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -26,12 +26,17 @@ pub struct PoolOptions<DB: Database> {
     >,
     pub(crate) after_release:
         Option<Box<dyn Fn(&mut DB::Connection) -> bool + 'static + Send + Sync>>,
+    pub(crate) on_connect: Option<Box<dyn Fn() + 'static + Send + Sync>>,
+    pub(crate) on_close: Option<Box<dyn Fn(CloseReason) + 'static + Send + Sync>>,
+    pub(crate) on_acquire_timeout: Option<Box<dyn Fn() + 'static + Send + Sync>>,
+    pub(crate) on_connect_error: Option<Box<dyn Fn(&Error) + 'static + Send + Sync>>,
     pub(crate) max_connections: u32,
     pub(crate) connect_timeout: Duration,
     pub(crate) min_connections: u32,
     pub(crate) max_lifetime: Option<Duration>,
     pub(crate) idle_timeout: Option<Duration>,
     pub(crate) fair: bool,
+    pub(crate) result_cache_capacity: usize,
 }
 
 impl<DB: Database> Default for PoolOptions<DB> {
@@ -47,12 +52,17 @@ impl<DB: Database> PoolOptions<DB> {
             test_before_acquire: true,
             before_acquire: None,
             after_release: None,
+            on_connect: None,
+            on_close: None,
+            on_acquire_timeout: None,
+            on_connect_error: None,
             max_connections: 10,
             min_connections: 0,
             connect_timeout: Duration::from_secs(30),
             idle_timeout: Some(Duration::from_secs(10 * 60)),
             max_lifetime: Some(Duration::from_secs(30 * 60)),
             fair: true,
+            result_cache_capacity: 100,
         }
     }
 
@@ -113,6 +123,15 @@ impl<DB: Database> PoolOptions<DB> {
         self
     }
 
+    /// Set the maximum number of query results cached in the pool by
+    /// [`Query::cached`](crate::query::Query::cached).
+    ///
+    /// When the cache is full, the least recently used results are dropped. Defaults to `100`.
+    pub fn result_cache_capacity(mut self, capacity: usize) -> Self {
+        self.result_cache_capacity = capacity;
+        self
+    }
+
     /// If true, the health of a connection will be verified by a call to [`Connection::ping`]
     /// before returning the connection.
     ///
@@ -190,6 +209,98 @@ impl<DB: Database> PoolOptions<DB> {
         self
     }
 
+    /// Perform an action whenever the pool opens a new connection.
+    ///
+    /// Called after [`after_connect`] has succeeded. Like the other event hooks, the callback is
+    /// invoked inline by the pool and should return quickly; it is intended for updating
+    /// metrics, not for doing I/O.
+    ///
+    /// [`after_connect`]: #method.after_connect
+    pub fn on_connect<F>(mut self, callback: F) -> Self
+    where
+        F: Fn() + 'static + Send + Sync,
+    {
+        self.on_connect = Some(Box::new(callback));
+        self
+    }
+
+    /// Perform an action whenever the pool closes or discards a connection, with the reason
+    /// it was closed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sqlx_core::pool::CloseReason;
+    /// use sqlx_core::postgres::PgPoolOptions;
+    ///
+    /// let pool = PgPoolOptions::new()
+    ///     .on_close(|reason| {
+    ///         if let CloseReason::Broken = reason {
+    ///             eprintln!("discarded a broken database connection");
+    ///         }
+    ///     })
+    ///     .connect("postgres:// …").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_close<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(CloseReason) + 'static + Send + Sync,
+    {
+        self.on_close = Some(Box::new(callback));
+        self
+    }
+
+    /// Perform an action whenever a call to [`Pool::acquire`] gives up waiting for a connection
+    /// and returns [`Error::PoolTimedOut`].
+    pub fn on_acquire_timeout<F>(mut self, callback: F) -> Self
+    where
+        F: Fn() + 'static + Send + Sync,
+    {
+        self.on_acquire_timeout = Some(Box::new(callback));
+        self
+    }
+
+    /// Perform an action whenever the pool fails to open a new connection, including when
+    /// [`after_connect`] returns an error.
+    ///
+    /// Errors that the pool retries, such as I/O errors while the database is starting up, are
+    /// reported for every attempt.
+    ///
+    /// [`after_connect`]: #method.after_connect
+    pub fn on_connect_error<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&Error) + 'static + Send + Sync,
+    {
+        self.on_connect_error = Some(Box::new(callback));
+        self
+    }
+
+    pub(crate) fn notify_connect(&self) {
+        if let Some(callback) = &self.on_connect {
+            callback();
+        }
+    }
+
+    pub(crate) fn notify_close(&self, reason: CloseReason) {
+        if let Some(callback) = &self.on_close {
+            callback(reason);
+        }
+    }
+
+    pub(crate) fn notify_acquire_timeout(&self) {
+        if let Some(callback) = &self.on_acquire_timeout {
+            callback();
+        }
+    }
+
+    pub(crate) fn notify_connect_error(&self, error: &Error) {
+        if let Some(callback) = &self.on_connect_error {
+            callback(error);
+        }
+    }
+
     /// Creates a new pool from this configuration and immediately establishes one connection.
     pub async fn connect(self, uri: &str) -> Result<Pool<DB>, Error> {
         self.connect_with(uri.parse()?).await
@@ -218,7 +329,7 @@ impl<DB: Database> PoolOptions<DB> {
     pub fn connect_lazy_with(self, options: <DB::Connection as Connection>::Options) -> Pool<DB> {
         let shared = SharedPool::new_arc(self, options);
 
-        let _ = spawn({
+        spawn({
             let shared = Arc::clone(&shared);
             async move {
                 let _ = init_min_connections(&shared).await;
@@ -229,6 +340,33 @@ impl<DB: Database> PoolOptions<DB> {
     }
 }
 
+/// The reason a connection was closed by the pool, as passed to [`PoolOptions::on_close`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CloseReason {
+    /// The connection was older than [`PoolOptions::max_lifetime`].
+    MaxLifetime,
+
+    /// The connection was idle for longer than [`PoolOptions::idle_timeout`].
+    IdleTimeout,
+
+    /// The connection failed a ping or could not be flushed and is assumed to be broken.
+    Broken,
+
+    /// The connection was rejected by [`PoolOptions::before_acquire`] or
+    /// [`PoolOptions::after_release`].
+    Rejected,
+
+    /// The connection was returned to the pool inside a transaction that was never ended.
+    InTransaction,
+
+    /// The pool was closed.
+    PoolClosed,
+
+    /// The connection was closed by [`Pool::close_idle`] or [`Pool::retain`].
+    Pruned,
+}
+
 async fn init_min_connections<DB: Database>(pool: &SharedPool<DB>) -> Result<(), Error> {
     for _ in 0..pool.options.min_connections.max(1) {
         let deadline = Instant::now() + pool.options.connect_timeout;
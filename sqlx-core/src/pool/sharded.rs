@@ -0,0 +1,170 @@
+use std::fmt::{self, Debug, Formatter};
+use std::future::Future;
+use std::sync::Arc;
+
+use futures_core::stream::BoxStream;
+use futures_util::future::{join_all, try_join_all};
+use futures_util::stream::{self, StreamExt};
+
+use crate::database::Database;
+use crate::error::Error;
+use crate::pool::Pool;
+
+/// The index of a shard in a [`ShardedPool`].
+pub type ShardId = usize;
+
+/// A set of connection pools, one for each shard of a partitioned database, with a function
+/// that routes keys such as tenant ids to the shard that holds them.
+///
+/// Cloning is cheap; clones refer to the same pools.
+///
+/// ```rust,ignore
+/// use sqlx::pool::ShardedPool;
+/// use sqlx::postgres::PgPool;
+///
+/// let shards = vec![
+///     PgPool::connect("postgres://shard-0/app").await?,
+///     PgPool::connect("postgres://shard-1/app").await?,
+/// ];
+///
+/// let pool = ShardedPool::new(shards, |tenant_id: &u64| (*tenant_id % 2) as usize);
+///
+/// let user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE tenant_id = $1 AND id = $2")
+///     .bind(tenant_id as i64)
+///     .bind(user_id)
+///     .fetch_one(pool.get(&tenant_id))
+///     .await?;
+///
+/// // ask every shard and collect the rows as they arrive
+/// let users: Vec<(ShardId, User)> = pool
+///     .broadcast(|pool| sqlx::query_as("SELECT * FROM users").fetch(pool))
+///     .try_collect()
+///     .await?;
+/// ```
+pub struct ShardedPool<DB: Database, K: ?Sized> {
+    shards: Arc<[Pool<DB>]>,
+    route: Arc<dyn Fn(&K) -> ShardId + 'static + Send + Sync>,
+}
+
+impl<DB: Database, K: ?Sized> ShardedPool<DB, K> {
+    /// Creates a sharded pool from one pool per shard, in order of their [`ShardId`].
+    ///
+    /// The routing function must return an id less than the number of shards.
+    ///
+    /// # Panics
+    /// If `shards` is empty.
+    pub fn new<F>(shards: Vec<Pool<DB>>, route: F) -> Self
+    where
+        F: Fn(&K) -> ShardId + 'static + Send + Sync,
+    {
+        assert!(
+            !shards.is_empty(),
+            "a sharded pool needs at least one shard"
+        );
+
+        Self {
+            shards: shards.into(),
+            route: Arc::new(route),
+        }
+    }
+
+    /// Returns the id of the shard that holds the key.
+    ///
+    /// # Panics
+    /// If the routing function returns an id that is out of range.
+    pub fn route(&self, key: &K) -> ShardId {
+        let id = (self.route)(key);
+
+        assert!(
+            id < self.shards.len(),
+            "routing function returned shard {} but there are only {} shards",
+            id,
+            self.shards.len()
+        );
+
+        id
+    }
+
+    /// Returns the pool of the shard that holds the key.
+    ///
+    /// # Panics
+    /// If the routing function returns an id that is out of range.
+    pub fn get(&self, key: &K) -> &Pool<DB> {
+        &self.shards[self.route(key)]
+    }
+
+    /// Returns the pool of the shard with the given id, if there is one.
+    pub fn shard(&self, id: ShardId) -> Option<&Pool<DB>> {
+        self.shards.get(id)
+    }
+
+    /// Returns the pools of all shards, in order of their [`ShardId`].
+    pub fn shards(&self) -> &[Pool<DB>] {
+        &self.shards
+    }
+
+    /// Returns the number of shards.
+    pub fn len(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// Returns `false`; a sharded pool always has at least one shard.
+    pub fn is_empty(&self) -> bool {
+        self.shards.is_empty()
+    }
+
+    /// Run an operation against every shard concurrently, returning the results in order of
+    /// their [`ShardId`].
+    ///
+    /// Fails with the first error returned by any shard; the operations on the other shards
+    /// are cancelled, so this is best suited to reads and idempotent statements.
+    pub async fn broadcast_all<'p, F, Fut, T>(&'p self, f: F) -> Result<Vec<T>, Error>
+    where
+        F: Fn(&'p Pool<DB>) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        try_join_all(self.shards.iter().map(f)).await
+    }
+
+    /// Run a query against every shard concurrently and merge the resulting streams.
+    ///
+    /// Each item is tagged with the id of the shard that produced it. Items are yielded as they
+    /// arrive, so items from different shards are interleaved.
+    pub fn broadcast<'p, F, T>(&'p self, f: F) -> BoxStream<'p, Result<(ShardId, T), Error>>
+    where
+        F: Fn(&'p Pool<DB>) -> BoxStream<'p, Result<T, Error>>,
+        T: 'p + Send,
+    {
+        let streams = self
+            .shards
+            .iter()
+            .enumerate()
+            .map(|(id, pool)| f(pool).map(move |item| item.map(|item| (id, item))).boxed());
+
+        stream::select_all(streams).boxed()
+    }
+
+    /// Close the pools of all shards, waiting until all connections are closed.
+    ///
+    /// See [`Pool::close`].
+    pub async fn close(&self) {
+        join_all(self.shards.iter().map(|pool| pool.close())).await;
+    }
+}
+
+impl<DB: Database, K: ?Sized> Clone for ShardedPool<DB, K> {
+    fn clone(&self) -> Self {
+        Self {
+            shards: Arc::clone(&self.shards),
+            route: Arc::clone(&self.route),
+        }
+    }
+}
+
+impl<DB: Database, K: ?Sized> Debug for ShardedPool<DB, K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShardedPool")
+            .field("shards", &self.shards)
+            .finish()
+    }
+}
@@ -1,10 +1,14 @@
 use std::ops::{Deref, DerefMut};
 
-use crate::arguments::Arguments;
+use crate::arguments::{ArgumentCount, Arguments};
 use crate::encode::{Encode, IsNull};
 use crate::error::Error;
 use crate::ext::ustr::UStr;
-use crate::postgres::{PgConnection, PgTypeInfo, Postgres};
+use crate::io::{recycle_buffer, take_buffer};
+use crate::postgres::{
+    PgConnection, PgTimestampZone, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
+use crate::type_info::TypeInfo;
 use crate::types::Type;
 
 // TODO: buf.patch(|| ...) is a poor name, can we think of a better name? Maybe `buf.lazy(||)` ?
@@ -17,7 +21,6 @@ use crate::types::Type;
 //            that has a patch, we then apply the patch which should write to &mut Vec<u8>,
 //            backtrack and update the prefixed-len, then write until the next patch offset
 
-#[derive(Default)]
 pub struct PgArgumentBuffer {
     buffer: Vec<u8>,
 
@@ -57,7 +60,8 @@ pub struct PgArguments {
 }
 
 impl PgArguments {
-    pub(crate) fn add<'q, T>(&mut self, value: T)
+    /// Add the value to the end of the arguments.
+    pub fn add<'q, T>(&mut self, value: T)
     where
         T: Encode<'q, Postgres> + Type<Postgres>,
     {
@@ -65,6 +69,9 @@ impl PgArguments {
         self.types
             .push(value.produces().unwrap_or_else(T::type_info));
 
+        // reserve space for the value and its prefixed length up front
+        self.buffer.reserve(4 + value.size_hint());
+
         // encode the value into our buffer
         self.buffer.encode(value);
 
@@ -116,6 +123,68 @@ impl<'q> Arguments<'q> for PgArguments {
     {
         self.add(value)
     }
+
+    fn cache_key(&self) -> Option<Vec<u8>> {
+        let mut key = Vec::with_capacity(self.buffer.len() + self.types.len() * 8);
+
+        for ty in &self.types {
+            key.extend_from_slice(ty.name().as_bytes());
+            key.push(0);
+        }
+
+        key.extend_from_slice(&self.buffer);
+
+        Some(key)
+    }
+
+    fn format_for_log(&self) -> Vec<String> {
+        let mut buf: &[u8] = &self.buffer;
+        let mut values = Vec::with_capacity(self.types.len());
+
+        for ty in &self.types {
+            // every value is prefixed with its length, which is -1 for NULL
+            if buf.len() < 4 {
+                return Vec::new();
+            }
+
+            let (len, rest) = buf.split_at(4);
+            let len = i32::from_be_bytes([len[0], len[1], len[2], len[3]]);
+            buf = rest;
+
+            let value = if len < 0 {
+                None
+            } else if buf.len() < len as usize {
+                return Vec::new();
+            } else {
+                let (value, rest) = buf.split_at(len as usize);
+                buf = rest;
+
+                Some(value)
+            };
+
+            let value = PgValueRef {
+                value,
+                row: None,
+                type_info: ty.clone(),
+                format: PgValueFormat::Binary,
+                timestamp_zone: PgTimestampZone::Utc,
+            };
+
+            values.push(format_bind_value!(
+                Postgres,
+                value,
+                [bool, i16, i32, i64, f32, f64, String]
+            ));
+        }
+
+        values
+    }
+}
+
+impl<'q> ArgumentCount<'q> for PgArguments {
+    fn len(&self) -> usize {
+        self.types.len()
+    }
 }
 
 impl PgArgumentBuffer {
@@ -163,6 +232,24 @@ impl PgArgumentBuffer {
     }
 }
 
+impl Default for PgArgumentBuffer {
+    fn default() -> Self {
+        Self {
+            // reuse the allocation of arguments that were already sent
+            buffer: take_buffer(),
+            count: 0,
+            patches: Vec::new(),
+            type_holes: Vec::new(),
+        }
+    }
+}
+
+impl Drop for PgArgumentBuffer {
+    fn drop(&mut self) {
+        recycle_buffer(std::mem::take(&mut self.buffer));
+    }
+}
+
 impl Deref for PgArgumentBuffer {
     type Target = Vec<u8>;
 
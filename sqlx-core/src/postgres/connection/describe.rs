@@ -64,7 +64,7 @@ impl PgConnection {
         Ok(params)
     }
 
-    async fn maybe_fetch_type_info_by_oid(
+    pub(crate) async fn maybe_fetch_type_info_by_oid(
         &mut self,
         oid: u32,
         should_fetch: bool,
@@ -87,8 +87,12 @@ impl PgConnection {
             // cache the type name <-> oid relationship in a paired hashmap
             // so we don't come down this road again
             self.cache_type_info.insert(oid, info.clone());
-            self.cache_type_oid
-                .insert(info.0.name().to_string().into(), oid);
+
+            // domains resolve to their base type which is named (and cached) separately
+            if info.0.try_oid() == Some(oid) {
+                self.cache_type_oid
+                    .insert(info.0.name().to_string().into(), oid);
+            }
 
             Ok(info)
         } else {
@@ -104,13 +108,34 @@ impl PgConnection {
 
     fn fetch_type_by_oid(&mut self, oid: u32) -> BoxFuture<'_, Result<PgTypeInfo, Error>> {
         Box::pin(async move {
-            let (name, category, relation_id, element): (String, i8, u32, u32) = query_as(
-                "SELECT typname, typcategory, typrelid, typelem FROM pg_catalog.pg_type WHERE oid = $1",
+            let (name, typ_type, category, relation_id, element, base_type): (
+                String,
+                i8,
+                i8,
+                u32,
+                u32,
+                u32,
+            ) = query_as(
+                // a type that is not visible through the search path is named with its schema,
+                // as it would have to be written in SQL, so that the name resolves back to it
+                "SELECT CASE WHEN pg_catalog.pg_type_is_visible(t.oid) THEN t.typname \
+                 ELSE n.nspname || '.' || t.typname END, \
+                 t.typtype, t.typcategory, t.typrelid, t.typelem, t.typbasetype \
+                 FROM pg_catalog.pg_type t \
+                 JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace \
+                 WHERE t.oid = $1",
             )
             .bind(oid)
             .fetch_one(&mut *self)
             .await?;
 
+            if typ_type as u8 == b'd' {
+                // a domain is sent and received in the format of its base type and Postgres
+                // already describes result columns of a domain by the base type, so we do the
+                // same for parameters; this lets domains be used wherever the base type is
+                return self.maybe_fetch_type_info_by_oid(base_type, true).await;
+            }
+
             match category as u8 {
                 b'A' => Ok(PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
                     kind: PgTypeKind::Array(self.fetch_type_by_oid(element).await?),
@@ -228,16 +253,38 @@ WHERE rngtypid = $1
             return Ok(*oid);
         }
 
+        // resolve the name like Postgres does, respecting the search path and an optional schema;
+        // names that would have to be quoted in SQL (e.g. with uppercase letters) are matched
+        // case-insensitively instead, preferring a type that is on the search path
+
+        // `to_regtype` raises a syntax error for names that are not identifiers
+        let is_identifier = name.split('.').all(|part| {
+            part.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+        });
+
         // language=SQL
-        let (oid,): (u32,) = query_as(
+        let (oid,): (Option<u32>,) = query_as(
             "
-SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
+SELECT coalesce(
+    CASE WHEN $2 THEN to_regtype($1)::oid END,
+    (
+        SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
+        ORDER BY pg_catalog.pg_type_is_visible(oid) DESC
+        LIMIT 1
+    )
+)
                 ",
         )
         .bind(name)
+        .bind(is_identifier)
         .fetch_one(&mut *self)
         .await?;
 
+        let oid = oid.ok_or(Error::RowNotFound)?;
+
         self.cache_type_oid.insert(name.to_string().into(), oid);
 
         Ok(oid)
@@ -285,4 +332,197 @@ SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
             .fetch_all(self)
             .await
     }
+
+    /// Infer the nullability of output columns from the plan of a prepared statement.
+    ///
+    /// Columns that come from the nullable side of an outer join are nullable even if the
+    /// underlying table column is `NOT NULL`, and a few expressions (`count(..)` and `COALESCE`
+    /// with a literal fallback) can never be `NULL`. Anything else is left as `None`.
+    ///
+    /// The plan is parsed with `serde_json`, so this needs the `json` feature.
+    #[cfg(feature = "json")]
+    pub(crate) async fn get_nullable_for_columns_from_explain(
+        &mut self,
+        statement: u32,
+        num_columns: usize,
+        num_params: usize,
+    ) -> Result<Vec<Option<bool>>, Error> {
+        use crate::types::Json;
+
+        let mut explain = format!(
+            "EXPLAIN (VERBOSE, FORMAT JSON) EXECUTE sqlx_s_{}",
+            statement
+        );
+
+        if num_params > 0 {
+            explain.push('(');
+
+            for i in 0..num_params {
+                if i > 0 {
+                    explain.push_str(", ");
+                }
+
+                explain.push_str("NULL");
+            }
+
+            explain.push(')');
+        }
+
+        let Json(plans): Json<Vec<explain::Explain>> =
+            query_scalar(&explain).fetch_one(&mut *self).await?;
+
+        let mut nullable = vec![None; num_columns];
+
+        if let Some(explain::Explain { plan }) = plans.into_iter().next() {
+            explain::visit_plan(&plan, num_columns, &mut nullable);
+        }
+
+        Ok(nullable)
+    }
+}
+
+#[cfg(feature = "json")]
+mod explain {
+    #[derive(serde::Deserialize)]
+    pub(super) struct Explain {
+        #[serde(rename = "Plan")]
+        pub(super) plan: Plan,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub(super) struct Plan {
+        #[serde(rename = "Join Type")]
+        join_type: Option<String>,
+
+        #[serde(rename = "Parent Relationship")]
+        parent_relationship: Option<String>,
+
+        #[serde(rename = "Output", default)]
+        output: Vec<String>,
+
+        #[serde(rename = "Plans", default)]
+        plans: Vec<Plan>,
+    }
+
+    pub(super) fn visit_plan(plan: &Plan, num_columns: usize, nullable: &mut [Option<bool>]) {
+        // the output list of the top-level node lines up with the columns of the statement;
+        // bail if it doesn't (e.g. the plan carries resjunk columns)
+        if plan.output.len() != num_columns {
+            return;
+        }
+
+        for (expr, nullable) in plan.output.iter().zip(nullable.iter_mut()) {
+            if is_never_null(expr) {
+                *nullable = Some(false);
+            }
+        }
+
+        mark_outer_joins(plan, &plan.output, nullable, false);
+    }
+
+    fn mark_outer_joins(
+        plan: &Plan,
+        outputs: &[String],
+        nullable: &mut [Option<bool>],
+        nullable_side: bool,
+    ) {
+        if nullable_side {
+            for expr in &plan.output {
+                for (i, _) in outputs.iter().enumerate().filter(|(_, o)| *o == expr) {
+                    nullable[i] = Some(true);
+                }
+            }
+        }
+
+        for child in &plan.plans {
+            let relationship = child.parent_relationship.as_deref();
+
+            let child_nullable = nullable_side
+                || match plan.join_type.as_deref() {
+                    Some("Full") => true,
+                    Some("Left") => relationship == Some("Inner"),
+                    Some("Right") => relationship == Some("Outer"),
+                    _ => false,
+                };
+
+            mark_outer_joins(child, outputs, nullable, child_nullable);
+        }
+    }
+
+    fn is_never_null(expr: &str) -> bool {
+        if expr.starts_with("count(") {
+            return true;
+        }
+
+        let args = match expr
+            .strip_prefix("COALESCE(")
+            .and_then(|args| args.strip_suffix(')'))
+        {
+            Some(args) => args,
+            None => return false,
+        };
+
+        split_args(args).last().map_or(false, |arg| is_literal(arg))
+    }
+
+    // split an argument list on the commas that are not nested in parens or string literals
+    fn split_args(args: &str) -> Vec<&str> {
+        let mut parts = Vec::new();
+        let mut depth = 0_usize;
+        let mut in_string = false;
+        let mut start = 0;
+
+        for (i, c) in args.char_indices() {
+            match c {
+                '\'' => in_string = !in_string,
+                '(' if !in_string => depth += 1,
+                ')' if !in_string => depth = depth.saturating_sub(1),
+                ',' if !in_string && depth == 0 => {
+                    parts.push(args[start..i].trim());
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+
+        parts.push(args[start..].trim());
+        parts
+    }
+
+    fn is_literal(arg: &str) -> bool {
+        // strip a trailing cast, e.g. `'foo'::text` or `0::bigint`
+        let arg = match arg.rfind("::") {
+            Some(idx) if !arg[idx..].contains('\'') => &arg[..idx],
+            _ => arg,
+        };
+
+        let arg = arg.trim_start_matches('(').trim_end_matches(')');
+
+        if arg.len() >= 2 && arg.starts_with('\'') && arg.ends_with('\'') {
+            return true;
+        }
+
+        if arg == "true" || arg == "false" {
+            return true;
+        }
+
+        let digits = arg.strip_prefix('-').unwrap_or(arg);
+
+        !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit() || c == '.')
+    }
+
+    #[test]
+    fn it_detects_never_null_expressions() {
+        assert!(is_never_null("count(*)"));
+        assert!(is_never_null("COALESCE(users.name, 'anonymous'::text)"));
+        assert!(is_never_null("COALESCE(sum(orders.total), 0::numeric)"));
+        assert!(is_never_null("COALESCE(a.x, b.y, '(,)'::text)"));
+        assert!(is_never_null("COALESCE(a.flag, false)"));
+        assert!(is_never_null("COALESCE(a.score, '-1'::integer)"));
+
+        assert!(!is_never_null("users.name"));
+        assert!(!is_never_null("COALESCE(a.x, b.y)"));
+        assert!(!is_never_null("COALESCE(a.x, NULL::text)"));
+        assert!(!is_never_null("(COALESCE(a.x, 0) + b.y)"));
+    }
 }
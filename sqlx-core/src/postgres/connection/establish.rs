@@ -1,19 +1,31 @@
+use std::collections::BTreeMap;
+
 use crate::HashMap;
 
 use crate::common::StatementCache;
+use crate::connection::{ConnectionStatistics, ServerFlavor, ServerVersion};
 use crate::error::Error;
 use crate::io::Decode;
+#[cfg(feature = "gssapi")]
+use crate::postgres::connection::gss;
 use crate::postgres::connection::{sasl, stream::PgStream, tls};
 use crate::postgres::message::{
     Authentication, BackendKeyData, MessageFormat, Password, ReadyForQuery, Startup,
 };
-use crate::postgres::{PgConnectOptions, PgConnection};
+use crate::postgres::{PgConnectOptions, PgConnection, PgGssEncMode};
 
 // https://www.postgresql.org/docs/current/protocol-flow.html#id-1.10.5.7.3
 // https://www.postgresql.org/docs/current/protocol-flow.html#id-1.10.5.7.11
 
 impl PgConnection {
     pub(crate) async fn establish(options: &PgConnectOptions) -> Result<Self, Error> {
+        if options.gss_enc_mode == PgGssEncMode::Require {
+            return Err(Error::Configuration(
+                "GSSAPI encryption is not supported; use `sslmode` to encrypt the connection"
+                    .into(),
+            ));
+        }
+
         let mut stream = PgStream::connect(options).await?;
 
         // Upgrade to TLS if we were asked to and the server supports it
@@ -40,6 +52,12 @@ impl PgConnection {
             params.push(("application_name", application_name));
         }
 
+        for (name, value) in &options.session_settings {
+            // settings of the user replace our defaults
+            params.retain(|(default, _)| !default.eq_ignore_ascii_case(name));
+            params.push((name, value));
+        }
+
         stream
             .send(Startup {
                 username: Some(&options.username),
@@ -96,6 +114,22 @@ impl PgConnection {
                         sasl::authenticate(&mut stream, options, body).await?;
                     }
 
+                    #[cfg(feature = "gssapi")]
+                    Authentication::Gss | Authentication::Sspi => {
+                        // SSPI is answered with Kerberos through GSSAPI, as libpq does
+                        // when it is built without SSPI
+                        gss::authenticate(&mut stream, options).await?;
+                    }
+
+                    #[cfg(not(feature = "gssapi"))]
+                    Authentication::Gss | Authentication::Sspi => {
+                        return Err(Error::Configuration(
+                            "the server requested GSSAPI authentication; \
+                             enable the `gssapi` feature of SQLx to support it"
+                                .into(),
+                        ));
+                    }
+
                     method => {
                         return Err(err_protocol!(
                             "unsupported authentication method: {:?}",
@@ -131,18 +165,65 @@ impl PgConnection {
             }
         }
 
+        let server_version = parse_server_version(&stream.parameter_statuses);
+
         Ok(PgConnection {
             stream,
             process_id,
             secret_key,
+            server_version,
             transaction_status,
             transaction_depth: 0,
             pending_ready_for_query_count: 0,
+            pending_copy_fail_count: 0,
             next_statement_id: 1,
             cache_statement: StatementCache::new(options.statement_cache_capacity),
             cache_type_oid: HashMap::new(),
             cache_type_info: HashMap::new(),
+            statistics: ConnectionStatistics::default(),
             log_settings: options.log_settings.clone(),
+            close_on_drop: options.close_on_drop,
+            timestamp_zone: options.timestamp_zone,
+            reset_timeouts: false,
         })
     }
 }
+
+fn parse_server_version(parameters: &BTreeMap<String, String>) -> Option<ServerVersion> {
+    // CockroachDB reports a PostgreSQL version for compatibility and its own version separately,
+    // e.g. `CockroachDB CCL v20.2.7 (x86_64-unknown-linux-gnu, built 2021/03/29 17:52:00)`
+    if let Some(crdb_version) = parameters.get("crdb_version") {
+        let version = crdb_version
+            .split_whitespace()
+            .find(|word| word.starts_with('v'))
+            .unwrap_or_default();
+
+        let mut server_version = ServerVersion::parse(ServerFlavor::CockroachDb, version);
+        server_version.raw = crdb_version.clone();
+
+        return Some(server_version);
+    }
+
+    parameters
+        .get("server_version")
+        .map(|version| ServerVersion::parse(ServerFlavor::Postgres, version))
+}
+
+#[test]
+fn test_parse_server_version() {
+    let mut parameters = BTreeMap::new();
+    parameters.insert("server_version".to_owned(), "13.0.0".to_owned());
+
+    let version = parse_server_version(&parameters).unwrap();
+    assert_eq!(version.flavor, ServerFlavor::Postgres);
+    assert_eq!((version.major, version.minor), (13, 0));
+
+    parameters.insert(
+        "crdb_version".to_owned(),
+        "CockroachDB CCL v20.2.7 (x86_64-unknown-linux-gnu)".to_owned(),
+    );
+
+    let version = parse_server_version(&parameters).unwrap();
+    assert_eq!(version.flavor, ServerFlavor::CockroachDb);
+    assert_eq!((version.major, version.minor, version.patch), (20, 2, 7));
+}
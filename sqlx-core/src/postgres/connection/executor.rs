@@ -1,16 +1,17 @@
+use crate::comment;
 use crate::describe::Describe;
-use crate::error::Error;
+use crate::error::{DatabaseError, Error};
 use crate::executor::{Execute, Executor};
-use crate::logger::QueryLogger;
+use crate::logger::{format_binds, instrument_query, instrument_query_one, QueryLogger};
 use crate::postgres::message::{
     self, Bind, Close, CommandComplete, DataRow, MessageFormat, ParameterDescription, Parse, Query,
-    RowDescription,
+    RowDescription, TransactionStatus,
 };
 use crate::postgres::statement::PgStatementMetadata;
 use crate::postgres::type_info::PgType;
 use crate::postgres::{
-    statement::PgStatement, PgArguments, PgConnection, PgDone, PgRow, PgTypeInfo, PgValueFormat,
-    Postgres,
+    statement::PgStatement, PgArguments, PgConnection, PgDone, PgNotice, PgRow, PgTypeInfo,
+    PgValueFormat, Postgres,
 };
 use either::Either;
 use futures_core::future::BoxFuture;
@@ -19,11 +20,22 @@ use futures_core::Stream;
 use futures_util::{pin_mut, TryStreamExt};
 use std::{borrow::Cow, sync::Arc};
 
+// a cached statement is stale when a schema change altered the type of its result; other
+// feature_not_supported (0A000) errors are reported as they are
+fn is_stale_plan(error: &dyn DatabaseError) -> bool {
+    error.code().as_deref() == Some("0A000")
+        && error
+            .message()
+            .contains("cached plan must not change result type")
+}
+
 async fn prepare(
     conn: &mut PgConnection,
     sql: &str,
     parameters: &[PgTypeInfo],
     metadata: Option<Arc<PgStatementMetadata>>,
+    // a statement evicted from the cache to close in the same round-trip
+    evicted: Option<u32>,
 ) -> Result<(u32, Arc<PgStatementMetadata>), Error> {
     let id = conn.next_statement_id;
     conn.next_statement_id = conn.next_statement_id.wrapping_add(1);
@@ -45,56 +57,62 @@ async fn prepare(
     // flush and wait until we are re-ready
     conn.wait_until_ready().await?;
 
+    if let Some(id) = evicted {
+        conn.stream.write(Close::Statement(id));
+    }
+
     // next we send the PARSE command to the server
+    // the statement is cached by the SQL without the comment, so that it is reused
     conn.stream.write(Parse {
         param_types: &*param_types,
-        query: sql,
+        query: &comment::apply(sql),
         statement: id,
     });
 
-    if metadata.is_none() {
-        // get the statement columns and parameters
-        conn.stream.write(message::Describe::Statement(id));
+    if let Some(metadata) = metadata {
+        // we already have metadata, so there is nothing to wait for before the statement is
+        // executed; the PARSE (and CLOSE) go out in the same write as the BIND and EXECUTE
+        // that follow, and their responses are skipped over in `run`
+        return Ok((id, metadata));
     }
 
+    // get the statement columns and parameters
+    conn.stream.write(message::Describe::Statement(id));
+
     // we ask for the server to immediately send us the result of the PARSE command
     conn.write_sync();
     conn.stream.flush().await?;
 
+    if evicted.is_some() {
+        conn.wait_for_close_complete(1).await?;
+    }
+
     // indicates that the SQL query string is now successfully parsed and has semantic validity
     let _ = conn
         .stream
         .recv_expect(MessageFormat::ParseComplete)
         .await?;
 
-    let metadata = if let Some(metadata) = metadata {
-        // each SYNC produces one READY FOR QUERY
-        conn.recv_ready_for_query().await?;
-
-        // we already have metadata
-        metadata
-    } else {
-        let parameters = recv_desc_params(conn).await?;
+    let parameters = recv_desc_params(conn).await?;
 
-        let rows = recv_desc_rows(conn).await?;
+    let rows = recv_desc_rows(conn).await?;
 
-        // each SYNC produces one READY FOR QUERY
-        conn.recv_ready_for_query().await?;
+    // each SYNC produces one READY FOR QUERY
+    conn.recv_ready_for_query().await?;
 
-        let parameters = conn.handle_parameter_description(parameters).await?;
+    let parameters = conn.handle_parameter_description(parameters).await?;
 
-        let (columns, column_names) = conn.handle_row_description(rows, true).await?;
+    let (columns, column_names) = conn.handle_row_description(rows, true).await?;
 
-        // ensure that if we did fetch custom data, we wait until we are fully ready before
-        // continuing
-        conn.wait_until_ready().await?;
+    // ensure that if we did fetch custom data, we wait until we are fully ready before
+    // continuing
+    conn.wait_until_ready().await?;
 
-        Arc::new(PgStatementMetadata {
-            parameters,
-            columns,
-            column_names,
-        })
-    };
+    let metadata = Arc::new(PgStatementMetadata {
+        parameters,
+        columns,
+        column_names: Arc::new(column_names),
+    });
 
     Ok((id, metadata))
 }
@@ -171,82 +189,148 @@ impl PgConnection {
         metadata: Option<Arc<PgStatementMetadata>>,
     ) -> Result<(u32, Arc<PgStatementMetadata>), Error> {
         if let Some(statement) = self.cache_statement.get_mut(sql) {
+            self.statistics.statement_cache_hits += 1;
+
             return Ok((*statement).clone());
         }
 
-        let statement = prepare(self, sql, parameters, metadata).await?;
+        self.statistics.statement_cache_misses += 1;
+
+        let store_to_cache = store_to_cache && self.cache_statement.is_enabled();
+        let full = self.cache_statement.len() == self.cache_statement.capacity();
 
-        if store_to_cache && self.cache_statement.is_enabled() {
-            if let Some((id, _)) = self.cache_statement.insert(sql, statement.clone()) {
-                self.stream.write(Close::Statement(id));
-                self.write_sync();
+        // make room in the cache before preparing so the least recently used statement
+        // is closed in the same round-trip as the PARSE
+        let evicted = if store_to_cache && full {
+            self.cache_statement.remove_lru().map(|(id, _)| id)
+        } else {
+            None
+        };
 
-                self.stream.flush().await?;
+        let statement = prepare(self, sql, parameters, metadata, evicted).await?;
 
-                self.wait_for_close_complete(1).await?;
-                self.recv_ready_for_query().await?;
-            }
+        if store_to_cache {
+            self.cache_statement.insert(sql, statement.clone());
         }
 
         Ok(statement)
     }
 
+    // prepares a cached statement again after it was found to be stale, closing the old one
+    async fn reprepare(
+        &mut self,
+        sql: &str,
+        parameters: &[PgTypeInfo],
+    ) -> Result<(u32, Arc<PgStatementMetadata>), Error> {
+        let stale = self.cache_statement.remove(sql).map(|(id, _)| id);
+        let statement = prepare(self, sql, parameters, None, stale).await?;
+
+        self.cache_statement.insert(sql, statement.clone());
+
+        Ok(statement)
+    }
+
+    // closes a statement that was not stored in the cache along with the next command, so that
+    // statements do not pile up on the server when caching is disabled or bypassed
+    fn close_if_uncached(&mut self, sql: &str, statement: u32) {
+        if !self.cache_statement.contains_key(sql) {
+            self.stream.write(Close::Statement(statement));
+            self.write_sync();
+        }
+    }
+
+    // the holes created during encoding must have been patched (see `apply_patches`)
+    fn write_execute(&mut self, statement: u32, arguments: &PgArguments, limit: u8) {
+        // bind to attach the arguments to the statement and create a portal
+        self.stream.write(Bind {
+            portal: None,
+            statement,
+            formats: &[PgValueFormat::Binary],
+            num_params: arguments.types.len() as i16,
+            params: &*arguments.buffer,
+            result_formats: &[PgValueFormat::Binary],
+        });
+
+        // executes the portal up to the passed limit
+        // the protocol-level limit acts nearly identically to the `LIMIT` in SQL
+        self.stream.write(message::Execute {
+            portal: None,
+            limit: limit.into(),
+        });
+
+        // finally, [Sync] asks postgres to process the messages that we sent and respond with
+        // a [ReadyForQuery] message when it's completely done. Theoretically, we could send
+        // dozens of queries before a [Sync] and postgres can handle that. Execution on the server
+        // is still serial but it would reduce round-trips. Some kind of builder pattern that is
+        // termed batching might suit this.
+        self.write_sync();
+    }
+
     async fn run<'e, 'c: 'e, 'q: 'e>(
         &'c mut self,
         query: &'q str,
         arguments: Option<PgArguments>,
+        binds: Vec<String>,
         limit: u8,
         persistent: bool,
         metadata_opt: Option<Arc<PgStatementMetadata>>,
     ) -> Result<impl Stream<Item = Result<Either<PgDone, PgRow>, Error>> + 'e, Error> {
-        let mut logger = QueryLogger::new(query, self.log_settings.clone());
+        let mut logger = QueryLogger::new::<Postgres>(query, binds, self.log_settings.clone());
 
         // before we continue, wait until we are "ready" to accept more queries
         self.wait_until_ready().await?;
 
+        self.statistics.statements_executed += 1;
+        self.statistics.last_statement_id = Some(logger.statement_id());
+
+        // notices raised outside of a statement, such as while connecting, are not reported
+        self.stream.notices.clear();
+
         let mut metadata: Arc<PgStatementMetadata>;
+        let mut arguments = arguments;
+
+        // a statement taken from the cache goes stale when a schema change alters its result
+        // type; it is prepared again and retried once, as long as nothing was returned yet
+        let mut can_reprepare = persistent && metadata_opt.is_none() && arguments.is_some();
+
+        // a statement prepared from the metadata of a statement object is parsed in the same
+        // round-trip it is executed in, so it is only known to exist once that succeeds
+        let mut pipelined = None;
+
+        let format = if let Some(arguments) = &mut arguments {
+            let parse_pending = metadata_opt.is_some() && !self.cache_statement.contains_key(query);
+
+            // patch holes created during encoding; this can run queries of its own to look up
+            // types, so it must happen before a pipelined PARSE is written
+            if let Some(metadata) = &metadata_opt {
+                arguments.apply_patches(self, &metadata.parameters).await?;
+            }
 
-        let format = if let Some(mut arguments) = arguments {
             // prepare the statement if this our first time executing it
             // always return the statement ID here
             let (statement, metadata_) = self
-                .get_or_prepare(query, &arguments.types, persistent, metadata_opt)
+                .get_or_prepare(query, &arguments.types, persistent, metadata_opt.clone())
                 .await?;
 
             metadata = metadata_;
 
-            // patch holes created during encoding
-            arguments.apply_patches(self, &metadata.parameters).await?;
-
-            // bind to attach the arguments to the statement and create a portal
-            self.stream.write(Bind {
-                portal: None,
-                statement,
-                formats: &[PgValueFormat::Binary],
-                num_params: arguments.types.len() as i16,
-                params: &*arguments.buffer,
-                result_formats: &[PgValueFormat::Binary],
-            });
-
-            // executes the portal up to the passed limit
-            // the protocol-level limit acts nearly identically to the `LIMIT` in SQL
-            self.stream.write(message::Execute {
-                portal: None,
-                limit: limit.into(),
-            });
-
-            // finally, [Sync] asks postgres to process the messages that we sent and respond with
-            // a [ReadyForQuery] message when it's completely done. Theoretically, we could send
-            // dozens of queries before a [Sync] and postgres can handle that. Execution on the server
-            // is still serial but it would reduce round-trips. Some kind of builder pattern that is
-            // termed batching might suit this.
-            self.write_sync();
+            if metadata_opt.is_none() {
+                arguments.apply_patches(self, &metadata.parameters).await?;
+            }
+
+            if parse_pending {
+                pipelined = Some(statement);
+            }
+
+            self.write_execute(statement, arguments, limit);
+
+            self.close_if_uncached(query, statement);
 
             // prepared statements are binary
             PgValueFormat::Binary
         } else {
             // Query will trigger a ReadyForQuery
-            self.stream.write(Query(query));
+            self.stream.write(Query(&comment::apply(query)));
             self.pending_ready_for_query_count += 1;
 
             // metadata starts out as "nothing"
@@ -260,11 +344,62 @@ impl PgConnection {
 
         Ok(try_stream! {
             loop {
-                let message = self.stream.recv().await?;
+                let message = match self.stream.recv().await {
+                    Ok(message) => message,
+
+                    Err(Error::Database(error)) if can_reprepare && is_stale_plan(&*error) => {
+                        can_reprepare = false;
+
+                        // the error aborts the rest of the messages up to the [Sync]
+                        self.wait_until_ready().await?;
+
+                        // inside a transaction the error has aborted it, so a retry would only
+                        // fail again with a less useful error
+                        if !matches!(self.transaction_status, TransactionStatus::Idle) {
+                            return Err(Error::Database(error));
+                        }
+
+                        let arguments = arguments
+                            .as_mut()
+                            .expect("BUG: re-preparing a statement without arguments");
+
+                        let (statement, metadata_) = self.reprepare(query, &arguments.types).await?;
+                        metadata = metadata_;
+
+                        arguments.apply_patches(self, &metadata.parameters).await?;
+
+                        self.write_execute(statement, arguments, limit);
+
+                        self.stream.flush().await?;
+
+                        continue;
+                    }
+
+                    Err(Error::Database(error)) if pipelined.is_some() => {
+                        // the PARSE may have been what failed, so the statement is dropped from
+                        // the cache to be prepared again; closing a statement that does not
+                        // exist is not an error
+                        if let Some(statement) = pipelined.take() {
+                            if self.cache_statement.remove(query).is_some() {
+                                self.stream.write(Close::Statement(statement));
+                                self.write_sync();
+                            }
+                        }
+
+                        return Err(Error::Database(error));
+                    }
+
+                    Err(error) => return Err(error),
+                };
 
                 match message.format {
-                    MessageFormat::BindComplete
-                    | MessageFormat::ParseComplete
+                    MessageFormat::BindComplete => {
+                        // the statement was parsed
+                        pipelined = None;
+                    }
+
+                    MessageFormat::ParseComplete
+                    | MessageFormat::CloseComplete
                     | MessageFormat::ParameterDescription
                     | MessageFormat::NoData => {
                         // harmless messages to ignore
@@ -273,9 +408,13 @@ impl PgConnection {
                     MessageFormat::CommandComplete => {
                         // a SQL command completed normally
                         let cc: CommandComplete = message.decode()?;
+                        can_reprepare = false;
+
+                        logger.increase_rows_affected(cc.rows_affected());
 
                         r#yield!(Either::Left(PgDone {
                             rows_affected: cc.rows_affected(),
+                            notices: self.stream.notices.drain(..).map(PgNotice).collect(),
                         }));
                     }
 
@@ -290,7 +429,7 @@ impl PgConnection {
                             .await?;
 
                         metadata = Arc::new(PgStatementMetadata {
-                            column_names,
+                            column_names: Arc::new(column_names),
                             columns,
                             parameters: Vec::default(),
                         });
@@ -298,6 +437,8 @@ impl PgConnection {
 
                     MessageFormat::DataRow => {
                         logger.increment_rows();
+                        self.statistics.rows_fetched += 1;
+                        can_reprepare = false;
 
                         // one of the set of rows returned by a SELECT, FETCH, etc query
                         let data: DataRow = message.decode()?;
@@ -305,6 +446,7 @@ impl PgConnection {
                             data,
                             format,
                             metadata: Arc::clone(&metadata),
+                            timestamp_zone: self.timestamp_zone,
                         };
 
                         r#yield!(Either::Right(row));
@@ -344,18 +486,23 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
         let sql = query.sql();
         let metadata = query.statement().map(|s| Arc::clone(&s.metadata));
         let arguments = query.take_arguments();
+        let redacted = query.take_redacted_binds();
+        let binds = format_binds(&self.log_settings, arguments.as_ref(), &redacted);
         let persistent = query.persistent();
 
-        Box::pin(try_stream! {
-            let s = self.run(sql, arguments, 0, persistent, metadata).await?;
-            pin_mut!(s);
+        instrument_query::<Postgres, _>(
+            sql,
+            Box::pin(try_stream! {
+                let s = self.run(sql, arguments, binds, 0, persistent, metadata).await?;
+                pin_mut!(s);
 
-            while let Some(v) = s.try_next().await? {
-                r#yield!(v);
-            }
+                while let Some(v) = s.try_next().await? {
+                    r#yield!(v);
+                }
 
-            Ok(())
-        })
+                Ok(())
+            }),
+        )
     }
 
     fn fetch_optional<'e, 'q: 'e, E: 'q>(
@@ -369,20 +516,27 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
         let sql = query.sql();
         let metadata = query.statement().map(|s| Arc::clone(&s.metadata));
         let arguments = query.take_arguments();
+        let redacted = query.take_redacted_binds();
+        let binds = format_binds(&self.log_settings, arguments.as_ref(), &redacted);
         let persistent = query.persistent();
 
-        Box::pin(async move {
-            let s = self.run(sql, arguments, 1, persistent, metadata).await?;
-            pin_mut!(s);
-
-            while let Some(s) = s.try_next().await? {
-                if let Either::Right(r) = s {
-                    return Ok(Some(r));
+        instrument_query_one::<Postgres, _>(
+            sql,
+            Box::pin(async move {
+                let s = self
+                    .run(sql, arguments, binds, 1, persistent, metadata)
+                    .await?;
+                pin_mut!(s);
+
+                while let Some(s) = s.try_next().await? {
+                    if let Either::Right(r) = s {
+                        return Ok(Some(r));
+                    }
                 }
-            }
 
-            Ok(None)
-        })
+                Ok(None)
+            }),
+        )
     }
 
     fn prepare_with<'e, 'q: 'e>(
@@ -393,16 +547,22 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
     where
         'c: 'e,
     {
-        Box::pin(async move {
-            self.wait_until_ready().await?;
-
-            let (_, metadata) = self.get_or_prepare(sql, parameters, true, None).await?;
-
-            Ok(PgStatement {
-                sql: Cow::Borrowed(sql),
-                metadata,
-            })
-        })
+        crate::logger::instrument_prepare::<Postgres, _>(
+            sql,
+            Box::pin(async move {
+                self.wait_until_ready().await?;
+
+                let (statement, metadata) =
+                    self.get_or_prepare(sql, parameters, true, None).await?;
+
+                self.close_if_uncached(sql, statement);
+
+                Ok(PgStatement {
+                    sql: Cow::Borrowed(sql),
+                    metadata,
+                })
+            }),
+        )
     }
 
     fn describe<'e, 'q: 'e>(
@@ -415,9 +575,43 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
         Box::pin(async move {
             self.wait_until_ready().await?;
 
-            let (_, metadata) = self.get_or_prepare(sql, &[], true, None).await?;
+            let (statement, metadata) = self.get_or_prepare(sql, &[], true, None).await?;
+
+            // planning the statement needs it to still be prepared; skip it inside of a
+            // transaction as a failing `EXPLAIN` would abort the transaction
+            #[cfg(feature = "json")]
+            let explained = {
+                use crate::connection::Connection;
+
+                if !self.is_in_transaction() && !metadata.columns.is_empty() {
+                    self.get_nullable_for_columns_from_explain(
+                        statement,
+                        metadata.columns.len(),
+                        metadata.parameters.len(),
+                    )
+                    .await
+                    .ok()
+                } else {
+                    None
+                }
+            };
+
+            self.close_if_uncached(sql, statement);
+
+            #[allow(unused_mut)]
+            let mut nullable = self.get_nullable_for_columns(&metadata.columns).await?;
 
-            let nullable = self.get_nullable_for_columns(&metadata.columns).await?;
+            #[cfg(feature = "json")]
+            if let Some(explained) = explained {
+                for (nullable, explained) in nullable.iter_mut().zip(explained) {
+                    match explained {
+                        // the nullable side of an outer join overrides `NOT NULL`
+                        Some(true) => *nullable = Some(true),
+                        Some(false) if nullable.is_none() => *nullable = Some(false),
+                        _ => {}
+                    }
+                }
+            }
 
             Ok(Describe {
                 columns: metadata.columns.clone(),
@@ -0,0 +1,72 @@
+use libgssapi::context::{ClientCtx, CtxFlags, SecurityContext};
+use libgssapi::credential::{Cred, CredUsage};
+use libgssapi::name::Name;
+use libgssapi::oid::{OidSet, GSS_MECH_KRB5, GSS_NT_HOSTBASED_SERVICE};
+
+use crate::error::Error;
+use crate::postgres::connection::stream::PgStream;
+use crate::postgres::message::{Authentication, GssResponse, MessageFormat};
+use crate::postgres::PgConnectOptions;
+
+// https://www.postgresql.org/docs/current/gssapi-auth.html
+// https://www.postgresql.org/docs/current/protocol-flow.html#id-1.10.5.7.3
+
+pub(crate) async fn authenticate(
+    stream: &mut PgStream,
+    options: &PgConnectOptions,
+) -> Result<(), Error> {
+    // the server's principal is `<krbsrvname>/<host>@<REALM>`; as a host-based service name
+    // the realm is found by the Kerberos library
+    let service = format!("{}@{}", options.krb_srv_name, options.host);
+
+    let mut mechanisms = OidSet::new().map_err(gss_error)?;
+    mechanisms.add(&GSS_MECH_KRB5).map_err(gss_error)?;
+
+    // NOTE: these calls may reach out to the KDC and block, but only while connecting
+    let target = Name::new(service.as_bytes(), Some(&GSS_NT_HOSTBASED_SERVICE))
+        .and_then(|name| name.canonicalize(Some(&GSS_MECH_KRB5)))
+        .map_err(gss_error)?;
+
+    // the default credentials of the process, e.g. the ticket cache filled by `kinit`
+    let credentials =
+        Cred::acquire(None, None, CredUsage::Initiate, Some(&mechanisms)).map_err(gss_error)?;
+
+    let mut context = ClientCtx::new(
+        credentials,
+        target,
+        CtxFlags::GSS_C_MUTUAL_FLAG,
+        Some(&GSS_MECH_KRB5),
+    );
+
+    let mut server_token = None;
+
+    loop {
+        let token = context.step(server_token.as_deref()).map_err(gss_error)?;
+
+        if let Some(token) = token {
+            stream.send(GssResponse(&token)).await?;
+        }
+
+        if context.is_complete() {
+            // the server will next send [Authentication::Ok]
+            return Ok(());
+        }
+
+        let message = stream.recv_expect(MessageFormat::Authentication).await?;
+
+        server_token = match message {
+            Authentication::GssContinue(data) => Some(data),
+
+            auth => {
+                return Err(err_protocol!(
+                    "expected GSSContinue but received {:?}",
+                    auth
+                ));
+            }
+        };
+    }
+}
+
+fn gss_error(error: libgssapi::error::Error) -> Error {
+    Error::Configuration(format!("GSSAPI authentication failed: {}", error).into())
+}
@@ -1,30 +1,36 @@
 use std::fmt::{self, Debug, Formatter};
 use std::sync::Arc;
+use std::task::Context;
 
 use crate::HashMap;
 use futures_core::future::BoxFuture;
-use futures_util::{FutureExt, TryFutureExt};
+use futures_util::task::noop_waker_ref;
 
 use crate::common::StatementCache;
-use crate::connection::{Connection, LogSettings};
+use crate::connection::{Connection, ConnectionStatistics, LogSettings, ServerVersion};
 use crate::error::Error;
-use crate::executor::Executor;
 use crate::ext::ustr::UStr;
 use crate::io::Decode;
+use crate::net::MaybeTlsStream;
 use crate::postgres::connection::stream::PgStream;
 use crate::postgres::message::{
     Close, Message, MessageFormat, ReadyForQuery, Terminate, TransactionStatus,
 };
 use crate::postgres::statement::PgStatementMetadata;
-use crate::postgres::{PgConnectOptions, PgTypeInfo, Postgres};
+use crate::postgres::{PgConnectOptions, PgTimestampZone, PgTypeInfo, Postgres};
 use crate::transaction::Transaction;
 
 pub(crate) mod describe;
 mod establish;
 mod executor;
+#[cfg(feature = "gssapi")]
+mod gss;
 mod sasl;
 mod stream;
+mod timeouts;
 mod tls;
+#[cfg(feature = "protocol-trace")]
+mod trace;
 
 /// A connection to a PostgreSQL database.
 pub struct PgConnection {
@@ -43,6 +49,9 @@ pub struct PgConnection {
     #[allow(dead_code)]
     secret_key: u32,
 
+    // version reported by the server during start-up
+    server_version: Option<ServerVersion>,
+
     // sequence of statement IDs for use in preparing statements
     // in PostgreSQL, the statement is prepared to a user-supplied identifier
     next_statement_id: u32,
@@ -57,22 +66,51 @@ pub struct PgConnection {
     // number of ReadyForQuery messages that we are currently expecting
     pub(crate) pending_ready_for_query_count: usize,
 
+    // number of COPYs aborted by dropping their `PgCopyIn`, whose error is still to be discarded
+    pub(crate) pending_copy_fail_count: usize,
+
     // current transaction status
     transaction_status: TransactionStatus,
     pub(crate) transaction_depth: usize,
 
+    // counters reported by [Connection::statistics]
+    statistics: ConnectionStatistics,
+
     log_settings: LogSettings,
+
+    // whether to send a Terminate message when dropped without being closed
+    close_on_drop: bool,
+
+    // time zone that `TIMESTAMP` values are decoded in, passed on to each row
+    pub(crate) timestamp_zone: PgTimestampZone,
+
+    // whether a timeout was changed with `SET SESSION` and should be reset before the
+    // connection is returned to a pool
+    reset_timeouts: bool,
 }
 
 impl PgConnection {
     // will return when the connection is ready for another query
-    async fn wait_until_ready(&mut self) -> Result<(), Error> {
+    pub(crate) async fn wait_until_ready(&mut self) -> Result<(), Error> {
         if !self.stream.wbuf.is_empty() {
             self.stream.flush().await?;
         }
 
         while self.pending_ready_for_query_count > 0 {
-            let message = self.stream.recv().await?;
+            let message = match self.stream.recv().await {
+                Ok(message) => message,
+
+                // the server reports an aborted COPY as a cancelled query
+                Err(Error::Database(error))
+                    if self.pending_copy_fail_count > 0
+                        && error.code().as_deref() == Some("57014") =>
+                {
+                    self.pending_copy_fail_count -= 1;
+                    continue;
+                }
+
+                Err(error) => return Err(error),
+            };
 
             if let MessageFormat::ReadyForQuery = message.format {
                 self.handle_ready_for_query(message)?;
@@ -82,7 +120,7 @@ impl PgConnection {
         Ok(())
     }
 
-    async fn recv_ready_for_query(&mut self) -> Result<(), Error> {
+    pub(crate) async fn recv_ready_for_query(&mut self) -> Result<(), Error> {
         let r: ReadyForQuery = self
             .stream
             .recv_expect(MessageFormat::ReadyForQuery)
@@ -94,6 +132,42 @@ impl PgConnection {
         Ok(())
     }
 
+    /// Look up a user-defined type, such as one created by an extension like PostGIS, and
+    /// cache its OID on this connection.
+    ///
+    /// Rust types for such types implement [`Type`](crate::types::Type) with
+    /// [`PgTypeInfo::with_name`] and their own [`Encode`](crate::encode::Encode) and
+    /// [`Decode`](crate::decode::Decode), in place of the derives. Their OID is otherwise looked
+    /// up the first time they are bound, and columns of that type returned by unprepared
+    /// statements are not checked against them at all.
+    ///
+    /// Use [`PgConnectOptions::register_type`] to do this for every connection.
+    ///
+    /// ```rust,ignore
+    /// struct Geometry(Vec<u8>);
+    ///
+    /// impl sqlx::Type<Postgres> for Geometry {
+    ///     fn type_info() -> PgTypeInfo {
+    ///         PgTypeInfo::with_name("geometry")
+    ///     }
+    /// }
+    ///
+    /// conn.register_type("geometry").await?;
+    /// ```
+    pub async fn register_type(&mut self, name: &str) -> Result<PgTypeInfo, Error> {
+        let oid = match self.fetch_type_id_by_name(name).await {
+            Err(Error::RowNotFound) => {
+                return Err(Error::Configuration(
+                    format!("type {:?} does not exist", name).into(),
+                ));
+            }
+
+            res => res?,
+        };
+
+        self.maybe_fetch_type_info_by_oid(oid, true).await
+    }
+
     fn handle_ready_for_query(&mut self, message: Message) -> Result<(), Error> {
         self.pending_ready_for_query_count -= 1;
         self.transaction_status = ReadyForQuery::decode(message.contents)?.transaction_status;
@@ -108,6 +182,23 @@ impl Debug for PgConnection {
     }
 }
 
+impl Drop for PgConnection {
+    fn drop(&mut self) {
+        // after an interrupted write the server would read the message as part of another one
+        if self.close_on_drop && !self.stream.is_interrupted() {
+            self.stream.write(Terminate);
+
+            // without a runtime to spawn on, the message is only written out as far as the
+            // socket accepts it without waiting
+            if !self.stream.flush_in_background(MaybeTlsStream::Upgrading) {
+                let _ = self
+                    .stream
+                    .poll_flush_buffer(&mut Context::from_waker(noop_waker_ref()));
+            }
+        }
+    }
+}
+
 impl Connection for PgConnection {
     type Database = Postgres;
 
@@ -121,6 +212,8 @@ impl Connection for PgConnection {
         // connection and terminates.
 
         Box::pin(async move {
+            self.close_on_drop = false;
+
             self.stream.send(Terminate).await?;
             self.stream.shutdown()?;
 
@@ -129,8 +222,20 @@ impl Connection for PgConnection {
     }
 
     fn ping(&mut self) -> BoxFuture<'_, Result<(), Error>> {
-        // By sending a comment we avoid an error if the connection was in the middle of a rowset
-        self.execute("/* SQLx ping */").map_ok(|_| ()).boxed()
+        // A lone Sync is answered with ReadyForQuery without the server parsing or
+        // planning anything, which makes it the cheapest possible round trip
+        Box::pin(async move {
+            self.wait_until_ready().await?;
+
+            self.write_sync();
+            self.stream.flush().await?;
+
+            self.recv_ready_for_query().await
+        })
+    }
+
+    fn is_in_transaction(&self) -> bool {
+        !matches!(self.transaction_status, TransactionStatus::Idle)
     }
 
     fn begin(&mut self) -> BoxFuture<'_, Result<Transaction<'_, Self::Database>, Error>>
@@ -167,13 +272,29 @@ impl Connection for PgConnection {
         })
     }
 
+    fn statistics(&self) -> ConnectionStatistics {
+        ConnectionStatistics {
+            bytes_sent: self.stream.bytes_written,
+            bytes_received: self.stream.bytes_read,
+            ..self.statistics
+        }
+    }
+
+    fn server_version(&self) -> Option<&ServerVersion> {
+        self.server_version.as_ref()
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
-        self.wait_until_ready().boxed()
+        // only called before the connection is returned to a pool
+        Box::pin(async move {
+            self.wait_until_ready().await?;
+            self.reset_timeouts().await
+        })
     }
 
     #[doc(hidden)]
     fn should_flush(&self) -> bool {
-        !self.stream.wbuf.is_empty()
+        !self.stream.wbuf.is_empty() || self.stream.is_interrupted() || self.reset_timeouts
     }
 }
@@ -1,14 +1,17 @@
+use std::collections::BTreeMap;
 use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
 
-use bytes::{Buf, Bytes};
-use futures_channel::mpsc::UnboundedSender;
-use futures_util::SinkExt;
 use log::Level;
+use parking_lot::Mutex;
 
 use crate::error::Error;
 use crate::io::{BufStream, Decode, Encode};
 use crate::net::{MaybeTlsStream, Socket};
-use crate::postgres::message::{Message, MessageFormat, Notice, Notification};
+use crate::postgres::listener::NotificationBuffer;
+use crate::postgres::message::{
+    content_len, Message, MessageFormat, Notice, Notification, ParameterStatus,
+};
 use crate::postgres::{PgConnectOptions, PgDatabaseError, PgSeverity};
 
 // the stream is a separate type from the connection to uphold the invariant where an instantiated
@@ -26,14 +29,20 @@ pub struct PgStream {
     // buffer of unreceived notification messages from `PUBLISH`
     // this is set when creating a PgListener and only written to if that listener is
     // re-used for query execution in-between receiving messages
-    pub(crate) notifications: Option<UnboundedSender<Notification>>,
+    pub(crate) notifications: Option<Arc<Mutex<NotificationBuffer>>>,
+
+    // the most recently reported value of each run-time parameter
+    pub(crate) parameter_statuses: BTreeMap<String, String>,
+
+    // notices received since the last statement completed
+    pub(crate) notices: Vec<Notice>,
 }
 
 impl PgStream {
     pub(super) async fn connect(options: &PgConnectOptions) -> Result<Self, Error> {
         let socket = match options.fetch_socket() {
             Some(ref path) => Socket::connect_uds(path).await?,
-            None => Socket::connect_tcp(&options.host, options.port).await?,
+            None => Socket::connect_tcp(&options.host, options.port, &options.tcp_settings).await?,
         };
 
         let inner = BufStream::new(MaybeTlsStream::Raw(socket));
@@ -41,9 +50,25 @@ impl PgStream {
         Ok(Self {
             inner,
             notifications: None,
+            parameter_statuses: BTreeMap::new(),
+            notices: Vec::new(),
         })
     }
 
+    // shadows [BufStream::write] so that every frontend message can be traced
+    pub(crate) fn write<'en, T>(&mut self, message: T)
+    where
+        T: Encode<'en>,
+    {
+        #[cfg(feature = "protocol-trace")]
+        let offset = self.inner.wbuf.len();
+
+        self.inner.write(message);
+
+        #[cfg(feature = "protocol-trace")]
+        super::trace::frontend(&self.inner.wbuf[offset..]);
+    }
+
     pub(crate) async fn send<'en, T>(&mut self, message: T) -> Result<(), Error>
     where
         T: Encode<'en>,
@@ -73,14 +98,18 @@ impl PgStream {
     pub(crate) async fn recv_unchecked(&mut self) -> Result<Message, Error> {
         // all packets in postgres start with a 5-byte header
         // this header contains the message type and the total length of the message
-        let mut header: Bytes = self.inner.read(5).await?;
+        let (header, contents) = self.inner.read_frame(5, content_len).await?;
 
-        let format = MessageFormat::try_from_u8(header.get_u8())?;
-        let size = (header.get_u32() - 4) as usize;
+        let format = MessageFormat::try_from_u8(header[0])?;
+        let message = Message {
+            format,
+            contents: contents.freeze(),
+        };
 
-        let contents = self.inner.read(size).await?;
+        #[cfg(feature = "protocol-trace")]
+        super::trace::backend(&message);
 
-        Ok(Message { format, contents })
+        Ok(message)
     }
 
     // Get the next message from the server
@@ -96,9 +125,9 @@ impl PgStream {
                 }
 
                 MessageFormat::NotificationResponse => {
-                    if let Some(buffer) = &mut self.notifications {
+                    if let Some(buffer) = &self.notifications {
                         let notification: Notification = message.decode()?;
-                        let _ = buffer.send(notification).await;
+                        buffer.lock().push(notification);
 
                         continue;
                     }
@@ -108,14 +137,14 @@ impl PgStream {
                     // informs the frontend about the current (initial)
                     // setting of backend parameters
 
-                    // we currently have no use for that data so we promptly ignore this message
+                    let status: ParameterStatus = message.decode()?;
+                    self.parameter_statuses.insert(status.name, status.value);
+
                     continue;
                 }
 
                 MessageFormat::NoticeResponse => {
-                    // do we need this to be more configurable?
-                    // if you are reading this comment and think so, open an issue
-
+                    // notices are logged and collected until the statement completes
                     let notice: Notice = message.decode()?;
 
                     let lvl = match notice.severity() {
@@ -139,6 +168,8 @@ impl PgStream {
                         );
                     }
 
+                    self.notices.push(notice);
+
                     continue;
                 }
 
@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use crate::connection::Connection;
+use crate::error::Error;
+use crate::executor::Executor;
+use crate::postgres::PgConnection;
+
+impl PgConnection {
+    /// Set `statement_timeout`, aborting any statement that runs for longer than `timeout`.
+    ///
+    /// `None` disables the timeout.
+    ///
+    /// Inside of a transaction this issues `SET LOCAL` and only lasts until the transaction
+    /// ends. Otherwise it issues `SET SESSION`; a connection from a [`Pool`](crate::pool::Pool)
+    /// resets it before the connection goes back into the pool.
+    ///
+    /// ```rust,ignore
+    /// let mut tx = pool.begin().await?;
+    ///
+    /// // only applies to the statements of this transaction
+    /// tx.set_statement_timeout(Some(Duration::from_secs(5))).await?;
+    /// ```
+    pub async fn set_statement_timeout(&mut self, timeout: Option<Duration>) -> Result<(), Error> {
+        self.set_timeout("statement_timeout", timeout).await
+    }
+
+    /// Set `lock_timeout`, aborting any statement that waits for longer than `timeout` to acquire
+    /// a lock.
+    ///
+    /// See [`set_statement_timeout`](PgConnection::set_statement_timeout) for how long it lasts.
+    pub async fn set_lock_timeout(&mut self, timeout: Option<Duration>) -> Result<(), Error> {
+        self.set_timeout("lock_timeout", timeout).await
+    }
+
+    /// Set `idle_in_transaction_session_timeout`, terminating the session when it stays idle
+    /// inside of an open transaction for longer than `timeout`.
+    ///
+    /// See [`set_statement_timeout`](PgConnection::set_statement_timeout) for how long it lasts.
+    pub async fn set_idle_in_transaction_session_timeout(
+        &mut self,
+        timeout: Option<Duration>,
+    ) -> Result<(), Error> {
+        self.set_timeout("idle_in_transaction_session_timeout", timeout)
+            .await
+    }
+
+    async fn set_timeout(&mut self, name: &str, timeout: Option<Duration>) -> Result<(), Error> {
+        // the settings take a positive `int4` number of milliseconds; 0 disables them
+        let millis = timeout.map_or(0, |timeout| {
+            timeout.as_millis().max(1).min(i32::MAX as u128)
+        });
+
+        self.wait_until_ready().await?;
+
+        if self.is_in_transaction() {
+            let sql = format!("SET LOCAL {} = {}", name, millis);
+            self.execute(&*sql).await?;
+        } else {
+            let sql = format!("SET SESSION {} = {}", name, millis);
+            self.execute(&*sql).await?;
+
+            self.reset_timeouts = true;
+        }
+
+        Ok(())
+    }
+
+    // restore the timeouts changed with `SET SESSION` to their defaults
+    pub(crate) async fn reset_timeouts(&mut self) -> Result<(), Error> {
+        if self.reset_timeouts {
+            self.execute(
+                "RESET statement_timeout; \
+                 RESET lock_timeout; \
+                 RESET idle_in_transaction_session_timeout",
+            )
+            .await?;
+
+            self.reset_timeouts = false;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::io::trace;
+use crate::postgres::message::{Message, MessageFormat};
+
+// logs every message that crosses the wire when the `protocol-trace` feature is enabled
+// https://www.postgresql.org/docs/current/protocol-message-formats.html
+
+pub(super) fn frontend(buf: &[u8]) {
+    trace::frontend(|| summarize_frontend(buf));
+}
+
+pub(super) fn backend(message: &Message) {
+    trace::backend(|| summarize_backend(message));
+}
+
+fn summarize_frontend(buf: &[u8]) -> String {
+    // the startup and SSL request messages are the only messages without a type byte;
+    // as every other message type is an ASCII letter we can tell them apart by the
+    // most significant byte of their length
+    if buf.first().map_or(true, |b| *b == 0) {
+        return format!("Startup {{ len: {} }}", buf.len());
+    }
+
+    let (name, payload) = match buf[0] {
+        b'B' => ("Bind", redacted(buf.len())),
+        b'C' => ("Close", String::new()),
+        b'D' => ("Describe", String::new()),
+        b'E' => ("Execute", String::new()),
+        b'H' => ("Flush", String::new()),
+        b'P' => ("Parse", sql_after_name(&buf[5..])),
+        b'Q' => ("Query", sql(&buf[5..])),
+        b'S' => ("Sync", String::new()),
+        b'X' => ("Terminate", String::new()),
+
+        // password, SASL and GSS responses
+        b'p' => ("PasswordMessage", redacted(buf.len())),
+
+        other => return format!("{:?} {{ len: {} }}", other as char, buf.len()),
+    };
+
+    if payload.is_empty() {
+        format!("{} {{ len: {} }}", name, buf.len())
+    } else {
+        format!("{} {{ len: {}, {} }}", name, buf.len(), payload)
+    }
+}
+
+fn summarize_backend(message: &Message) -> String {
+    let len = message.contents.len() + 4;
+
+    let payload = match message.format {
+        MessageFormat::CommandComplete => sql(&message.contents),
+
+        MessageFormat::DataRow => {
+            let columns = if message.contents.len() >= 2 {
+                u16::from_be_bytes([message.contents[0], message.contents[1]])
+            } else {
+                0
+            };
+
+            format!("columns: {}, values: <redacted>", columns)
+        }
+
+        _ => String::new(),
+    };
+
+    if payload.is_empty() {
+        format!("{:?} {{ len: {} }}", message.format, len)
+    } else {
+        format!("{:?} {{ len: {}, {} }}", message.format, len, payload)
+    }
+}
+
+fn redacted(len: usize) -> String {
+    format!("payload: <{} bytes redacted>", len.saturating_sub(5))
+}
+
+// Parse is laid out as the statement name followed by the query string
+fn sql_after_name(buf: &[u8]) -> String {
+    match buf.iter().position(|b| *b == 0) {
+        Some(nul) => sql(&buf[nul + 1..]),
+        None => String::new(),
+    }
+}
+
+// strings in the protocol are terminated by a nul byte
+fn sql(buf: &[u8]) -> String {
+    let end = buf.iter().position(|b| *b == 0).unwrap_or(buf.len());
+
+    trace::sql(&buf[..end])
+}
+
+#[test]
+fn it_redacts_bind_parameters() {
+    let summary = summarize_frontend(b"B\0\0\0\x12\0\0\0\0\0\x01\0\0\0\x04hunter2");
+
+    assert!(summary.starts_with("Bind"));
+    assert!(!summary.contains("hunter2"));
+}
+
+#[test]
+fn it_summarizes_query() {
+    let summary = summarize_frontend(b"Q\0\0\0\x0dSELECT 1\0");
+
+    assert_eq!(summary, "Query { len: 14, sql: \"SELECT 1\" }");
+}
@@ -0,0 +1,274 @@
+use std::ops::DerefMut;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures_core::stream::BoxStream;
+use futures_util::sink::Sink;
+
+use crate::error::Error;
+use crate::pool::{Pool, PoolConnection};
+use crate::postgres::message::{
+    CommandComplete, CopyData, CopyDone, CopyFail, CopyResponse, MessageFormat, Query,
+};
+use crate::postgres::{PgConnection, Postgres};
+
+// data is flushed to the server once this much is buffered
+const MAX_BUFFERED: usize = 64 * 1024;
+
+impl PgConnection {
+    /// Issue a `COPY ... FROM STDIN` statement and return a [`PgCopyIn`] to send the data with.
+    ///
+    /// The data must already be in the format named by the statement; see the documentation
+    /// of [`COPY`](https://www.postgresql.org/docs/current/sql-copy.html). The statement is sent
+    /// as-is, so any parameters have to be escaped and formatted into it.
+    pub async fn copy_in_raw(&mut self, statement: &str) -> Result<PgCopyIn<&mut Self>, Error> {
+        PgCopyIn::begin(self, statement).await
+    }
+
+    /// Issue a `COPY ... TO STDOUT` statement and return a stream of the data it produces.
+    ///
+    /// The chunks are returned as sent by the server, which is usually one row per chunk
+    /// in the format named by the statement.
+    pub async fn copy_out_raw<'c>(
+        &'c mut self,
+        statement: &str,
+    ) -> Result<BoxStream<'c, Result<Bytes, Error>>, Error> {
+        copy_out(self, statement).await
+    }
+}
+
+impl Pool<Postgres> {
+    /// Issue a `COPY ... FROM STDIN` statement on a connection from the pool.
+    ///
+    /// See [`PgConnection::copy_in_raw`].
+    pub async fn copy_in_raw(
+        &self,
+        statement: &str,
+    ) -> Result<PgCopyIn<PoolConnection<Postgres>>, Error> {
+        PgCopyIn::begin(self.acquire().await?, statement).await
+    }
+
+    /// Issue a `COPY ... TO STDOUT` statement on a connection from the pool.
+    ///
+    /// See [`PgConnection::copy_out_raw`].
+    pub async fn copy_out_raw(
+        &self,
+        statement: &str,
+    ) -> Result<BoxStream<'static, Result<Bytes, Error>>, Error> {
+        copy_out(self.acquire().await?, statement).await
+    }
+}
+
+/// A `COPY ... FROM STDIN` in progress, created by [`PgConnection::copy_in_raw`].
+///
+/// Data is sent either with [`send_data`](PgCopyIn::send_data) or through the [`Sink`]
+/// implementation, which accepts any chunk of bytes and so composes with the stream adapters
+/// of `futures`. Once all data has been sent, [`finish`](PgCopyIn::finish) completes the copy.
+/// Closing the sink only flushes the data sent so far.
+///
+/// If dropped before it is finished or aborted, the copy is aborted the next time the
+/// connection is used.
+#[must_use = "the copy must be completed with `.finish()` or `.abort()`"]
+pub struct PgCopyIn<C: DerefMut<Target = PgConnection>> {
+    conn: Option<C>,
+    response: CopyResponse,
+}
+
+impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
+    async fn begin(mut conn: C, statement: &str) -> Result<Self, Error> {
+        conn.wait_until_ready().await?;
+
+        conn.stream.write(Query(statement));
+        conn.pending_ready_for_query_count += 1;
+        conn.stream.flush().await?;
+
+        let response = conn
+            .stream
+            .recv_expect(MessageFormat::CopyInResponse)
+            .await?;
+
+        Ok(Self {
+            conn: Some(conn),
+            response,
+        })
+    }
+
+    /// Returns `true` if the data is expected in a textual format, `false` if binary.
+    pub fn is_textual(&self) -> bool {
+        self.response.format == 0
+    }
+
+    /// Returns the number of columns expected in the data.
+    pub fn num_columns(&self) -> usize {
+        self.response.column_formats.len()
+    }
+
+    /// Send a chunk of data to the server.
+    ///
+    /// Chunks do not need to line up with rows.
+    pub async fn send_data(&mut self, data: impl AsRef<[u8]>) -> Result<&mut Self, Error> {
+        let conn = self.conn_mut();
+
+        conn.stream.write(CopyData(data.as_ref()));
+
+        if conn.stream.wbuf.len() >= MAX_BUFFERED {
+            conn.stream.flush().await?;
+        }
+
+        Ok(self)
+    }
+
+    /// Signal that all data has been sent and wait for the server to process it.
+    ///
+    /// Returns the number of rows copied.
+    pub async fn finish(mut self) -> Result<u64, Error> {
+        let mut conn = self
+            .conn
+            .take()
+            .expect("BUG: PgCopyIn used after completion");
+
+        conn.stream.write(CopyDone);
+        conn.stream.flush().await?;
+
+        let cc: CommandComplete = conn
+            .stream
+            .recv_expect(MessageFormat::CommandComplete)
+            .await?;
+
+        conn.recv_ready_for_query().await?;
+
+        Ok(cc.rows_affected())
+    }
+
+    /// Abort the copy, discarding all data sent so far.
+    ///
+    /// The message is included in the error that the server logs.
+    pub async fn abort(mut self, message: impl AsRef<str>) -> Result<(), Error> {
+        let mut conn = self
+            .conn
+            .take()
+            .expect("BUG: PgCopyIn used after completion");
+
+        conn.stream.write(CopyFail(message.as_ref()));
+        conn.stream.flush().await?;
+
+        match conn.stream.recv().await {
+            // the server reports the abort as a cancelled query
+            Err(Error::Database(error)) if error.code().as_deref() == Some("57014") => {
+                conn.recv_ready_for_query().await?;
+
+                Ok(())
+            }
+
+            Err(error) => Err(error),
+
+            Ok(message) => Err(err_protocol!(
+                "expecting ErrorResponse after CopyFail but received {:?}",
+                message.format
+            )),
+        }
+    }
+
+    fn conn_mut(&mut self) -> &mut PgConnection {
+        self.conn
+            .as_mut()
+            .expect("BUG: PgCopyIn used after completion")
+    }
+}
+
+impl<C, B> Sink<B> for PgCopyIn<C>
+where
+    C: DerefMut<Target = PgConnection> + Unpin,
+    B: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        let conn = self.conn_mut();
+
+        if conn.stream.wbuf.len() < MAX_BUFFERED {
+            return Poll::Ready(Ok(()));
+        }
+
+        conn.stream.poll_flush_buffer(cx).map_err(Error::Io)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: B) -> Result<(), Error> {
+        self.conn_mut().stream.write(CopyData(item.as_ref()));
+
+        Ok(())
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        self.conn_mut()
+            .stream
+            .poll_flush_buffer(cx)
+            .map_err(Error::Io)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        <Self as Sink<B>>::poll_flush(self, cx)
+    }
+}
+
+impl<C: DerefMut<Target = PgConnection>> Drop for PgCopyIn<C> {
+    fn drop(&mut self) {
+        if let Some(mut conn) = self.conn.take() {
+            // the server answers with an error and a ReadyForQuery which are received, and the
+            // error discarded, the next time the connection is used
+            conn.stream
+                .write(CopyFail("PgCopyIn dropped without being finished"));
+            conn.pending_copy_fail_count += 1;
+        }
+    }
+}
+
+async fn copy_out<'c, C>(
+    mut conn: C,
+    statement: &str,
+) -> Result<BoxStream<'c, Result<Bytes, Error>>, Error>
+where
+    C: DerefMut<Target = PgConnection> + Send + 'c,
+{
+    conn.wait_until_ready().await?;
+
+    conn.stream.write(Query(statement));
+    conn.pending_ready_for_query_count += 1;
+    conn.stream.flush().await?;
+
+    let _: CopyResponse = conn
+        .stream
+        .recv_expect(MessageFormat::CopyOutResponse)
+        .await?;
+
+    Ok(Box::pin(try_stream! {
+        loop {
+            let message = conn.stream.recv().await?;
+
+            match message.format {
+                MessageFormat::CopyData => {
+                    r#yield!(message.contents);
+                }
+
+                MessageFormat::CopyDone => {
+                    let _: CommandComplete = conn
+                        .stream
+                        .recv_expect(MessageFormat::CommandComplete)
+                        .await?;
+
+                    conn.recv_ready_for_query().await?;
+
+                    return Ok(());
+                }
+
+                format => {
+                    return Err(err_protocol!(
+                        "expecting CopyData or CopyDone but received {:?}",
+                        format
+                    ));
+                }
+            }
+        }
+    }))
+}
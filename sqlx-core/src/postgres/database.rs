@@ -1,4 +1,6 @@
-use crate::database::{Database, HasArguments, HasStatement, HasStatementCache, HasValueRef};
+use crate::database::{
+    quote, Database, HasArguments, HasStatement, HasStatementCache, HasValueRef,
+};
 use crate::postgres::arguments::PgArgumentBuffer;
 use crate::postgres::value::{PgValue, PgValueRef};
 use crate::postgres::{
@@ -24,6 +26,37 @@ impl Database for Postgres {
     type TypeInfo = PgTypeInfo;
 
     type Value = PgValue;
+
+    const NAME: &'static str = "postgresql";
+
+    // the number of parameters is sent as a 16-bit integer
+    const MAX_BIND_PARAMETERS: usize = 65535;
+}
+
+impl Postgres {
+    /// Quote `ident` so it can be interpolated into SQL as an identifier, such as a table or
+    /// column name; it is wrapped with double quotes.
+    ///
+    /// Identifiers cannot be bind parameters, so this is the way to use a name that is only
+    /// known at runtime. Quoted identifiers are case-sensitive.
+    pub fn quote_identifier(ident: &str) -> String {
+        quote(ident, '"', '"')
+    }
+
+    /// Quote `value` so it can be interpolated into SQL as a string literal; it is wrapped
+    /// with single quotes, as an escape string (`E'...'`) if it contains a backslash.
+    ///
+    /// Prefer binding values as parameters; this is for the few places where a parameter is
+    /// not allowed, such as in DDL.
+    pub fn quote_literal(value: &str) -> String {
+        // like `quote_literal()` in Postgres, an escape string is used for backslashes so the
+        // result means the same regardless of `standard_conforming_strings`
+        if value.contains('\\') {
+            format!("E{}", quote(&value.replace('\\', r"\\"), '\'', '\''))
+        } else {
+            quote(value, '\'', '\'')
+        }
+    }
 }
 
 impl<'r> HasValueRef<'r> for Postgres {
@@ -47,3 +80,15 @@ impl<'q> HasStatement<'q> for Postgres {
 }
 
 impl HasStatementCache for Postgres {}
+
+#[test]
+fn it_quotes_identifiers_and_literals() {
+    assert_eq!(Postgres::quote_identifier("users"), r#""users""#);
+    assert_eq!(
+        Postgres::quote_identifier(r#"my "table""#),
+        r#""my ""table""""#
+    );
+
+    assert_eq!(Postgres::quote_literal("it's"), "'it''s'");
+    assert_eq!(Postgres::quote_literal(r"C:\dir"), r"E'C:\\dir'");
+}
@@ -1,10 +1,19 @@
 use crate::done::Done;
-use crate::postgres::Postgres;
+use crate::postgres::{PgNotice, Postgres};
 use std::iter::{Extend, IntoIterator};
 
 #[derive(Debug, Default)]
 pub struct PgDone {
     pub(super) rows_affected: u64,
+    pub(super) notices: Vec<PgNotice>,
+}
+
+impl PgDone {
+    /// Returns the notices and warnings raised by the server while executing the statement,
+    /// in the order they were received.
+    pub fn notices(&self) -> &[PgNotice] {
+        &self.notices
+    }
 }
 
 impl Done for PgDone {
@@ -19,6 +28,7 @@ impl Extend<PgDone> for PgDone {
     fn extend<T: IntoIterator<Item = PgDone>>(&mut self, iter: T) {
         for elem in iter {
             self.rows_affected += elem.rows_affected;
+            self.notices.extend(elem.notices);
         }
     }
 }
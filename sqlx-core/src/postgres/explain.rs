@@ -0,0 +1,58 @@
+use crate::error::Error;
+use crate::executor::Executor;
+use crate::postgres::{PgArguments, Postgres};
+use crate::query::Query;
+use crate::row::Row;
+
+impl<'q> Query<'q, Postgres, PgArguments> {
+    /// Returns the plan that Postgres chooses for this query, from `EXPLAIN (FORMAT JSON)`.
+    ///
+    /// The plan is the object with the `"Plan"` key; its shape is described in the
+    /// [Postgres manual](https://www.postgresql.org/docs/current/using-explain.html). Any bound
+    /// arguments are used for planning.
+    ///
+    /// ```rust,ignore
+    /// let plan = sqlx::query("SELECT * FROM users WHERE id = $1")
+    ///     .bind(42_i64)
+    ///     .explain(&pool)
+    ///     .await?;
+    ///
+    /// assert_eq!(plan["Plan"]["Node Type"], "Index Scan");
+    /// ```
+    pub async fn explain<'c, E>(self, executor: E) -> Result<serde_json::Value, Error>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        self.explain_with("FORMAT JSON", executor).await
+    }
+
+    /// Runs this query with `EXPLAIN (ANALYZE, FORMAT JSON)` and returns its plan, including the
+    /// actual row counts and timings of each node.
+    ///
+    /// This **executes** the query, so its effects are applied. Run it inside of a transaction
+    /// that is rolled back to explain a statement that modifies data without keeping the changes.
+    pub async fn explain_analyze<'c, E>(self, executor: E) -> Result<serde_json::Value, Error>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        self.explain_with("ANALYZE, FORMAT JSON", executor).await
+    }
+
+    async fn explain_with<'c, E>(
+        self,
+        options: &str,
+        executor: E,
+    ) -> Result<serde_json::Value, Error>
+    where
+        E: Executor<'c, Database = Postgres>,
+    {
+        let sql = format!("EXPLAIN ({}) {}", options, self.sql_str());
+        let row = executor.fetch_one(self.with_sql(&sql)).await?;
+
+        // the plan comes back as an array of exactly one object
+        match row.try_get::<serde_json::Value, _>(0)? {
+            serde_json::Value::Array(mut plans) if plans.len() == 1 => Ok(plans.remove(0)),
+            plan => Err(err_protocol!("unexpected EXPLAIN output: {}", plan)),
+        }
+    }
+}
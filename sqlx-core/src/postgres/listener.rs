@@ -5,13 +5,16 @@ use crate::pool::PoolOptions;
 use crate::pool::{Pool, PoolConnection};
 use crate::postgres::message::{MessageFormat, Notification};
 use crate::postgres::{PgConnection, PgDone, PgRow, PgStatement, PgTypeInfo, Postgres};
+use crate::runtime;
 use either::Either;
-use futures_channel::mpsc;
 use futures_core::future::BoxFuture;
 use futures_core::stream::{BoxStream, Stream};
+use parking_lot::Mutex;
+use std::collections::VecDeque;
 use std::fmt::{self, Debug};
 use std::io;
 use std::str::from_utf8;
+use std::sync::Arc;
 
 /// A stream of asynchronous notifications from Postgres.
 ///
@@ -19,14 +22,58 @@ use std::str::from_utf8;
 /// connection being used ever dies, this listener will detect that event, create a
 /// new connection, will re-subscribe to all of the originally specified channels, and will resume
 /// operations as normal.
+///
+/// Notifications that arrive while the listener is not waiting for them, such as while it is
+/// used to execute a query or is re-subscribing after a reconnect, are kept in a buffer until
+/// they are received. The buffer is unbounded unless limited with
+/// [`set_buffer_capacity`](PgListener::set_buffer_capacity).
 pub struct PgListener {
     pool: Pool<Postgres>,
     connection: Option<PoolConnection<Postgres>>,
-    buffer_rx: mpsc::UnboundedReceiver<Notification>,
-    buffer_tx: Option<mpsc::UnboundedSender<Notification>>,
+    buffer: Arc<Mutex<NotificationBuffer>>,
     channels: Vec<String>,
 }
 
+/// What a [`PgListener`] does with a notification that arrives while its buffer is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PgListenerOverflow {
+    /// Discard the oldest buffered notification to make room for the new one.
+    DropOldest,
+
+    /// Discard the new notification.
+    DropNewest,
+}
+
+pub(crate) struct NotificationBuffer {
+    queue: VecDeque<Notification>,
+    capacity: Option<usize>,
+    overflow: PgListenerOverflow,
+    dropped: u64,
+}
+
+impl NotificationBuffer {
+    pub(crate) fn push(&mut self, notification: Notification) {
+        if let Some(capacity) = self.capacity {
+            if self.queue.len() >= capacity {
+                self.dropped += 1;
+
+                log::warn!("notification buffer is full; dropping a notification");
+
+                match self.overflow {
+                    PgListenerOverflow::DropOldest => {
+                        self.queue.pop_front();
+                    }
+
+                    PgListenerOverflow::DropNewest => return,
+                }
+            }
+        }
+
+        self.queue.push_back(notification);
+    }
+}
+
 /// An asynchronous notification from Postgres.
 pub struct PgNotification(Notification);
 
@@ -44,23 +91,58 @@ impl PgListener {
         Self::connect_with(&pool).await
     }
 
+    /// Create a listener that takes its connection from an existing pool.
+    ///
+    /// The connection is held for as long as the listener lives and counts against the
+    /// `max_connections` of the pool. If it is lost, a new one is acquired from the pool.
+    /// When the listener is dropped, its subscriptions are removed with `UNLISTEN *` before the
+    /// connection is returned to the pool.
     pub async fn connect_with(pool: &Pool<Postgres>) -> Result<Self, Error> {
         // Pull out an initial connection
         let mut connection = pool.acquire().await?;
 
         // Setup a notification buffer
-        let (sender, receiver) = mpsc::unbounded();
-        connection.stream.notifications = Some(sender);
+        let buffer = Arc::new(Mutex::new(NotificationBuffer {
+            queue: VecDeque::new(),
+            capacity: None,
+            overflow: PgListenerOverflow::DropOldest,
+            dropped: 0,
+        }));
+
+        connection.stream.notifications = Some(Arc::clone(&buffer));
 
         Ok(Self {
             pool: pool.clone(),
             connection: Some(connection),
-            buffer_rx: receiver,
-            buffer_tx: None,
+            buffer,
             channels: Vec::new(),
         })
     }
 
+    /// Limit the number of notifications that are buffered while they are not being received.
+    ///
+    /// Once `capacity` notifications are buffered, `overflow` decides which one is discarded.
+    pub fn set_buffer_capacity(&mut self, capacity: usize, overflow: PgListenerOverflow) {
+        let mut buffer = self.buffer.lock();
+
+        buffer.capacity = Some(capacity);
+        buffer.overflow = overflow;
+
+        while buffer.queue.len() > capacity {
+            buffer.dropped += 1;
+
+            match overflow {
+                PgListenerOverflow::DropOldest => buffer.queue.pop_front(),
+                PgListenerOverflow::DropNewest => buffer.queue.pop_back(),
+            };
+        }
+    }
+
+    /// Returns the number of notifications that were discarded because the buffer was full.
+    pub fn dropped_notifications(&self) -> u64 {
+        self.buffer.lock().dropped
+    }
+
     /// Starts listening for notifications on a channel.
     /// The channel name is quoted here to ensure case sensitivity.
     pub async fn listen(&mut self, channel: &str) -> Result<(), Error> {
@@ -117,7 +199,7 @@ impl PgListener {
     async fn connect_if_needed(&mut self) -> Result<(), Error> {
         if self.connection.is_none() {
             let mut connection = self.pool.acquire().await?;
-            connection.stream.notifications = self.buffer_tx.take();
+            connection.stream.notifications = Some(Arc::clone(&self.buffer));
 
             connection
                 .execute(&*build_listen_all_query(&self.channels))
@@ -198,23 +280,28 @@ impl PgListener {
     /// # }).unwrap();
     /// ```
     pub async fn try_recv(&mut self) -> Result<Option<PgNotification>, Error> {
-        // Flush the buffer first, if anything
-        // This would only fill up if this listener is used as a connection
-        if let Ok(Some(notification)) = self.buffer_rx.try_next() {
-            return Ok(Some(PgNotification(notification)));
-        }
-
         loop {
+            // Flush the buffer first, if anything
+            // This fills up while this listener is used as a connection or is re-subscribing
+            if let Some(notification) = self.buffer.lock().queue.pop_front() {
+                return Ok(Some(PgNotification(notification)));
+            }
+
             // Ensure we have an active connection to work with.
             self.connect_if_needed().await?;
 
+            // notifications that arrived while re-subscribing are received first
+            if !self.buffer.lock().queue.is_empty() {
+                continue;
+            }
+
             let message = match self.connection().stream.recv_unchecked().await {
                 Ok(message) => message,
 
                 // The connection is dead, ensure that it is dropped,
                 // update self state, and loop to try again.
                 Err(Error::Io(err)) if err.kind() == io::ErrorKind::ConnectionAborted => {
-                    self.buffer_tx = self.connection().stream.notifications.take();
+                    self.connection().stream.notifications = None;
                     self.connection = None;
 
                     // lost connection
@@ -328,6 +415,82 @@ impl PgNotification {
     pub fn payload(&self) -> &str {
         from_utf8(&self.0.payload).unwrap()
     }
+
+    /// Deserialize the payload of the notification from JSON, such as one sent by [`notify`].
+    #[cfg(feature = "json")]
+    pub fn payload_json<T>(&self) -> Result<T, Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        serde_json::from_slice(&self.0.payload).map_err(|error| Error::Decode(error.into()))
+    }
+}
+
+/// Send a notification on a channel with the payload serialized to JSON.
+///
+/// This executes `SELECT pg_notify(channel, payload)`, so the notification is only delivered
+/// once the surrounding transaction, if any, is committed. Postgres limits payloads to
+/// 8000 bytes.
+///
+/// Returns [`Error::Encode`] if `payload` cannot be serialized.
+///
+/// ```rust,ignore
+/// sqlx::postgres::notify(&pool, "orders", &OrderPlaced { id: 42 }).await?;
+///
+/// // elsewhere
+/// let notification = listener.recv().await?;
+/// let order: OrderPlaced = notification.payload_json()?;
+/// ```
+#[cfg(feature = "json")]
+pub async fn notify<'c, E, T>(executor: E, channel: &str, payload: &T) -> Result<(), Error>
+where
+    E: Executor<'c, Database = Postgres>,
+    T: serde::Serialize + ?Sized,
+{
+    let payload = serde_json::to_string(payload).map_err(|error| Error::Encode(error.into()))?;
+
+    crate::query::query("SELECT pg_notify($1, $2)")
+        .bind(channel)
+        .bind(payload)
+        .execute(executor)
+        .await?;
+
+    Ok(())
+}
+
+impl Drop for PgListener {
+    fn drop(&mut self) {
+        if let Some(mut connection) = self.connection.take() {
+            if !runtime::can_spawn() {
+                // the subscriptions cannot be removed without a runtime, so the connection must
+                // not go back to the pool
+                connection.close_without_waiting();
+                return;
+            }
+
+            // the subscriptions must not outlive the listener once the connection is back in
+            // the pool; this also drains anything still pending on the connection
+            runtime::spawn(async move {
+                // notifications keep arriving until UNLISTEN completes, and go to the buffer
+                // (which nobody reads anymore) rather than to `execute`
+                match connection.execute("UNLISTEN *").await {
+                    Ok(_) => {
+                        connection.stream.notifications = None;
+                    }
+
+                    Err(error) => {
+                        log::warn!(
+                            "failed to UNLISTEN before returning the connection: {}",
+                            error
+                        );
+
+                        // the connection may still be subscribed
+                        connection.close_without_waiting();
+                    }
+                }
+            });
+        }
+    }
 }
 
 impl Debug for PgListener {
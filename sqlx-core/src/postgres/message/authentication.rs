@@ -34,6 +34,23 @@ pub enum Authentication {
     /// again using the 4-byte random salt.
     Md5Password(AuthenticationMd5Password),
 
+    /// The frontend must now initiate a GSSAPI negotiation.
+    ///
+    /// The frontend will send a [GssResponse] message with the first part of the
+    /// GSSAPI data stream in response to this. If further messages are needed, the
+    /// server will respond with [Authentication::GssContinue].
+    Gss,
+
+    /// This message contains the response data from the previous step of GSSAPI
+    /// or SSPI negotiation.
+    GssContinue(Bytes),
+
+    /// The frontend must now initiate a SSPI negotiation.
+    ///
+    /// The frontend will send a [GssResponse] with the first part of the SSPI data
+    /// stream in response to this.
+    Sspi,
+
     /// The frontend must now initiate a SASL negotiation,
     /// using one of the SASL mechanisms listed in the message.
     ///
@@ -72,6 +89,10 @@ impl Decode<'_> for Authentication {
                 Authentication::Md5Password(AuthenticationMd5Password { salt })
             }
 
+            7 => Authentication::Gss,
+            8 => Authentication::GssContinue(buf),
+            9 => Authentication::Sspi,
+
             10 => Authentication::Sasl(AuthenticationSasl(buf)),
             11 => Authentication::SaslContinue(AuthenticationSaslContinue::decode(buf)?),
             12 => Authentication::SaslFinal(AuthenticationSaslFinal::decode(buf)?),
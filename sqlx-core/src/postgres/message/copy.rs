@@ -0,0 +1,95 @@
+use bytes::{Buf, Bytes};
+
+use crate::error::Error;
+use crate::io::{BufMutExt, Decode, Encode};
+use crate::postgres::io::PgBufMutExt;
+
+/// The first response to `COPY ... FROM STDIN` or `COPY ... TO STDOUT`.
+///
+/// <https://www.postgresql.org/docs/current/protocol-message-formats.html>
+#[derive(Debug)]
+pub struct CopyResponse {
+    /// `0` if the overall format is textual, `1` if it is binary.
+    pub format: i8,
+
+    /// The format of each column; always `0` when the overall format is textual.
+    pub column_formats: Vec<i16>,
+}
+
+impl Decode<'_> for CopyResponse {
+    fn decode_with(mut buf: Bytes, _: ()) -> Result<Self, Error> {
+        let format = buf.get_i8();
+        let num_columns = buf.get_i16();
+
+        let mut column_formats = Vec::with_capacity(num_columns.max(0) as usize);
+
+        for _ in 0..num_columns {
+            column_formats.push(buf.get_i16());
+        }
+
+        Ok(Self {
+            format,
+            column_formats,
+        })
+    }
+}
+
+/// A chunk of `COPY` data sent to the server.
+#[derive(Debug)]
+pub struct CopyData<'a>(pub &'a [u8]);
+
+impl Encode<'_> for CopyData<'_> {
+    fn encode_with(&self, buf: &mut Vec<u8>, _: ()) {
+        buf.reserve(self.0.len() + 5);
+        buf.push(b'd');
+
+        buf.put_length_prefixed(|buf| {
+            buf.extend_from_slice(self.0);
+        });
+    }
+}
+
+/// Marks the end of the `COPY` data sent to the server.
+#[derive(Debug)]
+pub struct CopyDone;
+
+impl Encode<'_> for CopyDone {
+    fn encode_with(&self, buf: &mut Vec<u8>, _: ()) {
+        buf.push(b'c');
+        buf.extend(&4_i32.to_be_bytes());
+    }
+}
+
+/// Aborts a `COPY ... FROM STDIN` with the given error message.
+#[derive(Debug)]
+pub struct CopyFail<'a>(pub &'a str);
+
+impl Encode<'_> for CopyFail<'_> {
+    fn encode_with(&self, buf: &mut Vec<u8>, _: ()) {
+        buf.push(b'f');
+
+        buf.put_length_prefixed(|buf| {
+            buf.put_str_nul(self.0);
+        });
+    }
+}
+
+#[test]
+fn test_encode_copy_data() {
+    const EXPECTED: &[u8] = b"d\0\0\0\x081\t2\n";
+
+    let mut buf = Vec::new();
+    CopyData(b"1\t2\n").encode(&mut buf);
+
+    assert_eq!(buf, EXPECTED);
+}
+
+#[test]
+fn test_decode_copy_response() {
+    const DATA: &[u8] = b"\x01\0\x02\0\x01\0\x01";
+
+    let m = CopyResponse::decode(Bytes::from_static(DATA)).unwrap();
+
+    assert_eq!(m.format, 1);
+    assert_eq!(m.column_formats, vec![1, 1]);
+}
@@ -0,0 +1,26 @@
+use crate::io::Encode;
+use crate::postgres::io::PgBufMutExt;
+
+/// A GSSAPI or SSPI token sent in response to [`Authentication::Gss`] or
+/// [`Authentication::GssContinue`].
+///
+/// [`Authentication::Gss`]: crate::postgres::message::Authentication::Gss
+/// [`Authentication::GssContinue`]: crate::postgres::message::Authentication::GssContinue
+pub struct GssResponse<'a>(pub &'a [u8]);
+
+impl Encode<'_> for GssResponse<'_> {
+    fn encode_with(&self, buf: &mut Vec<u8>, _: ()) {
+        buf.push(b'p');
+        buf.put_length_prefixed(|buf| {
+            buf.extend(self.0);
+        });
+    }
+}
+
+#[test]
+fn test_encode_gss_response() {
+    let mut buf = Vec::new();
+    GssResponse(b"token").encode(&mut buf);
+
+    assert_eq!(buf, b"p\0\0\0\x09token");
+}
@@ -1,4 +1,4 @@
-use bytes::Bytes;
+use bytes::{Buf, Bytes};
 
 use crate::error::Error;
 use crate::io::Decode;
@@ -8,12 +8,15 @@ mod backend_key_data;
 mod bind;
 mod close;
 mod command_complete;
+mod copy;
 mod data_row;
 mod describe;
 mod execute;
 mod flush;
+mod gss;
 mod notification;
 mod parameter_description;
+mod parameter_status;
 mod parse;
 mod password;
 mod query;
@@ -31,12 +34,15 @@ pub use backend_key_data::BackendKeyData;
 pub use bind::Bind;
 pub use close::Close;
 pub use command_complete::CommandComplete;
+pub use copy::{CopyData, CopyDone, CopyFail, CopyResponse};
 pub use data_row::DataRow;
 pub use describe::Describe;
 pub use execute::Execute;
 pub use flush::Flush;
+pub use gss::GssResponse;
 pub use notification::Notification;
 pub use parameter_description::ParameterDescription;
+pub use parameter_status::ParameterStatus;
 pub use parse::Parse;
 pub use password::Password;
 pub use query::Query;
@@ -57,6 +63,10 @@ pub enum MessageFormat {
     BindComplete,
     CloseComplete,
     CommandComplete,
+    CopyData,
+    CopyDone,
+    CopyInResponse,
+    CopyOutResponse,
     DataRow,
     EmptyQueryResponse,
     ErrorResponse,
@@ -97,6 +107,8 @@ impl MessageFormat {
             b'3' => MessageFormat::CloseComplete,
             b'C' => MessageFormat::CommandComplete,
             b'D' => MessageFormat::DataRow,
+            b'G' => MessageFormat::CopyInResponse,
+            b'H' => MessageFormat::CopyOutResponse,
             b'E' => MessageFormat::ErrorResponse,
             b'I' => MessageFormat::EmptyQueryResponse,
             b'A' => MessageFormat::NotificationResponse,
@@ -106,6 +118,8 @@ impl MessageFormat {
             b'S' => MessageFormat::ParameterStatus,
             b'T' => MessageFormat::RowDescription,
             b'Z' => MessageFormat::ReadyForQuery,
+            b'c' => MessageFormat::CopyDone,
+            b'd' => MessageFormat::CopyData,
             b'n' => MessageFormat::NoData,
             b's' => MessageFormat::PortalSuspended,
             b't' => MessageFormat::ParameterDescription,
@@ -114,3 +128,14 @@ impl MessageFormat {
         })
     }
 }
+
+// length of the contents of a backend message, given its 5-byte header
+pub(crate) fn content_len(mut header: &[u8]) -> Result<usize, Error> {
+    header.advance(1);
+
+    // the length in the header counts itself but not the message type
+    let len = header.get_u32() as usize;
+
+    len.checked_sub(4)
+        .ok_or_else(|| err_protocol!("invalid message length: {}", len))
+}
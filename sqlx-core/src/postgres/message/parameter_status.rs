@@ -0,0 +1,33 @@
+use bytes::Bytes;
+
+use crate::error::Error;
+use crate::io::{BufExt, Decode};
+
+/// Reports the current value of a run-time parameter, such as `server_version`.
+///
+/// Sent during start-up for every parameter that the frontend could be interested in, and again
+/// whenever one of them changes.
+#[derive(Debug)]
+pub struct ParameterStatus {
+    pub name: String,
+    pub value: String,
+}
+
+impl Decode<'_> for ParameterStatus {
+    fn decode_with(mut buf: Bytes, _: ()) -> Result<Self, Error> {
+        let name = buf.get_str_nul()?;
+        let value = buf.get_str_nul()?;
+
+        Ok(Self { name, value })
+    }
+}
+
+#[test]
+fn test_decode_parameter_status() {
+    const DATA: &[u8] = b"server_version\x0013.2\x00";
+
+    let m = ParameterStatus::decode(Bytes::from_static(DATA)).unwrap();
+
+    assert_eq!(m.name, "server_version");
+    assert_eq!(m.value, "13.2");
+}
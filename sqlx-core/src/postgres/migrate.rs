@@ -83,6 +83,28 @@ impl MigrateDatabase for Postgres {
             Ok(())
         })
     }
+
+    fn force_drop_database(uri: &str) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            let (options, database) = parse_for_maintenance(uri)?;
+            let mut conn = options.connect().await?;
+
+            // `WITH (FORCE)` terminates the backends still connected to the database
+            let force = conn
+                .server_version()
+                .map_or(false, |version| version.at_least(13, 0));
+
+            let _ = conn
+                .execute(&*format!(
+                    "DROP DATABASE IF EXISTS \"{}\"{}",
+                    database.replace('"', "\"\""),
+                    if force { " WITH (FORCE)" } else { "" }
+                ))
+                .await?;
+
+            Ok(())
+        })
+    }
 }
 
 impl Migrate for PgConnection {
@@ -211,6 +233,28 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
             Ok(elapsed)
         })
     }
+
+    fn revert<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
+        Box::pin(async move {
+            let mut tx = self.begin().await?;
+            let start = Instant::now();
+
+            let _ = tx.execute(&*migration.sql).await?;
+
+            // language=SQL
+            let _ = query("DELETE FROM _sqlx_migrations WHERE version = $1")
+                .bind(migration.version)
+                .execute(&mut tx)
+                .await?;
+
+            tx.commit().await?;
+
+            Ok(start.elapsed())
+        })
+    }
 }
 
 async fn current_database(conn: &mut PgConnection) -> Result<String, MigrateError> {
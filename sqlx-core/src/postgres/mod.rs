@@ -3,13 +3,19 @@
 mod arguments;
 mod column;
 mod connection;
+mod copy;
 mod database;
 mod done;
 mod error;
+#[cfg(feature = "json")]
+mod explain;
 mod io;
 mod listener;
 mod message;
+mod notice;
 mod options;
+#[cfg(feature = "unstable-protocol")]
+pub mod protocol;
 mod row;
 mod statement;
 mod transaction;
@@ -23,16 +29,21 @@ mod migrate;
 pub use arguments::{PgArgumentBuffer, PgArguments};
 pub use column::PgColumn;
 pub use connection::PgConnection;
+pub use copy::PgCopyIn;
 pub use database::Postgres;
 pub use done::PgDone;
 pub use error::{PgDatabaseError, PgErrorPosition};
-pub use listener::{PgListener, PgNotification};
+#[cfg(feature = "json")]
+pub use listener::notify;
+pub use listener::{PgListener, PgListenerOverflow, PgNotification};
 pub use message::PgSeverity;
-pub use options::{PgConnectOptions, PgSslMode};
+pub use notice::PgNotice;
+pub use options::{PgConnectOptions, PgGssEncMode, PgSslMode, PgTimestampZone};
 pub use row::PgRow;
 pub use statement::PgStatement;
 pub use transaction::PgTransactionManager;
 pub use type_info::{PgTypeInfo, PgTypeKind};
+pub use types::PgHasArrayType;
 pub use value::{PgValue, PgValueFormat, PgValueRef};
 
 /// An alias for [`Pool`][crate::pool::Pool], specialized for Postgres.
@@ -50,3 +61,4 @@ impl_column_index_for_row!(PgRow);
 impl_column_index_for_statement!(PgStatement);
 impl_into_maybe_pool!(Postgres, PgConnection);
 impl_encode_for_option!(Postgres);
+impl_encode_for_null!(Postgres);
@@ -0,0 +1,63 @@
+use std::fmt::{self, Debug, Formatter};
+
+use crate::postgres::message::{Notice, PgSeverity};
+
+/// A notice or warning raised by the server while executing a statement, such as by
+/// `RAISE WARNING` in a PL/pgSQL function.
+///
+/// Notices are also logged under the `sqlx::postgres::notice` target as they are received.
+pub struct PgNotice(pub(crate) Notice);
+
+// Message fields are documented:
+// https://www.postgresql.org/docs/current/protocol-error-fields.html
+
+impl PgNotice {
+    #[inline]
+    pub fn severity(&self) -> PgSeverity {
+        self.0.severity()
+    }
+
+    /// The [SQLSTATE](https://www.postgresql.org/docs/current/errcodes-appendix.html) code for
+    /// this notice, such as `01000` for a warning raised without one.
+    #[inline]
+    pub fn code(&self) -> &str {
+        self.0.code()
+    }
+
+    /// The primary human-readable message.
+    #[inline]
+    pub fn message(&self) -> &str {
+        self.0.message()
+    }
+
+    /// An optional secondary message carrying more detail.
+    #[inline]
+    pub fn detail(&self) -> Option<&str> {
+        self.0.get(b'D')
+    }
+
+    /// An optional suggestion what to do about the notice.
+    #[inline]
+    pub fn hint(&self) -> Option<&str> {
+        self.0.get(b'H')
+    }
+
+    /// An indication of the context in which the notice was raised, such as a call stack
+    /// traceback of active procedural language functions.
+    pub fn r#where(&self) -> Option<&str> {
+        self.0.get(b'W')
+    }
+}
+
+impl Debug for PgNotice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PgNotice")
+            .field("severity", &self.severity())
+            .field("code", &self.code())
+            .field("message", &self.message())
+            .field("detail", &self.detail())
+            .field("hint", &self.hint())
+            .field("where", &self.r#where())
+            .finish()
+    }
+}
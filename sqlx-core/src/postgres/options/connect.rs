@@ -3,6 +3,7 @@ use crate::error::Error;
 use crate::postgres::{PgConnectOptions, PgConnection};
 use futures_core::future::BoxFuture;
 use log::LevelFilter;
+use std::borrow::Cow;
 use std::time::Duration;
 
 impl ConnectOptions for PgConnectOptions {
@@ -12,7 +13,21 @@ impl ConnectOptions for PgConnectOptions {
     where
         Self::Connection: Sized,
     {
-        Box::pin(PgConnection::establish(self))
+        Box::pin(async move {
+            let mut options = Cow::Borrowed(self);
+
+            if let Some(provider) = &self.password_provider {
+                options.to_mut().password = Some(provider.password().await?);
+            }
+
+            let mut conn = PgConnection::establish(&options).await?;
+
+            for name in &self.registered_types {
+                conn.register_type(name).await?;
+            }
+
+            Ok(conn)
+        })
     }
 
     fn log_statements(&mut self, level: LevelFilter) -> &mut Self {
@@ -24,4 +39,9 @@ impl ConnectOptions for PgConnectOptions {
         self.log_settings.log_slow_statements(level, duration);
         self
     }
+
+    fn log_binds(&mut self, enabled: bool) -> &mut Self {
+        self.log_settings.log_binds(enabled);
+        self
+    }
 }
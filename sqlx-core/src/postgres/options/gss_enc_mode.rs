@@ -0,0 +1,50 @@
+use crate::error::Error;
+use std::str::FromStr;
+
+/// Options for controlling whether a GSSAPI-encrypted connection is negotiated with
+/// the server.
+///
+/// It is used by the [`gss_enc_mode`](super::PgConnectOptions::gss_enc_mode) method.
+///
+/// GSSAPI transport encryption is not yet supported. Use [`PgSslMode`](super::PgSslMode) to
+/// encrypt the connection; GSSAPI can still be used to _authenticate_ with the `gssapi` feature.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PgGssEncMode {
+    /// Only try a non-GSSAPI-encrypted connection.
+    Disable,
+
+    /// Try a GSSAPI-encrypted connection if possible, falling back to a non-GSSAPI-encrypted
+    /// connection.
+    ///
+    /// As encryption is not supported, this currently behaves like [`Disable`](Self::Disable).
+    Prefer,
+
+    /// Only try a GSSAPI-encrypted connection.
+    ///
+    /// As encryption is not supported, connecting with this mode always fails.
+    Require,
+}
+
+impl Default for PgGssEncMode {
+    fn default() -> Self {
+        PgGssEncMode::Prefer
+    }
+}
+
+impl FromStr for PgGssEncMode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Ok(match &*s.to_ascii_lowercase() {
+            "disable" => PgGssEncMode::Disable,
+            "prefer" => PgGssEncMode::Prefer,
+            "require" => PgGssEncMode::Require,
+
+            _ => {
+                return Err(Error::Configuration(
+                    format!("unknown value {:?} for `gssencmode`", s).into(),
+                ));
+            }
+        })
+    }
+}
@@ -1,11 +1,20 @@
 use std::env::var;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 mod connect;
+mod gss_enc_mode;
 mod parse;
 mod ssl_mode;
+mod timestamp_zone;
+use crate::common::{PasswordProvider, Secret};
 use crate::connection::LogSettings;
+use crate::error::BoxDynError;
+use crate::net::{Socks5Proxy, TcpSettings};
+use futures_core::future::BoxFuture;
+pub use gss_enc_mode::PgGssEncMode;
 pub use ssl_mode::PgSslMode;
+pub use timestamp_zone::PgTimestampZone;
 
 /// Options and flags which can be used to configure a PostgreSQL connection.
 ///
@@ -24,6 +33,8 @@ pub use ssl_mode::PgSslMode;
 /// |---------|-------|-----------|
 /// | `sslmode` | `prefer` | Determines whether or with what priority a secure SSL TCP/IP connection will be negotiated. See [`PgSqlSslMode`]. |
 /// | `sslrootcert` | `None` | Sets the name of a file containing a list of trusted SSL Certificate Authorities. |
+/// | `gssencmode` | `prefer` | Determines whether a GSSAPI-encrypted connection will be negotiated. See [`PgGssEncMode`]. |
+/// | `krbsrvname` | `postgres` | Kerberos service name to use when authenticating with GSSAPI. |
 /// | `statement-cache-capacity` | `100` | The maximum number of prepared statements stored in the cache. Set to `0` to disable. |
 /// | `host` | `None` | Path to the directory containing a PostgreSQL unix domain socket, which will be used instead of TCP if set. |
 /// | `hostaddr` | `None` | Same as `host`, but only accepts IP addresses. |
@@ -32,6 +43,7 @@ pub use ssl_mode::PgSslMode;
 /// | `password` | `None` | Password to be used if the server demands password authentication. |
 /// | `port` | `5432` | Port number to connect to at the server host, or socket file name extension for Unix-domain connections. |
 /// | `dbname` | `None` | The database name. |
+/// | `options` | `None` | Session settings in the form `-c name=value`, separated by spaces. See [`session_setting`](PgConnectOptions::session_setting). |
 ///
 /// The URI scheme designator can be either `postgresql://` or `postgres://`.
 /// Each of the URI parts is optional.
@@ -79,12 +91,20 @@ pub struct PgConnectOptions {
     pub(crate) port: u16,
     pub(crate) socket: Option<PathBuf>,
     pub(crate) username: String,
-    pub(crate) password: Option<String>,
+    pub(crate) password: Option<Secret>,
+    pub(crate) password_provider: Option<PasswordProvider>,
     pub(crate) database: Option<String>,
     pub(crate) ssl_mode: PgSslMode,
     pub(crate) ssl_root_cert: Option<PathBuf>,
+    pub(crate) gss_enc_mode: PgGssEncMode,
+    pub(crate) krb_srv_name: String,
     pub(crate) statement_cache_capacity: usize,
     pub(crate) application_name: Option<String>,
+    pub(crate) session_settings: Vec<(String, String)>,
+    pub(crate) registered_types: Vec<String>,
+    pub(crate) close_on_drop: bool,
+    pub(crate) timestamp_zone: PgTimestampZone,
+    pub(crate) tcp_settings: TcpSettings,
     pub(crate) log_settings: LogSettings,
 }
 
@@ -107,6 +127,8 @@ impl PgConnectOptions {
     ///  * `PGDATABASE`
     ///  * `PGSSLROOTCERT`
     ///  * `PGSSLMODE`
+    ///  * `PGGSSENCMODE`
+    ///  * `PGKRBSRVNAME`
     ///  * `PGAPPNAME`
     ///
     /// # Example
@@ -128,15 +150,28 @@ impl PgConnectOptions {
             host,
             socket: None,
             username: var("PGUSER").ok().unwrap_or_else(whoami::username),
-            password: var("PGPASSWORD").ok(),
+            password: var("PGPASSWORD").ok().map(Secret::from),
+            password_provider: None,
             database: var("PGDATABASE").ok(),
             ssl_root_cert: var("PGSSLROOTCERT").ok().map(PathBuf::from),
             ssl_mode: var("PGSSLMODE")
                 .ok()
                 .and_then(|v| v.parse().ok())
                 .unwrap_or_default(),
+            gss_enc_mode: var("PGGSSENCMODE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_default(),
+            krb_srv_name: var("PGKRBSRVNAME")
+                .ok()
+                .unwrap_or_else(|| "postgres".to_owned()),
             statement_cache_capacity: 100,
             application_name: var("PGAPPNAME").ok(),
+            session_settings: Vec::new(),
+            registered_types: Vec::new(),
+            close_on_drop: true,
+            timestamp_zone: PgTimestampZone::Utc,
+            tcp_settings: Default::default(),
             log_settings: Default::default(),
         }
     }
@@ -215,7 +250,34 @@ impl PgConnectOptions {
     ///     .password("safe-and-secure");
     /// ```
     pub fn password(mut self, password: &str) -> Self {
-        self.password = Some(password.to_owned());
+        self.password = Some(password.into());
+        self
+    }
+
+    /// Sets a callback that fetches the password for every new connection.
+    ///
+    /// This is for short-lived credentials, such as AWS RDS IAM authentication tokens or
+    /// passwords leased from Vault, that would expire if they were set once with
+    /// [`password`](PgConnectOptions::password). The callback is invoked each time a connection
+    /// is opened, e.g. by a pool, and takes precedence over `password`. An error from the
+    /// callback fails the connection attempt.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .username("app")
+    ///     .password_provider(|| Box::pin(async move {
+    ///         let token = fetch_rds_auth_token().await?;
+    ///         Ok(token)
+    ///     }));
+    /// ```
+    pub fn password_provider<F>(mut self, provider: F) -> Self
+    where
+        F: Fn() -> BoxFuture<'static, Result<String, BoxDynError>> + 'static + Send + Sync,
+    {
+        self.password_provider = Some(PasswordProvider::new(provider));
         self
     }
 
@@ -271,12 +333,53 @@ impl PgConnectOptions {
         self
     }
 
+    /// Sets whether a GSSAPI-encrypted connection will be negotiated with the server.
+    ///
+    /// By default, the mode is [`Prefer`](PgGssEncMode::Prefer). Transport encryption with
+    /// GSSAPI is not supported yet, so `Prefer` never encrypts and
+    /// [`Require`](PgGssEncMode::Require) fails to connect.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::{PgGssEncMode, PgConnectOptions};
+    /// let options = PgConnectOptions::new()
+    ///     .gss_enc_mode(PgGssEncMode::Disable);
+    /// ```
+    pub fn gss_enc_mode(mut self, mode: PgGssEncMode) -> Self {
+        self.gss_enc_mode = mode;
+        self
+    }
+
+    /// Sets the Kerberos service name used when the server requests GSSAPI authentication.
+    ///
+    /// The service principal is `<name>@<host>`. Defaults to `postgres`, matching the
+    /// default of the server's `krb_srvname`.
+    ///
+    /// Authenticating with GSSAPI requires the `gssapi` feature and a credential cache with
+    /// a valid ticket, e.g. one obtained with `kinit`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .krb_srv_name("POSTGRES");
+    /// ```
+    pub fn krb_srv_name(mut self, name: &str) -> Self {
+        self.krb_srv_name = name.to_owned();
+        self
+    }
+
     /// Sets the capacity of the connection's statement cache in a number of stored
     /// distinct statements. Caching is handled using LRU, meaning when the
     /// amount of queries hits the defined limit, the oldest statement will get
     /// dropped.
     ///
-    /// The default cache capacity is 100 statements.
+    /// The default cache capacity is 100 statements. Set to `0` to disable caching, which keeps
+    /// the memory used for prepared statements on the server from growing when many distinct
+    /// queries are executed; every statement is then prepared again for each execution and
+    /// closed afterwards.
     pub fn statement_cache_capacity(mut self, capacity: usize) -> Self {
         self.statement_cache_capacity = capacity;
         self
@@ -296,6 +399,183 @@ impl PgConnectOptions {
         self
     }
 
+    /// Sets a run-time parameter for every session, such as `lock_timeout`.
+    ///
+    /// The parameters are sent along with the start-up message, so they are in effect before
+    /// the first statement is executed. Setting the same parameter again replaces its value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .session_setting("lock_timeout", "10s");
+    /// ```
+    pub fn session_setting(mut self, name: &str, value: &str) -> Self {
+        self.session_settings
+            .retain(|(existing, _)| !existing.eq_ignore_ascii_case(name));
+
+        self.session_settings
+            .push((name.to_owned(), value.to_owned()));
+
+        self
+    }
+
+    /// Sets the schema search path of every session.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .search_path("app, public");
+    /// ```
+    pub fn search_path(self, search_path: &str) -> Self {
+        self.session_setting("search_path", search_path)
+    }
+
+    /// Sets the time after which any statement is aborted, for every session.
+    ///
+    /// Disabled by default.
+    pub fn statement_timeout(self, timeout: Duration) -> Self {
+        self.session_setting("statement_timeout", &timeout.as_millis().to_string())
+    }
+
+    /// Sets the time zone of every session.
+    ///
+    /// Defaults to `UTC`. This only affects values that are converted to text, such as
+    /// `now()::text`; `TIMESTAMPTZ` values are always transferred in UTC.
+    pub fn time_zone(self, time_zone: &str) -> Self {
+        self.session_setting("TimeZone", time_zone)
+    }
+
+    /// Looks up a user-defined type on every new connection.
+    ///
+    /// See [`PgConnection::register_type`](super::PgConnection::register_type). Connecting fails
+    /// if the type does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .register_type("geometry");
+    /// ```
+    pub fn register_type(mut self, name: &str) -> Self {
+        self.registered_types.push(name.to_owned());
+        self
+    }
+
+    /// Sets whether a connection that is dropped without calling
+    /// [`close`](crate::connection::Connection::close) still sends a `Terminate` message to the
+    /// server.
+    ///
+    /// This lets the server tell a deliberate disconnect apart from a lost connection, so it
+    /// does not log an error for every dropped connection. The message is sent from a task of
+    /// its own when the connection is dropped inside of a runtime, and otherwise only written
+    /// as far as the socket accepts it without waiting; it is not guaranteed to be sent either
+    /// way. Enabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .close_on_drop(false);
+    /// ```
+    pub fn close_on_drop(mut self, close_on_drop: bool) -> Self {
+        self.close_on_drop = close_on_drop;
+        self
+    }
+
+    /// Sets the time zone that values of `TIMESTAMP` (without time zone) columns are assumed
+    /// to be in when decoded as `DateTime<Tz>` or `OffsetDateTime`.
+    ///
+    /// Defaults to UTC. Legacy schemas often store local wall-clock time in `TIMESTAMP`
+    /// columns; decoding those as `NaiveDateTime` or `PrimitiveDateTime` is unaffected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::{PgConnectOptions, PgTimestampZone};
+    /// let options = PgConnectOptions::new()
+    ///     .timestamp_zone(PgTimestampZone::FixedOffset(2 * 60 * 60));
+    /// ```
+    pub fn timestamp_zone(mut self, zone: PgTimestampZone) -> Self {
+        self.timestamp_zone = zone;
+        self
+    }
+
+    /// Sets whether `TCP_NODELAY` is set on the socket, disabling Nagle's algorithm.
+    ///
+    /// Enabled by default. Ignored for Unix domain socket communication.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .tcp_nodelay(false);
+    /// ```
+    pub fn tcp_nodelay(mut self, nodelay: bool) -> Self {
+        self.tcp_settings.nodelay = nodelay;
+        self
+    }
+
+    /// Enables TCP keepalive on the socket, sending the first probe after the connection
+    /// has been idle for `interval`.
+    ///
+    /// Disabled by default. Only supported by the Tokio and Actix runtimes.
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_settings.keepalive = Some(interval);
+        self
+    }
+
+    /// Sets the size of the socket send buffer (`SO_SNDBUF`) in bytes.
+    ///
+    /// Defaults to the operating system setting. Only supported by the Tokio and Actix runtimes.
+    pub fn tcp_send_buffer_size(mut self, size: usize) -> Self {
+        self.tcp_settings.send_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets the size of the socket receive buffer (`SO_RCVBUF`) in bytes.
+    ///
+    /// Defaults to the operating system setting. Only supported by the Tokio and Actix runtimes.
+    pub fn tcp_recv_buffer_size(mut self, size: usize) -> Self {
+        self.tcp_settings.recv_buffer_size = Some(size);
+        self
+    }
+
+    /// Connects to the server through a SOCKS5 proxy.
+    ///
+    /// The host name of the server is resolved by the proxy. To reach a server behind an SSH
+    /// bastion host, open a dynamic port forward with `ssh -D 1080 <bastion>` and use
+    /// `localhost:1080` as the proxy.
+    ///
+    /// Ignored for Unix domain socket communication.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .host("db.internal")
+    ///     .socks5_proxy("localhost", 1080);
+    /// ```
+    pub fn socks5_proxy(mut self, host: &str, port: u16) -> Self {
+        self.tcp_settings.socks5_proxy = Some(Socks5Proxy::new(host, port));
+        self
+    }
+
+    /// Sets the username and password to authenticate with the SOCKS5 proxy.
+    ///
+    /// Can be called before or after `socks5_proxy`; has no effect if no proxy is set.
+    pub fn socks5_proxy_credentials(mut self, username: &str, password: &str) -> Self {
+        self.tcp_settings.socks5_credentials = Some((username.to_owned(), password.into()));
+        self
+    }
+
     /// We try using a socket if hostname starts with `/` or if socket parameter
     /// is specified.
     pub(crate) fn fetch_socket(&self) -> Option<String> {
@@ -0,0 +1,25 @@
+/// The time zone that values of `TIMESTAMP` (without time zone) columns are assumed to be in
+/// when they are decoded as an instant, such as `DateTime<Utc>` or `OffsetDateTime`.
+///
+/// It is used by the [`timestamp_zone`](super::PgConnectOptions::timestamp_zone) method.
+/// Values of `TIMESTAMPTZ` columns always carry their own offset and are not affected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PgTimestampZone {
+    /// `TIMESTAMP` values are in UTC.
+    Utc,
+
+    /// `TIMESTAMP` values are wall-clock times in the local time zone of the client.
+    ///
+    /// A wall-clock time that occurs twice, at the end of daylight saving time, is taken to be
+    /// the earlier of the two; one that is skipped over at its start is an error.
+    Local,
+
+    /// `TIMESTAMP` values are in the time zone with the given offset in seconds east of UTC.
+    FixedOffset(i32),
+}
+
+impl Default for PgTimestampZone {
+    fn default() -> Self {
+        PgTimestampZone::Utc
+    }
+}
@@ -0,0 +1,78 @@
+//! The messages of the Postgres frontend/backend protocol and how they are framed.
+//!
+//! This exposes the same encoders and decoders the connection uses so that tests, fuzzers
+//! and other tooling can exercise them without a server. It is **unstable**: anything in
+//! this module may change in any release, including patch releases.
+
+use bytes::{Buf, Bytes};
+
+use crate::error::Error;
+
+pub use super::message::*;
+pub use crate::io::{Decode, Encode};
+
+use super::message::content_len;
+
+/// Splits the next backend message off the front of `buf`, exactly as the connection frames
+/// the data it reads from the server.
+///
+/// Returns `Ok(None)`, leaving `buf` untouched, if it does not yet hold a complete message.
+pub fn split_message(buf: &mut Bytes) -> Result<Option<Message>, Error> {
+    if buf.len() < 5 {
+        return Ok(None);
+    }
+
+    let len = content_len(&buf[..5])?;
+
+    if buf.len() - 5 < len {
+        return Ok(None);
+    }
+
+    let format = MessageFormat::try_from_u8(buf[0])?;
+
+    let mut contents = buf.split_to(5 + len);
+    contents.advance(5);
+
+    Ok(Some(Message { format, contents }))
+}
+
+#[test]
+fn it_splits_messages() -> Result<(), Error> {
+    let mut buf = Bytes::from_static(b"Z\0\0\0\x05IZ\0\0\0\x05T");
+
+    let message = split_message(&mut buf)?.unwrap();
+    assert_eq!(message.format, MessageFormat::ReadyForQuery);
+
+    let ready: ReadyForQuery = message.decode()?;
+    assert!(matches!(ready.transaction_status, TransactionStatus::Idle));
+
+    let message = split_message(&mut buf)?.unwrap();
+    let ready: ReadyForQuery = message.decode()?;
+    assert!(matches!(
+        ready.transaction_status,
+        TransactionStatus::Transaction
+    ));
+
+    assert!(buf.is_empty());
+    assert!(split_message(&mut buf)?.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn it_waits_for_a_complete_message() -> Result<(), Error> {
+    let mut buf = Bytes::from_static(b"C\0\0\0\x0dSELECT");
+
+    assert!(split_message(&mut buf)?.is_none());
+    assert_eq!(buf.len(), 11);
+
+    Ok(())
+}
+
+#[test]
+fn it_rejects_invalid_frames() {
+    // the length must at least cover itself
+    assert!(split_message(&mut Bytes::from_static(b"Z\0\0\0\x03I")).is_err());
+
+    assert!(split_message(&mut Bytes::from_static(b"?\0\0\0\x04")).is_err());
+}
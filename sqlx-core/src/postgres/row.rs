@@ -3,7 +3,7 @@ use crate::error::Error;
 use crate::postgres::message::DataRow;
 use crate::postgres::statement::PgStatementMetadata;
 use crate::postgres::value::PgValueFormat;
-use crate::postgres::{PgColumn, PgValueRef, Postgres};
+use crate::postgres::{PgColumn, PgTimestampZone, PgValueRef, Postgres};
 use crate::row::Row;
 use std::sync::Arc;
 
@@ -12,6 +12,7 @@ pub struct PgRow {
     pub(crate) data: DataRow,
     pub(crate) format: PgValueFormat,
     pub(crate) metadata: Arc<PgStatementMetadata>,
+    pub(crate) timestamp_zone: PgTimestampZone,
 }
 
 impl crate::row::private_row::Sealed for PgRow {}
@@ -36,6 +37,7 @@ impl Row for PgRow {
             row: Some(&self.data.storage),
             type_info: column.type_info.clone(),
             value,
+            timestamp_zone: self.timestamp_zone,
         })
     }
 }
@@ -18,7 +18,8 @@ pub struct PgStatement<'q> {
 #[derive(Debug, Default)]
 pub(crate) struct PgStatementMetadata {
     pub(crate) columns: Vec<PgColumn>,
-    pub(crate) column_names: HashMap<UStr, usize>,
+    // built once per description and shared with every row, cached or not
+    pub(crate) column_names: Arc<HashMap<UStr, usize>>,
     pub(crate) parameters: Vec<PgTypeInfo>,
 }
 
@@ -69,7 +70,7 @@ impl<'q> From<PgStatement<'q>> for crate::any::AnyStatement<'q> {
                 .iter()
                 .map(|col| col.clone().into())
                 .collect(),
-            column_names: std::sync::Arc::new(statement.metadata.column_names.clone()),
+            column_names: Arc::clone(&statement.metadata.column_names),
             parameters: Some(Either::Left(
                 statement
                     .metadata
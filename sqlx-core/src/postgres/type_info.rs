@@ -209,6 +209,10 @@ impl PgTypeInfo {
     ///
     /// The OID for the type will be fetched from Postgres on use of
     /// a value of this type. The fetched OID will be cached per-connection.
+    ///
+    /// The name is resolved against the `search_path` of the session, like a type name in SQL.
+    /// A type in a schema that is not on the search path can be named with its schema, e.g.
+    /// `inventory.status`.
     pub const fn with_name(name: &'static str) -> Self {
         Self(PgType::DeclareWithName(UStr::Static(name)))
     }
@@ -1006,11 +1010,34 @@ impl PartialEq<PgType> for PgType {
             true
         } else {
             // Otherwise, perform a match on the name
-            self.name().eq_ignore_ascii_case(other.name())
+            names_match(self.name(), other.name())
         }
     }
 }
 
+// a type declared with a schema-qualified name, such as `app.status`, matches the unqualified
+// name that is reported for a user-defined type; when both names are qualified, the schemas
+// must match as well
+fn names_match(a: &str, b: &str) -> bool {
+    fn split(name: &str) -> (Option<&str>, &str) {
+        match name.rfind('.') {
+            Some(pos) => (Some(&name[..pos]), &name[pos + 1..]),
+            None => (None, name),
+        }
+    }
+
+    let (a_schema, a_name) = split(a);
+    let (b_schema, b_name) = split(b);
+
+    if let (Some(a_schema), Some(b_schema)) = (a_schema, b_schema) {
+        if !a_schema.eq_ignore_ascii_case(b_schema) {
+            return false;
+        }
+    }
+
+    a_name.eq_ignore_ascii_case(b_name)
+}
+
 #[cfg(feature = "any")]
 impl From<PgTypeInfo> for crate::any::AnyTypeInfo {
     #[inline]
@@ -1018,3 +1045,14 @@ impl From<PgTypeInfo> for crate::any::AnyTypeInfo {
         crate::any::AnyTypeInfo(crate::any::type_info::AnyTypeInfoKind::Postgres(ty))
     }
 }
+
+#[test]
+fn test_names_match() {
+    assert!(names_match("status", "STATUS"));
+    assert!(names_match("app.status", "status"));
+    assert!(names_match("status", "app.status"));
+    assert!(names_match("app.status", "APP.status"));
+
+    assert!(!names_match("a.status", "b.status"));
+    assert!(!names_match("app.status", "state"));
+}
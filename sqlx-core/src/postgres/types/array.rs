@@ -7,6 +7,55 @@ use crate::postgres::type_info::PgType;
 use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
 use crate::types::Type;
 
+/// Provides the type of an array of `Self`, so `Vec<Self>` and `&[Self]` can be used as Postgres
+/// arrays.
+///
+/// The array types of the built-in types are provided by SQLx directly. This trait is for types
+/// defined outside of SQLx, which cannot implement [`Type`] for `Vec<Self>` themselves; it is
+/// implemented by `#[derive(sqlx::Type)]` for enumerations and composite types.
+///
+/// ```rust,ignore
+/// impl PgHasArrayType for Mood {
+///     fn array_type_info() -> PgTypeInfo {
+///         // the array type of a user-defined type is named after it with a leading `_`
+///         PgTypeInfo::with_name("_mood")
+///     }
+/// }
+/// ```
+pub trait PgHasArrayType {
+    fn array_type_info() -> PgTypeInfo;
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        *ty == Self::array_type_info()
+    }
+}
+
+impl<T> Type<Postgres> for [T]
+where
+    T: PgHasArrayType,
+{
+    fn type_info() -> PgTypeInfo {
+        T::array_type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        T::array_compatible(ty)
+    }
+}
+
+impl<T> Type<Postgres> for Vec<T>
+where
+    T: PgHasArrayType,
+{
+    fn type_info() -> PgTypeInfo {
+        T::array_type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        T::array_compatible(ty)
+    }
+}
+
 impl<T> Type<Postgres> for [Option<T>]
 where
     [T]: Type<Postgres>,
@@ -82,6 +131,7 @@ where
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
         let element_type_info;
         let format = value.format();
+        let timestamp_zone = value.timestamp_zone;
 
         match format {
             PgValueFormat::Binary => {
@@ -89,6 +139,15 @@ where
 
                 let mut buf = value.as_bytes()?;
 
+                // the header is at least the number of dimensions, the flags and the element OID
+                if buf.len() < 12 {
+                    return Err(format!(
+                        "expected an array header of at least 12 bytes but received {} bytes",
+                        buf.len()
+                    )
+                    .into());
+                }
+
                 // number of dimensions in the array
                 let ndim = buf.get_i32();
 
@@ -111,9 +170,22 @@ where
                 element_type_info = PgTypeInfo::try_from_oid(element_type_oid)
                     .unwrap_or_else(|| PgTypeInfo(PgType::DeclareWithOid(element_type_oid)));
 
+                // followed by the length and the lower bound of the one dimension
+                if buf.len() < 8 {
+                    return Err(format!(
+                        "expected an array dimension of 8 bytes but received {} bytes",
+                        buf.len()
+                    )
+                    .into());
+                }
+
                 // length of the array axis
                 let len = buf.get_i32();
 
+                if len < 0 {
+                    return Err(format!("encountered an array of negative length {}", len).into());
+                }
+
                 // the lower bound, we only support arrays starting from "1"
                 let lower = buf.get_i32();
 
@@ -121,14 +193,17 @@ where
                     return Err(format!("encountered an array with a lower bound of {} in the first dimension; only arrays starting at one are supported", lower).into());
                 }
 
-                let mut elements = Vec::with_capacity(len as usize);
+                // every element takes at least its 4 byte length prefix, which bounds how much
+                // to allocate up front for a corrupt length
+                let mut elements = Vec::with_capacity((len as usize).min(buf.len() / 4));
 
                 for _ in 0..len {
                     elements.push(T::decode(PgValueRef::get(
                         &mut buf,
                         format,
                         element_type_info.clone(),
-                    ))?)
+                        timestamp_zone,
+                    )?)?)
                 }
 
                 Ok(elements)
@@ -208,6 +283,7 @@ where
                         row: None,
                         type_info: element_type_info.clone(),
                         format,
+                        timestamp_zone,
                     })?);
 
                     value.clear();
@@ -218,3 +294,41 @@ where
         }
     }
 }
+
+#[test]
+fn it_rejects_truncated_arrays() {
+    use crate::postgres::PgTimestampZone;
+
+    fn decode(buf: &[u8]) -> Result<Vec<i32>, BoxDynError> {
+        <Vec<i32> as Decode<Postgres>>::decode(PgValueRef {
+            value: Some(buf),
+            row: None,
+            type_info: PgTypeInfo::INT4_ARRAY,
+            format: PgValueFormat::Binary,
+            timestamp_zone: PgTimestampZone::Utc,
+        })
+    }
+
+    // one dimension, no flags, INT4 elements
+    let header = [0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 23];
+
+    // truncated header
+    assert!(decode(&header[..6]).is_err());
+
+    // missing the dimension
+    assert!(decode(&header).is_err());
+
+    // two elements announced but only one sent
+    let mut buf = header.to_vec();
+    buf.extend(&[0, 0, 0, 2, 0, 0, 0, 1]);
+    buf.extend(&[0, 0, 0, 4, 0, 0, 0, 7]);
+    assert!(decode(&buf).is_err());
+
+    // an element shorter than its length prefix
+    let mut truncated = buf.clone();
+    truncated.extend(&[0, 0, 0, 4, 0, 0]);
+    assert!(decode(&truncated).is_err());
+
+    buf.extend(&[0, 0, 0, 4, 0, 0, 0, 8]);
+    assert_eq!(decode(&buf).unwrap(), vec![7, 8]);
+}
@@ -33,6 +33,8 @@ impl TryFrom<PgNumeric> for BigDecimal {
     type Error = BoxDynError;
 
     fn try_from(numeric: PgNumeric) -> Result<Self, BoxDynError> {
+        numeric.check_finite("BigDecimal")?;
+
         let (digits, sign, weight) = match numeric {
             PgNumeric::Number {
                 digits,
@@ -41,9 +43,7 @@ impl TryFrom<PgNumeric> for BigDecimal {
                 ..
             } => (digits, sign, weight),
 
-            PgNumeric::NotANumber => {
-                return Err("BigDecimal does not support NaN values".into());
-            }
+            _ => unreachable!("`check_finite` rejects NaN and the infinities"),
         };
 
         if digits.is_empty() {
@@ -164,7 +164,12 @@ impl Decode<'_, Postgres> for BigDecimal {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         match value.format() {
             PgValueFormat::Binary => PgNumeric::decode(value.as_bytes()?)?.try_into(),
-            PgValueFormat::Text => Ok(value.as_str()?.parse::<BigDecimal>()?),
+            PgValueFormat::Text => {
+                let s = value.as_str()?;
+                PgNumeric::check_finite_text(s, "BigDecimal")?;
+
+                Ok(s.parse::<BigDecimal>()?)
+            }
         }
     }
 }
@@ -57,6 +57,14 @@ impl Decode<'_, Postgres> for BitVec {
         match value.format() {
             PgValueFormat::Binary => {
                 let mut bytes = value.as_bytes()?;
+
+                if bytes.len() < 4 {
+                    Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "Missing VARBIT length.",
+                    ))?
+                }
+
                 let len = bytes.get_i32();
 
                 if len < 0 {
@@ -109,3 +117,23 @@ impl Decode<'_, Postgres> for BitVec {
         }
     }
 }
+
+#[test]
+fn it_rejects_truncated_varbit() {
+    use crate::postgres::PgTimestampZone;
+
+    fn decode(buf: &[u8]) -> Result<BitVec, BoxDynError> {
+        BitVec::decode(PgValueRef {
+            value: Some(buf),
+            row: None,
+            type_info: PgTypeInfo::VARBIT,
+            format: PgValueFormat::Binary,
+            timestamp_zone: PgTimestampZone::Utc,
+        })
+    }
+
+    assert!(decode(&[0, 0]).is_err());
+    assert!(decode(&[0, 0, 0, 9, 0xff]).is_err());
+
+    assert_eq!(decode(&[0, 0, 0, 2, 0b1000_0000]).unwrap().len(), 2);
+}
@@ -1,4 +1,4 @@
-use crate::decode::Decode;
+use crate::decode::{check_width, Decode};
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
@@ -33,7 +33,7 @@ impl Encode<'_, Postgres> for bool {
 impl Decode<'_, Postgres> for bool {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         Ok(match value.format() {
-            PgValueFormat::Binary => value.as_bytes()?[0] != 0,
+            PgValueFormat::Binary => check_width(value.as_bytes()?, 1)?[0] != 0,
 
             PgValueFormat::Text => match value.as_str()? {
                 "t" => true,
@@ -46,12 +46,20 @@ impl Encode<'_, Postgres> for &'_ [u8] {
 
         IsNull::No
     }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
 }
 
 impl Encode<'_, Postgres> for Vec<u8> {
     fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
         <&[u8] as Encode<Postgres>>::encode(self, buf)
     }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
 }
 
 impl<'r> Decode<'r, Postgres> for &'r [u8] {
@@ -1,7 +1,9 @@
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
-use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::postgres::{
+    PgArgumentBuffer, PgTimestampZone, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
 use crate::types::Type;
 use chrono::{
     DateTime, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc,
@@ -18,6 +20,10 @@ impl<Tz: TimeZone> Type<Postgres> for DateTime<Tz> {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::TIMESTAMPTZ
     }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::TIMESTAMPTZ || *ty == PgTypeInfo::TIMESTAMP
+    }
 }
 
 impl Type<Postgres> for [NaiveDateTime] {
@@ -30,6 +36,10 @@ impl<Tz: TimeZone> Type<Postgres> for [DateTime<Tz>] {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::TIMESTAMPTZ_ARRAY
     }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::TIMESTAMPTZ_ARRAY || *ty == PgTypeInfo::TIMESTAMP_ARRAY
+    }
 }
 
 impl Type<Postgres> for Vec<NaiveDateTime> {
@@ -42,6 +52,10 @@ impl<Tz: TimeZone> Type<Postgres> for Vec<DateTime<Tz>> {
     fn type_info() -> PgTypeInfo {
         <[DateTime<Tz>] as Type<Postgres>>::type_info()
     }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[DateTime<Tz>] as Type<Postgres>>::compatible(ty)
+    }
 }
 
 impl Encode<'_, Postgres> for NaiveDateTime {
@@ -101,21 +115,45 @@ impl<Tz: TimeZone> Encode<'_, Postgres> for DateTime<Tz> {
 
 impl<'r> Decode<'r, Postgres> for DateTime<Local> {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
-        let naive = <NaiveDateTime as Decode<Postgres>>::decode(value)?;
+        let naive = decode_as_utc(value)?;
         Ok(Local.from_utc_datetime(&naive))
     }
 }
 
 impl<'r> Decode<'r, Postgres> for DateTime<Utc> {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
-        let naive = <NaiveDateTime as Decode<Postgres>>::decode(value)?;
+        let naive = decode_as_utc(value)?;
         Ok(Utc.from_utc_datetime(&naive))
     }
 }
 
 impl<'r> Decode<'r, Postgres> for DateTime<FixedOffset> {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
-        let naive = <NaiveDateTime as Decode<Postgres>>::decode(value)?;
+        let naive = decode_as_utc(value)?;
         Ok(Utc.fix().from_utc_datetime(&naive))
     }
 }
+
+// TIMESTAMPTZ is always sent in UTC; TIMESTAMP is a wall-clock time in the zone
+// configured for the connection
+fn decode_as_utc(value: PgValueRef<'_>) -> Result<NaiveDateTime, BoxDynError> {
+    let is_timestamp = value.type_info == PgTypeInfo::TIMESTAMP;
+    let zone = value.timestamp_zone;
+    let naive = <NaiveDateTime as Decode<Postgres>>::decode(value)?;
+
+    if !is_timestamp {
+        return Ok(naive);
+    }
+
+    Ok(match zone {
+        PgTimestampZone::Utc => naive,
+
+        PgTimestampZone::Local => Local
+            .from_local_datetime(&naive)
+            .earliest()
+            .ok_or_else(|| format!("{} does not exist in the local time zone", naive))?
+            .naive_utc(),
+
+        PgTimestampZone::FixedOffset(offset) => naive - Duration::seconds(offset.into()),
+    })
+}
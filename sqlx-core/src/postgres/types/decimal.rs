@@ -34,6 +34,8 @@ impl TryFrom<PgNumeric> for Decimal {
     type Error = BoxDynError;
 
     fn try_from(numeric: PgNumeric) -> Result<Self, BoxDynError> {
+        numeric.check_finite("Decimal")?;
+
         let (digits, sign, weight) = match numeric {
             PgNumeric::Number {
                 digits,
@@ -42,9 +44,7 @@ impl TryFrom<PgNumeric> for Decimal {
                 ..
             } => (digits, sign, weight),
 
-            PgNumeric::NotANumber => {
-                return Err("Decimal does not support NaN values".into());
-            }
+            _ => unreachable!("`check_finite` rejects NaN and the infinities"),
         };
 
         if digits.is_empty() {
@@ -170,7 +170,12 @@ impl Decode<'_, Postgres> for Decimal {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         match value.format() {
             PgValueFormat::Binary => PgNumeric::decode(value.as_bytes()?)?.try_into(),
-            PgValueFormat::Text => Ok(value.as_str()?.parse::<Decimal>()?),
+            PgValueFormat::Text => {
+                let s = value.as_str()?;
+                PgNumeric::check_finite_text(s, "Decimal")?;
+
+                Ok(s.parse::<Decimal>()?)
+            }
         }
     }
 }
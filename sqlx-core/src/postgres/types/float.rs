@@ -1,10 +1,11 @@
 use byteorder::{BigEndian, ByteOrder};
 
-use crate::decode::Decode;
+use crate::decode::{check_width, Decode};
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
 use crate::types::Type;
+use std::str::FromStr;
 
 impl Type<Postgres> for f32 {
     fn type_info() -> PgTypeInfo {
@@ -35,8 +36,8 @@ impl Encode<'_, Postgres> for f32 {
 impl Decode<'_, Postgres> for f32 {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         Ok(match value.format() {
-            PgValueFormat::Binary => BigEndian::read_f32(value.as_bytes()?),
-            PgValueFormat::Text => value.as_str()?.parse()?,
+            PgValueFormat::Binary => BigEndian::read_f32(check_width(value.as_bytes()?, 4)?),
+            PgValueFormat::Text => parse_text(value.as_str()?)?,
         })
     }
 }
@@ -45,6 +46,11 @@ impl Type<Postgres> for f64 {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::FLOAT8
     }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        // a `FLOAT4` is widened losslessly on decode
+        *ty == PgTypeInfo::FLOAT4 || *ty == PgTypeInfo::FLOAT8
+    }
 }
 
 impl Type<Postgres> for [f64] {
@@ -70,8 +76,37 @@ impl Encode<'_, Postgres> for f64 {
 impl Decode<'_, Postgres> for f64 {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         Ok(match value.format() {
-            PgValueFormat::Binary => BigEndian::read_f64(value.as_bytes()?),
-            PgValueFormat::Text => value.as_str()?.parse()?,
+            PgValueFormat::Binary => {
+                let buf = value.as_bytes()?;
+
+                match buf.len() {
+                    4 => BigEndian::read_f32(buf).into(),
+                    _ => BigEndian::read_f64(check_width(buf, 8)?),
+                }
+            }
+            PgValueFormat::Text => parse_text(value.as_str()?)?,
         })
     }
 }
+
+// Postgres writes the special values as `NaN`, `Infinity` and `-Infinity`
+fn parse_text<T>(s: &str) -> Result<T, BoxDynError>
+where
+    T: FromStr + From<f32>,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    Ok(match s {
+        "NaN" => f32::NAN.into(),
+        "Infinity" => f32::INFINITY.into(),
+        "-Infinity" => f32::NEG_INFINITY.into(),
+        _ => s.parse()?,
+    })
+}
+
+#[test]
+fn it_parses_special_values_from_text() {
+    assert!(parse_text::<f64>("NaN").unwrap().is_nan());
+    assert_eq!(parse_text::<f64>("Infinity").unwrap(), f64::INFINITY);
+    assert_eq!(parse_text::<f32>("-Infinity").unwrap(), f32::NEG_INFINITY);
+    assert_eq!(parse_text::<f32>("-1.5").unwrap(), -1.5);
+}
@@ -1,6 +1,6 @@
 use byteorder::{BigEndian, ByteOrder};
 
-use crate::decode::Decode;
+use crate::decode::{check_width, Decode};
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
@@ -68,7 +68,7 @@ impl Encode<'_, Postgres> for i16 {
 impl Decode<'_, Postgres> for i16 {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         Ok(match value.format() {
-            PgValueFormat::Binary => BigEndian::read_i16(value.as_bytes()?),
+            PgValueFormat::Binary => BigEndian::read_i16(check_width(value.as_bytes()?, 2)?),
             PgValueFormat::Text => value.as_str()?.parse()?,
         })
     }
@@ -103,7 +103,7 @@ impl Encode<'_, Postgres> for u32 {
 impl Decode<'_, Postgres> for u32 {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         Ok(match value.format() {
-            PgValueFormat::Binary => BigEndian::read_u32(value.as_bytes()?),
+            PgValueFormat::Binary => BigEndian::read_u32(check_width(value.as_bytes()?, 4)?),
             PgValueFormat::Text => value.as_str()?.parse()?,
         })
     }
@@ -113,6 +113,11 @@ impl Type<Postgres> for i32 {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::INT4
     }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        // an `INT2` is widened losslessly on decode
+        *ty == PgTypeInfo::INT2 || *ty == PgTypeInfo::INT4
+    }
 }
 
 impl Type<Postgres> for [i32] {
@@ -138,7 +143,14 @@ impl Encode<'_, Postgres> for i32 {
 impl Decode<'_, Postgres> for i32 {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         Ok(match value.format() {
-            PgValueFormat::Binary => BigEndian::read_i32(value.as_bytes()?),
+            PgValueFormat::Binary => {
+                let buf = value.as_bytes()?;
+
+                match buf.len() {
+                    2 => BigEndian::read_i16(buf).into(),
+                    _ => BigEndian::read_i32(check_width(buf, 4)?),
+                }
+            }
             PgValueFormat::Text => value.as_str()?.parse()?,
         })
     }
@@ -148,6 +160,11 @@ impl Type<Postgres> for i64 {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::INT8
     }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        // an `INT2` or `INT4` is widened losslessly on decode
+        *ty == PgTypeInfo::INT2 || *ty == PgTypeInfo::INT4 || *ty == PgTypeInfo::INT8
+    }
 }
 
 impl Type<Postgres> for [i64] {
@@ -173,7 +190,15 @@ impl Encode<'_, Postgres> for i64 {
 impl Decode<'_, Postgres> for i64 {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         Ok(match value.format() {
-            PgValueFormat::Binary => BigEndian::read_i64(value.as_bytes()?),
+            PgValueFormat::Binary => {
+                let buf = value.as_bytes()?;
+
+                match buf.len() {
+                    2 => BigEndian::read_i16(buf).into(),
+                    4 => BigEndian::read_i32(buf).into(),
+                    _ => BigEndian::read_i64(check_width(buf, 8)?),
+                }
+            }
             PgValueFormat::Text => value.as_str()?.parse()?,
         })
     }
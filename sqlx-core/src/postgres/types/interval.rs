@@ -115,6 +115,43 @@ impl TryFrom<std::time::Duration> for PgInterval {
     }
 }
 
+impl Decode<'_, Postgres> for std::time::Duration {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        PgInterval::decode(value)?.try_into()
+    }
+}
+
+impl TryFrom<PgInterval> for std::time::Duration {
+    type Error = BoxDynError;
+
+    /// Convert a `PgInterval` to a `std::time::Duration`, counting each day as 24 hours.
+    ///
+    /// This returns an error if the interval has a month component, as months have no fixed
+    /// length, or if it is negative.
+    fn try_from(value: PgInterval) -> Result<Self, BoxDynError> {
+        if value.months != 0 {
+            return Err(
+                "PostgreSQL `INTERVAL` with months cannot be converted to `std::time::Duration`"
+                    .into(),
+            );
+        }
+
+        let microseconds = i64::from(value.days)
+            .checked_mul(86_400_000_000)
+            .and_then(|days| days.checked_add(value.microseconds))
+            .ok_or("PostgreSQL `INTERVAL` overflows `std::time::Duration`")?;
+
+        if microseconds < 0 {
+            return Err(
+                "negative PostgreSQL `INTERVAL` cannot be converted to `std::time::Duration`"
+                    .into(),
+            );
+        }
+
+        Ok(std::time::Duration::from_micros(microseconds as u64))
+    }
+}
+
 #[cfg(feature = "chrono")]
 impl Type<Postgres> for chrono::Duration {
     fn type_info() -> PgTypeInfo {
@@ -201,6 +238,38 @@ impl TryFrom<time::Duration> for PgInterval {
     }
 }
 
+#[test]
+fn test_interval_to_std_duration() {
+    use std::time::Duration;
+
+    let interval = PgInterval {
+        months: 0,
+        days: 1,
+        microseconds: 1_500_000,
+    };
+
+    assert_eq!(
+        Duration::try_from(interval).unwrap(),
+        Duration::from_micros(86_401_500_000)
+    );
+
+    let interval = PgInterval {
+        months: 1,
+        days: 0,
+        microseconds: 0,
+    };
+
+    assert!(Duration::try_from(interval).is_err());
+
+    let interval = PgInterval {
+        months: 0,
+        days: 0,
+        microseconds: -1,
+    };
+
+    assert!(Duration::try_from(interval).is_err());
+}
+
 #[test]
 fn test_encode_interval() {
     let mut buf = PgArgumentBuffer::default();
@@ -0,0 +1,228 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+// https://github.com/rust-lang/rust/search?q=AF_INET&unscoped_q=AF_INET
+
+#[cfg(windows)]
+const AF_INET: u8 = 2;
+
+#[cfg(not(any(unix, windows)))]
+const AF_INET: u8 = 0;
+
+#[cfg(unix)]
+const AF_INET: u8 = libc::AF_INET as u8;
+
+// https://github.com/postgres/postgres/blob/574925bfd0a8175f6e161936ea11d9695677ba09/src/include/utils/inet.h#L39
+
+pub(crate) const PGSQL_AF_INET: u8 = AF_INET;
+pub(crate) const PGSQL_AF_INET6: u8 = AF_INET + 1;
+
+impl Type<Postgres> for IpAddr {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INET
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::CIDR || *ty == PgTypeInfo::INET
+    }
+}
+
+impl Type<Postgres> for [IpAddr] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INET_ARRAY
+    }
+}
+
+impl Type<Postgres> for Vec<IpAddr> {
+    fn type_info() -> PgTypeInfo {
+        <[IpAddr] as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[IpAddr] as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for IpAddr {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        // a bare address is an INET with a full network mask
+        // https://github.com/postgres/postgres/blob/574925bfd0a8175f6e161936ea11d9695677ba09/src/backend/utils/adt/network.c#L293
+
+        match self {
+            IpAddr::V4(addr) => {
+                buf.push(PGSQL_AF_INET); // ip_family
+                buf.push(32); // ip_bits
+                buf.push(0); // is_cidr
+                buf.push(4); // nb (number of bytes)
+                buf.extend_from_slice(&addr.octets()) // address
+            }
+
+            IpAddr::V6(addr) => {
+                buf.push(PGSQL_AF_INET6); // ip_family
+                buf.push(128); // ip_bits
+                buf.push(0); // is_cidr
+                buf.push(16); // nb (number of bytes)
+                buf.extend_from_slice(&addr.octets()); // address
+            }
+        }
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        match self {
+            IpAddr::V4(_) => 8,
+            IpAddr::V6(_) => 20,
+        }
+    }
+}
+
+impl Decode<'_, Postgres> for IpAddr {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes = match value.format() {
+            PgValueFormat::Binary => value.as_bytes()?,
+            PgValueFormat::Text => return decode_text(value.as_str()?),
+        };
+
+        if bytes.len() < 8 {
+            return Err("invalid data received when expecting an INET".into());
+        }
+
+        let family = bytes[0];
+        let prefix = bytes[1];
+        let len = bytes[3];
+
+        let (addr, bits) = match family {
+            PGSQL_AF_INET if bytes.len() == 8 && len == 4 => (
+                IpAddr::V4(Ipv4Addr::new(bytes[4], bytes[5], bytes[6], bytes[7])),
+                32,
+            ),
+
+            PGSQL_AF_INET6 if bytes.len() == 20 && len == 16 => {
+                let mut octets = [0; 16];
+                octets.copy_from_slice(&bytes[4..]);
+
+                (IpAddr::V6(Ipv6Addr::from(octets)), 128)
+            }
+
+            PGSQL_AF_INET | PGSQL_AF_INET6 => {
+                return Err("invalid data received when expecting an INET".into());
+            }
+
+            _ => {
+                return Err(format!("unknown ip family {}", family).into());
+            }
+        };
+
+        check_prefix(addr, prefix, bits)
+    }
+}
+
+fn decode_text(s: &str) -> Result<IpAddr, BoxDynError> {
+    match s.find('/') {
+        Some(index) => {
+            let addr: IpAddr = s[..index].parse()?;
+            let prefix: u8 = s[index + 1..].parse()?;
+            let bits = if addr.is_ipv4() { 32 } else { 128 };
+
+            check_prefix(addr, prefix, bits)
+        }
+
+        None => Ok(s.parse()?),
+    }
+}
+
+fn check_prefix(addr: IpAddr, prefix: u8, bits: u8) -> Result<IpAddr, BoxDynError> {
+    if prefix == bits {
+        Ok(addr)
+    } else {
+        Err(format!(
+            "INET value {}/{} has a network mask and cannot be decoded as an address; \
+             decode it as `ipnetwork::IpNetwork` instead",
+            addr, prefix
+        )
+        .into())
+    }
+}
+
+impl Type<Postgres> for Ipv4Addr {
+    fn type_info() -> PgTypeInfo {
+        <IpAddr as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <IpAddr as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for Ipv4Addr {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        <IpAddr as Encode<Postgres>>::encode(IpAddr::V4(*self), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+impl Decode<'_, Postgres> for Ipv4Addr {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match <IpAddr as Decode<Postgres>>::decode(value)? {
+            IpAddr::V4(addr) => Ok(addr),
+            IpAddr::V6(addr) => {
+                Err(format!("expected an IPv4 address but received {}", addr).into())
+            }
+        }
+    }
+}
+
+impl Type<Postgres> for Ipv6Addr {
+    fn type_info() -> PgTypeInfo {
+        <IpAddr as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <IpAddr as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for Ipv6Addr {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        <IpAddr as Encode<Postgres>>::encode(IpAddr::V6(*self), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        20
+    }
+}
+
+impl Decode<'_, Postgres> for Ipv6Addr {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match <IpAddr as Decode<Postgres>>::decode(value)? {
+            IpAddr::V6(addr) => Ok(addr),
+            IpAddr::V4(addr) => {
+                Err(format!("expected an IPv6 address but received {}", addr).into())
+            }
+        }
+    }
+}
+
+#[test]
+fn it_decodes_text_addresses() {
+    assert_eq!(
+        decode_text("192.168.0.1").unwrap(),
+        IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1))
+    );
+
+    assert_eq!(
+        decode_text("::1/128").unwrap(),
+        IpAddr::V6(Ipv6Addr::LOCALHOST)
+    );
+
+    assert!(decode_text("192.168.0.0/24").is_err());
+}
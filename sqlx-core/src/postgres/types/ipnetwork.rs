@@ -5,25 +5,10 @@ use ipnetwork::{IpNetwork, Ipv4Network, Ipv6Network};
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
+use crate::postgres::types::ip_addr::{PGSQL_AF_INET, PGSQL_AF_INET6};
 use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
 use crate::types::Type;
 
-// https://github.com/rust-lang/rust/search?q=AF_INET&unscoped_q=AF_INET
-
-#[cfg(windows)]
-const AF_INET: u8 = 2;
-
-#[cfg(not(any(unix, windows)))]
-const AF_INET: u8 = 0;
-
-#[cfg(unix)]
-const AF_INET: u8 = libc::AF_INET as u8;
-
-// https://github.com/postgres/postgres/blob/574925bfd0a8175f6e161936ea11d9695677ba09/src/include/utils/inet.h#L39
-
-const PGSQL_AF_INET: u8 = AF_INET;
-const PGSQL_AF_INET6: u8 = AF_INET + 1;
-
 impl Type<Postgres> for IpNetwork {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::INET
@@ -74,13 +74,19 @@ where
         let mut buf = value.as_bytes()?;
 
         if value.format() == PgValueFormat::Binary && value.type_info == PgTypeInfo::JSONB {
-            assert_eq!(
-                buf[0], 1,
-                "unsupported JSONB format version {}; please open an issue",
-                buf[0]
-            );
-
-            buf = &buf[1..];
+            match buf.split_first() {
+                Some((1, rest)) => buf = rest,
+
+                Some((version, _)) => {
+                    return Err(format!(
+                        "unsupported JSONB format version {}; please open an issue",
+                        version
+                    )
+                    .into());
+                }
+
+                None => return Err("expected a JSONB format version but received 0 bytes".into()),
+            }
         }
 
         serde_json::from_slice(buf).map(Json).map_err(Into::into)
@@ -14,8 +14,14 @@
 //! | `&str`, `String`                      | VARCHAR, CHAR(N), TEXT, NAME                         |
 //! | `&[u8]`, `Vec<u8>`                    | BYTEA                                                |
 //! | [`PgInterval`]                        | INTERVAL                                             |
+//! | `std::time::Duration`                 | INTERVAL                                             |
 //! | [`PgRange<T>`]                        | INT8RANGE, INT4RANGE, TSRANGE, TSTZTRANGE, DATERANGE, NUMRANGE |
 //! | [`PgMoney`]                           | MONEY                                                |
+//! | `std::net::IpAddr`                    | INET, CIDR                                           |
+//! | `std::net::Ipv4Addr`, `Ipv6Addr`      | INET, CIDR                                           |
+//!
+//! Decoding an address fails if the value has a network mask, such as `10.0.0.0/8`; use
+//! `ipnetwork::IpNetwork` for those.
 //!
 //! [`PgInterval`]: struct.PgInterval.html
 //! [`PgRange<T>`]: struct.PgRange.html
@@ -48,6 +54,11 @@
 //! | `chrono::NaiveTime`                   | TIME                                                 |
 //! | [`PgTimeTz`]                          | TIMETZ                                               |
 //!
+//! `DateTime<Tz>` may also be decoded from a TIMESTAMP, which is assumed to be in the zone set
+//! with [`PgConnectOptions::timestamp_zone`] (UTC by default).
+//!
+//! [`PgConnectOptions::timestamp_zone`]: crate::postgres::PgConnectOptions::timestamp_zone
+//!
 //! ### [`time`](https://crates.io/crates/time)
 //!
 //! Requires the `time` Cargo feature flag.
@@ -60,6 +71,8 @@
 //! | `time::Time`                          | TIME                                                 |
 //! | [`PgTimeTz`]                          | TIMETZ                                               |
 //!
+//! `OffsetDateTime` may also be decoded from a TIMESTAMP, in the same way as `DateTime<Tz>`.
+//!
 //! [`PgTimeTz`]: struct.PgTimeTz.html
 //!
 //! ### [`uuid`](https://crates.io/crates/uuid)
@@ -132,6 +145,9 @@
 //!
 //! One-dimensional arrays are supported as `Vec<T>` or `&[T]` where `T` implements `Type`.
 //!
+//! This includes arrays of user-defined enumerations and composite types that derive `Type`,
+//! such as a `mood[]` column decoded as `Vec<Mood>`; see [`PgHasArrayType`].
+//!
 //! # [Enumerations](https://www.postgresql.org/docs/current/datatype-enum.html)
 //!
 //! User-defined enumerations are supported through a derive for `Type`.
@@ -167,6 +183,7 @@ mod bytes;
 mod float;
 mod int;
 mod interval;
+mod ip_addr;
 mod money;
 mod range;
 mod record;
@@ -204,6 +221,7 @@ mod ipnetwork;
 #[cfg(feature = "bit-vec")]
 mod bit_vec;
 
+pub use array::PgHasArrayType;
 pub use interval::PgInterval;
 pub use money::PgMoney;
 pub use range::PgRange;
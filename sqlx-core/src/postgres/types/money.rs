@@ -1,5 +1,5 @@
 use crate::{
-    decode::Decode,
+    decode::{check_width, Decode},
     encode::{Encode, IsNull},
     error::BoxDynError,
     postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres},
@@ -128,7 +128,7 @@ impl Decode<'_, Postgres> for PgMoney {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         match value.format() {
             PgValueFormat::Binary => {
-                let cents = BigEndian::read_i64(value.as_bytes()?);
+                let cents = BigEndian::read_i64(check_width(value.as_bytes()?, 8)?);
 
                 Ok(PgMoney(cents))
             }
@@ -11,6 +11,12 @@ pub(crate) enum PgNumeric {
     /// Equivalent to the `'NaN'` value in Postgres. The result of, e.g. `1 / 0`.
     NotANumber,
 
+    /// Equivalent to the `'Infinity'` value in Postgres 14 and later.
+    PositiveInfinity,
+
+    /// Equivalent to the `'-Infinity'` value in Postgres 14 and later.
+    NegativeInfinity,
+
     /// A populated `NUMERIC` value.
     ///
     /// A description of these fields can be found here (although the type being described is the
@@ -54,6 +60,8 @@ pub(crate) enum PgNumeric {
 const SIGN_POS: u16 = 0x0000;
 const SIGN_NEG: u16 = 0x4000;
 const SIGN_NAN: u16 = 0xC000; // overflows i16 (C equivalent truncates from integer literal)
+const SIGN_PINF: u16 = 0xD000;
+const SIGN_NINF: u16 = 0xF000;
 
 /// Possible sign values for [PgNumeric].
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -69,7 +77,9 @@ impl PgNumericSign {
             SIGN_POS => Ok(PgNumericSign::Positive),
             SIGN_NEG => Ok(PgNumericSign::Negative),
 
-            SIGN_NAN => unreachable!("sign value for NaN passed to PgNumericSign"),
+            SIGN_NAN | SIGN_PINF | SIGN_NINF => {
+                unreachable!("sign value for NaN or infinity passed to PgNumericSign")
+            }
 
             _ => Err(format!("invalid value for PgNumericSign: {:#04X}", val).into()),
         }
@@ -79,6 +89,14 @@ impl PgNumericSign {
 impl PgNumeric {
     pub(crate) fn decode(mut buf: &[u8]) -> Result<Self, BoxDynError> {
         // https://github.com/postgres/postgres/blob/bcd1c3630095e48bc3b1eb0fc8e8c8a7c851eba1/src/backend/utils/adt/numeric.c#L874
+        if buf.len() < 8 {
+            return Err(format!(
+                "expected at least 8 bytes for NUMERIC but received {} bytes",
+                buf.len()
+            )
+            .into());
+        }
+
         let num_digits = buf.get_u16();
         let weight = buf.get_i16();
         let sign = buf.get_u16();
@@ -86,6 +104,17 @@ impl PgNumeric {
 
         if sign == SIGN_NAN {
             Ok(PgNumeric::NotANumber)
+        } else if sign == SIGN_PINF {
+            Ok(PgNumeric::PositiveInfinity)
+        } else if sign == SIGN_NINF {
+            Ok(PgNumeric::NegativeInfinity)
+        } else if buf.len() != usize::from(num_digits) * 2 {
+            Err(format!(
+                "expected {} digits for NUMERIC but received {} bytes",
+                num_digits,
+                buf.len()
+            )
+            .into())
         } else {
             let digits: Vec<_> = (0..num_digits).map(|_| buf.get_i16()).collect::<_>();
 
@@ -127,12 +156,82 @@ impl PgNumeric {
                 }
             }
 
-            PgNumeric::NotANumber => {
-                buf.extend(&0_i16.to_be_bytes());
-                buf.extend(&0_i16.to_be_bytes());
-                buf.extend(&SIGN_NAN.to_be_bytes());
-                buf.extend(&0_i16.to_be_bytes());
-            }
+            PgNumeric::NotANumber => encode_special(buf, SIGN_NAN),
+            PgNumeric::PositiveInfinity => encode_special(buf, SIGN_PINF),
+            PgNumeric::NegativeInfinity => encode_special(buf, SIGN_NINF),
         }
     }
+
+    // the text form of a value that is not a finite number, as written by Postgres
+    fn special_name(&self) -> Option<&'static str> {
+        match self {
+            PgNumeric::NotANumber => Some("NaN"),
+            PgNumeric::PositiveInfinity => Some("Infinity"),
+            PgNumeric::NegativeInfinity => Some("-Infinity"),
+            PgNumeric::Number { .. } => None,
+        }
+    }
+
+    /// Returns an error for `NaN` and the infinities, which the Rust type `ty` cannot represent.
+    pub(crate) fn check_finite(&self, ty: &str) -> Result<(), BoxDynError> {
+        match self.special_name() {
+            Some(name) => Err(special_value_error(ty, name)),
+            None => Ok(()),
+        }
+    }
+
+    /// Like [`check_finite`](Self::check_finite), for a value in the text format.
+    pub(crate) fn check_finite_text(s: &str, ty: &str) -> Result<(), BoxDynError> {
+        match s {
+            "NaN" | "Infinity" | "-Infinity" => Err(special_value_error(ty, s)),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn encode_special(buf: &mut PgArgumentBuffer, sign: u16) {
+    buf.extend(&0_i16.to_be_bytes());
+    buf.extend(&0_i16.to_be_bytes());
+    buf.extend(&sign.to_be_bytes());
+    buf.extend(&0_i16.to_be_bytes());
+}
+
+fn special_value_error(ty: &str, name: &str) -> BoxDynError {
+    format!("{} cannot represent the NUMERIC value {}", ty, name).into()
+}
+
+#[test]
+fn it_rejects_truncated_numeric() {
+    // header only partially received
+    assert!(PgNumeric::decode(&[0, 1, 0, 0]).is_err());
+
+    // header announces two digits but only one follows
+    assert!(PgNumeric::decode(&[0, 2, 0, 0, 0, 0, 0, 0, 0, 1]).is_err());
+
+    assert!(PgNumeric::decode(&[0, 1, 0, 0, 0, 0, 0, 0, 0, 1]).is_ok());
+}
+
+#[test]
+fn it_decodes_special_values() {
+    assert_eq!(
+        PgNumeric::decode(&[0, 0, 0, 0, 0xC0, 0, 0, 0]).unwrap(),
+        PgNumeric::NotANumber
+    );
+
+    assert_eq!(
+        PgNumeric::decode(&[0, 0, 0, 0, 0xD0, 0, 0, 0]).unwrap(),
+        PgNumeric::PositiveInfinity
+    );
+
+    let negative_infinity = PgNumeric::decode(&[0, 0, 0, 0, 0xF0, 0, 0, 0]).unwrap();
+    assert_eq!(negative_infinity, PgNumeric::NegativeInfinity);
+
+    let err = negative_infinity.check_finite("Decimal").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Decimal cannot represent the NUMERIC value -Infinity"
+    );
+
+    assert!(PgNumeric::check_finite_text("NaN", "Decimal").is_err());
+    assert!(PgNumeric::check_finite_text("-12.5", "Decimal").is_ok());
 }
@@ -384,6 +384,10 @@ where
                 let mut start = Bound::Unbounded;
                 let mut end = Bound::Unbounded;
 
+                if buf.is_empty() {
+                    return Err("expected the flags of a range but received 0 bytes".into());
+                }
+
                 let flags = RangeFlags::from_bits_truncate(buf.get_u8());
 
                 if flags.contains(RangeFlags::EMPTY) {
@@ -391,8 +395,12 @@ where
                 }
 
                 if !flags.contains(RangeFlags::LB_INF) {
-                    let value =
-                        T::decode(PgValueRef::get(&mut buf, value.format, element_ty.clone()))?;
+                    let value = T::decode(PgValueRef::get(
+                        &mut buf,
+                        value.format,
+                        element_ty.clone(),
+                        value.timestamp_zone,
+                    )?)?;
 
                     start = if flags.contains(RangeFlags::LB_INC) {
                         Bound::Included(value)
@@ -402,8 +410,12 @@ where
                 }
 
                 if !flags.contains(RangeFlags::UB_INF) {
-                    let value =
-                        T::decode(PgValueRef::get(&mut buf, value.format, element_ty.clone()))?;
+                    let value = T::decode(PgValueRef::get(
+                        &mut buf,
+                        value.format,
+                        element_ty.clone(),
+                        value.timestamp_zone,
+                    )?)?;
 
                     end = if flags.contains(RangeFlags::UB_INC) {
                         Bound::Included(value)
@@ -493,6 +505,7 @@ where
                             format: PgValueFormat::Text,
                             value: Some(element.as_bytes()),
                             row: None,
+                            timestamp_zone: value.timestamp_zone,
                         })?);
 
                         if count == 1 {
@@ -4,7 +4,9 @@ use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::error::{mismatched_types, BoxDynError};
 use crate::postgres::type_info::{PgType, PgTypeKind};
-use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::postgres::{
+    PgArgumentBuffer, PgTimestampZone, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
 use crate::type_info::TypeInfo;
 use crate::types::Type;
 
@@ -62,6 +64,7 @@ pub struct PgRecordDecoder<'r> {
     typ: PgTypeInfo,
     fmt: PgValueFormat,
     ind: usize,
+    timestamp_zone: PgTimestampZone,
 }
 
 impl<'r> PgRecordDecoder<'r> {
@@ -70,13 +73,26 @@ impl<'r> PgRecordDecoder<'r> {
         let fmt = value.format();
         let mut buf = value.as_bytes()?;
         let typ = value.type_info;
+        let timestamp_zone = value.timestamp_zone;
 
         match fmt {
             PgValueFormat::Binary => {
+                if buf.len() < 4 {
+                    return Err(format!(
+                        "expected a field count of 4 bytes but received {} bytes",
+                        buf.len()
+                    )
+                    .into());
+                }
+
                 let _len = buf.get_u32();
             }
 
             PgValueFormat::Text => {
+                if buf.len() < 2 || buf[0] != b'(' || buf[buf.len() - 1] != b')' {
+                    return Err("expected a record enclosed in `(` .. `)`".into());
+                }
+
                 // remove the enclosing `(` .. `)`
                 buf = &buf[1..(buf.len() - 1)];
             }
@@ -87,6 +103,7 @@ impl<'r> PgRecordDecoder<'r> {
             fmt,
             typ,
             ind: 0,
+            timestamp_zone,
         })
     }
 
@@ -101,6 +118,15 @@ impl<'r> PgRecordDecoder<'r> {
 
         match self.fmt {
             PgValueFormat::Binary => {
+                if self.buf.len() < 4 {
+                    return Err(format!(
+                        "expected the type OID of field `{}` but received {} bytes",
+                        self.ind,
+                        self.buf.len()
+                    )
+                    .into());
+                }
+
                 let element_type_oid = self.buf.get_u32();
                 let element_type_opt = match self.typ.0.kind() {
                     PgTypeKind::Simple if self.typ.0 == PgType::Record => {
@@ -108,7 +134,17 @@ impl<'r> PgRecordDecoder<'r> {
                     }
 
                     PgTypeKind::Composite(fields) => {
-                        let ty = fields[self.ind].1.clone();
+                        let ty = match fields.get(self.ind) {
+                            Some((_, ty)) => ty.clone(),
+                            None => {
+                                return Err(format!(
+                                    "no field `{}` found on composite type",
+                                    self.ind
+                                )
+                                .into());
+                            }
+                        };
+
                         if ty.0.oid() != element_type_oid {
                             return Err("unexpected mismatch of composite type information".into());
                         }
@@ -135,7 +171,12 @@ impl<'r> PgRecordDecoder<'r> {
                 let element_type =
                     element_type_opt.unwrap_or_else(|| PgTypeInfo::with_oid(element_type_oid));
 
-                T::decode(PgValueRef::get(&mut self.buf, self.fmt, element_type))
+                T::decode(PgValueRef::get(
+                    &mut self.buf,
+                    self.fmt,
+                    element_type,
+                    self.timestamp_zone,
+                )?)
             }
 
             PgValueFormat::Text => {
@@ -196,8 +237,39 @@ impl<'r> PgRecordDecoder<'r> {
                     format: self.fmt,
                     value: buf,
                     row: None,
+                    timestamp_zone: self.timestamp_zone,
                 })
             }
         }
     }
 }
+
+#[test]
+fn it_rejects_truncated_records() {
+    fn decoder(buf: &[u8], format: PgValueFormat) -> Result<PgRecordDecoder<'_>, BoxDynError> {
+        PgRecordDecoder::new(PgValueRef {
+            value: Some(buf),
+            row: None,
+            type_info: PgTypeInfo::RECORD,
+            format,
+            timestamp_zone: PgTimestampZone::Utc,
+        })
+    }
+
+    assert!(decoder(&[0, 0], PgValueFormat::Binary).is_err());
+    assert!(decoder(b"", PgValueFormat::Text).is_err());
+
+    // one INT4 field whose value is cut short
+    let buf = [0, 0, 0, 1, 0, 0, 0, 23, 0, 0, 0, 4, 0, 0];
+    let mut record = decoder(&buf, PgValueFormat::Binary).unwrap();
+    assert!(record.try_decode::<i32>().is_err());
+
+    // the type OID of the field is cut short
+    let buf = [0, 0, 0, 1, 0, 0];
+    let mut record = decoder(&buf, PgValueFormat::Binary).unwrap();
+    assert!(record.try_decode::<i32>().is_err());
+
+    let buf = [0, 0, 0, 1, 0, 0, 0, 23, 0, 0, 0, 4, 0, 0, 0, 7];
+    let mut record = decoder(&buf, PgValueFormat::Binary).unwrap();
+    assert_eq!(record.try_decode::<i32>().unwrap(), 7);
+}
@@ -1,3 +1,6 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
@@ -48,12 +51,20 @@ impl Encode<'_, Postgres> for &'_ str {
 
         IsNull::No
     }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
 }
 
 impl Encode<'_, Postgres> for String {
     fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
         <&str as Encode<Postgres>>::encode(&**self, buf)
     }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
 }
 
 impl<'r> Decode<'r, Postgres> for &'r str {
@@ -92,6 +103,36 @@ impl Type<Postgres> for Vec<String> {
     }
 }
 
+impl Encode<'_, Postgres> for Cow<'_, str> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        <&str as Encode<Postgres>>::encode(&**self, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        <&str as Encode<Postgres>>::size_hint(&&**self)
+    }
+}
+
+impl Encode<'_, Postgres> for Box<str> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        <&str as Encode<Postgres>>::encode(&**self, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        <&str as Encode<Postgres>>::size_hint(&&**self)
+    }
+}
+
+impl Encode<'_, Postgres> for Arc<str> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        <&str as Encode<Postgres>>::encode(&**self, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        <&str as Encode<Postgres>>::size_hint(&&**self)
+    }
+}
+
 impl Decode<'_, Postgres> for String {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         Ok(value.as_str()?.to_owned())
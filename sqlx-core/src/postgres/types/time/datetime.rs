@@ -2,11 +2,13 @@ use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::postgres::types::time::PG_EPOCH;
-use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::postgres::{
+    PgArgumentBuffer, PgTimestampZone, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
 use crate::types::Type;
 use std::borrow::Cow;
 use std::mem;
-use time::{offset, Duration, OffsetDateTime, PrimitiveDateTime};
+use time::{offset, Duration, OffsetDateTime, PrimitiveDateTime, UtcOffset};
 
 impl Type<Postgres> for PrimitiveDateTime {
     fn type_info() -> PgTypeInfo {
@@ -18,6 +20,10 @@ impl Type<Postgres> for OffsetDateTime {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::TIMESTAMPTZ
     }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::TIMESTAMPTZ || *ty == PgTypeInfo::TIMESTAMP
+    }
 }
 
 impl Type<Postgres> for [PrimitiveDateTime] {
@@ -30,6 +36,10 @@ impl Type<Postgres> for [OffsetDateTime] {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::TIMESTAMPTZ_ARRAY
     }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::TIMESTAMPTZ_ARRAY || *ty == PgTypeInfo::TIMESTAMP_ARRAY
+    }
 }
 
 impl Type<Postgres> for Vec<PrimitiveDateTime> {
@@ -42,6 +52,10 @@ impl Type<Postgres> for Vec<OffsetDateTime> {
     fn type_info() -> PgTypeInfo {
         <[OffsetDateTime] as Type<Postgres>>::type_info()
     }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[OffsetDateTime] as Type<Postgres>>::compatible(ty)
+    }
 }
 
 impl Encode<'_, Postgres> for PrimitiveDateTime {
@@ -115,6 +129,31 @@ impl Encode<'_, Postgres> for OffsetDateTime {
 
 impl<'r> Decode<'r, Postgres> for OffsetDateTime {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
-        Ok(<PrimitiveDateTime as Decode<Postgres>>::decode(value)?.assume_utc())
+        let is_timestamp = value.type_info == PgTypeInfo::TIMESTAMP;
+        let zone = value.timestamp_zone;
+        let primitive = <PrimitiveDateTime as Decode<Postgres>>::decode(value)?;
+
+        // TIMESTAMPTZ is always sent in UTC; TIMESTAMP is a wall-clock time in the zone
+        // configured for the connection
+        if !is_timestamp {
+            return Ok(primitive.assume_utc());
+        }
+
+        Ok(match zone {
+            PgTimestampZone::Utc => primitive.assume_utc(),
+
+            PgTimestampZone::Local => {
+                // the offset in effect at the wall-clock time read as UTC may be off by an hour
+                // around a daylight saving transition; look it up again at the resulting instant
+                let guess = UtcOffset::local_offset_at(primitive.assume_utc());
+                let offset = UtcOffset::local_offset_at(primitive.assume_offset(guess));
+
+                primitive.assume_offset(offset).to_offset(offset!(UTC))
+            }
+
+            PgTimestampZone::FixedOffset(offset) => {
+                (primitive - Duration::seconds(offset.into())).assume_utc()
+            }
+        })
     }
 }
@@ -88,10 +88,11 @@ mod chrono {
                     // OFFSET is encoded as seconds from UTC
                     let seconds = buf.read_i32::<BigEndian>()?;
 
-                    Ok(PgTimeTz {
-                        time,
-                        offset: FixedOffset::west(seconds),
-                    })
+                    let offset = FixedOffset::west_opt(seconds).ok_or_else(|| {
+                        format!("TIMETZ offset of {} seconds is out of range", seconds)
+                    })?;
+
+                    Ok(PgTimeTz { time, offset })
                 }
 
                 PgValueFormat::Text => {
@@ -166,10 +167,14 @@ mod time {
                     // OFFSET is encoded as seconds from UTC
                     let seconds = buf.read_i32::<BigEndian>()?;
 
-                    Ok(PgTimeTz {
-                        time,
-                        offset: UtcOffset::west_seconds(seconds as u32),
-                    })
+                    let offset = seconds
+                        .checked_neg()
+                        .and_then(|seconds| UtcOffset::try_seconds(seconds).ok())
+                        .ok_or_else(|| {
+                            format!("TIMETZ offset of {} seconds is out of range", seconds)
+                        })?;
+
+                    Ok(PgTimeTz { time, offset })
                 }
 
                 PgValueFormat::Text => {
@@ -1,5 +1,5 @@
 use crate::error::{BoxDynError, UnexpectedNullError};
-use crate::postgres::{PgTypeInfo, Postgres};
+use crate::postgres::{PgTimestampZone, PgTypeInfo, Postgres};
 use crate::value::{Value, ValueRef};
 use bytes::{Buf, Bytes};
 use std::borrow::Cow;
@@ -19,6 +19,7 @@ pub struct PgValueRef<'r> {
     pub(crate) row: Option<&'r Bytes>,
     pub(crate) type_info: PgTypeInfo,
     pub(crate) format: PgValueFormat,
+    pub(crate) timestamp_zone: PgTimestampZone,
 }
 
 /// Implementation of [`Value`] for PostgreSQL.
@@ -27,27 +28,50 @@ pub struct PgValue {
     pub(crate) value: Option<Bytes>,
     pub(crate) type_info: PgTypeInfo,
     pub(crate) format: PgValueFormat,
+    pub(crate) timestamp_zone: PgTimestampZone,
 }
 
 impl<'r> PgValueRef<'r> {
-    pub(crate) fn get(buf: &mut &'r [u8], format: PgValueFormat, ty: PgTypeInfo) -> Self {
-        let mut element_len = buf.get_i32();
+    pub(crate) fn get(
+        buf: &mut &'r [u8],
+        format: PgValueFormat,
+        ty: PgTypeInfo,
+        timestamp_zone: PgTimestampZone,
+    ) -> Result<Self, BoxDynError> {
+        if buf.len() < 4 {
+            return Err(format!(
+                "expected a length prefix of 4 bytes but received {} bytes",
+                buf.len()
+            )
+            .into());
+        }
+
+        let element_len = buf.get_i32();
 
         let element_val = if element_len == -1 {
-            element_len = 0;
             None
+        } else if element_len < 0 || element_len as usize > buf.len() {
+            return Err(format!(
+                "expected a value of {} bytes but received {} bytes",
+                element_len,
+                buf.len()
+            )
+            .into());
         } else {
-            Some(&buf[..(element_len as usize)])
-        };
+            let bytes: &'r [u8] = *buf;
+            let (element, rest) = bytes.split_at(element_len as usize);
+            *buf = rest;
 
-        buf.advance(element_len as usize);
+            Some(element)
+        };
 
-        PgValueRef {
+        Ok(PgValueRef {
             value: element_val,
             row: None,
             type_info: ty,
             format,
-        }
+            timestamp_zone,
+        })
     }
 
     pub(crate) fn format(&self) -> PgValueFormat {
@@ -76,6 +100,7 @@ impl Value for PgValue {
             row: None,
             type_info: self.type_info.clone(),
             format: self.format,
+            timestamp_zone: self.timestamp_zone,
         }
     }
 
@@ -104,6 +129,7 @@ impl<'r> ValueRef<'r> for PgValueRef<'r> {
             value,
             format: self.format,
             type_info: self.type_info.clone(),
+            timestamp_zone: self.timestamp_zone,
         }
     }
 
@@ -1,14 +1,19 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use either::Either;
 use futures_core::stream::BoxStream;
 use futures_util::{future, StreamExt, TryFutureExt, TryStreamExt};
 
 use crate::arguments::{Arguments, IntoArguments};
+use crate::column::ColumnIndex;
 use crate::database::{Database, HasArguments, HasStatement, HasStatementCache};
+use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
+use crate::query_cache::CachedQuery;
+use crate::row::Row;
 use crate::statement::Statement;
 use crate::types::Type;
 
@@ -19,6 +24,11 @@ pub struct Query<'q, DB: Database, A> {
     pub(crate) arguments: Option<A>,
     pub(crate) database: PhantomData<DB>,
     pub(crate) persistent: bool,
+
+    // the number of values bound with `bind` or `bind_sensitive`, and which of them are
+    // redacted in the statement log
+    pub(crate) bound: usize,
+    pub(crate) redacted: Vec<usize>,
 }
 
 /// SQL query that will map its results to owned Rust types.
@@ -64,6 +74,16 @@ where
     fn persistent(&self) -> bool {
         self.persistent
     }
+
+    fn take_redacted_binds(&mut self) -> Vec<usize> {
+        let bound = self.bound;
+
+        // more arguments may have been passed to `query_with` before any were bound here
+        std::mem::take(&mut self.redacted)
+            .into_iter()
+            .map(|index| bound - 1 - index)
+            .collect()
+    }
 }
 
 impl<'q, DB: Database> Query<'q, DB, <DB as HasArguments<'q>>::Arguments> {
@@ -75,13 +95,45 @@ impl<'q, DB: Database> Query<'q, DB, <DB as HasArguments<'q>>::Arguments> {
     ///
     /// There is no validation that the value is of the type expected by the query. Most SQL
     /// flavors will perform type coercion (Postgres will return a database error).
+    ///
+    /// A `NULL` is bound as `None::<T>` or [`Null::<T>`](crate::types::Null), which sends it
+    /// with the SQL type of `T`.
     pub fn bind<T: 'q + Send + Encode<'q, DB> + Type<DB>>(mut self, value: T) -> Self {
         if let Some(arguments) = &mut self.arguments {
             arguments.add(value);
+            self.bound += 1;
         }
 
         self
     }
+
+    /// Bind a value for use with this SQL query, logging it as `[REDACTED]`.
+    ///
+    /// Behaves exactly like [`bind`](Query::bind) otherwise. Bind values are only logged if
+    /// enabled with [`ConnectOptions::log_binds`](crate::connection::ConnectOptions::log_binds).
+    pub fn bind_sensitive<T: 'q + Send + Encode<'q, DB> + Type<DB>>(mut self, value: T) -> Self {
+        if let Some(arguments) = &mut self.arguments {
+            arguments.add(value);
+            self.redacted.push(self.bound);
+            self.bound += 1;
+        }
+
+        self
+    }
+
+    /// Cache the results of this query, for `ttl`, in the pool it is executed against.
+    ///
+    /// Results are keyed by the SQL and the encoded bind arguments; executing an identical
+    /// query again before the results expire returns the cached rows without contacting the
+    /// database. Use [`Pool::invalidate_cached`](crate::pool::Pool::invalidate_cached) to drop
+    /// results early after writing to the underlying tables.
+    ///
+    /// Concurrent executions that miss the cache each run the query. Results are never
+    /// cached for queries whose arguments cannot be compared, such as those of the `Any`
+    /// driver.
+    pub fn cached(self, ttl: Duration) -> CachedQuery<'q, DB> {
+        CachedQuery { inner: self, ttl }
+    }
 }
 
 impl<'q, DB, A> Query<'q, DB, A>
@@ -102,6 +154,28 @@ where
     }
 }
 
+impl<'q, DB: Database, A> Query<'q, DB, A> {
+    // the SQL of this query, whether or not it was prepared
+    pub(crate) fn sql_str(&self) -> &'q str {
+        match self.statement {
+            Either::Right(statement) => statement.sql(),
+            Either::Left(sql) => sql,
+        }
+    }
+
+    // the same arguments bound to different SQL, e.g. to `EXPLAIN` this query
+    pub(crate) fn with_sql<'s>(self, sql: &'s str) -> Query<'s, DB, A> {
+        Query {
+            statement: Either::Left(sql),
+            arguments: self.arguments,
+            database: PhantomData,
+            persistent: false,
+            bound: self.bound,
+            redacted: self.redacted,
+        }
+    }
+}
+
 impl<'q, DB, A: Send> Query<'q, DB, A>
 where
     DB: Database,
@@ -199,6 +273,36 @@ where
         executor.fetch_all(self).await
     }
 
+    /// Execute the query and return the first column of every row, collected into a [`Vec`].
+    ///
+    /// A shorthand for [`query_scalar`](crate::query_scalar::query_scalar) when the query is
+    /// already built, e.g., to collect the keys generated by a bulk insert:
+    ///
+    /// ```rust,ignore
+    /// let ids: Vec<i64> = sqlx::query("INSERT INTO tags (name) SELECT UNNEST($1) RETURNING id")
+    ///     .bind(&names)
+    ///     .fetch_all_scalar(&pool)
+    ///     .await?;
+    /// ```
+    ///
+    /// The rows are decoded as they are received; use [`fetch`](Query::fetch) to process a large
+    /// result without holding all of it in memory.
+    #[inline]
+    pub async fn fetch_all_scalar<'e, 'c: 'e, O, E>(self, executor: E) -> Result<Vec<O>, Error>
+    where
+        'q: 'e,
+        A: 'e,
+        E: Executor<'c, Database = DB>,
+        O: for<'r> Decode<'r, DB> + Type<DB>,
+        usize: ColumnIndex<DB::Row>,
+    {
+        executor
+            .fetch(self)
+            .and_then(|row| future::ready(row.try_get(0)))
+            .try_collect()
+            .await
+    }
+
     /// Execute the query and returns exactly one row.
     #[inline]
     pub async fn fetch_one<'e, 'c: 'e, E>(self, executor: E) -> Result<DB::Row, Error>
@@ -246,6 +350,11 @@ where
     fn persistent(&self) -> bool {
         self.inner.arguments.is_some()
     }
+
+    #[inline]
+    fn take_redacted_binds(&mut self) -> Vec<usize> {
+        self.inner.take_redacted_binds()
+    }
 }
 
 impl<'q, DB, F, O, A> Map<'q, DB, F, A>
@@ -397,6 +506,8 @@ where
         arguments: Some(Default::default()),
         statement: Either::Right(statement),
         persistent: true,
+        bound: 0,
+        redacted: Vec::new(),
     }
 }
 
@@ -414,6 +525,8 @@ where
         arguments: Some(arguments),
         statement: Either::Right(statement),
         persistent: true,
+        bound: 0,
+        redacted: Vec::new(),
     }
 }
 
@@ -427,6 +540,8 @@ where
         arguments: Some(Default::default()),
         statement: Either::Left(sql),
         persistent: true,
+        bound: 0,
+        redacted: Vec::new(),
     }
 }
 
@@ -441,6 +556,8 @@ where
         arguments: Some(arguments),
         statement: Either::Left(sql),
         persistent: true,
+        bound: 0,
+        redacted: Vec::new(),
     }
 }
 
@@ -1,4 +1,5 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use either::Either;
 use futures_core::stream::BoxStream;
@@ -11,6 +12,7 @@ use crate::error::Error;
 use crate::executor::{Execute, Executor};
 use crate::from_row::FromRow;
 use crate::query::{query, query_statement, query_statement_with, query_with, Query};
+use crate::query_cache::CachedQueryAs;
 use crate::types::Type;
 
 /// Raw SQL query with bind parameters, mapped to a concrete type using [`FromRow`].
@@ -45,6 +47,11 @@ where
     fn persistent(&self) -> bool {
         self.inner.persistent()
     }
+
+    #[inline]
+    fn take_redacted_binds(&mut self) -> Vec<usize> {
+        self.inner.take_redacted_binds()
+    }
 }
 
 impl<'q, DB: Database, O> QueryAs<'q, DB, O, <DB as HasArguments<'q>>::Arguments> {
@@ -55,6 +62,21 @@ impl<'q, DB: Database, O> QueryAs<'q, DB, O, <DB as HasArguments<'q>>::Arguments
         self.inner = self.inner.bind(value);
         self
     }
+
+    /// Bind a value for use with this SQL query, logging it as `[REDACTED]`.
+    ///
+    /// See [`Query::bind_sensitive`](crate::query::Query::bind_sensitive).
+    pub fn bind_sensitive<T: 'q + Send + Encode<'q, DB> + Type<DB>>(mut self, value: T) -> Self {
+        self.inner = self.inner.bind_sensitive(value);
+        self
+    }
+
+    /// Cache the results of this query, for `ttl`, in the pool it is executed against.
+    ///
+    /// See [`Query::cached`](crate::query::Query::cached).
+    pub fn cached(self, ttl: Duration) -> CachedQueryAs<'q, DB, O> {
+        CachedQueryAs { inner: self, ttl }
+    }
 }
 
 // FIXME: This is very close, nearly 1:1 with `Map`
@@ -66,6 +88,37 @@ where
     O: Send + Unpin + for<'r> FromRow<'r, DB::Row>,
 {
     /// Execute the query and return the generated results as a stream.
+    ///
+    /// Each row is mapped to `O` as it is received, so the stream can be consumed with the
+    /// usual combinators without collecting the raw rows first:
+    ///
+    /// ```rust,ignore
+    /// use futures::{StreamExt, TryStreamExt};
+    ///
+    /// #[derive(sqlx::FromRow)]
+    /// struct User { id: i64, name: String }
+    ///
+    /// let users: Vec<User> = sqlx::query_as("SELECT id, name FROM users")
+    ///     .fetch(&mut conn)
+    ///     .try_collect()
+    ///     .await?;
+    ///
+    /// let mut batches = sqlx::query_as::<_, User>("SELECT id, name FROM users")
+    ///     .fetch(&pool)
+    ///     .chunks(100);
+    ///
+    /// while let Some(batch) = batches.next().await {
+    ///     let batch = batch.into_iter().collect::<Result<Vec<User>, _>>()?;
+    ///     // ..
+    /// }
+    /// ```
+    ///
+    /// The stream ends after the first error, including a row that fails to map to `O`.
+    ///
+    /// # Cancellation
+    /// Dropping the stream before it is exhausted is safe. The remaining rows are discarded
+    /// the next time the connection is used, and a pool connection is returned to the pool
+    /// once the stream is dropped.
     pub fn fetch<'e, 'c: 'e, E>(self, executor: E) -> BoxStream<'e, Result<O, Error>>
     where
         'q: 'e,
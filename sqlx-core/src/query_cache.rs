@@ -0,0 +1,242 @@
+//! Read-through caching of query results in a [`Pool`].
+//!
+//! See [`Query::cached`], [`QueryAs::cached`] and
+//! [`QueryScalar::cached`](crate::query_scalar::QueryScalar::cached).
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use lru_cache::LruCache;
+use parking_lot::Mutex;
+
+use crate::arguments::Arguments;
+use crate::database::{Database, HasArguments};
+use crate::error::Error;
+use crate::executor::{Execute, Executor};
+use crate::from_row::FromRow;
+use crate::pool::Pool;
+use crate::query::Query;
+use crate::query_as::QueryAs;
+
+/// Results cached in a pool, keyed by SQL and encoded arguments.
+///
+/// When full, the least recently used results are dropped. Expired results are dropped when they
+/// are next looked up, or when they are evicted to make room for others.
+pub(crate) struct ResultCache<DB: Database> {
+    entries: Mutex<LruCache<Key, Entry<DB>>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Key {
+    sql: String,
+    arguments: Vec<u8>,
+}
+
+struct Entry<DB: Database> {
+    rows: Arc<Vec<DB::Row>>,
+    expires_at: Instant,
+}
+
+impl<DB: Database> ResultCache<DB> {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    fn get(&self, key: &Key) -> Option<Arc<Vec<DB::Row>>> {
+        let mut entries = self.entries.lock();
+
+        match entries.get_mut(key) {
+            Some(entry) if entry.expires_at > Instant::now() => Some(Arc::clone(&entry.rows)),
+
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: Key, rows: Arc<Vec<DB::Row>>, ttl: Duration) {
+        // a full cache drops its least recently used entry
+        self.entries.lock().insert(
+            key,
+            Entry {
+                rows,
+                expires_at: Instant::now() + ttl,
+            },
+        );
+    }
+
+    pub(crate) fn invalidate(&self, sql: &str) {
+        let mut entries = self.entries.lock();
+
+        let keys: Vec<Key> = entries
+            .iter()
+            .filter(|(key, _)| key.sql == sql)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        for key in keys {
+            entries.remove(&key);
+        }
+    }
+
+    pub(crate) fn clear(&self) {
+        self.entries.lock().clear();
+    }
+}
+
+/// A query whose results are cached in the pool it is executed against.
+///
+/// Returned by [`Query::cached`].
+#[must_use = "query must be executed to affect database"]
+pub struct CachedQuery<'q, DB: Database> {
+    pub(crate) inner: Query<'q, DB, <DB as HasArguments<'q>>::Arguments>,
+    pub(crate) ttl: Duration,
+}
+
+/// A query whose results are cached in the pool it is executed against and mapped to
+/// a concrete type using [`FromRow`].
+///
+/// Returned by [`QueryAs::cached`].
+#[must_use = "query must be executed to affect database"]
+pub struct CachedQueryAs<'q, DB: Database, O> {
+    pub(crate) inner: QueryAs<'q, DB, O, <DB as HasArguments<'q>>::Arguments>,
+    pub(crate) ttl: Duration,
+}
+
+/// A query whose results are cached in the pool it is executed against and mapped to
+/// a concrete type using [`FromRow`] on `(O,)`.
+///
+/// Returned by [`QueryScalar::cached`](crate::query_scalar::QueryScalar::cached).
+#[must_use = "query must be executed to affect database"]
+pub struct CachedQueryScalar<'q, DB: Database, O> {
+    pub(crate) inner: CachedQueryAs<'q, DB, (O,)>,
+}
+
+impl<'q, DB: Database> CachedQuery<'q, DB> {
+    /// Execute the query and return all the generated results, or the cached results of a
+    /// previous execution if they have not yet expired.
+    pub async fn fetch_all(self, pool: &Pool<DB>) -> Result<Arc<Vec<DB::Row>>, Error>
+    where
+        for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    {
+        let key = match cache_key(&self.inner) {
+            Some(key) => key,
+            None => return Ok(Arc::new(self.inner.fetch_all(pool).await?)),
+        };
+
+        if let Some(rows) = pool.0.results.get(&key) {
+            return Ok(rows);
+        }
+
+        let rows = Arc::new(self.inner.fetch_all(pool).await?);
+
+        pool.0.results.insert(key, Arc::clone(&rows), self.ttl);
+
+        Ok(rows)
+    }
+}
+
+impl<'q, DB, O> CachedQueryAs<'q, DB, O>
+where
+    DB: Database,
+    O: Send + Unpin + for<'r> FromRow<'r, DB::Row>,
+{
+    /// Execute the query and return all the generated results, collected into a [`Vec`].
+    ///
+    /// Rows are mapped from the cached results of a previous execution if they have not
+    /// yet expired.
+    pub async fn fetch_all(self, pool: &Pool<DB>) -> Result<Vec<O>, Error>
+    where
+        for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    {
+        let rows = self.rows(pool).await?;
+
+        rows.iter().map(O::from_row).collect()
+    }
+
+    /// Execute the query and returns exactly one row.
+    pub async fn fetch_one(self, pool: &Pool<DB>) -> Result<O, Error>
+    where
+        for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    {
+        self.fetch_optional(pool).await?.ok_or(Error::RowNotFound)
+    }
+
+    /// Execute the query and returns at most one row.
+    pub async fn fetch_optional(self, pool: &Pool<DB>) -> Result<Option<O>, Error>
+    where
+        for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    {
+        let rows = self.rows(pool).await?;
+
+        rows.first().map(O::from_row).transpose()
+    }
+
+    async fn rows(self, pool: &Pool<DB>) -> Result<Arc<Vec<DB::Row>>, Error>
+    where
+        for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    {
+        CachedQuery {
+            inner: self.inner.inner,
+            ttl: self.ttl,
+        }
+        .fetch_all(pool)
+        .await
+    }
+}
+
+impl<'q, DB, O> CachedQueryScalar<'q, DB, O>
+where
+    DB: Database,
+    O: Send + Unpin,
+    (O,): Send + Unpin + for<'r> FromRow<'r, DB::Row>,
+{
+    /// Execute the query and return all the generated results, collected into a [`Vec`].
+    ///
+    /// Rows are mapped from the cached results of a previous execution if they have not
+    /// yet expired.
+    pub async fn fetch_all(self, pool: &Pool<DB>) -> Result<Vec<O>, Error>
+    where
+        for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    {
+        let rows = self.inner.fetch_all(pool).await?;
+
+        Ok(rows.into_iter().map(|(value,)| value).collect())
+    }
+
+    /// Execute the query and returns exactly one row.
+    pub async fn fetch_one(self, pool: &Pool<DB>) -> Result<O, Error>
+    where
+        for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    {
+        self.inner.fetch_one(pool).await.map(|(value,)| value)
+    }
+
+    /// Execute the query and returns at most one row.
+    pub async fn fetch_optional(self, pool: &Pool<DB>) -> Result<Option<O>, Error>
+    where
+        for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    {
+        Ok(self.inner.fetch_optional(pool).await?.map(|(value,)| value))
+    }
+}
+
+// returns `None` if the arguments cannot be compared, in which case the results are not cached
+fn cache_key<'q, DB: Database>(
+    query: &Query<'q, DB, <DB as HasArguments<'q>>::Arguments>,
+) -> Option<Key> {
+    let arguments = match &query.arguments {
+        Some(arguments) => arguments.cache_key()?,
+        None => Vec::new(),
+    };
+
+    Some(Key {
+        sql: query.sql().to_owned(),
+        arguments,
+    })
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use either::Either;
 use futures_core::stream::BoxStream;
 use futures_util::{StreamExt, TryFutureExt, TryStreamExt};
@@ -11,6 +13,7 @@ use crate::from_row::FromRow;
 use crate::query_as::{
     query_as, query_as_with, query_statement_as, query_statement_as_with, QueryAs,
 };
+use crate::query_cache::CachedQueryScalar;
 use crate::types::Type;
 
 /// Raw SQL query with bind parameters, mapped to a concrete type using [`FromRow`] on `(O,)`.
@@ -42,6 +45,11 @@ where
     fn persistent(&self) -> bool {
         self.inner.persistent()
     }
+
+    #[inline]
+    fn take_redacted_binds(&mut self) -> Vec<usize> {
+        self.inner.take_redacted_binds()
+    }
 }
 
 impl<'q, DB: Database, O> QueryScalar<'q, DB, O, <DB as HasArguments<'q>>::Arguments> {
@@ -52,6 +60,23 @@ impl<'q, DB: Database, O> QueryScalar<'q, DB, O, <DB as HasArguments<'q>>::Argum
         self.inner = self.inner.bind(value);
         self
     }
+
+    /// Bind a value for use with this SQL query, logging it as `[REDACTED]`.
+    ///
+    /// See [`Query::bind_sensitive`](crate::query::Query::bind_sensitive).
+    pub fn bind_sensitive<T: 'q + Send + Encode<'q, DB> + Type<DB>>(mut self, value: T) -> Self {
+        self.inner = self.inner.bind_sensitive(value);
+        self
+    }
+
+    /// Cache the results of this query, for `ttl`, in the pool it is executed against.
+    ///
+    /// See [`Query::cached`](crate::query::Query::cached).
+    pub fn cached(self, ttl: Duration) -> CachedQueryScalar<'q, DB, O> {
+        CachedQueryScalar {
+            inner: self.inner.cached(ttl),
+        }
+    }
 }
 
 // FIXME: This is very close, nearly 1:1 with `Map`
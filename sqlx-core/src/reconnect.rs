@@ -0,0 +1,263 @@
+//! A dedicated connection that is re-established after it is lost.
+//!
+//! Long-running processes that hold a single connection outside of a [`Pool`](crate::pool::Pool),
+//! such as daemons consuming a queue, otherwise have to notice a dropped connection and rebuild
+//! their session themselves.
+//!
+//! ```rust,ignore
+//! use sqlx::reconnect::ReconnectingConnection;
+//! use sqlx::postgres::Postgres;
+//!
+//! let mut conn = ReconnectingConnection::<Postgres>::new(options)
+//!     .max_attempts(10)
+//!     .after_connect(|conn| Box::pin(async move {
+//!         conn.execute("SET search_path = 'jobs'").await?;
+//!
+//!         Ok(())
+//!     }));
+//!
+//! loop {
+//!     let job = sqlx::query("SELECT * FROM jobs WHERE done = false LIMIT 1")
+//!         .fetch_optional(conn.acquire().await?)
+//!         .await?;
+//!
+//!     // ..
+//! }
+//! ```
+//!
+//! [`PgListener`](crate::postgres::PgListener) reconnects on its own; use
+//! [`PgListener::connect_with`](crate::postgres::PgListener::connect_with) and
+//! [`PoolOptions::after_connect`](crate::pool::PoolOptions::after_connect) to set up its session.
+
+use std::cmp;
+use std::fmt::{self, Debug, Formatter};
+use std::time::{Duration, Instant};
+
+use futures_core::future::BoxFuture;
+
+use crate::connection::Connection;
+use crate::database::Database;
+use crate::error::Error;
+use crate::runtime::sleep;
+
+type ConnectOptions<DB> = <<DB as Database>::Connection as Connection>::Options;
+
+/// A connection that is transparently re-established if it was lost since it was last used.
+///
+/// The connection is checked with [`Connection::ping`] when it is
+/// [acquired](ReconnectingConnection::acquire) after it has been idle for a while (see
+/// [`ping_after_idle`](ReconnectingConnection::ping_after_idle)), or after a statement failed
+/// in a way that suggests the connection was lost (see
+/// [`check_result`](ReconnectingConnection::check_result)). If that fails, a new connection is
+/// established, retrying with exponential backoff while the failure is an I/O error. Statements
+/// that fail because the connection was lost while they were executing are **not** retried, as
+/// they may already have taken effect.
+pub struct ReconnectingConnection<DB: Database> {
+    options: ConnectOptions<DB>,
+    connection: Option<DB::Connection>,
+    // when the connection was last acquired
+    last_used: Instant,
+    // set once a statement failed with an error that may mean the connection is lost
+    suspect: bool,
+    ping_after_idle: Duration,
+    after_connect: Option<
+        Box<
+            dyn Fn(&mut DB::Connection) -> BoxFuture<'_, Result<(), Error>> + 'static + Send + Sync,
+        >,
+    >,
+    max_attempts: u32,
+    min_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl<DB: Database> ReconnectingConnection<DB> {
+    /// Creates a new connection with the given options.
+    ///
+    /// The connection is established the first time it is acquired.
+    pub fn new(options: ConnectOptions<DB>) -> Self {
+        Self {
+            options,
+            connection: None,
+            last_used: Instant::now(),
+            suspect: false,
+            ping_after_idle: Duration::from_secs(30),
+            after_connect: None,
+            max_attempts: 5,
+            min_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+
+    /// Set the maximum number of attempts made to establish the connection before the error is
+    /// returned.
+    ///
+    /// Defaults to 5.
+    pub fn max_attempts(mut self, max: u32) -> Self {
+        self.max_attempts = cmp::max(max, 1);
+        self
+    }
+
+    /// Set the time waited after the first failed attempt to establish the connection, and the
+    /// maximum it doubles to after each further failed attempt.
+    ///
+    /// Defaults to 100 milliseconds and 10 seconds.
+    pub fn backoff(mut self, min: Duration, max: Duration) -> Self {
+        self.min_backoff = min;
+        self.max_backoff = cmp::max(min, max);
+        self
+    }
+
+    /// Set how long the connection may go unused before it is checked with
+    /// [`Connection::ping`] the next time it is acquired.
+    ///
+    /// A connection that was used more recently is handed out without a round-trip to the server.
+    /// Defaults to 30 seconds.
+    pub fn ping_after_idle(mut self, idle: Duration) -> Self {
+        self.ping_after_idle = idle;
+        self
+    }
+
+    /// Perform an action every time the connection is established, such as setting up
+    /// the session.
+    ///
+    /// If it fails, the connection is discarded and the error is returned.
+    pub fn after_connect<F>(mut self, callback: F) -> Self
+    where
+        for<'c> F:
+            Fn(&'c mut DB::Connection) -> BoxFuture<'c, Result<(), Error>> + 'static + Send + Sync,
+    {
+        self.after_connect = Some(Box::new(callback));
+        self
+    }
+
+    /// Returns the connection, establishing it again first if it was lost.
+    pub async fn acquire(&mut self) -> Result<&mut DB::Connection, Error> {
+        let check = self.suspect || self.last_used.elapsed() >= self.ping_after_idle;
+
+        let alive = match &mut self.connection {
+            Some(_) if !check => true,
+
+            Some(conn) => match conn.ping().await {
+                Ok(()) => true,
+
+                Err(error) => {
+                    log::warn!("connection lost, reconnecting: {}", error);
+                    false
+                }
+            },
+
+            None => false,
+        };
+
+        if !alive {
+            // drop the lost connection first so it is not held on to while reconnecting
+            self.connection = None;
+            self.connection = Some(self.establish().await?);
+        }
+
+        self.suspect = false;
+        self.last_used = Instant::now();
+
+        Ok(self
+            .connection
+            .as_mut()
+            .expect("BUG: connection not established"))
+    }
+
+    /// Returns `result`, making sure the connection is checked the next time it is acquired if
+    /// `result` is an error that may have been caused by losing the connection.
+    ///
+    /// ```rust,ignore
+    /// let result = sqlx::query("DELETE FROM jobs WHERE done")
+    ///     .execute(conn.acquire().await?)
+    ///     .await;
+    ///
+    /// let done = conn.check_result(result)?;
+    /// ```
+    pub fn check_result<T>(&mut self, result: Result<T, Error>) -> Result<T, Error> {
+        if let Err(error) = &result {
+            self.suspect |= may_be_disconnected(error);
+        }
+
+        result
+    }
+
+    /// Returns the current connection, if it is established, without checking that it is alive.
+    pub fn into_inner(self) -> Option<DB::Connection> {
+        self.connection
+    }
+
+    /// Explicitly close the connection, if it is established.
+    ///
+    /// See [`Connection::close`].
+    pub async fn close(self) -> Result<(), Error> {
+        match self.connection {
+            Some(conn) => conn.close().await,
+            None => Ok(()),
+        }
+    }
+
+    async fn establish(&self) -> Result<DB::Connection, Error> {
+        let mut backoff = self.min_backoff;
+        let mut attempt = 1;
+
+        loop {
+            match self.try_establish().await {
+                Ok(conn) => return Ok(conn),
+
+                Err(Error::Io(error)) if attempt < self.max_attempts => {
+                    log::warn!(
+                        "failed to connect (attempt {} of {}): {}",
+                        attempt,
+                        self.max_attempts,
+                        error
+                    );
+
+                    sleep(backoff).await;
+
+                    backoff = cmp::min(backoff * 2, self.max_backoff);
+                    attempt += 1;
+                }
+
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    async fn try_establish(&self) -> Result<DB::Connection, Error> {
+        let mut conn = DB::Connection::connect_with(&self.options).await?;
+
+        if let Some(callback) = &self.after_connect {
+            callback(&mut conn).await?;
+        }
+
+        Ok(conn)
+    }
+}
+
+fn may_be_disconnected(error: &Error) -> bool {
+    match error {
+        Error::Io(_) | Error::Protocol(_) | Error::WorkerCrashed => true,
+
+        // SQLSTATE class 08 is a connection exception; 57P01 to 57P03 are sent by a server that
+        // terminates the session, e.g. when it is shut down or the backend is killed
+        Error::Database(error) => error.code().map_or(false, |code| {
+            code.starts_with("08") || matches!(&*code, "57P01" | "57P02" | "57P03")
+        }),
+
+        _ => false,
+    }
+}
+
+impl<DB: Database> Debug for ReconnectingConnection<DB> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReconnectingConnection")
+            .field("options", &self.options)
+            .field("connected", &self.connection.is_some())
+            .field("ping_after_idle", &self.ping_after_idle)
+            .field("max_attempts", &self.max_attempts)
+            .field("min_backoff", &self.min_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .finish()
+    }
+}
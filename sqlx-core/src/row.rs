@@ -54,6 +54,25 @@ pub trait Row: private_row::Sealed + Unpin + Send + Sync + 'static {
     /// Gets all columns in this statement.
     fn columns(&self) -> &[<Self::Database as Database>::Column];
 
+    /// Returns the position of the column named `name`, or `None` if there is no such column.
+    ///
+    /// The column is looked up in the same way as [`try_get`](Row::try_get) looks it up, so it
+    /// allows handling result sets where a column may or may not be present without having to
+    /// tell a [`ColumnNotFound`](Error::ColumnNotFound) error apart from other errors.
+    ///
+    /// ```rust,ignore
+    /// let nickname: Option<String> = match row.column_ordinal("nickname") {
+    ///     Some(ordinal) => row.try_get(ordinal)?,
+    ///     None => None,
+    /// };
+    /// ```
+    fn column_ordinal<I>(&self, name: I) -> Option<usize>
+    where
+        I: ColumnIndex<Self>,
+    {
+        name.index(self).ok()
+    }
+
     /// Index into the database row and decode a single value.
     ///
     /// A string index can be used to access a column by name and a `usize` index
@@ -115,21 +134,41 @@ pub trait Row: private_row::Sealed + Unpin + Send + Sync + 'static {
     {
         let value = self.try_get_raw(&index)?;
 
-        if !value.is_null() {
-            let ty = value.type_info();
+        decode_checked::<Self, T, I>(&index, value)
+    }
+
+    /// Index into the database row and decode a single value, or return `default` if the
+    /// value is `NULL`.
+    ///
+    /// This is the nullable column with a fallback pattern without going through
+    /// `Option<T>`: a `NULL` is detected before anything is decoded or type checked.
+    ///
+    /// ```rust,ignore
+    /// let discount: i32 = row.try_get_or("discount", 0)?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    ///  * [`ColumnNotFound`] if the column by the given name was not found.
+    ///  * [`ColumnIndexOutOfBounds`] if the `usize` index was greater than the number of columns in the row.
+    ///  * [`ColumnDecode`] if a non-`NULL` value could not be decoded into the requested type.
+    ///
+    /// [`ColumnDecode`]: Error::ColumnDecode
+    /// [`ColumnNotFound`]: Error::ColumnNotFound
+    /// [`ColumnIndexOutOfBounds`]: Error::ColumnIndexOutOfBounds
+    ///
+    fn try_get_or<'r, T, I>(&'r self, index: I, default: T) -> Result<T, Error>
+    where
+        I: ColumnIndex<Self>,
+        T: Decode<'r, Self::Database> + Type<Self::Database>,
+    {
+        let value = self.try_get_raw(&index)?;
 
-            if !ty.is_null() && !T::compatible(&ty) {
-                return Err(Error::ColumnDecode {
-                    index: format!("{:?}", index),
-                    source: mismatched_types::<Self::Database, T>(&ty),
-                });
-            }
+        if value.is_null() {
+            return Ok(default);
         }
 
-        T::decode(value).map_err(|source| Error::ColumnDecode {
-            index: format!("{:?}", index),
-            source,
-        })
+        decode_checked::<Self, T, I>(&index, value)
     }
 
     /// Index into the database row and decode a single value.
@@ -162,6 +201,41 @@ pub trait Row: private_row::Sealed + Unpin + Send + Sync + 'static {
         })
     }
 
+    /// Index into the database row and decode a single value, or return `default` if the
+    /// value is `NULL`.
+    ///
+    /// Unlike [`try_get_or`](#method.try_get_or), this method does not check that the type
+    /// being returned from the database is compatible with the Rust type and blindly tries
+    /// to decode the value.
+    ///
+    /// # Errors
+    ///
+    ///  * [`ColumnNotFound`] if the column by the given name was not found.
+    ///  * [`ColumnIndexOutOfBounds`] if the `usize` index was greater than the number of columns in the row.
+    ///  * [`ColumnDecode`] if a non-`NULL` value could not be decoded into the requested type.
+    ///
+    /// [`ColumnDecode`]: Error::ColumnDecode
+    /// [`ColumnNotFound`]: Error::ColumnNotFound
+    /// [`ColumnIndexOutOfBounds`]: Error::ColumnIndexOutOfBounds
+    ///
+    #[inline]
+    fn try_get_unchecked_or<'r, T, I>(&'r self, index: I, default: T) -> Result<T, Error>
+    where
+        I: ColumnIndex<Self>,
+        T: Decode<'r, Self::Database>,
+    {
+        let value = self.try_get_raw(&index)?;
+
+        if value.is_null() {
+            return Ok(default);
+        }
+
+        T::decode(value).map_err(|source| Error::ColumnDecode {
+            index: format!("{:?}", index),
+            source,
+        })
+    }
+
     /// Index into the database row and decode a single value.
     ///
     /// # Errors
@@ -180,6 +254,33 @@ pub trait Row: private_row::Sealed + Unpin + Send + Sync + 'static {
         I: ColumnIndex<Self>;
 }
 
+// decodes a value taken from `row`, first checking that its type is compatible with `T`
+fn decode_checked<'r, R, T, I>(
+    index: &I,
+    value: <R::Database as HasValueRef<'r>>::ValueRef,
+) -> Result<T, Error>
+where
+    R: Row + ?Sized,
+    I: ColumnIndex<R>,
+    T: Decode<'r, R::Database> + Type<R::Database>,
+{
+    if !value.is_null() {
+        let ty = value.type_info();
+
+        if !ty.is_null() && !T::compatible(&ty) {
+            return Err(Error::ColumnDecode {
+                index: format!("{:?}", index),
+                source: mismatched_types::<R::Database, T>(&ty),
+            });
+        }
+    }
+
+    T::decode(value).map_err(|source| Error::ColumnDecode {
+        index: format!("{:?}", index),
+        source,
+    })
+}
+
 // Prevent users from implementing the `Row` trait.
 pub(crate) mod private_row {
     pub trait Sealed {}
@@ -0,0 +1,158 @@
+//! Run SQLx on an async runtime other than the one selected by its `runtime-*` feature.
+//!
+//! The pool spawns background tasks, sleeps between reaping idle connections, and times out
+//! connection attempts; the drivers open TCP and Unix domain sockets. By default, all of these
+//! use the runtime chosen at compile time. Installing a [`Runtime`] with [`set_runtime`] routes
+//! them through it instead, so other executors can be supported from outside of SQLx.
+//!
+//! ```rust,ignore
+//! struct Smol;
+//!
+//! impl sqlx::runtime::Runtime for Smol {
+//!     fn spawn(&self, future: BoxFuture<'static, ()>) {
+//!         smol::spawn(future).detach();
+//!     }
+//!
+//!     fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+//!         Box::pin(async move {
+//!             smol::Timer::after(duration).await;
+//!         })
+//!     }
+//!
+//!     fn connect_tcp<'a>(
+//!         &'a self,
+//!         host: &'a str,
+//!         port: u16,
+//!     ) -> BoxFuture<'a, io::Result<Box<dyn RuntimeStream>>> {
+//!         Box::pin(async move {
+//!             let stream = smol::net::TcpStream::connect((host, port)).await?;
+//!
+//!             Ok(Box::new(stream) as Box<dyn RuntimeStream>)
+//!         })
+//!     }
+//! }
+//!
+//! sqlx::runtime::set_runtime(Smol);
+//! ```
+//!
+//! Streams returned by a custom runtime implement the `AsyncRead` and `AsyncWrite` traits of
+//! `futures-io`, whichever runtime feature SQLx was compiled with, and are used as-is:
+//! connection options such as `tcp_nodelay` are not applied to them.
+
+use std::fmt::Debug;
+use std::future::Future;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use futures_core::future::BoxFuture;
+use futures_util::future::{self, Either};
+use futures_util::io::{AsyncRead, AsyncWrite};
+use futures_util::pin_mut;
+use once_cell::sync::OnceCell;
+
+/// A connected socket returned by a [`Runtime`], using the I/O traits of `futures-io`.
+pub trait RuntimeStream: AsyncRead + AsyncWrite + Debug + Send + Unpin {}
+
+impl<T> RuntimeStream for T where T: AsyncRead + AsyncWrite + Debug + Send + Unpin {}
+
+/// The operations SQLx needs from an async runtime.
+pub trait Runtime: Send + Sync + 'static {
+    /// Run the future to completion in the background.
+    fn spawn(&self, future: BoxFuture<'static, ()>);
+
+    /// Returns a future that completes once `duration` has passed.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+
+    /// Open a TCP connection to the given host and port.
+    fn connect_tcp<'a>(
+        &'a self,
+        host: &'a str,
+        port: u16,
+    ) -> BoxFuture<'a, io::Result<Box<dyn RuntimeStream>>>;
+
+    /// Open a connection to the Unix domain socket at the given path.
+    ///
+    /// Returns an error unless overridden.
+    fn connect_unix<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxFuture<'a, io::Result<Box<dyn RuntimeStream>>> {
+        let _ = path;
+
+        Box::pin(future::err(io::Error::new(
+            io::ErrorKind::Other,
+            "Unix domain sockets are not supported by this runtime",
+        )))
+    }
+}
+
+static RUNTIME: OnceCell<Box<dyn Runtime>> = OnceCell::new();
+
+/// Install the process-wide runtime.
+///
+/// This must be called before any pool is created or connection is opened. A runtime can only
+/// be installed once; returns `false` if one was already set.
+pub fn set_runtime<R: Runtime>(runtime: R) -> bool {
+    RUNTIME.set(Box::new(runtime)).is_ok()
+}
+
+pub(crate) fn custom() -> Option<&'static dyn Runtime> {
+    RUNTIME.get().map(|runtime| &**runtime)
+}
+
+pub(crate) fn spawn<F>(future: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    match custom() {
+        Some(runtime) => runtime.spawn(Box::pin(future)),
+
+        None => {
+            let _ = sqlx_rt::spawn(future);
+        }
+    }
+}
+
+// whether `spawn` can be called from the current thread; the runtimes built on Tokio can only
+// spawn tasks from within their context
+#[cfg(any(feature = "_rt-actix", feature = "_rt-tokio"))]
+pub(crate) fn can_spawn() -> bool {
+    custom().is_some() || sqlx_rt::tokio::runtime::Handle::try_current().is_ok()
+}
+
+#[cfg(not(any(feature = "_rt-actix", feature = "_rt-tokio")))]
+pub(crate) fn can_spawn() -> bool {
+    true
+}
+
+pub(crate) async fn sleep(duration: Duration) {
+    match custom() {
+        Some(runtime) => runtime.sleep(duration).await,
+        None => sqlx_rt::sleep(duration).await,
+    }
+}
+
+/// The error returned by [`timeout`] when the future did not complete in time.
+#[derive(Debug)]
+pub(crate) struct Elapsed;
+
+pub(crate) async fn timeout<F: Future>(
+    duration: Duration,
+    future: F,
+) -> Result<F::Output, Elapsed> {
+    match custom() {
+        Some(runtime) => {
+            pin_mut!(future);
+
+            match future::select(future, runtime.sleep(duration)).await {
+                Either::Left((output, _)) => Ok(output),
+                Either::Right(_) => Err(Elapsed),
+            }
+        }
+
+        None => sqlx_rt::timeout(duration, future)
+            .await
+            .map_err(|_| Elapsed),
+    }
+}
@@ -1,6 +1,7 @@
-use crate::arguments::Arguments;
+use crate::arguments::{ArgumentCount, Arguments};
 use crate::encode::{Encode, IsNull};
 use crate::error::Error;
+use crate::logger::format_bind;
 use crate::sqlite::statement::StatementHandle;
 use crate::sqlite::Sqlite;
 use atoi::atoi;
@@ -23,7 +24,8 @@ pub struct SqliteArguments<'q> {
 }
 
 impl<'q> SqliteArguments<'q> {
-    pub(crate) fn add<T>(&mut self, value: T)
+    /// Add the value to the end of the arguments.
+    pub fn add<T>(&mut self, value: T)
     where
         T: Encode<'q, Sqlite>,
     {
@@ -46,6 +48,65 @@ impl<'q> Arguments<'q> for SqliteArguments<'q> {
     {
         self.add(value)
     }
+
+    fn cache_key(&self) -> Option<Vec<u8>> {
+        let mut key = Vec::new();
+
+        for value in &self.values {
+            match value {
+                SqliteArgumentValue::Null => key.push(0),
+
+                SqliteArgumentValue::Text(text) => {
+                    key.push(1);
+                    key.extend_from_slice(&(text.len() as u64).to_le_bytes());
+                    key.extend_from_slice(text.as_bytes());
+                }
+
+                SqliteArgumentValue::Blob(blob) => {
+                    key.push(2);
+                    key.extend_from_slice(&(blob.len() as u64).to_le_bytes());
+                    key.extend_from_slice(blob);
+                }
+
+                SqliteArgumentValue::Double(v) => {
+                    key.push(3);
+                    key.extend_from_slice(&v.to_bits().to_le_bytes());
+                }
+
+                SqliteArgumentValue::Int(v) => {
+                    key.push(4);
+                    key.extend_from_slice(&v.to_le_bytes());
+                }
+
+                SqliteArgumentValue::Int64(v) => {
+                    key.push(5);
+                    key.extend_from_slice(&v.to_le_bytes());
+                }
+            }
+        }
+
+        Some(key)
+    }
+
+    fn format_for_log(&self) -> Vec<String> {
+        self.values
+            .iter()
+            .map(|value| match value {
+                SqliteArgumentValue::Null => String::from("NULL"),
+                SqliteArgumentValue::Text(v) => format_bind(v),
+                SqliteArgumentValue::Blob(_) => String::from("<BLOB>"),
+                SqliteArgumentValue::Double(v) => format_bind(v),
+                SqliteArgumentValue::Int(v) => format_bind(v),
+                SqliteArgumentValue::Int64(v) => format_bind(v),
+            })
+            .collect()
+    }
+}
+
+impl<'q> ArgumentCount<'q> for SqliteArguments<'q> {
+    fn len(&self) -> usize {
+        self.values.len()
+    }
 }
 
 impl SqliteArguments<'_> {
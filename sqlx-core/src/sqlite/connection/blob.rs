@@ -0,0 +1,251 @@
+use std::ffi::CString;
+use std::future::Future;
+use std::io::{self, SeekFrom};
+use std::os::raw::{c_int, c_void};
+use std::pin::Pin;
+use std::ptr::{null_mut, NonNull};
+use std::task::{Context, Poll};
+
+use futures_channel::oneshot;
+use futures_util::io::{AsyncRead, AsyncSeek};
+use libsqlite3_sys::{
+    sqlite3, sqlite3_blob, sqlite3_blob_bytes, sqlite3_blob_close, sqlite3_blob_open,
+    sqlite3_blob_read, SQLITE_OK,
+};
+
+use crate::error::Error;
+use crate::sqlite::statement::StatementWorker;
+use crate::sqlite::{SqliteConnection, SqliteError};
+
+/// A handle for reading a single `BLOB` or `TEXT` value incrementally.
+///
+/// Returned by [`SqliteConnection::open_blob`]. Rather than loading the whole value into memory
+/// as part of a row, the value is read in chunks through the [`AsyncRead`] and [`AsyncSeek`]
+/// implementations of `futures-io`. As with statements, the reads run on the worker thread of
+/// the connection.
+///
+/// This is specific to SQLite, which reads the value in place from the database file. Postgres
+/// and MySQL always send a value whole as part of its row, so `BYTEA`, `TEXT` and `BLOB` values
+/// from those databases are still buffered in full.
+pub struct SqliteBlob<'c> {
+    handle: BlobHandle,
+    len: usize,
+    offset: usize,
+
+    // the blob handle is only valid while the connection is borrowed
+    worker: &'c mut StatementWorker,
+
+    // a read sent to the worker that has not completed yet
+    pending: Option<oneshot::Receiver<Result<Vec<u8>, Error>>>,
+}
+
+// The pointers of an open blob and its connection, sent to the worker thread of the connection
+// to read from the blob there.
+#[derive(Clone, Copy)]
+pub(crate) struct BlobHandle {
+    blob: NonNull<sqlite3_blob>,
+    db: NonNull<sqlite3>,
+}
+
+// A blob handle may be sent between threads for the same reasons as the connection handle;
+// the exclusive borrow of the connection prevents concurrent access.
+unsafe impl Send for BlobHandle {}
+
+impl BlobHandle {
+    pub(crate) fn read(self, offset: usize, len: usize) -> Result<Vec<u8>, Error> {
+        let mut buf = vec![0_u8; len];
+
+        // https://www.sqlite.org/c3ref/blob_read.html
+        let status = unsafe {
+            sqlite3_blob_read(
+                self.blob.as_ptr(),
+                buf.as_mut_ptr() as *mut c_void,
+                len as c_int,
+                offset as c_int,
+            )
+        };
+
+        if status != SQLITE_OK {
+            return Err(Error::Database(Box::new(SqliteError::new(
+                self.db.as_ptr(),
+            ))));
+        }
+
+        Ok(buf)
+    }
+
+    pub(crate) fn close(self) {
+        // https://www.sqlite.org/c3ref/blob_close.html
+        unsafe {
+            sqlite3_blob_close(self.blob.as_ptr());
+        }
+    }
+}
+
+impl<'c> SqliteBlob<'c> {
+    pub(crate) fn open(
+        conn: &'c mut SqliteConnection,
+        table: &str,
+        column: &str,
+        rowid: i64,
+    ) -> Result<Self, Error> {
+        // a blob dropped before this one may still be closing on the worker
+        conn.worker.wait_for_blob_close_blocking();
+
+        let db = conn.handle.as_ptr();
+
+        let table =
+            CString::new(table).map_err(|_| err_protocol!("invalid table name: {}", table))?;
+
+        let column =
+            CString::new(column).map_err(|_| err_protocol!("invalid column name: {}", column))?;
+
+        let mut blob = null_mut();
+
+        // https://www.sqlite.org/c3ref/blob_open.html
+        let status = unsafe {
+            sqlite3_blob_open(
+                db,
+                b"main\0".as_ptr() as *const _,
+                table.as_ptr(),
+                column.as_ptr(),
+                rowid,
+                0, // read-only
+                &mut blob,
+            )
+        };
+
+        if status != SQLITE_OK {
+            return Err(Error::Database(Box::new(SqliteError::new(db))));
+        }
+
+        // SAFE: a successful open always writes a valid handle, and the connection handle is
+        // never null
+        let handle = unsafe {
+            BlobHandle {
+                blob: NonNull::new_unchecked(blob),
+                db: NonNull::new_unchecked(db),
+            }
+        };
+
+        // https://www.sqlite.org/c3ref/blob_bytes.html
+        let len = unsafe { sqlite3_blob_bytes(blob) } as usize;
+
+        Ok(Self {
+            handle,
+            len,
+            offset: 0,
+            worker: &mut conn.worker,
+            pending: None,
+        })
+    }
+
+    /// Returns the size of the value, in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the value is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl AsyncRead for SqliteBlob<'_> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+
+        if this.pending.is_none() {
+            let len = buf.len().min(this.len - this.offset);
+
+            if len == 0 {
+                return Poll::Ready(Ok(0));
+            }
+
+            let pending = this
+                .worker
+                .read_blob(this.handle, this.offset, len)
+                .map_err(|error| io::Error::new(io::ErrorKind::Other, error))?;
+
+            this.pending = Some(pending);
+        }
+
+        let result = match this
+            .pending
+            .as_mut()
+            .map(|pending| Pin::new(pending).poll(cx))
+        {
+            Some(Poll::Ready(result)) => result,
+            _ => return Poll::Pending,
+        };
+
+        this.pending = None;
+
+        let chunk = result
+            .map_err(|_| Error::WorkerCrashed)
+            .and_then(|result| result)
+            .map_err(|error| io::Error::new(io::ErrorKind::Other, error))?;
+
+        // the buffer may have shrunk since the read was sent; the rest is read again next time
+        let n = chunk.len().min(buf.len());
+        buf[..n].copy_from_slice(&chunk[..n]);
+
+        this.offset += n;
+
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl AsyncSeek for SqliteBlob<'_> {
+    fn poll_seek(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<io::Result<u64>> {
+        let this = &mut *self;
+
+        // a read still in flight is for the old position; it is finished and discarded, so
+        // that the blob is never closed while the worker is reading from it
+        if let Some(pending) = &mut this.pending {
+            if Pin::new(pending).poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+
+            this.pending = None;
+        }
+
+        let offset = match pos {
+            SeekFrom::Start(n) => n as i64,
+            SeekFrom::End(n) => this.len as i64 + n,
+            SeekFrom::Current(n) => this.offset as i64 + n,
+        };
+
+        if offset < 0 {
+            return Poll::Ready(Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative position",
+            )));
+        }
+
+        // reads past the end return nothing, as with a file
+        this.offset = (offset as usize).min(this.len);
+
+        Poll::Ready(Ok(this.offset as u64))
+    }
+}
+
+impl Drop for SqliteBlob<'_> {
+    fn drop(&mut self) {
+        if self.pending.is_some() {
+            // a read is still running on the worker, so the worker closes the blob after it
+            // rather than this waiting for the read
+            self.worker.close_blob(self.handle);
+        } else {
+            self.handle.close();
+        }
+    }
+}
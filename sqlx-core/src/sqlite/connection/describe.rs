@@ -16,6 +16,8 @@ pub(super) fn describe<'c: 'e, 'q: 'e, 'e>(
         // describing a statement from SQLite can be involved
         // each SQLx statement is comprised of multiple SQL statements
 
+        conn.worker.wait_for_blob_close().await?;
+
         let statement = VirtualStatement::new(query, false);
 
         let mut columns = Vec::new();
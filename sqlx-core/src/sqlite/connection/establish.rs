@@ -1,3 +1,4 @@
+use crate::connection::ConnectionStatistics;
 use crate::error::Error;
 use crate::sqlite::connection::handle::ConnectionHandle;
 use crate::sqlite::statement::StatementWorker;
@@ -114,6 +115,7 @@ pub(crate) async fn establish(options: &SqliteConnectOptions) -> Result<SqliteCo
         statements: StatementCache::new(options.statement_cache_capacity),
         statement: None,
         transaction_depth: 0,
+        statistics: ConnectionStatistics::default(),
         log_settings: options.log_settings.clone(),
     })
 }
@@ -1,8 +1,10 @@
+use crate::comment;
 use crate::common::StatementCache;
+use crate::connection::ConnectionStatistics;
 use crate::describe::Describe;
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
-use crate::logger::QueryLogger;
+use crate::logger::{format_binds, instrument_query, QueryLogger};
 use crate::sqlite::connection::describe::describe;
 use crate::sqlite::statement::{StatementHandle, VirtualStatement};
 use crate::sqlite::{
@@ -20,18 +22,26 @@ use std::sync::Arc;
 fn prepare<'a>(
     statements: &'a mut StatementCache<VirtualStatement>,
     statement: &'a mut Option<VirtualStatement>,
+    statistics: &mut ConnectionStatistics,
     query: &str,
     persistent: bool,
 ) -> Result<&'a mut VirtualStatement, Error> {
     if !persistent || !statements.is_enabled() {
-        *statement = Some(VirtualStatement::new(query, false)?);
+        *statement = Some(VirtualStatement::new(&comment::apply(query), false)?);
         return Ok(statement.as_mut().unwrap());
     }
 
     let exists = statements.contains_key(query);
 
+    if exists {
+        statistics.statement_cache_hits += 1;
+    } else {
+        statistics.statement_cache_misses += 1;
+    }
+
     if !exists {
-        let statement = VirtualStatement::new(query, true)?;
+        // the statement is cached by the SQL without the comment, so that it is reused
+        let statement = VirtualStatement::new(&comment::apply(query), true)?;
         statements.insert(query, statement);
     }
 
@@ -72,74 +82,89 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
         E: Execute<'q, Self::Database>,
     {
         let sql = query.sql();
-        let mut logger = QueryLogger::new(sql, self.log_settings.clone());
         let arguments = query.take_arguments();
+        let redacted = query.take_redacted_binds();
+        let binds = format_binds(&self.log_settings, arguments.as_ref(), &redacted);
         let persistent = query.persistent() && arguments.is_some();
 
-        Box::pin(try_stream! {
-            let SqliteConnection {
-                handle: ref mut conn,
-                ref mut statements,
-                ref mut statement,
-                ref mut worker,
-                ..
-            } = self;
-
-            // prepare statement object (or checkout from cache)
-            let stmt = prepare(statements, statement, sql, persistent)?;
-
-            // keep track of how many arguments we have bound
-            let mut num_arguments = 0;
-
-            while let Some((stmt, columns, column_names, last_row_values)) = stmt.prepare(conn)? {
-                // bind values to the statement
-                num_arguments += bind(stmt, &arguments, num_arguments)?;
-
-                loop {
-                    // save the rows from the _current_ position on the statement
-                    // and send them to the still-live row object
-                    SqliteRow::inflate_if_needed(stmt, &*columns, last_row_values.take());
-
-                    // invoke [sqlite3_step] on the dedicated worker thread
-                    // this will move us forward one row or finish the statement
-                    let s = worker.step(*stmt).await?;
-
-                    match s {
-                        Either::Left(changes) => {
-                            let last_insert_rowid = unsafe {
-                                sqlite3_last_insert_rowid(conn.as_ptr())
-                            };
-
-                            let done = SqliteDone {
-                                changes: changes,
-                                last_insert_rowid: last_insert_rowid,
-                            };
-
-                            r#yield!(Either::Left(done));
-
-                            break;
-                        }
-
-                        Either::Right(()) => {
-                            let (row, weak_values_ref) = SqliteRow::current(
-                                *stmt,
-                                columns,
-                                column_names
-                            );
-
-                            let v = Either::Right(row);
-                            *last_row_values = Some(weak_values_ref);
-
-                            logger.increment_rows();
-
-                            r#yield!(v);
+        instrument_query::<Sqlite, _>(
+            sql,
+            Box::pin(try_stream! {
+                let mut logger = QueryLogger::new::<Sqlite>(sql, binds, self.log_settings.clone());
+
+                let SqliteConnection {
+                    handle: ref mut conn,
+                    ref mut statements,
+                    ref mut statement,
+                    ref mut worker,
+                    ref mut statistics,
+                    ..
+                } = self;
+
+                // a dropped blob may still be closing on the worker
+                worker.wait_for_blob_close().await?;
+
+                // prepare statement object (or checkout from cache)
+                let stmt = prepare(statements, statement, statistics, sql, persistent)?;
+                statistics.statements_executed += 1;
+                statistics.last_statement_id = Some(logger.statement_id());
+
+                // keep track of how many arguments we have bound
+                let mut num_arguments = 0;
+
+                while let Some((stmt, columns, column_names, last_row_values)) = stmt.prepare(conn)? {
+                    // bind values to the statement
+                    num_arguments += bind(stmt, &arguments, num_arguments)?;
+
+                    loop {
+                        // save the rows from the _current_ position on the statement
+                        // and send them to the still-live row object
+                        SqliteRow::inflate_if_needed(stmt, &*columns, last_row_values.take());
+
+                        // invoke [sqlite3_step] on the dedicated worker thread
+                        // this will move us forward one row or finish the statement
+                        let s = worker.step(*stmt).await?;
+
+                        match s {
+                            Either::Left(changes) => {
+                                let last_insert_rowid = unsafe {
+                                    sqlite3_last_insert_rowid(conn.as_ptr())
+                                };
+
+                                let done = SqliteDone {
+                                    changes: changes,
+                                    last_insert_rowid: last_insert_rowid,
+                                };
+
+                                logger.increase_rows_affected(done.changes);
+
+                                r#yield!(Either::Left(done));
+
+                                break;
+                            }
+
+                            Either::Right(()) => {
+                                let (row, weak_values_ref) = SqliteRow::current(
+                                    *stmt,
+                                    columns,
+                                    column_names
+                                );
+
+                                let v = Either::Right(row);
+                                *last_row_values = Some(weak_values_ref);
+
+                                logger.increment_rows();
+                                statistics.rows_fetched += 1;
+
+                                r#yield!(v);
+                            }
                         }
                     }
                 }
-            }
 
-            Ok(())
-        })
+                Ok(())
+            }),
+        )
     }
 
     fn fetch_optional<'e, 'q: 'e, E: 'q>(
@@ -171,38 +196,45 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
     where
         'c: 'e,
     {
-        Box::pin(async move {
-            let SqliteConnection {
-                handle: ref mut conn,
-                ref mut statements,
-                ref mut statement,
-                ..
-            } = self;
-
-            // prepare statement object (or checkout from cache)
-            let statement = prepare(statements, statement, sql, true)?;
-
-            let mut parameters = 0;
-            let mut columns = None;
-            let mut column_names = None;
-
-            while let Some((statement, columns_, column_names_, _)) = statement.prepare(conn)? {
-                parameters += statement.bind_parameter_count();
-
-                // the first non-empty statement is chosen as the statement we pull columns from
-                if !columns_.is_empty() && columns.is_none() {
-                    columns = Some(Arc::clone(columns_));
-                    column_names = Some(Arc::clone(column_names_));
+        crate::logger::instrument_prepare::<Sqlite, _>(
+            sql,
+            Box::pin(async move {
+                let SqliteConnection {
+                    handle: ref mut conn,
+                    ref mut statements,
+                    ref mut statement,
+                    ref mut worker,
+                    ref mut statistics,
+                    ..
+                } = self;
+
+                worker.wait_for_blob_close().await?;
+
+                // prepare statement object (or checkout from cache)
+                let statement = prepare(statements, statement, statistics, sql, true)?;
+
+                let mut parameters = 0;
+                let mut columns = None;
+                let mut column_names = None;
+
+                while let Some((statement, columns_, column_names_, _)) = statement.prepare(conn)? {
+                    parameters += statement.bind_parameter_count();
+
+                    // the first non-empty statement is chosen as the statement we pull columns from
+                    if !columns_.is_empty() && columns.is_none() {
+                        columns = Some(Arc::clone(columns_));
+                        column_names = Some(Arc::clone(column_names_));
+                    }
                 }
-            }
 
-            Ok(SqliteStatement {
-                sql: Cow::Borrowed(sql),
-                columns: columns.unwrap_or_default(),
-                column_names: column_names.unwrap_or_default(),
-                parameters,
-            })
-        })
+                Ok(SqliteStatement {
+                    sql: Cow::Borrowed(sql),
+                    columns: columns.unwrap_or_default(),
+                    column_names: column_names.unwrap_or_default(),
+                    parameters,
+                })
+            }),
+        )
     }
 
     #[doc(hidden)]
@@ -1,15 +1,20 @@
 use crate::common::StatementCache;
-use crate::connection::{Connection, LogSettings};
+use crate::connection::{
+    Connection, ConnectionStatistics, LogSettings, ServerFlavor, ServerVersion,
+};
 use crate::error::Error;
 use crate::sqlite::statement::{StatementWorker, VirtualStatement};
 use crate::sqlite::{Sqlite, SqliteConnectOptions};
 use crate::transaction::Transaction;
 use futures_core::future::BoxFuture;
 use futures_util::future;
-use libsqlite3_sys::sqlite3;
+use libsqlite3_sys::{sqlite3, sqlite3_get_autocommit, sqlite3_libversion};
+use once_cell::sync::Lazy;
 use std::cmp::Ordering;
+use std::ffi::CStr;
 use std::fmt::{self, Debug, Formatter};
 
+mod blob;
 mod collation;
 mod describe;
 pub(crate) mod establish;
@@ -17,6 +22,8 @@ mod executor;
 mod explain;
 mod handle;
 
+pub(crate) use blob::BlobHandle;
+pub use blob::SqliteBlob;
 pub(crate) use handle::ConnectionHandle;
 
 /// A connection to a [Sqlite] database.
@@ -33,12 +40,16 @@ pub struct SqliteConnection {
     // most recent non-persistent statement
     pub(crate) statement: Option<VirtualStatement>,
 
+    // counters reported by [Connection::statistics]
+    pub(crate) statistics: ConnectionStatistics,
+
     log_settings: LogSettings,
 }
 
 impl SqliteConnection {
     /// Returns the underlying sqlite3* connection handle
     pub fn as_raw_handle(&mut self) -> *mut sqlite3 {
+        self.worker.wait_for_blob_close_blocking();
         self.handle.as_ptr()
     }
 
@@ -47,8 +58,27 @@ impl SqliteConnection {
         name: &str,
         compare: impl Fn(&str, &str) -> Ordering + Send + Sync + 'static,
     ) -> Result<(), Error> {
+        self.worker.wait_for_blob_close_blocking();
         collation::create_collation(&self.handle, name, compare)
     }
+
+    /// Open a `BLOB` or `TEXT` value for incremental reading.
+    ///
+    /// The value is identified by its table, column and `ROWID` in the `main` database.
+    /// Unlike fetching the value in a row, the returned [`SqliteBlob`] reads the value in
+    /// chunks, so it never has to be held in memory all at once. It implements `AsyncRead` and
+    /// `AsyncSeek` from `futures-io`.
+    ///
+    /// The handle is invalidated if the row is modified or deleted; subsequent reads
+    /// return an error.
+    pub fn open_blob(
+        &mut self,
+        table: &str,
+        column: &str,
+        rowid: i64,
+    ) -> Result<SqliteBlob<'_>, Error> {
+        SqliteBlob::open(self, table, column, rowid)
+    }
 }
 
 impl Debug for SqliteConnection {
@@ -72,6 +102,11 @@ impl Connection for SqliteConnection {
         Box::pin(future::ok(()))
     }
 
+    fn is_in_transaction(&self) -> bool {
+        // SAFE: the handle is valid for as long as the connection is
+        unsafe { sqlite3_get_autocommit(self.handle.as_ptr()) == 0 }
+    }
+
     fn begin(&mut self) -> BoxFuture<'_, Result<Transaction<'_, Self::Database>, Error>>
     where
         Self: Sized,
@@ -90,6 +125,22 @@ impl Connection for SqliteConnection {
         })
     }
 
+    fn statistics(&self) -> ConnectionStatistics {
+        self.statistics
+    }
+
+    fn server_version(&self) -> Option<&ServerVersion> {
+        // SQLite is a library, so this is the version that is linked in
+        static VERSION: Lazy<ServerVersion> = Lazy::new(|| {
+            // SAFE: sqlite3_libversion returns a pointer to a static NUL-terminated string
+            let version = unsafe { CStr::from_ptr(sqlite3_libversion()) };
+
+            ServerVersion::parse(ServerFlavor::Sqlite, &version.to_string_lossy())
+        });
+
+        Some(&VERSION)
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         // For SQLite, FLUSH does effectively nothing
@@ -104,6 +155,9 @@ impl Connection for SqliteConnection {
 
 impl Drop for SqliteConnection {
     fn drop(&mut self) {
+        // a dropped blob may still be closing on the worker
+        self.worker.wait_for_blob_close_blocking();
+
         // before the connection handle is dropped,
         // we must explicitly drop the statements as the drop-order in a struct is undefined
         self.statements.clear();
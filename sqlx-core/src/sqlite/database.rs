@@ -1,4 +1,6 @@
-use crate::database::{Database, HasArguments, HasStatement, HasStatementCache, HasValueRef};
+use crate::database::{
+    quote, Database, HasArguments, HasStatement, HasStatementCache, HasValueRef,
+};
 use crate::sqlite::{
     SqliteArgumentValue, SqliteArguments, SqliteColumn, SqliteConnection, SqliteDone, SqliteRow,
     SqliteStatement, SqliteTransactionManager, SqliteTypeInfo, SqliteValue, SqliteValueRef,
@@ -22,6 +24,26 @@ impl Database for Sqlite {
     type TypeInfo = SqliteTypeInfo;
 
     type Value = SqliteValue;
+
+    const NAME: &'static str = "sqlite";
+
+    // SQLITE_MAX_VARIABLE_NUMBER, as compiled into the bundled SQLite 3.32+
+    const MAX_BIND_PARAMETERS: usize = 32766;
+}
+
+impl Sqlite {
+    /// Quote `ident` so it can be interpolated into SQL as an identifier, such as a table or
+    /// column name; it is wrapped with double quotes.
+    pub fn quote_identifier(ident: &str) -> String {
+        quote(ident, '"', '"')
+    }
+
+    /// Quote `value` so it can be interpolated into SQL as a string literal; it is wrapped
+    /// with single quotes.
+    pub fn quote_literal(value: &str) -> String {
+        // backslashes have no special meaning in SQLite string literals
+        quote(value, '\'', '\'')
+    }
 }
 
 impl<'r> HasValueRef<'r> for Sqlite {
@@ -45,3 +67,15 @@ impl<'q> HasStatement<'q> for Sqlite {
 }
 
 impl HasStatementCache for Sqlite {}
+
+#[test]
+fn it_quotes_identifiers_and_literals() {
+    assert_eq!(Sqlite::quote_identifier("users"), r#""users""#);
+    assert_eq!(
+        Sqlite::quote_identifier(r#"my "table""#),
+        r#""my ""table""""#
+    );
+
+    assert_eq!(Sqlite::quote_literal("it's"), "'it''s'");
+    assert_eq!(Sqlite::quote_literal(r"C:\dir\"), r"'C:\dir\'");
+}
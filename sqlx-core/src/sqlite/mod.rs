@@ -24,7 +24,7 @@ mod migrate;
 
 pub use arguments::{SqliteArgumentValue, SqliteArguments};
 pub use column::SqliteColumn;
-pub use connection::SqliteConnection;
+pub use connection::{SqliteBlob, SqliteConnection};
 pub use database::Sqlite;
 pub use done::SqliteDone;
 pub use error::SqliteError;
@@ -53,3 +53,4 @@ impl_into_maybe_pool!(Sqlite, SqliteConnection);
 
 // required because some databases have a different handling of NULL
 impl_encode_for_option!(Sqlite);
+impl_encode_for_null!(Sqlite);
@@ -39,4 +39,9 @@ impl ConnectOptions for SqliteConnectOptions {
         self.log_settings.log_slow_statements(level, duration);
         self
     }
+
+    fn log_binds(&mut self, enabled: bool) -> &mut Self {
+        self.log_settings.log_binds(enabled);
+        self
+    }
 }
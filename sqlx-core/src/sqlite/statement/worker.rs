@@ -1,6 +1,7 @@
 use crate::error::Error;
+use crate::sqlite::connection::BlobHandle;
 use crate::sqlite::statement::StatementHandle;
-use crossbeam_channel::{unbounded, Sender};
+use crossbeam_channel::{bounded, unbounded, Receiver, Sender};
 use either::Either;
 use futures_channel::oneshot;
 use libsqlite3_sys::{sqlite3_step, SQLITE_DONE, SQLITE_ROW};
@@ -14,6 +15,10 @@ use std::thread;
 
 pub(crate) struct StatementWorker {
     tx: Sender<StatementWorkerCommand>,
+
+    // a blob close sent without waiting for it; the connection must not be used outside of
+    // the worker until it is done
+    closing_blob: Option<Receiver<()>>,
 }
 
 enum StatementWorkerCommand {
@@ -21,6 +26,22 @@ enum StatementWorkerCommand {
         statement: StatementHandle,
         tx: oneshot::Sender<Result<Either<u64, ()>, Error>>,
     },
+
+    ReadBlob {
+        blob: BlobHandle,
+        offset: usize,
+        len: usize,
+        tx: oneshot::Sender<Result<Vec<u8>, Error>>,
+    },
+
+    CloseBlob {
+        blob: BlobHandle,
+        tx: Sender<()>,
+    },
+
+    Sync {
+        tx: oneshot::Sender<()>,
+    },
 }
 
 impl StatementWorker {
@@ -41,11 +62,33 @@ impl StatementWorker {
 
                         let _ = tx.send(resp);
                     }
+
+                    StatementWorkerCommand::ReadBlob {
+                        blob,
+                        offset,
+                        len,
+                        tx,
+                    } => {
+                        let _ = tx.send(blob.read(offset, len));
+                    }
+
+                    StatementWorkerCommand::CloseBlob { blob, tx } => {
+                        blob.close();
+
+                        let _ = tx.send(());
+                    }
+
+                    StatementWorkerCommand::Sync { tx } => {
+                        let _ = tx.send(());
+                    }
                 }
             }
         });
 
-        Self { tx }
+        Self {
+            tx,
+            closing_blob: None,
+        }
     }
 
     pub(crate) async fn step(
@@ -60,4 +103,69 @@ impl StatementWorker {
 
         rx.await.map_err(|_| Error::WorkerCrashed)?
     }
+
+    // starts reading from a blob; the receiver is polled directly by the blob
+    pub(crate) fn read_blob(
+        &mut self,
+        blob: BlobHandle,
+        offset: usize,
+        len: usize,
+    ) -> Result<oneshot::Receiver<Result<Vec<u8>, Error>>, Error> {
+        let (tx, rx) = oneshot::channel();
+
+        self.tx
+            .send(StatementWorkerCommand::ReadBlob {
+                blob,
+                offset,
+                len,
+                tx,
+            })
+            .map_err(|_| Error::WorkerCrashed)?;
+
+        Ok(rx)
+    }
+
+    // closes a blob after any read still running on it, without waiting; the next use of the
+    // connection waits for it instead
+    pub(crate) fn close_blob(&mut self, blob: BlobHandle) {
+        let (tx, rx) = bounded(1);
+
+        if self
+            .tx
+            .send(StatementWorkerCommand::CloseBlob { blob, tx })
+            .is_err()
+        {
+            // the worker is gone, so nothing else can be using the blob
+            blob.close();
+            return;
+        }
+
+        self.closing_blob = Some(rx);
+    }
+
+    // waits for a blob close sent by [close_blob], before the connection is used directly
+    pub(crate) async fn wait_for_blob_close(&mut self) -> Result<(), Error> {
+        match self.closing_blob.take() {
+            Some(rx) if rx.try_recv().is_err() => {
+                // commands run in order, so once this one is done the blob is closed
+                let (tx, rx) = oneshot::channel();
+
+                self.tx
+                    .send(StatementWorkerCommand::Sync { tx })
+                    .map_err(|_| Error::WorkerCrashed)?;
+
+                rx.await.map_err(|_| Error::WorkerCrashed)
+            }
+
+            _ => Ok(()),
+        }
+    }
+
+    // as [wait_for_blob_close], for where the connection is used outside of a future; this
+    // only blocks for as long as the read that was still running on the blob takes
+    pub(crate) fn wait_for_blob_close_blocking(&mut self) {
+        if let Some(rx) = self.closing_blob.take() {
+            let _ = rx.recv();
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::sync::Arc;
 
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
@@ -47,6 +48,36 @@ impl<'q> Encode<'q, Sqlite> for String {
     }
 }
 
+impl<'q> Encode<'q, Sqlite> for Cow<'q, str> {
+    fn encode(self, args: &mut Vec<SqliteArgumentValue<'q>>) -> IsNull {
+        args.push(SqliteArgumentValue::Text(self));
+
+        IsNull::No
+    }
+
+    fn encode_by_ref(&self, args: &mut Vec<SqliteArgumentValue<'q>>) -> IsNull {
+        args.push(SqliteArgumentValue::Text(self.clone()));
+
+        IsNull::No
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for Box<str> {
+    fn encode_by_ref(&self, args: &mut Vec<SqliteArgumentValue<'q>>) -> IsNull {
+        args.push(SqliteArgumentValue::Text(Cow::Owned(self.to_string())));
+
+        IsNull::No
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for Arc<str> {
+    fn encode_by_ref(&self, args: &mut Vec<SqliteArgumentValue<'q>>) -> IsNull {
+        args.push(SqliteArgumentValue::Text(Cow::Owned(self.to_string())));
+
+        IsNull::No
+    }
+}
+
 impl<'r> Decode<'r, Sqlite> for String {
     fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
         value.text().map(ToOwned::to_owned)
@@ -7,6 +7,7 @@ use crate::query::Query;
 use crate::query_as::QueryAs;
 use crate::query_scalar::QueryScalar;
 use either::Either;
+use std::fmt::{self, Display, Formatter};
 
 /// An explicitly prepared statement.
 ///
@@ -35,6 +36,13 @@ pub trait Statement<'q>: Send + Sync {
     /// Get the columns expected to be returned by executing this statement.
     fn columns(&self) -> &[<Self::Database as Database>::Column];
 
+    /// Gets the stable identifier of this statement's SQL.
+    ///
+    /// See [`StatementId`].
+    fn id(&self) -> StatementId {
+        StatementId::of(self.sql())
+    }
+
     /// Gets the column information at `index`.
     ///
     /// A string index can be used to access a column by name and a `usize` index
@@ -88,6 +96,90 @@ pub trait Statement<'q>: Send + Sync {
         A: IntoArguments<'s, Self::Database>;
 }
 
+/// A stable identifier for the SQL of a statement.
+///
+/// It is computed from the SQL text alone, ignoring differences in whitespace outside of quoted
+/// strings and identifiers, so the same query has the same id on every connection, in every
+/// process and across restarts. The id is included in the `sqlx::query` log lines and tracing
+/// spans, and the id of the last statement executed on a connection is in its
+/// [`statistics`](crate::connection::Connection::statistics), so application-side metrics and
+/// logs for the same query can be grouped together.
+///
+/// Once a commenter is installed, the id is also sent to the server in a `/* sqlx:id=<hex> */`
+/// comment at the end of the SQL (see [`comment`](crate::comment)). It is unrelated to the `queryid` of `pg_stat_statements`
+/// or the digest of `performance_schema`, which ignore comments, but it can be found in the
+/// query text they record.
+///
+/// It is displayed as 16 hexadecimal digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StatementId(u64);
+
+impl StatementId {
+    /// Computes the id of `sql`.
+    pub fn of(sql: &str) -> Self {
+        // 64-bit FNV-1a; unlike the hashers in std, the result does not depend on a
+        // per-process seed or on the version of Rust
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0100_0000_01b3;
+
+        let mut hash = OFFSET_BASIS;
+        let mut hash_byte = |byte: u8| {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(PRIME);
+        };
+
+        // the quote character of the string or identifier we are in, if any
+        let mut quote = None;
+
+        // whitespace outside of quotes hashes as a single space between words
+        let mut space = false;
+        let mut started = false;
+
+        for &byte in sql.as_bytes() {
+            match quote {
+                Some(q) => {
+                    if byte == q {
+                        quote = None;
+                    }
+                }
+
+                None if byte.is_ascii_whitespace() => {
+                    space = started;
+                    continue;
+                }
+
+                None => {
+                    if let b'\'' | b'"' | b'`' = byte {
+                        quote = Some(byte);
+                    }
+
+                    if space {
+                        hash_byte(b' ');
+                        space = false;
+                    }
+
+                    started = true;
+                }
+            }
+
+            hash_byte(byte);
+        }
+
+        Self(hash)
+    }
+
+    /// Returns the id as an integer.
+    pub fn as_u64(self) -> u64 {
+        self.0
+    }
+}
+
+impl Display for StatementId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
 macro_rules! impl_statement_query {
     ($A:ty) => {
         #[inline]
@@ -170,3 +262,28 @@ macro_rules! impl_statement_query {
         }
     };
 }
+
+#[test]
+fn test_statement_id() {
+    let id = StatementId::of("SELECT * FROM tweet WHERE id = $1");
+
+    assert_eq!(
+        id,
+        StatementId::of("SELECT *\n  FROM tweet\n  WHERE id = $1\n")
+    );
+    assert_ne!(id, StatementId::of("SELECT * FROM tweet WHERE id = $2"));
+
+    // whitespace inside of quotes is part of the value
+    assert_ne!(
+        StatementId::of("SELECT 'a  b', \"c  d\""),
+        StatementId::of("SELECT 'a b', \"c d\"")
+    );
+    assert_eq!(
+        StatementId::of("SELECT  'a  b',\n  \"c  d\""),
+        StatementId::of("SELECT 'a  b', \"c  d\"")
+    );
+
+    // FNV-1a is stable, so ids can be compared across processes and releases
+    assert_eq!(StatementId::of("").as_u64(), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(StatementId::of("a").to_string(), "af63dc4c8601ec8c");
+}
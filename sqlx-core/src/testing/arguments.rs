@@ -0,0 +1,33 @@
+use crate::arguments::{ArgumentCount, Arguments};
+use crate::encode::{Encode, IsNull};
+use crate::testing::{Mock, MockValue};
+use crate::types::Type;
+
+/// Implementation of [`Arguments`] for the mock driver.
+#[derive(Debug, Default, Clone)]
+pub struct MockArguments {
+    pub(crate) values: Vec<MockValue>,
+}
+
+impl<'q> Arguments<'q> for MockArguments {
+    type Database = Mock;
+
+    fn reserve(&mut self, additional: usize, _size: usize) {
+        self.values.reserve(additional);
+    }
+
+    fn add<T>(&mut self, value: T)
+    where
+        T: 'q + Send + Encode<'q, Self::Database> + Type<Self::Database>,
+    {
+        if let IsNull::Yes = value.encode(&mut self.values) {
+            self.values.push(MockValue::Null);
+        }
+    }
+}
+
+impl<'q> ArgumentCount<'q> for MockArguments {
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+}
@@ -0,0 +1,28 @@
+use crate::column::Column;
+use crate::ext::ustr::UStr;
+use crate::testing::{Mock, MockTypeInfo};
+
+#[derive(Debug, Clone)]
+pub struct MockColumn {
+    pub(crate) name: UStr,
+    pub(crate) ordinal: usize,
+    pub(crate) type_info: MockTypeInfo,
+}
+
+impl crate::column::private_column::Sealed for MockColumn {}
+
+impl Column for MockColumn {
+    type Database = Mock;
+
+    fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    fn name(&self) -> &str {
+        &*self.name
+    }
+
+    fn type_info(&self) -> &MockTypeInfo {
+        &self.type_info
+    }
+}
@@ -0,0 +1,260 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::iter;
+
+use either::Either;
+use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+use futures_util::{future, stream};
+
+use crate::connection::Connection;
+use crate::describe::Describe;
+use crate::error::Error;
+use crate::executor::{Execute, Executor};
+use crate::testing::{
+    Mock, MockArguments, MockConnectOptions, MockDone, MockRow, MockStatement, MockTypeInfo,
+    MockValue,
+};
+use crate::transaction::Transaction;
+
+/// A connection that answers statements from a list of [`Expectation`]s instead of a database.
+///
+/// See the [module documentation](crate::testing) for an example.
+#[derive(Debug, Default)]
+pub struct MockConnection {
+    expectations: VecDeque<Expectation>,
+    executed: Vec<Executed>,
+    pub(crate) transaction_depth: usize,
+}
+
+/// A statement that is expected to be executed against a [`MockConnection`], and the response
+/// to give when it is.
+#[derive(Debug)]
+pub struct Expectation {
+    sql: String,
+    rows: Vec<MockRow>,
+    rows_affected: u64,
+}
+
+/// A statement that was executed against a [`MockConnection`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Executed {
+    /// The SQL of the statement, exactly as it was given.
+    pub sql: String,
+
+    /// The values bound to the statement, in order.
+    pub arguments: Vec<MockValue>,
+}
+
+impl MockConnection {
+    /// Creates a connection that does not expect any statements.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expect `sql` to be the next statement executed, after any previously expected statements.
+    ///
+    /// By default the statement returns no rows and affects no rows; use the returned
+    /// [`Expectation`] to change that.
+    pub fn expect(&mut self, sql: impl Into<String>) -> &mut Expectation {
+        self.expectations.push_back(Expectation {
+            sql: sql.into(),
+            rows: Vec::new(),
+            rows_affected: 0,
+        });
+
+        self.expectations.back_mut().unwrap()
+    }
+
+    /// Every statement executed against this connection so far, in order, including the
+    /// statements that controlled transactions.
+    pub fn executed(&self) -> &[Executed] {
+        &self.executed
+    }
+
+    /// Returns `true` if every expected statement has been executed.
+    pub fn is_satisfied(&self) -> bool {
+        self.expectations.is_empty()
+    }
+
+    pub(crate) fn record(&mut self, sql: &str) {
+        self.executed.push(Executed {
+            sql: sql.to_owned(),
+            arguments: Vec::new(),
+        });
+    }
+
+    fn run(
+        &mut self,
+        sql: &str,
+        arguments: Option<MockArguments>,
+    ) -> Result<(Vec<MockRow>, MockDone), Error> {
+        self.executed.push(Executed {
+            sql: sql.to_owned(),
+            arguments: arguments
+                .map(|arguments| arguments.values)
+                .unwrap_or_default(),
+        });
+
+        match self.expectations.front() {
+            Some(expected) if expected.matches(sql) => {
+                let expected = self.expectations.pop_front().unwrap();
+
+                Ok((
+                    expected.rows,
+                    MockDone {
+                        rows_affected: expected.rows_affected,
+                    },
+                ))
+            }
+
+            Some(expected) => Err(err_protocol!(
+                "unexpected statement {:?}; expected {:?}",
+                sql,
+                expected.sql
+            )),
+
+            None => Err(err_protocol!(
+                "unexpected statement {:?}; no more statements were expected",
+                sql
+            )),
+        }
+    }
+}
+
+impl Expectation {
+    /// Return these rows when the statement is executed.
+    pub fn returning(&mut self, rows: impl IntoIterator<Item = MockRow>) -> &mut Self {
+        self.rows.extend(rows);
+        self
+    }
+
+    /// Report this many affected rows when the statement is executed.
+    pub fn rows_affected(&mut self, rows_affected: u64) -> &mut Self {
+        self.rows_affected = rows_affected;
+        self
+    }
+
+    fn matches(&self, sql: &str) -> bool {
+        normalize(&self.sql) == normalize(sql)
+    }
+}
+
+// collapse every run of whitespace to a single space so formatting differences don't matter
+fn normalize(sql: &str) -> String {
+    sql.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+impl Connection for MockConnection {
+    type Database = Mock;
+
+    type Options = MockConnectOptions;
+
+    fn close(self) -> BoxFuture<'static, Result<(), Error>> {
+        Box::pin(future::ok(()))
+    }
+
+    fn ping(&mut self) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(future::ok(()))
+    }
+
+    fn is_in_transaction(&self) -> bool {
+        self.transaction_depth > 0
+    }
+
+    fn begin(&mut self) -> BoxFuture<'_, Result<Transaction<'_, Self::Database>, Error>>
+    where
+        Self: Sized,
+    {
+        Transaction::begin(self)
+    }
+
+    #[doc(hidden)]
+    fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(future::ok(()))
+    }
+
+    #[doc(hidden)]
+    fn should_flush(&self) -> bool {
+        false
+    }
+}
+
+impl<'c> Executor<'c> for &'c mut MockConnection {
+    type Database = Mock;
+
+    fn fetch_many<'e, 'q: 'e, E: 'q>(
+        self,
+        mut query: E,
+    ) -> BoxStream<'e, Result<Either<MockDone, MockRow>, Error>>
+    where
+        'c: 'e,
+        E: Execute<'q, Self::Database>,
+    {
+        let sql = query.sql();
+        let arguments = query.take_arguments();
+
+        let results: Vec<_> = match self.run(sql, arguments) {
+            Ok((rows, done)) => rows
+                .into_iter()
+                .map(|row| Ok(Either::Right(row)))
+                .chain(iter::once(Ok(Either::Left(done))))
+                .collect(),
+
+            Err(error) => vec![Err(error)],
+        };
+
+        Box::pin(stream::iter(results))
+    }
+
+    fn fetch_optional<'e, 'q: 'e, E: 'q>(
+        self,
+        mut query: E,
+    ) -> BoxFuture<'e, Result<Option<MockRow>, Error>>
+    where
+        'c: 'e,
+        E: Execute<'q, Self::Database>,
+    {
+        let sql = query.sql();
+        let arguments = query.take_arguments();
+
+        let row = self
+            .run(sql, arguments)
+            .map(|(rows, _)| rows.into_iter().next());
+
+        Box::pin(future::ready(row))
+    }
+
+    fn prepare_with<'e, 'q: 'e>(
+        self,
+        sql: &'q str,
+        _parameters: &'e [MockTypeInfo],
+    ) -> BoxFuture<'e, Result<MockStatement<'q>, Error>>
+    where
+        'c: 'e,
+    {
+        // take the columns from the rows of the first matching expectation, if there are any
+        let columns = self
+            .expectations
+            .iter()
+            .find(|expected| expected.matches(sql))
+            .and_then(|expected| expected.rows.first())
+            .map(|row| row.columns.clone())
+            .unwrap_or_default();
+
+        Box::pin(future::ok(MockStatement {
+            sql: Cow::Borrowed(sql),
+            columns,
+        }))
+    }
+
+    #[doc(hidden)]
+    fn describe<'e, 'q: 'e>(self, _sql: &'q str) -> BoxFuture<'e, Result<Describe<Mock>, Error>>
+    where
+        'c: 'e,
+    {
+        Box::pin(future::err(err_protocol!(
+            "the mock driver cannot describe statements"
+        )))
+    }
+}
@@ -0,0 +1,49 @@
+use crate::database::{Database, HasArguments, HasStatement, HasValueRef};
+use crate::testing::{
+    MockArguments, MockColumn, MockConnection, MockDone, MockRow, MockStatement,
+    MockTransactionManager, MockTypeInfo, MockValue, MockValueRef,
+};
+
+/// Mock database driver.
+#[derive(Debug)]
+pub struct Mock;
+
+impl Database for Mock {
+    type Connection = MockConnection;
+
+    type TransactionManager = MockTransactionManager;
+
+    type Row = MockRow;
+
+    type Done = MockDone;
+
+    type Column = MockColumn;
+
+    type TypeInfo = MockTypeInfo;
+
+    type Value = MockValue;
+
+    const NAME: &'static str = "other_sql";
+
+    const MAX_BIND_PARAMETERS: usize = 65535;
+}
+
+impl<'r> HasValueRef<'r> for Mock {
+    type Database = Mock;
+
+    type ValueRef = MockValueRef<'r>;
+}
+
+impl<'q> HasArguments<'q> for Mock {
+    type Database = Mock;
+
+    type Arguments = MockArguments;
+
+    type ArgumentBuffer = Vec<MockValue>;
+}
+
+impl<'q> HasStatement<'q> for Mock {
+    type Database = Mock;
+
+    type Statement = MockStatement<'q>;
+}
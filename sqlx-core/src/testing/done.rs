@@ -0,0 +1,24 @@
+use crate::done::Done;
+use crate::testing::Mock;
+use std::iter::{Extend, IntoIterator};
+
+#[derive(Debug, Default)]
+pub struct MockDone {
+    pub(super) rows_affected: u64,
+}
+
+impl Done for MockDone {
+    type Database = Mock;
+
+    fn rows_affected(&self) -> u64 {
+        self.rows_affected
+    }
+}
+
+impl Extend<MockDone> for MockDone {
+    fn extend<T: IntoIterator<Item = MockDone>>(&mut self, iter: T) {
+        for elem in iter {
+            self.rows_affected += elem.rows_affected;
+        }
+    }
+}
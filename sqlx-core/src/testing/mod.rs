@@ -0,0 +1,81 @@
+//! An in-memory **mock** database driver for unit testing code that uses SQLx.
+//!
+//! A [`MockConnection`] is programmed with the statements it should expect, in order, and the
+//! rows to return for each. Every statement executed against it is recorded, together with the
+//! values bound to it, so a test can assert on exactly what was sent.
+//!
+//! ```rust
+//! # use sqlx_core::testing::{MockConnection, MockRow, MockValue};
+//! # use sqlx_core::row::Row;
+//! # sqlx_rt::block_on(async {
+//! let mut conn = MockConnection::new();
+//!
+//! conn.expect("SELECT name FROM users WHERE id = ?")
+//!     .returning(vec![MockRow::new().with("name", "alice")]);
+//!
+//! let row = sqlx_core::query::query("SELECT name FROM users WHERE id = ?")
+//!     .bind(1_i64)
+//!     .fetch_one(&mut conn)
+//!     .await?;
+//!
+//! assert_eq!(row.try_get::<String, _>("name")?, "alice");
+//! assert_eq!(conn.executed()[0].arguments, [MockValue::Int(1)]);
+//! assert!(conn.is_satisfied());
+//! # Ok::<(), sqlx_core::error::Error>(())
+//! # }).unwrap();
+//! ```
+//!
+//! Statements are compared after collapsing runs of whitespace, so a multi-line query matches
+//! the same query written on one line. Executing a statement that does not match the next
+//! expectation fails with [`Error::Protocol`](crate::error::Error::Protocol). Transaction
+//! control statements (`BEGIN`, `COMMIT`, savepoints, ...) are recorded but never need to be
+//! expected.
+//!
+//! For tests that need a real database, [`TestPool`] creates a temporary database per test
+//! and runs the migrations against it.
+
+mod arguments;
+mod column;
+mod connection;
+mod database;
+mod done;
+mod options;
+mod row;
+mod statement;
+#[cfg(feature = "migrate")]
+mod test_pool;
+mod transaction;
+mod type_info;
+mod types;
+mod value;
+
+pub use arguments::MockArguments;
+pub use column::MockColumn;
+pub use connection::{Executed, Expectation, MockConnection};
+pub use database::Mock;
+pub use done::MockDone;
+pub use options::MockConnectOptions;
+pub use row::MockRow;
+pub use statement::MockStatement;
+#[cfg(feature = "migrate")]
+pub use test_pool::{TestArg, TestPool};
+pub use transaction::MockTransactionManager;
+pub use type_info::MockTypeInfo;
+pub use value::{MockValue, MockValueRef};
+
+/// An alias for [`Pool`][crate::pool::Pool], specialized for the mock driver.
+pub type MockPool = crate::pool::Pool<Mock>;
+
+// NOTE: required due to the lack of lazy normalization
+impl_into_arguments_for_arguments!(MockArguments);
+impl_executor_for_pool_connection!(Mock, MockConnection, MockRow);
+impl_executor_for_transaction!(Mock, MockRow);
+impl_map_row!(Mock, MockRow);
+impl_column_index_for_row!(MockRow);
+impl_column_index_for_statement!(MockStatement);
+impl_acquire!(Mock, MockConnection);
+impl_into_maybe_pool!(Mock, MockConnection);
+
+// required because some databases have a different handling of NULL
+impl_encode_for_option!(Mock);
+impl_encode_for_null!(Mock);
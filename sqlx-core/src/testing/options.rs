@@ -0,0 +1,52 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use futures_core::future::BoxFuture;
+use log::LevelFilter;
+
+use crate::connection::ConnectOptions;
+use crate::error::Error;
+use crate::testing::MockConnection;
+
+/// Options for "connecting" to the mock driver.
+///
+/// Every connection starts out without any expectations; this exists so that a
+/// [`MockPool`](crate::testing::MockPool) can be created from the URL `mock:`.
+#[derive(Debug, Clone, Default)]
+pub struct MockConnectOptions {}
+
+impl MockConnectOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl FromStr for MockConnectOptions {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        if s.starts_with("mock:") {
+            Ok(Self::new())
+        } else {
+            Err(Error::Configuration(
+                format!("expected a URL of the form `mock:`, got {:?}", s).into(),
+            ))
+        }
+    }
+}
+
+impl ConnectOptions for MockConnectOptions {
+    type Connection = MockConnection;
+
+    fn connect(&self) -> BoxFuture<'_, Result<MockConnection, Error>> {
+        Box::pin(async move { Ok(MockConnection::new()) })
+    }
+
+    fn log_statements(&mut self, _level: LevelFilter) -> &mut Self {
+        self
+    }
+
+    fn log_slow_statements(&mut self, _level: LevelFilter, _duration: Duration) -> &mut Self {
+        self
+    }
+}
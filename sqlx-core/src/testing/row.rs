@@ -0,0 +1,63 @@
+use crate::column::ColumnIndex;
+use crate::error::Error;
+use crate::row::Row;
+use crate::testing::{Mock, MockColumn, MockTypeInfo, MockValue, MockValueRef};
+
+/// Implementation of [`Row`] for the mock driver.
+///
+/// Rows are built up one column at a time with [`with`](MockRow::with) and handed to
+/// [`Expectation::returning`](crate::testing::Expectation::returning).
+#[derive(Debug, Clone, Default)]
+pub struct MockRow {
+    pub(crate) columns: Vec<MockColumn>,
+    pub(crate) values: Vec<MockValue>,
+}
+
+impl MockRow {
+    /// Creates a row without any columns.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a column with the given name and value to this row.
+    pub fn with(mut self, name: &str, value: impl Into<MockValue>) -> Self {
+        let value = value.into();
+
+        self.columns.push(MockColumn {
+            name: name.to_owned().into(),
+            ordinal: self.columns.len(),
+            type_info: MockTypeInfo::of(&value),
+        });
+
+        self.values.push(value);
+        self
+    }
+}
+
+impl crate::row::private_row::Sealed for MockRow {}
+
+impl Row for MockRow {
+    type Database = Mock;
+
+    fn columns(&self) -> &[MockColumn] {
+        &self.columns
+    }
+
+    fn try_get_raw<I>(&self, index: I) -> Result<MockValueRef<'_>, Error>
+    where
+        I: ColumnIndex<Self>,
+    {
+        let index = index.index(self)?;
+
+        Ok(MockValueRef(&self.values[index]))
+    }
+}
+
+impl ColumnIndex<MockRow> for &'_ str {
+    fn index(&self, row: &MockRow) -> Result<usize, Error> {
+        row.columns
+            .iter()
+            .position(|column| &*column.name == *self)
+            .ok_or_else(|| Error::ColumnNotFound((*self).into()))
+    }
+}
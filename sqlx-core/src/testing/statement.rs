@@ -0,0 +1,48 @@
+use crate::column::ColumnIndex;
+use crate::error::Error;
+use crate::statement::Statement;
+use crate::testing::{Mock, MockArguments, MockColumn, MockTypeInfo};
+use either::Either;
+use std::borrow::Cow;
+
+#[derive(Debug, Clone)]
+pub struct MockStatement<'q> {
+    pub(crate) sql: Cow<'q, str>,
+    pub(crate) columns: Vec<MockColumn>,
+}
+
+impl<'q> Statement<'q> for MockStatement<'q> {
+    type Database = Mock;
+
+    fn to_owned(&self) -> MockStatement<'static> {
+        MockStatement::<'static> {
+            sql: Cow::Owned(self.sql.clone().into_owned()),
+            columns: self.columns.clone(),
+        }
+    }
+
+    fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    fn parameters(&self) -> Option<Either<&[MockTypeInfo], usize>> {
+        // the mock driver never parses SQL so it cannot know how many parameters there are
+        None
+    }
+
+    fn columns(&self) -> &[MockColumn] {
+        &self.columns
+    }
+
+    impl_statement_query!(MockArguments);
+}
+
+impl ColumnIndex<MockStatement<'_>> for &'_ str {
+    fn index(&self, statement: &MockStatement<'_>) -> Result<usize, Error> {
+        statement
+            .columns
+            .iter()
+            .position(|column| &*column.name == *self)
+            .ok_or_else(|| Error::ColumnNotFound((*self).into()))
+    }
+}
@@ -0,0 +1,281 @@
+use std::borrow::Cow;
+use std::ops::Deref;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use url::Url;
+
+use crate::database::Database;
+use crate::error::Error;
+use crate::executor::Executor;
+use crate::migrate::{Migrate, MigrateDatabase, Migrator};
+use crate::pool::{Pool, PoolOptions};
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A [`Pool`] to a temporary database created for a single test.
+///
+/// [`TestPool::new`] creates a uniquely named database on the server that the given URL points
+/// to and runs the migrations against it, so every test starts from a fresh schema without
+/// touching a shared development database. The database is dropped when the `TestPool` is,
+/// including when a test fails or panics.
+///
+/// ```rust,ignore
+/// static MIGRATOR: Migrator = sqlx::migrate!();
+///
+/// #[tokio::test]
+/// async fn it_creates_users() -> anyhow::Result<()> {
+///     let pool = TestPool::<Postgres>::new(&env::var("DATABASE_URL")?, &MIGRATOR).await?;
+///
+///     sqlx::query("INSERT INTO users (name) VALUES ('alice')").execute(&*pool).await?;
+///
+///     pool.close().await?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// Prefer calling [`close`](TestPool::close) at the end of a test: it closes the pool before
+/// dropping the database and reports any error. Dropping a `TestPool` instead blocks until the
+/// database has been dropped from a separate thread, and ignores errors. Every test database is
+/// named `_sqlx_test_<...>`, so anything left behind (e.g. by a killed test process) is easy to
+/// find and remove.
+///
+/// For SQLite, the URL must name a file; the test database is created as a new file in the
+/// same directory.
+pub struct TestPool<DB>
+where
+    DB: Database + MigrateDatabase,
+{
+    // `None` once closed
+    pool: Option<Pool<DB>>,
+    url: String,
+}
+
+impl<DB> TestPool<DB>
+where
+    DB: Database + MigrateDatabase,
+    DB::Connection: Migrate,
+{
+    /// Creates a new test database next to the one `url` points to, runs `migrator` against it
+    /// and connects a pool to it with the default [`PoolOptions`].
+    pub async fn new(url: &str, migrator: &Migrator) -> Result<Self, Error> {
+        Self::with_options(url, migrator, PoolOptions::new()).await
+    }
+
+    /// Creates a new test database next to the one `url` points to, runs `migrator` against it
+    /// and connects a pool to it with the given `options`.
+    pub async fn with_options(
+        url: &str,
+        migrator: &Migrator,
+        options: PoolOptions<DB>,
+    ) -> Result<Self, Error> {
+        let url = test_database_url(url, &test_database_name())?;
+
+        DB::create_database(&url).await?;
+
+        // from here on the database exists and must be dropped again if anything fails
+        match connect_and_migrate(&url, migrator, options).await {
+            Ok(pool) => Ok(Self {
+                pool: Some(pool),
+                url,
+            }),
+
+            Err(error) => {
+                let _ = DB::drop_database(&url).await;
+
+                Err(error)
+            }
+        }
+    }
+
+    /// Creates a new, empty test database next to the one `url` points to, without running
+    /// any migrations.
+    pub async fn empty(url: &str) -> Result<Self, Error> {
+        let migrator = Migrator {
+            migrations: Cow::Borrowed(&[]),
+        };
+
+        Self::new(url, &migrator).await
+    }
+
+    /// Executes the (possibly multi-statement) SQL of a fixture against the test database.
+    pub async fn apply_fixture(&self, sql: &str) -> Result<(), Error>
+    where
+        for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    {
+        let mut conn = self.acquire().await?;
+
+        (&mut *conn).execute(sql).await?;
+
+        Ok(())
+    }
+
+    /// The URL of the test database.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Closes the pool and drops the test database.
+    pub async fn close(mut self) -> Result<(), Error> {
+        if let Some(pool) = self.pool.take() {
+            pool.close().await;
+        }
+
+        DB::force_drop_database(&self.url).await
+    }
+}
+
+impl<DB> Deref for TestPool<DB>
+where
+    DB: Database + MigrateDatabase,
+{
+    type Target = Pool<DB>;
+
+    fn deref(&self) -> &Pool<DB> {
+        self.pool.as_ref().expect("BUG: TestPool used after close")
+    }
+}
+
+impl<DB> Drop for TestPool<DB>
+where
+    DB: Database + MigrateDatabase,
+{
+    fn drop(&mut self) {
+        let pool = match self.pool.take() {
+            Some(pool) => pool,
+            None => return,
+        };
+
+        // closing the pool needs the runtime this is being dropped on, which may be shutting
+        // down or be blocked by us; dropping it closes the idle connections without waiting,
+        // and a forced drop takes care of any backend that has not exited yet
+        drop(pool);
+
+        let url = self.url.clone();
+
+        // there may not be a runtime to spawn on, or it may stop before a spawned task runs
+        let dropped = thread::spawn(move || {
+            let _ = sqlx_rt::block_on(DB::force_drop_database(&url));
+        });
+
+        let _ = dropped.join();
+    }
+}
+
+/// The argument a `#[sqlx::test]` function can take to receive the pool of its own test
+/// database.
+#[doc(hidden)]
+pub trait TestArg {
+    type Database: Database + MigrateDatabase;
+
+    fn from_test_pool(pool: &TestPool<Self::Database>) -> Self;
+}
+
+impl<DB> TestArg for Pool<DB>
+where
+    DB: Database + MigrateDatabase,
+{
+    type Database = DB;
+
+    fn from_test_pool(pool: &TestPool<DB>) -> Self {
+        Pool::clone(pool)
+    }
+}
+
+async fn connect_and_migrate<DB>(
+    url: &str,
+    migrator: &Migrator,
+    options: PoolOptions<DB>,
+) -> Result<Pool<DB>, Error>
+where
+    DB: Database,
+    DB::Connection: Migrate,
+{
+    let pool = options.connect(url).await?;
+
+    if let Err(error) = migrator.run(&pool).await {
+        pool.close().await;
+
+        return Err(error.into());
+    }
+
+    Ok(pool)
+}
+
+// unique across concurrently running tests in one process and across test runs
+fn test_database_name() -> String {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+
+    format!(
+        "_sqlx_test_{}_{}_{}",
+        timestamp,
+        std::process::id(),
+        NEXT_ID.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+// replaces the database in `url` with `name`
+fn test_database_url(url: &str, name: &str) -> Result<String, Error> {
+    if url.starts_with("sqlite:") {
+        let path = url
+            .trim_start_matches("sqlite://")
+            .trim_start_matches("sqlite:");
+
+        let (path, params) = match path.find('?') {
+            Some(pos) => path.split_at(pos),
+            None => (path, ""),
+        };
+
+        if path.is_empty() || path == ":memory:" || params.contains("mode=memory") {
+            return Err(Error::Configuration(
+                "a test database cannot be created next to an in-memory SQLite database".into(),
+            ));
+        }
+
+        let dir = match path.rfind('/') {
+            Some(pos) => &path[..=pos],
+            None => "",
+        };
+
+        return Ok(format!("sqlite://{}{}.db{}", dir, name, params));
+    }
+
+    let mut url: Url = url.parse().map_err(Error::config)?;
+    url.set_path(&format!("/{}", name));
+
+    Ok(url.into())
+}
+
+#[test]
+fn it_replaces_the_database_in_the_url() -> Result<(), Error> {
+    assert_eq!(
+        test_database_url("postgres://u:p@localhost:5432/app?sslmode=disable", "t")?,
+        "postgres://u:p@localhost:5432/t?sslmode=disable"
+    );
+
+    assert_eq!(
+        test_database_url("mysql://localhost", "t")?,
+        "mysql://localhost/t"
+    );
+
+    assert_eq!(
+        test_database_url("sqlite://tests/sqlite/app.db?mode=rwc", "t")?,
+        "sqlite://tests/sqlite/t.db?mode=rwc"
+    );
+
+    assert_eq!(test_database_url("sqlite:app.db", "t")?, "sqlite://t.db");
+
+    assert!(test_database_url("sqlite::memory:", "t").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn it_names_test_databases_uniquely() {
+    assert_ne!(test_database_name(), test_database_name());
+}
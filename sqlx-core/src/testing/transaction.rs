@@ -0,0 +1,71 @@
+use futures_core::future::BoxFuture;
+
+use crate::error::Error;
+use crate::testing::{Mock, MockConnection};
+use crate::transaction::{
+    begin_ansi_transaction_sql, commit_ansi_transaction_sql, rollback_ansi_transaction_sql,
+    savepoint_ansi_sql, SavepointCommand, TransactionManager,
+};
+
+/// Implementation of [`TransactionManager`] for the mock driver.
+///
+/// Transaction control statements are recorded on the connection but never matched
+/// against its expectations.
+pub struct MockTransactionManager;
+
+impl TransactionManager for MockTransactionManager {
+    type Database = Mock;
+
+    fn begin(conn: &mut MockConnection) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            let depth = conn.transaction_depth;
+
+            conn.record(&begin_ansi_transaction_sql(depth));
+            conn.transaction_depth = depth + 1;
+
+            Ok(())
+        })
+    }
+
+    fn commit(conn: &mut MockConnection) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            let depth = conn.transaction_depth;
+
+            if depth > 0 {
+                conn.record(&commit_ansi_transaction_sql(depth));
+                conn.transaction_depth = depth - 1;
+            }
+
+            Ok(())
+        })
+    }
+
+    fn rollback(conn: &mut MockConnection) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            Self::start_rollback(conn);
+
+            Ok(())
+        })
+    }
+
+    fn start_rollback(conn: &mut MockConnection) {
+        let depth = conn.transaction_depth;
+
+        if depth > 0 {
+            conn.record(&rollback_ansi_transaction_sql(depth));
+            conn.transaction_depth = depth - 1;
+        }
+    }
+
+    fn savepoint<'c>(
+        conn: &'c mut MockConnection,
+        command: SavepointCommand,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            conn.record(&savepoint_ansi_sql(command, name));
+
+            Ok(())
+        })
+    }
+}
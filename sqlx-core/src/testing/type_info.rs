@@ -0,0 +1,51 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::testing::MockValue;
+use crate::type_info::TypeInfo;
+
+/// Type information for a mock value.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MockTypeInfo {
+    Null,
+    Bool,
+    Int,
+    Float,
+    Text,
+    Blob,
+}
+
+impl MockTypeInfo {
+    pub(crate) fn of(value: &MockValue) -> Self {
+        match value {
+            MockValue::Null => MockTypeInfo::Null,
+            MockValue::Bool(_) => MockTypeInfo::Bool,
+            MockValue::Int(_) => MockTypeInfo::Int,
+            MockValue::Float(_) => MockTypeInfo::Float,
+            MockValue::Text(_) => MockTypeInfo::Text,
+            MockValue::Blob(_) => MockTypeInfo::Blob,
+        }
+    }
+}
+
+impl Display for MockTypeInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl TypeInfo for MockTypeInfo {
+    fn is_null(&self) -> bool {
+        matches!(self, MockTypeInfo::Null)
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            MockTypeInfo::Null => "NULL",
+            MockTypeInfo::Bool => "BOOLEAN",
+            MockTypeInfo::Int => "INTEGER",
+            MockTypeInfo::Float => "REAL",
+            MockTypeInfo::Text => "TEXT",
+            MockTypeInfo::Blob => "BLOB",
+        }
+    }
+}
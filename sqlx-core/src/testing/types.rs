@@ -0,0 +1,208 @@
+//! Conversions between Rust and mock values.
+//!
+//! | Rust type                             | Mock type |
+//! |---------------------------------------|-----------|
+//! | `bool`                                | BOOLEAN   |
+//! | `i8`, `i16`, `i32`, `i64`             | INTEGER   |
+//! | `f32`, `f64`                          | REAL      |
+//! | `&str`, `String`                      | TEXT      |
+//! | `&[u8]`, `Vec<u8>`                    | BLOB      |
+
+use std::convert::TryFrom;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::testing::{Mock, MockTypeInfo, MockValue, MockValueRef};
+use crate::types::Type;
+
+fn unexpected(expected: MockTypeInfo, value: &MockValue) -> BoxDynError {
+    format!(
+        "expected a mock value of type {} but found {:?}",
+        expected, value
+    )
+    .into()
+}
+
+impl Type<Mock> for bool {
+    fn type_info() -> MockTypeInfo {
+        MockTypeInfo::Bool
+    }
+}
+
+impl Encode<'_, Mock> for bool {
+    fn encode_by_ref(&self, buf: &mut Vec<MockValue>) -> IsNull {
+        buf.push(MockValue::Bool(*self));
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Mock> for bool {
+    fn decode(value: MockValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.0 {
+            MockValue::Bool(v) => Ok(*v),
+            other => Err(unexpected(MockTypeInfo::Bool, other)),
+        }
+    }
+}
+
+macro_rules! impl_mock_int {
+    ($($ty:ty),*) => {
+        $(
+            impl Type<Mock> for $ty {
+                fn type_info() -> MockTypeInfo {
+                    MockTypeInfo::Int
+                }
+            }
+
+            impl Encode<'_, Mock> for $ty {
+                fn encode_by_ref(&self, buf: &mut Vec<MockValue>) -> IsNull {
+                    buf.push(MockValue::Int((*self).into()));
+
+                    IsNull::No
+                }
+            }
+
+            impl Decode<'_, Mock> for $ty {
+                fn decode(value: MockValueRef<'_>) -> Result<Self, BoxDynError> {
+                    match value.0 {
+                        MockValue::Int(v) => Ok(<$ty>::try_from(*v)?),
+                        other => Err(unexpected(MockTypeInfo::Int, other)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_mock_int!(i8, i16, i32, i64);
+
+macro_rules! impl_mock_float {
+    ($($ty:ty),*) => {
+        $(
+            impl Type<Mock> for $ty {
+                fn type_info() -> MockTypeInfo {
+                    MockTypeInfo::Float
+                }
+            }
+
+            impl Encode<'_, Mock> for $ty {
+                fn encode_by_ref(&self, buf: &mut Vec<MockValue>) -> IsNull {
+                    buf.push(MockValue::Float((*self).into()));
+
+                    IsNull::No
+                }
+            }
+
+            impl Decode<'_, Mock> for $ty {
+                fn decode(value: MockValueRef<'_>) -> Result<Self, BoxDynError> {
+                    match value.0 {
+                        MockValue::Float(v) => Ok(*v as $ty),
+                        other => Err(unexpected(MockTypeInfo::Float, other)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_mock_float!(f32, f64);
+
+impl Type<Mock> for str {
+    fn type_info() -> MockTypeInfo {
+        MockTypeInfo::Text
+    }
+}
+
+impl Type<Mock> for String {
+    fn type_info() -> MockTypeInfo {
+        MockTypeInfo::Text
+    }
+}
+
+impl<'q> Encode<'q, Mock> for &'q str {
+    fn encode_by_ref(&self, buf: &mut Vec<MockValue>) -> IsNull {
+        buf.push(MockValue::Text((*self).to_owned()));
+
+        IsNull::No
+    }
+}
+
+impl Encode<'_, Mock> for String {
+    fn encode(self, buf: &mut Vec<MockValue>) -> IsNull {
+        buf.push(MockValue::Text(self));
+
+        IsNull::No
+    }
+
+    fn encode_by_ref(&self, buf: &mut Vec<MockValue>) -> IsNull {
+        buf.push(MockValue::Text(self.clone()));
+
+        IsNull::No
+    }
+}
+
+impl<'r> Decode<'r, Mock> for &'r str {
+    fn decode(value: MockValueRef<'r>) -> Result<Self, BoxDynError> {
+        match value.0 {
+            MockValue::Text(v) => Ok(v.as_str()),
+            other => Err(unexpected(MockTypeInfo::Text, other)),
+        }
+    }
+}
+
+impl Decode<'_, Mock> for String {
+    fn decode(value: MockValueRef<'_>) -> Result<Self, BoxDynError> {
+        <&str as Decode<Mock>>::decode(value).map(ToOwned::to_owned)
+    }
+}
+
+impl Type<Mock> for [u8] {
+    fn type_info() -> MockTypeInfo {
+        MockTypeInfo::Blob
+    }
+}
+
+impl Type<Mock> for Vec<u8> {
+    fn type_info() -> MockTypeInfo {
+        MockTypeInfo::Blob
+    }
+}
+
+impl<'q> Encode<'q, Mock> for &'q [u8] {
+    fn encode_by_ref(&self, buf: &mut Vec<MockValue>) -> IsNull {
+        buf.push(MockValue::Blob((*self).to_owned()));
+
+        IsNull::No
+    }
+}
+
+impl Encode<'_, Mock> for Vec<u8> {
+    fn encode(self, buf: &mut Vec<MockValue>) -> IsNull {
+        buf.push(MockValue::Blob(self));
+
+        IsNull::No
+    }
+
+    fn encode_by_ref(&self, buf: &mut Vec<MockValue>) -> IsNull {
+        buf.push(MockValue::Blob(self.clone()));
+
+        IsNull::No
+    }
+}
+
+impl<'r> Decode<'r, Mock> for &'r [u8] {
+    fn decode(value: MockValueRef<'r>) -> Result<Self, BoxDynError> {
+        match value.0 {
+            MockValue::Blob(v) => Ok(v.as_slice()),
+            other => Err(unexpected(MockTypeInfo::Blob, other)),
+        }
+    }
+}
+
+impl Decode<'_, Mock> for Vec<u8> {
+    fn decode(value: MockValueRef<'_>) -> Result<Self, BoxDynError> {
+        <&[u8] as Decode<Mock>>::decode(value).map(ToOwned::to_owned)
+    }
+}
@@ -0,0 +1,86 @@
+use std::borrow::Cow;
+
+use crate::testing::{Mock, MockTypeInfo};
+use crate::value::{Value, ValueRef};
+
+/// A value bound to, or returned by, a [`MockConnection`](crate::testing::MockConnection).
+#[derive(Debug, Clone, PartialEq)]
+pub enum MockValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+/// Implementation of [`ValueRef`] for the mock driver.
+#[derive(Debug, Clone, Copy)]
+pub struct MockValueRef<'r>(pub(crate) &'r MockValue);
+
+impl Value for MockValue {
+    type Database = Mock;
+
+    fn as_ref(&self) -> MockValueRef<'_> {
+        MockValueRef(self)
+    }
+
+    fn type_info(&self) -> Cow<'_, MockTypeInfo> {
+        Cow::Owned(MockTypeInfo::of(self))
+    }
+
+    fn is_null(&self) -> bool {
+        matches!(self, MockValue::Null)
+    }
+}
+
+impl<'r> ValueRef<'r> for MockValueRef<'r> {
+    type Database = Mock;
+
+    fn to_owned(&self) -> MockValue {
+        self.0.clone()
+    }
+
+    fn type_info(&self) -> Cow<'_, MockTypeInfo> {
+        Cow::Owned(MockTypeInfo::of(self.0))
+    }
+
+    fn is_null(&self) -> bool {
+        matches!(self.0, MockValue::Null)
+    }
+}
+
+macro_rules! impl_from_for_mock_value {
+    ($($ty:ty => |$v:ident| $expr:expr),* $(,)?) => {
+        $(
+            impl From<$ty> for MockValue {
+                fn from($v: $ty) -> Self {
+                    $expr
+                }
+            }
+        )*
+    };
+}
+
+impl_from_for_mock_value! {
+    bool => |v| MockValue::Bool(v),
+    i8 => |v| MockValue::Int(v.into()),
+    i16 => |v| MockValue::Int(v.into()),
+    i32 => |v| MockValue::Int(v.into()),
+    i64 => |v| MockValue::Int(v),
+    f32 => |v| MockValue::Float(v.into()),
+    f64 => |v| MockValue::Float(v),
+    &'_ str => |v| MockValue::Text(v.to_owned()),
+    String => |v| MockValue::Text(v),
+    &'_ [u8] => |v| MockValue::Blob(v.to_owned()),
+    Vec<u8> => |v| MockValue::Blob(v),
+}
+
+impl<T> From<Option<T>> for MockValue
+where
+    T: Into<MockValue>,
+{
+    fn from(value: Option<T>) -> Self {
+        value.map_or(MockValue::Null, Into::into)
+    }
+}
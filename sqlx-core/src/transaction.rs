@@ -6,6 +6,7 @@ use futures_core::future::BoxFuture;
 
 use crate::database::Database;
 use crate::error::Error;
+use crate::executor::Executor;
 use crate::pool::MaybePoolConnection;
 
 /// Generic management of database transactions.
@@ -32,6 +33,35 @@ pub trait TransactionManager {
 
     /// Starts to abort the active transaction or restore from the most recent snapshot.
     fn start_rollback(conn: &mut <Self::Database as Database>::Connection);
+
+    /// Establish, release or restore from the named savepoint within the active transaction.
+    ///
+    /// By default, this executes the ANSI SQL `SAVEPOINT`, `RELEASE SAVEPOINT` or
+    /// `ROLLBACK TO SAVEPOINT` statement.
+    fn savepoint<'c>(
+        conn: &'c mut <Self::Database as Database>::Connection,
+        command: SavepointCommand,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>>
+    where
+        for<'e> &'e mut <Self::Database as Database>::Connection:
+            Executor<'e, Database = Self::Database>,
+    {
+        Box::pin(async move {
+            conn.execute(&*savepoint_ansi_sql(command, name)).await?;
+
+            Ok(())
+        })
+    }
+}
+
+/// A command on a named savepoint, passed to [`TransactionManager::savepoint`].
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SavepointCommand {
+    Create,
+    Release,
+    RollbackTo,
 }
 
 /// An in-progress database transaction or savepoint.
@@ -94,6 +124,167 @@ where
     }
 }
 
+impl<'c, DB> Transaction<'c, DB>
+where
+    DB: Database,
+    for<'e> &'e mut DB::Connection: Executor<'e, Database = DB>,
+{
+    /// Establishes a savepoint with the given name in this transaction.
+    ///
+    /// Unlike a nested transaction started with [`Connection::begin`], the savepoint is not
+    /// rolled back when the returned guard is dropped; it stays in place, and may still be
+    /// referred to with [`rollback_to`] or [`release`], until it is released or the
+    /// transaction ends. Establishing a savepoint with the name of an existing one hides the
+    /// existing savepoint until the new one is released.
+    ///
+    /// The name must be an identifier consisting of ASCII letters, digits and underscores.
+    ///
+    /// ```rust,ignore
+    /// let mut tx = pool.begin().await?;
+    ///
+    /// let mut savepoint = tx.savepoint("before_import").await?;
+    ///
+    /// if let Err(e) = import(&mut savepoint).await {
+    ///     log::warn!("import failed: {}", e);
+    ///     savepoint.rollback().await?;
+    /// }
+    ///
+    /// tx.commit().await?;
+    /// ```
+    ///
+    /// [`Connection::begin`]: crate::connection::Connection::begin
+    /// [`rollback_to`]: #method.rollback_to
+    /// [`release`]: #method.release
+    pub async fn savepoint(&mut self, name: &str) -> Result<Savepoint<'_, DB>, Error> {
+        check_savepoint_name(name)?;
+
+        DB::TransactionManager::savepoint(&mut self.connection, SavepointCommand::Create, name)
+            .await?;
+
+        Ok(Savepoint {
+            connection: &mut self.connection,
+            name: name.to_owned(),
+        })
+    }
+
+    /// Restores the transaction to the state it was in when the named savepoint was
+    /// established. The savepoint remains in place.
+    pub async fn rollback_to(&mut self, name: &str) -> Result<(), Error> {
+        check_savepoint_name(name)?;
+
+        DB::TransactionManager::savepoint(&mut self.connection, SavepointCommand::RollbackTo, name)
+            .await
+    }
+
+    /// Releases the named savepoint, and any savepoints established after it, keeping the
+    /// changes made since it was established.
+    pub async fn release(&mut self, name: &str) -> Result<(), Error> {
+        check_savepoint_name(name)?;
+
+        DB::TransactionManager::savepoint(&mut self.connection, SavepointCommand::Release, name)
+            .await
+    }
+}
+
+/// A named savepoint established with [`Transaction::savepoint`].
+///
+/// Dereferences to the connection of the transaction, so that queries may be executed with
+/// `&mut *savepoint`.
+pub struct Savepoint<'t, DB>
+where
+    DB: Database,
+{
+    connection: &'t mut DB::Connection,
+    name: String,
+}
+
+impl<'t, DB> Savepoint<'t, DB>
+where
+    DB: Database,
+{
+    /// Returns the name of this savepoint.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<'t, DB> Savepoint<'t, DB>
+where
+    DB: Database,
+    for<'e> &'e mut DB::Connection: Executor<'e, Database = DB>,
+{
+    /// Releases this savepoint, keeping the changes made since it was established.
+    pub async fn release(self) -> Result<(), Error> {
+        DB::TransactionManager::savepoint(self.connection, SavepointCommand::Release, &self.name)
+            .await
+    }
+
+    /// Restores the transaction to the state it was in when this savepoint was established,
+    /// and releases the savepoint.
+    pub async fn rollback(self) -> Result<(), Error> {
+        DB::TransactionManager::savepoint(
+            self.connection,
+            SavepointCommand::RollbackTo,
+            &self.name,
+        )
+        .await?;
+
+        DB::TransactionManager::savepoint(self.connection, SavepointCommand::Release, &self.name)
+            .await
+    }
+}
+
+impl<'t, DB> Debug for Savepoint<'t, DB>
+where
+    DB: Database,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Savepoint")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+impl<'t, DB> Deref for Savepoint<'t, DB>
+where
+    DB: Database,
+{
+    type Target = DB::Connection;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.connection
+    }
+}
+
+impl<'t, DB> DerefMut for Savepoint<'t, DB>
+where
+    DB: Database,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.connection
+    }
+}
+
+// savepoint names are interpolated into SQL, so only plain identifiers are accepted
+fn check_savepoint_name(name: &str) -> Result<(), Error> {
+    let valid = name
+        .chars()
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !name.starts_with("_sqlx_savepoint_");
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::Configuration(
+            format!("invalid savepoint name: {:?}", name).into(),
+        ))
+    }
+}
+
 // NOTE: required due to lack of lazy normalization
 #[allow(unused_macros)]
 macro_rules! impl_executor_for_transaction {
@@ -223,6 +414,14 @@ pub(crate) fn begin_ansi_transaction_sql(depth: usize) -> Cow<'static, str> {
     }
 }
 
+pub(crate) fn savepoint_ansi_sql(command: SavepointCommand, name: &str) -> String {
+    match command {
+        SavepointCommand::Create => format!("SAVEPOINT {}", name),
+        SavepointCommand::Release => format!("RELEASE SAVEPOINT {}", name),
+        SavepointCommand::RollbackTo => format!("ROLLBACK TO SAVEPOINT {}", name),
+    }
+}
+
 #[allow(dead_code)]
 pub(crate) fn commit_ansi_transaction_sql(depth: usize) -> Cow<'static, str> {
     if depth == 1 {
@@ -243,3 +442,14 @@ pub(crate) fn rollback_ansi_transaction_sql(depth: usize) -> Cow<'static, str> {
         ))
     }
 }
+
+#[test]
+fn it_checks_savepoint_names() {
+    assert!(check_savepoint_name("before_import").is_ok());
+    assert!(check_savepoint_name("_sp1").is_ok());
+
+    assert!(check_savepoint_name("").is_err());
+    assert!(check_savepoint_name("1sp").is_err());
+    assert!(check_savepoint_name("sp; DROP TABLE users").is_err());
+    assert!(check_savepoint_name("_sqlx_savepoint_1").is_err());
+}
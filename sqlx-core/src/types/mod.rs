@@ -17,6 +17,25 @@
 //! To represents nullable SQL types, `Option<T>` is supported where `T` implements `Type`.
 //! An `Option<T>` represents a potentially `NULL` value from SQL.
 //!
+//! # Borrowed and shared strings
+//!
+//! Besides `&str` and `String`, strings can be bound as `Cow<'_, str>`, `Box<str>` and
+//! `Arc<str>` without first being copied into a `String`.
+//!
+//! # Non-zero integers
+//!
+//! The integer types of `std::num`, such as `NonZeroI32`, are supported wherever their
+//! primitive is. Decoding a zero as one of them is an error.
+//!
+//! # Text
+//!
+//! Types that have a textual form but no SQL type of their own, such as `url::Url`, can be
+//! stored in a text column by wrapping them in [`Text`], which encodes with `Display` and
+//! decodes with `FromStr`.
+//!
+
+use std::borrow::Cow;
+use std::sync::Arc;
 
 use crate::database::Database;
 
@@ -24,6 +43,10 @@ use crate::database::Database;
 #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
 mod json;
 
+mod non_zero;
+mod null;
+mod text;
+
 #[cfg(feature = "uuid")]
 #[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
 pub use uuid::{self, Uuid};
@@ -63,6 +86,9 @@ pub mod ipnetwork {
 #[cfg(feature = "json")]
 pub use json::Json;
 
+pub use null::Null;
+pub use text::Text;
+
 /// Indicates that a SQL type is supported for a database.
 ///
 /// ## Compile-time verification
@@ -177,6 +203,37 @@ impl<T: ?Sized + Type<DB>, DB: Database> Type<DB> for &'_ T {
     }
 }
 
+// for smart pointers, the underlying SQL type is identical
+impl<T: ?Sized + Type<DB>, DB: Database> Type<DB> for Box<T> {
+    fn type_info() -> DB::TypeInfo {
+        <T as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <T as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<T: ?Sized + Type<DB>, DB: Database> Type<DB> for Arc<T> {
+    fn type_info() -> DB::TypeInfo {
+        <T as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <T as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<T: ?Sized + ToOwned + Type<DB>, DB: Database> Type<DB> for Cow<'_, T> {
+    fn type_info() -> DB::TypeInfo {
+        <T as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <T as Type<DB>>::compatible(ty)
+    }
+}
+
 // for optionals, the underlying SQL type is identical
 impl<T: Type<DB>, DB: Database> Type<DB> for Option<T> {
     fn type_info() -> DB::TypeInfo {
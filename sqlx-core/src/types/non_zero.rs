@@ -0,0 +1,70 @@
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8,
+};
+
+use crate::database::{Database, HasArguments, HasValueRef};
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+
+// a non-zero integer is stored as its primitive; decoding a zero is an error
+macro_rules! impl_non_zero {
+    ($($non_zero:ident($int:ident)),* $(,)?) => {$(
+        impl<DB> Type<DB> for $non_zero
+        where
+            $int: Type<DB>,
+            DB: Database,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <$int as Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <$int as Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'q, DB> Encode<'q, DB> for $non_zero
+        where
+            $int: Encode<'q, DB>,
+            DB: Database,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer,
+            ) -> IsNull {
+                <$int as Encode<'q, DB>>::encode(self.get(), buf)
+            }
+
+            fn size_hint(&self) -> usize {
+                <$int as Encode<'q, DB>>::size_hint(&self.get())
+            }
+        }
+
+        impl<'r, DB> Decode<'r, DB> for $non_zero
+        where
+            $int: Decode<'r, DB>,
+            DB: Database,
+        {
+            fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+                let value = <$int as Decode<'r, DB>>::decode(value)?;
+
+                $non_zero::new(value).ok_or_else(|| {
+                    concat!("unexpected zero when decoding `", stringify!($non_zero), "`").into()
+                })
+            }
+        }
+    )*};
+}
+
+impl_non_zero!(
+    NonZeroI8(i8),
+    NonZeroI16(i16),
+    NonZeroI32(i32),
+    NonZeroI64(i64),
+    NonZeroU8(u8),
+    NonZeroU16(u16),
+    NonZeroU32(u32),
+    NonZeroU64(u64),
+);
@@ -0,0 +1,65 @@
+use std::fmt::{self, Debug, Formatter};
+use std::marker::PhantomData;
+
+use crate::database::Database;
+use crate::types::Type;
+
+/// A `NULL` of the SQL type of `T`.
+///
+/// A database cannot infer the type of a parameter that is only used in, e.g., `SELECT $1` or
+/// `COALESCE($1, $2)`, so it takes the type that SQLx sends for the bound value. `Null<T>` is
+/// sent as a `NULL` of the type of `T`, like `None::<T>`, without needing an `Option` of the
+/// right type at hand.
+///
+/// ```rust,ignore
+/// use sqlx::types::Null;
+///
+/// let (value,): (Option<i64>,) = sqlx::query_as("SELECT COALESCE($1, $2)")
+///     .bind(Null::<i64>::new())
+///     .bind(Null::<i64>::new())
+///     .fetch_one(&mut conn)
+///     .await?;
+/// ```
+///
+/// MSSQL sends every `NULL` with a type of its own, so there `Null<T>` is the same as `None`.
+pub struct Null<T>(PhantomData<fn() -> T>);
+
+impl<T> Null<T> {
+    pub fn new() -> Self {
+        Null(PhantomData)
+    }
+}
+
+impl<T> Default for Null<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for Null<T> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Copy for Null<T> {}
+
+impl<T> Debug for Null<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("NULL")
+    }
+}
+
+impl<T, DB> Type<DB> for Null<T>
+where
+    T: Type<DB>,
+    DB: Database,
+{
+    fn type_info() -> DB::TypeInfo {
+        T::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        T::compatible(ty)
+    }
+}
@@ -0,0 +1,107 @@
+use std::fmt::Display;
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+
+use crate::database::{Database, HasArguments, HasValueRef};
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+
+/// Stores a value as text, using its [`Display`] implementation to encode and its [`FromStr`]
+/// implementation to decode.
+///
+/// This is useful for types that have a well-defined textual form but no SQL type of their own,
+/// such as `url::Url` or `semver::Version`.
+///
+/// ```rust,ignore
+/// use sqlx::types::Text;
+/// use url::Url;
+///
+/// let homepage: Url = "https://example.com/".parse()?;
+///
+/// sqlx::query("INSERT INTO sites (homepage) VALUES ($1)")
+///     .bind(Text(&homepage))
+///     .execute(&pool)
+///     .await?;
+///
+/// let (homepage,): (Text<Url>,) = sqlx::query_as("SELECT homepage FROM sites")
+///     .fetch_one(&pool)
+///     .await?;
+/// ```
+///
+/// With the query macros, override the type of the column as `Text<Url>`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Text<T>(pub T);
+
+impl<T> Text<T> {
+    /// Extract the inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Text<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Text<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> AsRef<T> for Text<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsMut<T> for Text<T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T, DB> Type<DB> for Text<T>
+where
+    String: Type<DB>,
+    DB: Database,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, T, DB> Encode<'q, DB> for Text<T>
+where
+    T: Display,
+    String: Encode<'q, DB>,
+    DB: Database,
+{
+    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+        <String as Encode<'q, DB>>::encode(self.0.to_string(), buf)
+    }
+}
+
+impl<'r, T, DB> Decode<'r, DB> for Text<T>
+where
+    T: FromStr,
+    T::Err: Into<BoxDynError>,
+    &'r str: Decode<'r, DB>,
+    DB: Database,
+{
+    fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        let s = <&'r str as Decode<'r, DB>>::decode(value)?;
+
+        s.parse().map(Text).map_err(Into::into)
+    }
+}
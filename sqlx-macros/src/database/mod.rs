@@ -27,6 +27,12 @@ pub trait DatabaseExt: Database {
     fn return_type_for_id(id: &Self::TypeInfo) -> Option<&'static str>;
 
     fn get_feature_gate(info: &Self::TypeInfo) -> Option<&'static str>;
+
+    /// Whether the type is defined by the user (e.g. an enum) and so can only be named by a
+    /// type that implements `Type` for it, which is left to be inferred at the call site.
+    fn is_user_defined(_info: &Self::TypeInfo) -> bool {
+        false
+    }
 }
 
 macro_rules! impl_database_ext {
@@ -36,6 +42,7 @@ macro_rules! impl_database_ext {
         },
         ParamChecking::$param_checking:ident,
         feature-types: $ty_info:ident => $get_gate:expr,
+        $(user-types: $user_info:ident => $is_user:expr,)?
         row = $row:path,
         name = $db_name:literal
     ) => {
@@ -76,6 +83,12 @@ macro_rules! impl_database_ext {
             fn get_feature_gate($ty_info: &Self::TypeInfo) -> Option<&'static str> {
                 $get_gate
             }
+
+            $(
+                fn is_user_defined($user_info: &Self::TypeInfo) -> bool {
+                    $is_user
+                }
+            )?
         }
     }
 }
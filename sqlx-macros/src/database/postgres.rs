@@ -75,12 +75,13 @@ impl_database_ext! {
         Vec<i64> | &[i64],
         Vec<f32> | &[f32],
         Vec<f64> | &[f64],
+        Vec<Vec<u8>> | &[Vec<u8>],
 
         #[cfg(feature = "uuid")]
         Vec<sqlx::types::Uuid> | &[sqlx::types::Uuid],
 
         #[cfg(feature = "chrono")]
-        Vec<sqlx::types::chrono::NaiveTime> | &[sqlx::types::sqlx::types::chrono::NaiveTime],
+        Vec<sqlx::types::chrono::NaiveTime> | &[sqlx::types::chrono::NaiveTime],
 
         #[cfg(feature = "chrono")]
         Vec<sqlx::types::chrono::NaiveDate> | &[sqlx::types::chrono::NaiveDate],
@@ -106,12 +107,18 @@ impl_database_ext! {
         #[cfg(feature = "bigdecimal")]
         Vec<sqlx::types::BigDecimal> | &[sqlx::types::BigDecimal],
 
+        #[cfg(feature = "decimal")]
+        Vec<sqlx::types::Decimal> | &[sqlx::types::Decimal],
+
         #[cfg(feature = "ipnetwork")]
         Vec<sqlx::types::ipnetwork::IpNetwork> | &[sqlx::types::ipnetwork::IpNetwork],
 
         #[cfg(feature = "json")]
         Vec<serde_json::Value> | &[serde_json::Value],
 
+        #[cfg(feature = "bit-vec")]
+        Vec<sqlx::types::BitVec> | &[sqlx::types::BitVec],
+
         // Ranges
 
         sqlx::postgres::types::PgRange<i32>,
@@ -178,6 +185,10 @@ impl_database_ext! {
     },
     ParamChecking::Strong,
     feature-types: info => info.__type_feature_gate(),
+    user-types: info => matches!(
+        info.kind(),
+        sqlx::postgres::PgTypeKind::Enum(_) | sqlx::postgres::PgTypeKind::Composite(_)
+    ),
     row = sqlx::postgres::PgRow,
     name = "PostgreSQL"
 }
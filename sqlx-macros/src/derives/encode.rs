@@ -77,6 +77,11 @@ fn expand_derive_encode_transparent(
 
     Ok(quote!(
         impl #impl_generics sqlx::encode::Encode<#lifetime, DB> for #ident #ty_generics #where_clause {
+            // forward the by-value variant as well so the inner type can re-use its memory
+            fn encode(self, buf: &mut <DB as sqlx::database::HasArguments<#lifetime>>::ArgumentBuffer) -> sqlx::encode::IsNull {
+                <#ty as sqlx::encode::Encode<#lifetime, DB>>::encode(self.0, buf)
+            }
+
             fn encode_by_ref(&self, buf: &mut <DB as sqlx::database::HasArguments<#lifetime>>::ArgumentBuffer) -> sqlx::encode::IsNull {
                 <#ty as sqlx::encode::Encode<#lifetime, DB>>::encode_by_ref(&self.0, buf)
             }
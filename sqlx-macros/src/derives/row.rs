@@ -80,12 +80,10 @@ fn expand_derive_from_row_struct(
 
         if attributes.default {
             Some(
-                parse_quote!(let #id: #ty = row.try_get(#id_s).or_else(|e| match e {
-                sqlx::Error::ColumnNotFound(_) => {
-                    Ok(Default::default())
-                },
-                e => Err(e)
-            })?;),
+                parse_quote!(let #id: #ty = match row.column_ordinal(#id_s) {
+                Some(_) => row.try_get(#id_s)?,
+                None => Default::default(),
+            };),
             )
         } else {
             Some(parse_quote!(
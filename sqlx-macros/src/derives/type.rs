@@ -112,6 +112,10 @@ fn expand_derive_has_sql_type_weak_enum(
             fn type_info() -> DB::TypeInfo {
                 <#repr as sqlx::Type<DB>>::type_info()
             }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <#repr as sqlx::Type<DB>>::compatible(ty)
+            }
         }
     );
 
@@ -135,7 +139,9 @@ fn expand_derive_has_sql_type_strong_enum(
                 }
 
                 fn compatible(ty: &sqlx::mysql::MySqlTypeInfo) -> bool {
+                    // the enum may also be stored as text, e.g. in a `VARCHAR` column
                     *ty == sqlx::mysql::MySqlTypeInfo::__enum()
+                        || <str as sqlx::Type<sqlx::MySql>>::compatible(ty)
                 }
             }
         ));
@@ -143,6 +149,7 @@ fn expand_derive_has_sql_type_strong_enum(
 
     if cfg!(feature = "postgres") {
         let ty_name = attributes.rename.unwrap_or_else(|| ident.to_string());
+        let array_ty_name = array_type_name(&ty_name);
 
         tts.extend(quote!(
             impl sqlx::Type< sqlx::Postgres > for #ident {
@@ -150,6 +157,12 @@ fn expand_derive_has_sql_type_strong_enum(
                     sqlx::postgres::PgTypeInfo::with_name(#ty_name)
                 }
             }
+
+            impl sqlx::postgres::PgHasArrayType for #ident {
+                fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+                    sqlx::postgres::PgTypeInfo::with_name(#array_ty_name)
+                }
+            }
         ));
     }
 
@@ -181,6 +194,7 @@ fn expand_derive_has_sql_type_struct(
 
     if cfg!(feature = "postgres") {
         let ty_name = attributes.rename.unwrap_or_else(|| ident.to_string());
+        let array_ty_name = array_type_name(&ty_name);
 
         tts.extend(quote!(
             impl sqlx::Type< sqlx::Postgres > for #ident {
@@ -188,8 +202,23 @@ fn expand_derive_has_sql_type_struct(
                     sqlx::postgres::PgTypeInfo::with_name(#ty_name)
                 }
             }
+
+            impl sqlx::postgres::PgHasArrayType for #ident {
+                fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+                    sqlx::postgres::PgTypeInfo::with_name(#array_ty_name)
+                }
+            }
         ));
     }
 
     Ok(tts)
 }
+
+// the array type that Postgres creates for a user-defined type is named after it with a leading
+// `_`, in the same schema, e.g. `app._mood` for `app.mood`
+fn array_type_name(ty_name: &str) -> String {
+    match ty_name.rfind('.') {
+        Some(dot) => format!("{}_{}", &ty_name[..=dot], &ty_name[dot + 1..]),
+        None => format!("_{}", ty_name),
+    }
+}
@@ -16,6 +16,7 @@ mod common;
 mod database;
 mod derives;
 mod query;
+mod test_attr;
 
 #[cfg(feature = "migrate")]
 mod migrate;
@@ -37,6 +38,23 @@ pub fn expand_query(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro]
+pub fn expand_execute_many(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as query::QueryMacroInput);
+
+    match query::expand_execute_many(input) {
+        Ok(ts) => ts.into(),
+        Err(e) => {
+            if let Some(parse_err) = e.downcast_ref::<syn::Error>() {
+                parse_err.to_compile_error().into()
+            } else {
+                let msg = e.to_string();
+                quote!(compile_error!(#msg)).into()
+            }
+        }
+    }
+}
+
 #[proc_macro_derive(Encode, attributes(sqlx))]
 pub fn derive_encode(tokenstream: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(tokenstream as syn::DeriveInput);
@@ -95,12 +113,18 @@ pub fn migrate(input: TokenStream) -> TokenStream {
 
 #[doc(hidden)]
 #[proc_macro_attribute]
-pub fn test(_attr: TokenStream, input: TokenStream) -> TokenStream {
+pub fn test(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = syn::parse_macro_input!(args as syn::AttributeArgs);
     let input = syn::parse_macro_input!(input as syn::ItemFn);
 
+    // sets up the test database, if the test takes a pool, around the body of the test
+    let body = match test_attr::expand_body(args, &input) {
+        Ok(body) => body,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
     let ret = &input.sig.output;
     let name = &input.sig.ident;
-    let body = &input.block;
     let attrs = &input.attrs;
 
     let result = if cfg!(feature = "_rt-tokio") {
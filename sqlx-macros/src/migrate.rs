@@ -1,13 +1,15 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens, TokenStreamExt};
 use sha2::{Digest, Sha384};
+use sqlx_core::migrate::MigrationType;
 use std::fs;
 use syn::LitStr;
 
 struct QuotedMigration {
     version: i64,
     description: String,
-    sql: String,
+    migration_type: MigrationType,
+    path: String,
     checksum: Vec<u8>,
 }
 
@@ -16,15 +18,24 @@ impl ToTokens for QuotedMigration {
         let QuotedMigration {
             version,
             description,
-            sql,
+            migration_type,
+            path,
             checksum,
         } = &self;
 
+        let migration_type = match migration_type {
+            MigrationType::Simple => quote! { Simple },
+            MigrationType::ReversibleUp => quote! { ReversibleUp },
+            MigrationType::ReversibleDown => quote! { ReversibleDown },
+        };
+
         let ts = quote! {
             sqlx::migrate::Migration {
                 version: #version,
                 description: std::borrow::Cow::Borrowed(#description),
-                sql: std::borrow::Cow::Borrowed(#sql),
+                migration_type: sqlx::migrate::MigrationType::#migration_type,
+                // `include_str!` so that editing a migration triggers a rebuild
+                sql: std::borrow::Cow::Borrowed(include_str!(#path)),
                 checksum: std::borrow::Cow::Borrowed(&[
                     #(#checksum),*
                 ]),
@@ -59,26 +70,56 @@ pub(crate) fn expand_migrator_from_dir(dir: LitStr) -> crate::Result<proc_macro2
 
         let version: i64 = parts[0].parse()?;
 
-        // remove the `.sql` and replace `_` with ` `
+        let migration_type = MigrationType::from_filename(parts[1]);
+
+        // remove the `.sql` (or `.up.sql` / `.down.sql`) and replace `_` with ` `
         let description = parts[1]
-            .trim_end_matches(".sql")
+            .trim_end_matches(migration_type.suffix())
             .replace('_', " ")
             .to_owned();
 
-        let sql = fs::read_to_string(&entry.path())?;
+        let path = entry.path().canonicalize()?;
+        let sql = fs::read_to_string(&path)?;
 
         let checksum = Vec::from(Sha384::digest(sql.as_bytes()).as_slice());
 
+        let path = path
+            .to_str()
+            .ok_or_else(|| {
+                format!(
+                    "migration path cannot be represented as a string: {:?}",
+                    path
+                )
+            })?
+            .to_owned();
+
         migrations.push(QuotedMigration {
             version,
             description,
-            sql,
+            migration_type,
+            path,
             checksum,
         })
     }
 
-    // ensure that we are sorted by `VERSION ASC`
-    migrations.sort_by_key(|m| m.version);
+    // ensure that we are sorted by `VERSION ASC`, with a down migration after its up migration
+    migrations.sort_by_key(|m| (m.version, m.migration_type.is_down_migration()));
+
+    if let Some(pair) = migrations.windows(2).find(|pair| {
+        pair[0].version == pair[1].version
+            && pair[0].migration_type.is_down_migration()
+                == pair[1].migration_type.is_down_migration()
+    }) {
+        return Err(syn::Error::new(
+            dir.span(),
+            format!(
+                "migration version {} is used by more than one file in {:?}",
+                pair[0].version,
+                dir.value()
+            ),
+        )
+        .into());
+    }
 
     Ok(quote! {
         sqlx::migrate::Migrator {
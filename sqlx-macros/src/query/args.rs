@@ -53,10 +53,14 @@ pub fn quote_args<DB: DatabaseExt>(
                         // cast or type ascription will fail to compile if the type does not match
                         // and we strip casts to wildcard
                         Some(_) => return Ok(quote!()),
+                        // a user-defined type is named by whatever type implements `Type` for
+                        // it, which binding the argument already requires; the server checks
+                        // the name that type gives against the parameter
+                        None if DB::is_user_defined(&param_ty) => return Ok(quote!()),
                         None => {
                             DB::param_type_for_id(&param_ty)
                                 .ok_or_else(|| {
-                                    if let Some(feature_gate) = <DB as DatabaseExt>::get_feature_gate(&param_ty) {
+                                    let message = if let Some(feature_gate) = <DB as DatabaseExt>::get_feature_gate(&param_ty) {
                                         format!(
                                             "optional feature `{}` required for type {} of param #{}",
                                             feature_gate,
@@ -64,8 +68,16 @@ pub fn quote_args<DB: DatabaseExt>(
                                             i + 1,
                                         )
                                     } else {
-                                        format!("unsupported type {} for param #{}", param_ty, i + 1)
-                                    }
+                                        format!(
+                                            "unsupported type {} for param #{}; \
+                                             opt out of the check with an override like `arg as _`",
+                                            param_ty,
+                                            i + 1
+                                        )
+                                    };
+
+                                    // point at the argument itself rather than the whole invocation
+                                    syn::Error::new(expr.span(), message)
                                 })?
                                 .parse::<proc_macro2::TokenStream>()
                                 .map_err(|_| format!("Rust type mapping for {} not parsable", param_ty))?
@@ -41,7 +41,6 @@ pub mod offline {
     use std::fmt::{self, Formatter};
 
     use crate::database::DatabaseExt;
-    use proc_macro2::Span;
     use serde::de::{Deserializer, IgnoredAny, MapAccess, Visitor};
     use sqlx_core::describe::Describe;
     use std::path::Path;
@@ -98,13 +97,12 @@ pub mod offline {
             }
         }
 
-        pub fn save_in(&self, dir: impl AsRef<Path>, input_span: Span) -> crate::Result<()> {
-            // we save under the hash of the span representation because that should be unique
-            // per invocation
-            let path = dir.as_ref().join(format!(
-                "query-{}.json",
-                hash_string(&format!("{:?}", input_span))
-            ));
+        pub fn save_in(&self, dir: impl AsRef<Path>, key: &str) -> crate::Result<()> {
+            // we save under the hash of a key that is unique per query, such as the span of the
+            // invocation
+            let path = dir
+                .as_ref()
+                .join(format!("query-{}.json", hash_string(key)));
 
             serde_json::to_writer_pretty(
                 File::create(&path)
@@ -146,7 +144,9 @@ pub mod offline {
                 // doesn't support deserializing borrowed values
                 let key = map.next_key::<String>()?.ok_or_else(|| {
                     serde::de::Error::custom(format_args!(
-                        "failed to find data for query {}",
+                        "failed to find data for query {} in `sqlx-data.json`; \
+                         if the query was added or changed, `cargo sqlx prepare` \
+                         needs to be rerun",
                         self.hash
                     ))
                 })?;
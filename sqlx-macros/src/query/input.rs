@@ -1,23 +1,30 @@
 use std::fs;
+use std::path::{Path, PathBuf};
 
 use proc_macro2::{Ident, Span};
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::{Expr, LitBool, LitStr, Token};
+use syn::{Expr, LitBool, LitStr, Macro, Token};
 use syn::{ExprArray, Type};
 
 /// Macro input shared by `query!()` and `query_file!()`
 pub struct QueryMacroInput {
     pub(super) src: String,
 
-    #[cfg_attr(not(feature = "offline"), allow(dead_code))]
     pub(super) src_span: Span,
 
+    // the resolved path of the query file for `query_file!()` and friends
+    // so the expansion can tell the compiler to rebuild when it changes
+    pub(super) src_file: Option<PathBuf>,
+
     pub(super) record_type: RecordType,
 
     pub(super) arg_exprs: Vec<Expr>,
 
     pub(super) checked: bool,
+
+    // the environment variable to read the database URL from instead of `DATABASE_URL`
+    pub(super) db_url_var: Option<String>,
 }
 
 enum QuerySrc {
@@ -27,6 +34,7 @@ enum QuerySrc {
 
 pub enum RecordType {
     Given(Type),
+    Scalar,
     Generated,
 }
 
@@ -36,6 +44,7 @@ impl Parse for QueryMacroInput {
         let mut args: Option<Vec<Expr>> = None;
         let mut record_type = RecordType::Generated;
         let mut checked = true;
+        let mut db_url_var = None;
 
         let mut expect_comma = false;
 
@@ -50,10 +59,13 @@ impl Parse for QueryMacroInput {
 
             if key == "source" {
                 let span = input.span();
-                let query_str = Punctuated::<LitStr, Token![+]>::parse_separated_nonempty(input)?
-                    .iter()
-                    .map(LitStr::value)
-                    .collect();
+                let mut query_str = parse_source_part(input)?;
+
+                while input.peek(Token![+]) {
+                    input.parse::<Token![+]>()?;
+                    query_str.push_str(&parse_source_part(input)?);
+                }
+
                 query_src = Some((QuerySrc::String(query_str), span));
             } else if key == "source_file" {
                 let lit_str = input.parse::<LitStr>()?;
@@ -63,6 +75,28 @@ impl Parse for QueryMacroInput {
                 args = Some(exprs.elems.into_iter().collect())
             } else if key == "record" {
                 record_type = RecordType::Given(input.parse()?);
+            } else if key == "scalar" {
+                // we currently expect only `scalar = _`
+                // a `query_as_scalar!()` variant seems less useful than just overriding the type
+                // of the column in SQL
+                input.parse::<Token![_]>()?;
+                record_type = RecordType::Scalar;
+            } else if key == "url" {
+                let lit_str = input.parse::<LitStr>()?;
+                let value = lit_str.value();
+
+                // only environment variables are supported as URLs tend to contain credentials
+                // which don't belong in source code
+                match value.strip_prefix("env:") {
+                    Some(var) if !var.is_empty() => db_url_var = Some(var.to_owned()),
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            lit_str,
+                            "expected `env:<VARIABLE>` naming the environment variable \
+                             that contains the database URL",
+                        ))
+                    }
+                }
             } else if key == "checked" {
                 let lit_bool = input.parse::<LitBool>()?;
                 checked = lit_bool.value;
@@ -79,30 +113,61 @@ impl Parse for QueryMacroInput {
 
         let arg_exprs = args.unwrap_or_default();
 
+        let (src, src_file) = src.resolve(src_span)?;
+
         Ok(QueryMacroInput {
-            src: src.resolve(src_span)?,
+            src,
             src_span,
+            src_file,
             record_type,
             arg_exprs,
             checked,
+            db_url_var,
         })
     }
 }
 
+/// Parses a string literal or a `concat!()` of string literals.
+fn parse_source_part(input: ParseStream) -> syn::Result<String> {
+    if input.peek(Ident) && input.peek2(Token![!]) {
+        let mac = input.parse::<Macro>()?;
+
+        if !mac.path.is_ident("concat") {
+            return Err(syn::Error::new_spanned(
+                mac.path,
+                "expected a string literal or `concat!()` of string literals",
+            ));
+        }
+
+        Ok(mac
+            .parse_body_with(Punctuated::<LitStr, Token![,]>::parse_terminated)?
+            .iter()
+            .map(LitStr::value)
+            .collect())
+    } else {
+        Ok(input.parse::<LitStr>()?.value())
+    }
+}
+
 impl QuerySrc {
     /// If the query source is a file, read it to a string. Otherwise return the query string.
-    fn resolve(self, source_span: Span) -> syn::Result<String> {
+    ///
+    /// Also returns the resolved path of the file, if any.
+    fn resolve(self, source_span: Span) -> syn::Result<(String, Option<PathBuf>)> {
         match self {
-            QuerySrc::String(string) => Ok(string),
-            QuerySrc::File(file) => read_file_src(&file, source_span),
+            QuerySrc::String(string) => Ok((string, None)),
+            QuerySrc::File(file) => {
+                let file_path = crate::common::resolve_path(&file, source_span)?;
+                let src = read_file_src(&file_path, source_span)?;
+
+                Ok((src, Some(file_path)))
+            }
         }
     }
 }
 
-fn read_file_src(source: &str, source_span: Span) -> syn::Result<String> {
-    let file_path = crate::common::resolve_path(source, source_span)?;
-
-    fs::read_to_string(&file_path).map_err(|e| {
+fn read_file_src(file_path: &Path, source_span: Span) -> syn::Result<String> {
+    fs::read_to_string(file_path).map_err(|e| {
         syn::Error::new(
             source_span,
             format!(
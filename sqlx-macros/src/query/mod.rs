@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::env;
 
 use proc_macro2::{Span, TokenStream};
+use syn::spanned::Spanned;
 use syn::Type;
 use url::Url;
 
@@ -9,6 +10,7 @@ pub use input::QueryMacroInput;
 use quote::{format_ident, quote};
 use sqlx_core::connection::Connection;
 use sqlx_core::database::Database;
+use sqlx_core::executor::Executor;
 use sqlx_core::{column::Column, describe::Describe, type_info::TypeInfo};
 use sqlx_rt::block_on;
 
@@ -21,8 +23,47 @@ mod args;
 mod data;
 mod input;
 mod output;
+mod statements;
+
+// where the macros get the data for a query from
+enum DataSource {
+    Database(String),
+
+    #[cfg(feature = "offline")]
+    File(std::path::PathBuf),
+}
 
 pub fn expand_input(input: QueryMacroInput) -> crate::Result<TokenStream> {
+    // only the first statement would be described, so a script is rejected outright; it must
+    // split into several statements whether or not backslashes escape quotes (as in MySQL)
+    let num_statements = statements::split(&input.src, false)
+        .len()
+        .min(statements::split(&input.src, true).len());
+
+    if num_statements > 1 {
+        return Err(syn::Error::new(
+            input.src_span,
+            format!(
+                "expected a single statement, found {}; \
+                 use `sqlx::execute_many!()` to check a script of several statements",
+                num_statements
+            ),
+        )
+        .into());
+    }
+
+    // `url = "env:<VARIABLE>"` lets an invocation check against a different database
+    let db_url_var = input.db_url_var.as_deref().unwrap_or("DATABASE_URL");
+
+    match data_source(db_url_var)? {
+        DataSource::Database(db_url) => expand_from_db(input, db_url_var, &db_url),
+
+        #[cfg(feature = "offline")]
+        DataSource::File(data_file_path) => expand_from_file(input, data_file_path),
+    }
+}
+
+fn data_source(db_url_var: &str) -> crate::Result<DataSource> {
     let manifest_dir =
         env::var("CARGO_MANIFEST_DIR").map_err(|_| "`CARGO_MANIFEST_DIR` must be set")?;
 
@@ -35,26 +76,30 @@ pub fn expand_input(input: QueryMacroInput) -> crate::Result<TokenStream> {
     }
 
     // if `dotenv` wasn't initialized by the above we make sure to do it here
-    match (
-        dotenv::var("SQLX_OFFLINE")
-            .map(|s| s.to_lowercase() == "true")
-            .unwrap_or(false),
-        dotenv::var("DATABASE_URL"),
-    ) {
-        (false, Ok(db_url)) => expand_from_db(input, &db_url),
+    let offline = dotenv::var("SQLX_OFFLINE")
+        .map(|s| s.to_lowercase() == "true")
+        .unwrap_or(false);
+
+    match (offline, dotenv::var(db_url_var)) {
+        (false, Ok(db_url)) => Ok(DataSource::Database(db_url)),
 
         #[cfg(feature = "offline")]
         _ => {
             let data_file_path = std::path::Path::new(&manifest_dir).join("sqlx-data.json");
 
             if data_file_path.exists() {
-                expand_from_file(input, data_file_path)
+                Ok(DataSource::File(data_file_path))
+            } else if offline {
+                Err("`SQLX_OFFLINE` is set but sqlx-data.json does not exist; \
+                     `cargo sqlx prepare` must be run to use query macros in offline mode"
+                    .into())
             } else {
-                Err(
-                    "`DATABASE_URL` must be set, or `cargo sqlx prepare` must have been run \
-                     and sqlx-data.json must exist, to use query macros"
-                        .into(),
+                Err(format!(
+                    "`{}` must be set, or `cargo sqlx prepare` must have been run \
+                     and sqlx-data.json must exist, to use query macros",
+                    db_url_var
                 )
+                .into())
             }
         }
 
@@ -64,16 +109,24 @@ pub fn expand_input(input: QueryMacroInput) -> crate::Result<TokenStream> {
         }
 
         #[cfg(not(feature = "offline"))]
-        (false, Err(_)) => Err("`DATABASE_URL` must be set to use query macros".into()),
+        (false, Err(_)) => Err(format!("`{}` must be set to use query macros", db_url_var).into()),
     }
 }
 
 #[allow(unused_variables)]
-fn expand_from_db(input: QueryMacroInput, db_url: &str) -> crate::Result<TokenStream> {
+fn expand_from_db(
+    input: QueryMacroInput,
+    db_url_var: &str,
+    db_url: &str,
+) -> crate::Result<TokenStream> {
     // FIXME: Introduce [sqlx::any::AnyConnection] and [sqlx::any::AnyDatabase] to support
     //        runtime determinism here
 
-    let db_url = Url::parse(db_url)?;
+    let db_url = Url::parse(db_url)
+        .map_err(|e| format!("`{}` is not a valid database URL: {}", db_url_var, e))?;
+
+    check_scheme(input.src_span, db_url_var, &db_url)?;
+
     match db_url.scheme() {
         #[cfg(feature = "postgres")]
         "postgres" | "postgresql" => {
@@ -83,10 +136,7 @@ fn expand_from_db(input: QueryMacroInput, db_url: &str) -> crate::Result<TokenSt
             })?;
 
             expand_with_data(input, data, false)
-        },
-
-        #[cfg(not(feature = "postgres"))]
-        "postgres" | "postgresql" => Err("database URL has the scheme of a PostgreSQL database but the `postgres` feature is not enabled".into()),
+        }
 
         #[cfg(feature = "mssql")]
         "mssql" | "sqlserver" => {
@@ -96,10 +146,7 @@ fn expand_from_db(input: QueryMacroInput, db_url: &str) -> crate::Result<TokenSt
             })?;
 
             expand_with_data(input, data, false)
-        },
-
-        #[cfg(not(feature = "mssql"))]
-        "mssql" | "sqlserver" => Err("database URL has the scheme of a MSSQL database but the `mssql` feature is not enabled".into()),
+        }
 
         #[cfg(feature = "mysql")]
         "mysql" | "mariadb" => {
@@ -109,26 +156,224 @@ fn expand_from_db(input: QueryMacroInput, db_url: &str) -> crate::Result<TokenSt
             })?;
 
             expand_with_data(input, data, false)
-        },
-
-        #[cfg(not(feature = "mysql"))]
-        "mysql" | "mariadb" => Err("database URL has the scheme of a MySQL/MariaDB database but the `mysql` feature is not enabled".into()),
+        }
 
         #[cfg(feature = "sqlite")]
         "sqlite" => {
             let data = block_on(async {
-                let mut conn = sqlx_core::sqlite::SqliteConnection::connect(db_url.as_str()).await?;
+                let mut conn =
+                    sqlx_core::sqlite::SqliteConnection::connect(db_url.as_str()).await?;
                 QueryData::from_db(&mut conn, &input.src).await
             })?;
 
             expand_with_data(input, data, false)
-        },
+        }
 
-        #[cfg(not(feature = "sqlite"))]
-        "sqlite" => Err("database URL has the scheme of a SQLite database but the `sqlite` feature is not enabled".into()),
+        // `check_scheme` rejected everything else
+        _ => unreachable!(),
+    }
+}
+
+/// Check each statement of the script given to `execute_many!()` and expand to the script.
+pub fn expand_execute_many(input: QueryMacroInput) -> crate::Result<TokenStream> {
+    if let Some(arg) = input.arg_exprs.first() {
+        return Err(
+            syn::Error::new(arg.span(), "`execute_many!()` does not take arguments").into(),
+        );
+    }
+
+    let statements = statements::split(&input.src, false);
+
+    if statements.is_empty() {
+        return Err(syn::Error::new(input.src_span, "expected at least one statement").into());
+    }
+
+    let db_url_var = input.db_url_var.as_deref().unwrap_or("DATABASE_URL");
+
+    match data_source(db_url_var)? {
+        DataSource::Database(db_url) => {
+            check_statements_from_db(&input, &statements, db_url_var, &db_url)?
+        }
+
+        #[cfg(feature = "offline")]
+        DataSource::File(data_file_path) => {
+            for (i, statement) in statements.iter().enumerate() {
+                data::offline::DynQueryData::from_data_file(&data_file_path, statement)
+                    .map_err(|e| statement_error(&input, &statements, i, e))?;
+            }
+        }
+    }
+
+    let sql = &input.src;
+
+    let src_file_tokens = input.src_file.as_ref().map(|path| {
+        let path = path.to_string_lossy();
+
+        quote! {
+            const _: &str = include_str!(#path);
+        }
+    });
+
+    Ok(quote! {
+        {
+            #src_file_tokens
+
+            #sql
+        }
+    })
+}
+
+#[allow(unused_variables)]
+fn check_statements_from_db(
+    input: &QueryMacroInput,
+    statements: &[&str],
+    db_url_var: &str,
+    db_url: &str,
+) -> crate::Result<()> {
+    let db_url = Url::parse(db_url)
+        .map_err(|e| format!("`{}` is not a valid database URL: {}", db_url_var, e))?;
+
+    check_scheme(input.src_span, db_url_var, &db_url)?;
+
+    match db_url.scheme() {
+        #[cfg(feature = "postgres")]
+        "postgres" | "postgresql" => block_on(async {
+            let mut conn = sqlx_core::postgres::PgConnection::connect(db_url.as_str()).await?;
+            check_statements(&mut conn, input, statements).await
+        }),
 
-        scheme => Err(format!("unknown database URL scheme {:?}", scheme).into())
+        #[cfg(feature = "mssql")]
+        "mssql" | "sqlserver" => block_on(async {
+            let mut conn = sqlx_core::mssql::MssqlConnection::connect(db_url.as_str()).await?;
+            check_statements(&mut conn, input, statements).await
+        }),
+
+        #[cfg(feature = "mysql")]
+        "mysql" | "mariadb" => block_on(async {
+            let mut conn = sqlx_core::mysql::MySqlConnection::connect(db_url.as_str()).await?;
+            check_statements(&mut conn, input, statements).await
+        }),
+
+        #[cfg(feature = "sqlite")]
+        "sqlite" => block_on(async {
+            let mut conn = sqlx_core::sqlite::SqliteConnection::connect(db_url.as_str()).await?;
+            check_statements(&mut conn, input, statements).await
+        }),
+
+        // `check_scheme` rejected everything else
+        _ => unreachable!(),
+    }
+}
+
+// describe each statement on its own; statements that depend on objects created earlier in the
+// same script cannot be checked this way
+#[allow(dead_code)]
+async fn check_statements<DB: DatabaseExt>(
+    conn: &mut DB::Connection,
+    input: &QueryMacroInput,
+    statements: &[&str],
+) -> crate::Result<()>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    Describe<DB>: DescribeExt,
+{
+    for (i, statement) in statements.iter().enumerate() {
+        let data = QueryData::from_db(&mut *conn, statement)
+            .await
+            .map_err(|e| statement_error(input, statements, i, e))?;
+
+        // saved like any other query so that `cargo sqlx prepare` picks the statements up
+        #[cfg(feature = "offline")]
+        save_query_data(&data, &format!("{:?}#{}", input.src_span, i))?;
+
+        #[cfg(not(feature = "offline"))]
+        let _ = data;
     }
+
+    Ok(())
+}
+
+fn statement_error(
+    input: &QueryMacroInput,
+    statements: &[&str],
+    index: usize,
+    error: crate::Error,
+) -> crate::Error {
+    syn::Error::new(
+        input.src_span,
+        format!(
+            "statement {} of {} failed to check: {}\n{}",
+            index + 1,
+            statements.len(),
+            error,
+            statements[index]
+        ),
+    )
+    .into()
+}
+
+// the URL schemes of each database, its name and the feature of SQLx that enables it
+const DATABASES: &[(&[&str], &str, &str, bool)] = &[
+    (
+        &["postgres", "postgresql"],
+        "PostgreSQL",
+        "postgres",
+        cfg!(feature = "postgres"),
+    ),
+    (
+        &["mysql", "mariadb"],
+        "MySQL/MariaDB",
+        "mysql",
+        cfg!(feature = "mysql"),
+    ),
+    (&["sqlite"], "SQLite", "sqlite", cfg!(feature = "sqlite")),
+    (
+        &["mssql", "sqlserver"],
+        "MSSQL",
+        "mssql",
+        cfg!(feature = "mssql"),
+    ),
+];
+
+// the database a query is checked against is only known once the URL is read, so a missing
+// feature cannot be caught by `cfg` alone; this is checked before anything else is done with
+// the URL, and reported at the query
+fn check_scheme(span: Span, db_url_var: &str, db_url: &Url) -> crate::Result<()> {
+    let scheme = db_url.scheme();
+    let database = DATABASES
+        .iter()
+        .find(|(schemes, ..)| schemes.contains(&scheme));
+
+    let message = match database {
+        Some((_, _, _, true)) => return Ok(()),
+
+        Some((_, name, feature, false)) => feature_not_enabled(db_url_var, name, feature),
+
+        None => {
+            let schemes: Vec<String> = DATABASES
+                .iter()
+                .flat_map(|(schemes, ..)| schemes.iter())
+                .map(|scheme| format!("`{}`", scheme))
+                .collect();
+
+            format!(
+                "`{}` has the unknown scheme {:?}; expected one of {}",
+                db_url_var,
+                scheme,
+                schemes.join(", ")
+            )
+        }
+    };
+
+    Err(syn::Error::new(span, message).into())
+}
+
+fn feature_not_enabled(source: &str, database: &str, feature: &str) -> String {
+    format!(
+        "`{}` is for a {} database but the `{}` feature of SQLx is not enabled; \
+         add it to the `features` of the `sqlx` dependency in Cargo.toml",
+        source, database, feature
+    )
 }
 
 #[cfg(feature = "offline")]
@@ -160,6 +405,12 @@ pub fn expand_from_file(
             QueryData::<sqlx_core::sqlite::Sqlite>::from_dyn_data(query_data)?,
             true,
         ),
+        #[cfg(not(feature = "postgres"))]
+        "postgresql" => Err(feature_not_enabled("sqlx-data.json", "PostgreSQL", "postgres").into()),
+        #[cfg(not(feature = "mysql"))]
+        "mysql" => Err(feature_not_enabled("sqlx-data.json", "MySQL/MariaDB", "mysql").into()),
+        #[cfg(not(feature = "sqlite"))]
+        "sqlite" => Err(feature_not_enabled("sqlx-data.json", "SQLite", "sqlite").into()),
         _ => Err(format!(
             "found query data for {} but the feature for that database was not enabled",
             query_data.db_name
@@ -201,10 +452,30 @@ where
     };
 
     if let Some(num) = num_parameters {
-        if num != input.arg_exprs.len() {
+        let got = input.arg_exprs.len();
+
+        if num < got {
+            // point at the first argument that has no placeholder to bind to
             return Err(syn::Error::new(
-                Span::call_site(),
-                format!("expected {} parameters, got {}", num, input.arg_exprs.len()),
+                input.arg_exprs[num].span(),
+                format!(
+                    "expected {} parameters, got {}; this argument has no matching placeholder",
+                    num, got
+                ),
+            )
+            .into());
+        }
+
+        if num > got {
+            // point at the query, which has a placeholder without a matching argument
+            return Err(syn::Error::new(
+                input.src_span,
+                format!(
+                    "expected {} parameters, got {}; missing argument for parameter #{}",
+                    num,
+                    got,
+                    got + 1
+                ),
             )
             .into());
         }
@@ -214,7 +485,9 @@ where
 
     let query_args = format_ident!("query_args");
 
-    let output = if data
+    let output = if let RecordType::Scalar = input.record_type {
+        output::quote_query_scalar::<DB>(&input, &query_args, &data.describe)?
+    } else if data
         .describe
         .columns()
         .iter()
@@ -233,12 +506,26 @@ where
             RecordType::Generated => {
                 let record_name: Type = syn::parse_str("Record").unwrap();
 
-                for rust_col in &columns {
+                for (i, rust_col) in columns.iter().enumerate() {
+                    if rust_col.user_defined {
+                        return Err(format!(
+                            "column `{}` has the user-defined type {} which can only be \
+                             inferred from a struct field in `query_as!()`; \
+                             name the Rust type with an override (`\"{0}: MyType\"`)",
+                            rust_col.ident.to_string().trim_start_matches("r#"),
+                            data.describe.columns()[i].type_info(),
+                        )
+                        .into());
+                    }
+
                     if rust_col.type_.is_wildcard() {
-                        return Err(
-                            "columns may not have wildcard overrides in `query!()` or `query_as!()"
-                                .into(),
-                        );
+                        return Err(format!(
+                            "column `{}` has a wildcard type override but the type can only be \
+                             inferred from a struct field in `query_as!()`; \
+                             use an explicit type override (`\"{0}: MyType\"`) instead",
+                            rust_col.ident.to_string().trim_start_matches("r#"),
+                        )
+                        .into());
                     }
                 }
 
@@ -246,6 +533,7 @@ where
                     |&output::RustColumn {
                          ref ident,
                          ref type_,
+                         ..
                      }| quote!(#ident: #type_,),
                 );
 
@@ -259,6 +547,7 @@ where
                 (Cow::Owned(record_name), record_tokens)
             }
             RecordType::Given(ref out_ty) => (Cow::Borrowed(out_ty), quote!()),
+            RecordType::Scalar => unreachable!("scalar output is expanded above"),
         };
 
         record_tokens.extend(output::quote_query_as::<DB>(
@@ -271,12 +560,24 @@ where
         record_tokens
     };
 
+    // `include_str!()` makes the compiler track the query file so that editing it
+    // triggers a rebuild and the query is checked again
+    let src_file_tokens = input.src_file.as_ref().map(|path| {
+        let path = path.to_string_lossy();
+
+        quote! {
+            const _: &str = include_str!(#path);
+        }
+    });
+
     let ret_tokens = quote! {
         {
             #[allow(clippy::all)]
             {
                 use sqlx::Arguments as _;
 
+                #src_file_tokens
+
                 #args_tokens
 
                 #output
@@ -288,15 +589,23 @@ where
     // If the build is offline, the cache is our input so it's pointless to also write data for it.
     #[cfg(feature = "offline")]
     if !offline {
-        let mut save_dir = std::path::PathBuf::from(
-            env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| "target/".into()),
-        );
-
-        save_dir.push("sqlx");
-
-        std::fs::create_dir_all(&save_dir)?;
-        data.save_in(save_dir, input.src_span)?;
+        save_query_data(&data, &format!("{:?}", input.src_span))?;
     }
 
     Ok(ret_tokens)
 }
+
+// `key` must be unique per query in the crate, e.g. derived from the span of the invocation
+#[cfg(feature = "offline")]
+fn save_query_data<DB: DatabaseExt>(data: &QueryData<DB>, key: &str) -> crate::Result<()>
+where
+    Describe<DB>: DescribeExt,
+{
+    let mut save_dir =
+        std::path::PathBuf::from(env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| "target/".into()));
+
+    save_dir.push("sqlx");
+
+    std::fs::create_dir_all(&save_dir)?;
+    data.save_in(save_dir, key)
+}
@@ -8,6 +8,7 @@ use sqlx_core::describe::Describe;
 use crate::database::DatabaseExt;
 
 use crate::query::QueryMacroInput;
+use std::collections::HashSet;
 use std::fmt::{self, Display, Formatter};
 use syn::parse::{Parse, ParseStream};
 use syn::Token;
@@ -15,6 +16,8 @@ use syn::Token;
 pub struct RustColumn {
     pub(super) ident: Ident,
     pub(super) type_: ColumnType,
+    // the column has a user-defined type that is left to be inferred
+    pub(super) user_defined: bool,
 }
 
 pub(super) enum ColumnType {
@@ -75,7 +78,7 @@ impl Display for DisplayColumn<'_> {
 }
 
 pub fn columns_to_rust<DB: DatabaseExt>(describe: &Describe<DB>) -> crate::Result<Vec<RustColumn>> {
-    describe
+    let columns = describe
         .columns()
         .iter()
         .enumerate()
@@ -84,40 +87,77 @@ pub fn columns_to_rust<DB: DatabaseExt>(describe: &Describe<DB>) -> crate::Resul
             let decl = ColumnDecl::parse(&column.name())
                 .map_err(|e| format!("column name {:?} is invalid: {}", column.name(), e))?;
 
-            let ColumnOverride { nullability, type_ } = decl.r#override;
-
-            let nullable = match nullability {
-                ColumnNullabilityOverride::NonNull => false,
-                ColumnNullabilityOverride::Nullable => true,
-                ColumnNullabilityOverride::None => describe.nullable(i).unwrap_or(true),
-            };
-            let type_ = match (type_, nullable) {
-                (ColumnTypeOverride::Exact(type_), false) => {
-                    ColumnType::Exact(type_.to_token_stream())
-                }
-                (ColumnTypeOverride::Exact(type_), true) => {
-                    ColumnType::Exact(quote! { Option<#type_> })
-                }
-
-                (ColumnTypeOverride::Wildcard, false) => ColumnType::Wildcard,
-                (ColumnTypeOverride::Wildcard, true) => ColumnType::OptWildcard,
-
-                (ColumnTypeOverride::None, _) => {
-                    let type_ = get_column_type::<DB>(i, column);
-                    if !nullable {
-                        ColumnType::Exact(type_)
-                    } else {
-                        ColumnType::Exact(quote! { Option<#type_> })
-                    }
-                }
-            };
+            let user_defined = decl.r#override.type_.is_none() && is_user_defined(describe, i);
 
             Ok(RustColumn {
                 ident: decl.ident,
-                type_,
+                type_: column_type_with_override(describe, i, decl.r#override),
+                user_defined,
             })
         })
-        .collect::<crate::Result<Vec<_>>>()
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    // each column becomes a field of the output struct so a repeated name would otherwise
+    // surface as a confusing "field specified more than once" error at the call site
+    let mut seen = HashSet::with_capacity(columns.len());
+
+    for (i, column) in describe.columns().iter().enumerate() {
+        let ident = columns[i].ident.to_string();
+
+        if !seen.insert(ident) {
+            return Err(format!(
+                "{} has the same name as a previous column; \
+                 use an alias (`AS`) to give each column a unique name",
+                DisplayColumn {
+                    idx: i,
+                    name: &*column.name()
+                }
+            )
+            .into());
+        }
+    }
+
+    Ok(columns)
+}
+
+fn column_type_with_override<DB: DatabaseExt>(
+    describe: &Describe<DB>,
+    i: usize,
+    r#override: ColumnOverride,
+) -> ColumnType {
+    let ColumnOverride { nullability, type_ } = r#override;
+
+    let nullable = match nullability {
+        ColumnNullabilityOverride::NonNull => false,
+        ColumnNullabilityOverride::Nullable => true,
+        ColumnNullabilityOverride::None => describe.nullable(i).unwrap_or(true),
+    };
+
+    match (type_, nullable) {
+        (ColumnTypeOverride::Exact(type_), false) => ColumnType::Exact(type_.to_token_stream()),
+        (ColumnTypeOverride::Exact(type_), true) => ColumnType::Exact(quote! { Option<#type_> }),
+
+        (ColumnTypeOverride::Wildcard, false) => ColumnType::Wildcard,
+        (ColumnTypeOverride::Wildcard, true) => ColumnType::OptWildcard,
+
+        // a user-defined type can only be named by the type implementing `Type` for it, so the
+        // field (or scalar) it is decoded into names it and it is checked when decoding
+        (ColumnTypeOverride::None, false) if is_user_defined(describe, i) => ColumnType::Wildcard,
+        (ColumnTypeOverride::None, true) if is_user_defined(describe, i) => ColumnType::OptWildcard,
+
+        (ColumnTypeOverride::None, _) => {
+            let type_ = get_column_type::<DB>(i, &describe.columns()[i]);
+            if !nullable {
+                ColumnType::Exact(type_)
+            } else {
+                ColumnType::Exact(quote! { Option<#type_> })
+            }
+        }
+    }
+}
+
+fn is_user_defined<DB: DatabaseExt>(describe: &Describe<DB>, i: usize) -> bool {
+    <DB as DatabaseExt>::is_user_defined(&*describe.columns()[i].type_info())
 }
 
 pub fn quote_query_as<DB: DatabaseExt>(
@@ -171,6 +211,41 @@ pub fn quote_query_as<DB: DatabaseExt>(
     }
 }
 
+pub fn quote_query_scalar<DB: DatabaseExt>(
+    input: &QueryMacroInput,
+    bind_args: &Ident,
+    describe: &Describe<DB>,
+) -> crate::Result<TokenStream> {
+    let columns = describe.columns();
+
+    if columns.len() != 1 {
+        return Err(syn::Error::new(
+            input.src_span,
+            format!("expected exactly 1 column, got {}", columns.len()),
+        )
+        .into());
+    }
+
+    let ty = if input.checked {
+        // the column name doesn't need to be a valid identifier here (e.g. `count(*)` in MySQL)
+        // so if it doesn't parse we assume there's no override and use the inferred type
+        let r#override = ColumnDecl::parse(&columns[0].name())
+            .map(|decl| decl.r#override)
+            .unwrap_or_else(|_| ColumnOverride::none());
+
+        column_type_with_override(describe, 0, r#override).into_token_stream()
+    } else {
+        quote!(_)
+    };
+
+    let db = DB::db_path();
+    let query = &input.src;
+
+    Ok(quote! {
+        sqlx::query_scalar_with::<#db, #ty, _>(#query, #bind_args)
+    })
+}
+
 fn get_column_type<DB: DatabaseExt>(i: usize, column: &DB::Column) -> TokenStream {
     let type_info = &*column.type_info();
 
@@ -189,12 +264,14 @@ fn get_column_type<DB: DatabaseExt>(i: usize, column: &DB::Column) -> TokenStrea
                     )
                 } else {
                     format!(
-                        "unsupported type {ty} of {col}",
+                        "unsupported type {ty} of {col}; \
+                         name the Rust type with an override like `AS \"{name}: MyType\"`",
                         ty = type_info,
                         col = DisplayColumn {
                             idx: i,
                             name: &*column.name()
-                        }
+                        },
+                        name = column.name(),
                     )
                 };
             syn::Error::new(Span::call_site(), message).to_compile_error()
@@ -221,15 +298,27 @@ impl ColumnDecl {
             r#override: if !remainder.is_empty() {
                 syn::parse_str(remainder)?
             } else {
-                ColumnOverride {
-                    nullability: ColumnNullabilityOverride::None,
-                    type_: ColumnTypeOverride::None,
-                }
+                ColumnOverride::none()
             },
         })
     }
 }
 
+impl ColumnTypeOverride {
+    fn is_none(&self) -> bool {
+        matches!(self, ColumnTypeOverride::None)
+    }
+}
+
+impl ColumnOverride {
+    fn none() -> Self {
+        ColumnOverride {
+            nullability: ColumnNullabilityOverride::None,
+            type_: ColumnTypeOverride::None,
+        }
+    }
+}
+
 impl Parse for ColumnOverride {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let lookahead = input.lookahead1();
@@ -246,6 +335,14 @@ impl Parse for ColumnOverride {
             ColumnNullabilityOverride::None
         };
 
+        // `!` and `?` contradict each other; without this check the second one
+        // produces an "unexpected token" error that doesn't explain the problem
+        if nullability != ColumnNullabilityOverride::None
+            && (input.peek(Token![!]) || input.peek(Token![?]))
+        {
+            return Err(input.error("expected at most one of `!` (not null) or `?` (nullable)"));
+        }
+
         let type_ = if input.lookahead1().peek(Token![:]) {
             input.parse::<Token![:]>()?;
 
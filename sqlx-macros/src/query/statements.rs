@@ -0,0 +1,184 @@
+/// Split a SQL script on the `;` that end its statements.
+///
+/// Semicolons inside of string literals, quoted identifiers, comments and Postgres dollar-quoted
+/// strings are skipped; with `backslash_escapes` (MySQL), a backslash escapes the next character
+/// of a string literal, as it always does in a Postgres `E'...'` escape string. Statements are
+/// trimmed and those without any SQL, such as a trailing comment, are dropped.
+///
+/// Compound statements that contain `;` themselves, such as the body of a trigger, are not
+/// recognized.
+pub(crate) fn split(sql: &str, backslash_escapes: bool) -> Vec<&str> {
+    let mut statements = Vec::new();
+    let mut start = 0;
+    let mut has_sql = false;
+
+    let bytes = sql.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\'' | b'"' | b'`' => {
+                let quote = bytes[i];
+                let escapes = quote == b'\'' && (backslash_escapes || is_escape_string(bytes, i));
+
+                has_sql = true;
+                i += 1;
+
+                while i < bytes.len() {
+                    if escapes && bytes[i] == b'\\' {
+                        i += 2;
+                        continue;
+                    }
+
+                    if bytes[i] == quote {
+                        // a doubled quote is an escaped quote
+                        if bytes.get(i + 1) == Some(&quote) {
+                            i += 2;
+                            continue;
+                        }
+
+                        break;
+                    }
+
+                    i += 1;
+                }
+            }
+
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+
+                while i < bytes.len() && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/')) {
+                    i += 1;
+                }
+
+                i += 1;
+            }
+
+            b'$' => {
+                has_sql = true;
+
+                if let Some(tag) = dollar_quote_tag(&sql[i..]) {
+                    i += tag.len();
+
+                    match sql[i..].find(tag) {
+                        Some(end) => i += end + tag.len() - 1,
+                        None => i = bytes.len(),
+                    }
+                }
+            }
+
+            b';' => {
+                if has_sql {
+                    statements.push(sql[start..i].trim());
+                }
+
+                start = i + 1;
+                has_sql = false;
+            }
+
+            c if !c.is_ascii_whitespace() => has_sql = true,
+
+            _ => {}
+        }
+
+        i += 1;
+    }
+
+    if has_sql {
+        statements.push(sql[start..].trim());
+    }
+
+    statements
+}
+
+// whether the literal opened by the quote at `quote` is a Postgres `E'...'` escape string
+fn is_escape_string(bytes: &[u8], quote: usize) -> bool {
+    let is_ident = |c: &u8| c.is_ascii_alphanumeric() || *c == b'_' || *c == b'$';
+
+    quote > 0
+        && matches!(bytes[quote - 1], b'E' | b'e')
+        && (quote == 1 || !is_ident(&bytes[quote - 2]))
+}
+
+// `$$` or `$tag$` at the start of `sql`; a positional parameter such as `$1` is not a tag
+fn dollar_quote_tag(sql: &str) -> Option<&str> {
+    let end = sql[1..].find('$')? + 1;
+    let tag = &sql[1..end];
+
+    let is_tag = tag.chars().all(|c| c.is_alphanumeric() || c == '_')
+        && !tag.starts_with(|c: char| c.is_ascii_digit());
+
+    if is_tag {
+        Some(&sql[..=end])
+    } else {
+        None
+    }
+}
+
+#[test]
+fn it_splits_statements() {
+    assert_eq!(split("SELECT 1", false), ["SELECT 1"]);
+    assert_eq!(split(" SELECT 1; ", false), ["SELECT 1"]);
+    assert_eq!(split("SELECT 1;SELECT 2;", false), ["SELECT 1", "SELECT 2"]);
+    assert_eq!(split("SELECT 1; -- done", false), ["SELECT 1"]);
+    assert_eq!(split(";;", false), Vec::<&str>::new());
+}
+
+#[test]
+fn it_skips_semicolons_in_literals_and_comments() {
+    assert_eq!(
+        split("SELECT 'a;b', \"c;d\", `e;f`; SELECT 'it''s;'", false),
+        ["SELECT 'a;b', \"c;d\", `e;f`", "SELECT 'it''s;'"]
+    );
+
+    assert_eq!(
+        split("SELECT 1 -- one; two\n; /* three; */ SELECT 2", false),
+        ["SELECT 1 -- one; two", "/* three; */ SELECT 2"]
+    );
+
+    assert_eq!(
+        split("SELECT 'a\\';b'; SELECT 2", true),
+        ["SELECT 'a\\';b'", "SELECT 2"]
+    );
+
+    assert_eq!(
+        split("SELECT 'C:\\'; SELECT 2", false),
+        ["SELECT 'C:\\'", "SELECT 2"]
+    );
+
+    assert_eq!(
+        split("SELECT E'a\\';b', e'\\\\'; SELECT 2", false),
+        ["SELECT E'a\\';b', e'\\\\'", "SELECT 2"]
+    );
+
+    // a typed literal whose type name ends in `e` is not an escape string
+    assert_eq!(
+        split("SELECT some_type'C:\\'; SELECT 2", false),
+        ["SELECT some_type'C:\\'", "SELECT 2"]
+    );
+}
+
+#[test]
+fn it_skips_semicolons_in_dollar_quotes() {
+    assert_eq!(
+        split(
+            "CREATE FUNCTION f() RETURNS int AS $body$ SELECT 1; $body$ LANGUAGE sql; SELECT $1",
+            false
+        ),
+        [
+            "CREATE FUNCTION f() RETURNS int AS $body$ SELECT 1; $body$ LANGUAGE sql",
+            "SELECT $1"
+        ]
+    );
+
+    assert_eq!(
+        split("DO $$ BEGIN PERFORM 1; END $$; SELECT 2", false),
+        ["DO $$ BEGIN PERFORM 1; END $$", "SELECT 2"]
+    );
+}
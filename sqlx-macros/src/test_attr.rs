@@ -0,0 +1,120 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{AttributeArgs, FnArg, ItemFn, Lit, Meta, NestedMeta};
+
+// `#[sqlx_macros::test(fixtures("users", "orders"))] async fn test(pool: PgPool)`
+//
+// a test that takes a pool runs against its own, empty test database (see `TestPool`), created
+// next to the one `DATABASE_URL` points to; the database is inferred from the type of the pool
+//
+// each fixture is read from `tests/fixtures/<name>.sql` (relative to the manifest directory)
+// and executed, in the order given, against the test database before the body of the test
+//
+// returns the body of the `async` block the test is run in
+pub(crate) fn expand_body(args: AttributeArgs, input: &ItemFn) -> syn::Result<TokenStream> {
+    let fixtures = expand_fixtures(args)?;
+    let body = &input.block;
+
+    let arg = match input.sig.inputs.len() {
+        0 if fixtures.is_empty() => return Ok(quote!(#body)),
+
+        0 => {
+            return Err(syn::Error::new_spanned(
+                &input.sig,
+                "a test with fixtures must take the pool to run against, \
+                 e.g. `async fn test(pool: PgPool)`",
+            ))
+        }
+
+        1 => match &input.sig.inputs[0] {
+            FnArg::Typed(arg) => arg,
+            arg => {
+                return Err(syn::Error::new_spanned(
+                    arg,
+                    "expected the pool to run the test against",
+                ))
+            }
+        },
+
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.sig.inputs,
+                "a test takes at most one argument, the pool to run against",
+            ))
+        }
+    };
+
+    let pat = &arg.pat;
+    let ty = &arg.ty;
+    let ret = &input.sig.output;
+
+    Ok(quote! {
+        async fn test(#pat: #ty) #ret #body
+
+        let url = std::env::var("DATABASE_URL")
+            .expect("DATABASE_URL must be set to create a test database");
+
+        let db = sqlx::testing::TestPool::<<#ty as sqlx::testing::TestArg>::Database>::empty(&url)
+            .await
+            .expect("failed to create the test database");
+
+        #(#fixtures)*
+
+        let result = test(sqlx::testing::TestArg::from_test_pool(&db)).await;
+
+        db.close().await.expect("failed to drop the test database");
+
+        result
+    })
+}
+
+fn expand_fixtures(args: AttributeArgs) -> syn::Result<Vec<TokenStream>> {
+    let mut fixtures = Vec::new();
+
+    for arg in args {
+        let list = match arg {
+            NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("fixtures") => list,
+            arg => {
+                return Err(syn::Error::new_spanned(
+                    arg,
+                    "expected `fixtures(\"<name>\", ...)`",
+                ))
+            }
+        };
+
+        for fixture in list.nested {
+            let name = match fixture {
+                NestedMeta::Lit(Lit::Str(name)) => name,
+                fixture => {
+                    return Err(syn::Error::new_spanned(
+                        fixture,
+                        "expected the name of a fixture as a string literal",
+                    ))
+                }
+            };
+
+            let path = crate::common::resolve_path(
+                &format!("tests/fixtures/{}.sql", name.value()),
+                name.span(),
+            )?;
+
+            if !path.is_file() {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!("fixture not found: {}", path.display()),
+                ));
+            }
+
+            let path = path.to_string_lossy().into_owned();
+            let name = name.value();
+
+            fixtures.push(quote! {
+                db.apply_fixture(include_str!(#path))
+                    .await
+                    .expect(concat!("failed to apply fixture `", #name, "`"));
+            });
+        }
+    }
+
+    Ok(fixtures)
+}
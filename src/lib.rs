@@ -12,10 +12,14 @@ compile_error!(
 );
 
 pub use sqlx_core::acquire::Acquire;
-pub use sqlx_core::arguments::{Arguments, IntoArguments};
+pub use sqlx_core::arguments::{ArgumentCount, Arguments, IntoArguments};
+pub use sqlx_core::blocking;
 pub use sqlx_core::column::Column;
 pub use sqlx_core::column::ColumnIndex;
-pub use sqlx_core::connection::{ConnectOptions, Connection};
+pub use sqlx_core::comment;
+pub use sqlx_core::connection::{
+    ConnectOptions, Connection, ConnectionStatistics, ServerFlavor, ServerVersion,
+};
 pub use sqlx_core::database::{self, Database};
 pub use sqlx_core::describe::Describe;
 pub use sqlx_core::done::Done;
@@ -25,9 +29,11 @@ pub use sqlx_core::pool::{self, Pool};
 pub use sqlx_core::query::{query, query_with};
 pub use sqlx_core::query_as::{query_as, query_as_with};
 pub use sqlx_core::query_scalar::{query_scalar, query_scalar_with};
+pub use sqlx_core::reconnect;
 pub use sqlx_core::row::Row;
-pub use sqlx_core::statement::Statement;
-pub use sqlx_core::transaction::{Transaction, TransactionManager};
+pub use sqlx_core::runtime;
+pub use sqlx_core::statement::{Statement, StatementId};
+pub use sqlx_core::transaction::{Savepoint, Transaction, TransactionManager};
 pub use sqlx_core::type_info::TypeInfo;
 pub use sqlx_core::types::Type;
 pub use sqlx_core::value::{Value, ValueRef};
@@ -65,6 +71,10 @@ pub use sqlx_core::postgres::{self, PgConnection, PgPool, Postgres};
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
 pub use sqlx_core::sqlite::{self, Sqlite, SqliteConnection, SqlitePool};
 
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub use sqlx_core::testing::{self, Mock, MockConnection};
+
 #[cfg(feature = "macros")]
 #[doc(hidden)]
 pub extern crate sqlx_macros;
@@ -28,6 +28,22 @@
 /// # fn main() {}
 /// ```
 ///
+/// A query with no output columns, such as an `INSERT` without `RETURNING`, expands to a plain
+/// [`query::Query`][crate::query::Query]. Calling `.execute()` on it returns the result type of
+/// the database, which reports the number of rows affected and, for MySQL and SQLite, the id of
+/// the inserted row:
+///
+/// ```rust,ignore
+/// use sqlx::Done;
+///
+/// // MySQL
+/// let done = sqlx::query!("INSERT INTO accounts (name) VALUES (?)", "Herp Derpinson")
+///     .execute(&mut conn)
+///     .await?;
+///
+/// println!("inserted {} row(s) with id {}", done.rows_affected(), done.last_insert_id());
+/// ```
+///
 /// ## Requirements
 /// * The `DATABASE_URL` environment variable must be set at build-time to point to a database
 /// server with the schema that the query string will be checked against. All variants of `query!()`
@@ -36,9 +52,9 @@
 ///     * Or, `sqlx-data.json` must exist at the workspace root. See [Offline Mode](#offline-mode)
 ///       below.
 ///
-/// * The query must be a string literal, or concatenation of string literals using `+` (useful
-/// for queries generated by macro), or else it cannot be introspected (and thus cannot be dynamic
-/// or the result of another macro).
+/// * The query must be a string literal, or concatenation of string literals using `+` or
+/// `concat!()` (useful for queries generated by macro), or else it cannot be introspected (and
+/// thus cannot be dynamic or the result of another macro).
 ///
 /// * The `QueryAs` instance will be bound to the same database type as `query!()` was compiled
 /// against (e.g. you cannot build against a Postgres database and then run the query against
@@ -106,6 +122,12 @@
 /// has a `NOT NULL` constraint. Columns that do not have a `NOT NULL` constraint or are the result
 /// of an expression are assumed to be nullable and so `Option<T>` is used instead of `T`.
 ///
+/// With the `json` feature enabled, the plan of the query (from `EXPLAIN (VERBOSE, FORMAT JSON)`)
+/// is used to refine this: columns from the nullable side of a `LEFT`, `RIGHT` or `FULL` join are
+/// nullable even if they are `NOT NULL` in their table, while `count(..)` and `COALESCE(..)` with
+/// a literal as its last argument are known to never be `NULL`. This is skipped when describing
+/// a query inside of a transaction.
+///
 /// For MySQL, the implementation looks at [the `NOT_NULL` flag](https://dev.mysql.com/doc/dev/mysql-server/8.0.12/group__group__cs__column__definition__flags.html#ga50377f5ca5b3e92f3931a81fe7b44043)
 /// of [the `ColumnDefinition` structure in `COM_QUERY_OK`](https://dev.mysql.com/doc/internals/en/com-query-response.html#column-definition):
 /// if it is set, `T` is used; if it is not set, `Option<T>` is used.
@@ -239,6 +261,16 @@
 /// # }
 /// ```
 ///
+/// In Postgres, domains are inferred as their base type. A column of a user-defined enum or
+/// composite type is decoded into whatever type the field of `query_as!()` (or the output of
+/// `query_scalar!()`) has, which must implement `sqlx::Type` for it, e.g. with
+/// `#[derive(sqlx::Type)]`; the type name it gives is checked when the row is decoded. `query!()`
+/// has no field to infer from, so such a column needs an override like the above. A bind
+/// parameter of such a type takes any value whose type implements `sqlx::Type`.
+/// Such types are resolved through the `search_path` of the connection used to check the query;
+/// one that is not on it is reported by its schema-qualified name, e.g. `inventory.status`, which
+/// is also the name to give the Rust type with `#[sqlx(rename = "..")]`.
+///
 /// ##### Overrides cheatsheet
 ///
 /// | Syntax    | Nullability     | Type       |
@@ -249,6 +281,20 @@
 /// | `foo!: T` | Forced not-null | Overridden |
 /// | `foo?: T` | Forced nullable | Overridden |
 ///
+/// ## Multiple Databases
+/// By default the query is checked against the database at `DATABASE_URL`. If a crate talks to
+/// more than one database, an invocation can name a different environment variable (which may
+/// also be set in `.env`) to read the URL from:
+///
+/// ```rust,ignore
+/// let user = sqlx::query!(url = "env:USERS_DATABASE_URL", "SELECT name FROM users WHERE id = $1", id)
+///     .fetch_one(&users_pool)
+///     .await?;
+/// ```
+///
+/// This is also supported by [query_as!] and [query_scalar!], with `url = ...` given first.
+/// Note that offline mode still reads all query data from the single `sqlx-data.json`.
+///
 /// ## Offline Mode (requires the `offline` feature)
 /// The macros can be configured to not require a live database connection for compilation,
 /// but it requires a couple extra steps:
@@ -276,6 +322,13 @@
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 macro_rules! query (
+    // these arms must come first as `url = "..."` would otherwise parse as an expression
+    (url = $url:literal, $query:expr) => ({
+        $crate::sqlx_macros::expand_query!(url = $url, source = $query)
+    });
+    (url = $url:literal, $query:expr, $($args:tt)*) => ({
+        $crate::sqlx_macros::expand_query!(url = $url, source = $query, args = [$($args)*])
+    });
     // in Rust 1.45 we can now invoke proc macros in expression position
     ($query:expr) => ({
         $crate::sqlx_macros::expand_query!(source = $query)
@@ -295,6 +348,12 @@ macro_rules! query (
 
 /// A variant of [query!] which does not check the input or output types. This still does parse
 /// the query to ensure it's syntactically and semantically valid for the current database.
+///
+/// The number of bind arguments is still checked against the parameters of the query but their
+/// types are not, which makes this useful as an escape hatch for types the macros don't know
+/// about (such as user-defined enums in Postgres or unsigned integers in MySQL). Output columns
+/// are decoded into the inferred (or overridden) type without a runtime compatibility check, so
+/// a mismatch will be reported as a decoding error instead.
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 macro_rules! query_unchecked (
@@ -317,6 +376,9 @@ macro_rules! query_unchecked (
 /// unlike `include_str!()` which uses compiler internals to get the path of the file where it
 /// was invoked.
 ///
+/// Like `include_str!()`, the file is tracked by the compiler so changing it will cause the
+/// invoking crate to be rebuilt and the query to be checked again.
+///
 /// -----
 ///
 /// `examples/queries/account-by-id.sql`:
@@ -459,6 +521,11 @@ macro_rules! query_file_unchecked (
 /// assert_eq!(record.id, MyInt4(1));
 /// ```
 ///
+/// ### Column Names
+/// Each column is assigned to the struct field of the same name, so every column in the result
+/// must have a unique name. Queries which return the same name twice (e.g. `id` from both sides of
+/// a `JOIN`) are rejected at compile time; use `AS` to give the columns distinct names.
+///
 /// ### Troubleshooting: "error: mismatched types"
 /// If you get a "mismatched types" error from an invocation of this macro and the error
 /// isn't pointing specifically at a parameter.
@@ -512,6 +579,12 @@ macro_rules! query_file_unchecked (
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 macro_rules! query_as (
+    (url = $url:literal, $out_struct:path, $query:expr) => ( {
+        $crate::sqlx_macros::expand_query!(url = $url, record = $out_struct, source = $query)
+    });
+    (url = $url:literal, $out_struct:path, $query:expr, $($args:tt)*) => ( {
+        $crate::sqlx_macros::expand_query!(url = $url, record = $out_struct, source = $query, args = [$($args)*])
+    });
     ($out_struct:path, $query:expr) => ( {
         $crate::sqlx_macros::expand_query!(record = $out_struct, source = $query)
     });
@@ -593,6 +666,130 @@ macro_rules! query_file_as_unchecked (
     })
 );
 
+/// A variant of [query!] which expects a single column from the query and evaluates to an
+/// instance of [QueryScalar][crate::query::QueryScalar].
+///
+/// The name of the column is not required to be a valid Rust identifier, however you can still
+/// use the column type override syntax in which case the column name _does_ have to be a valid
+/// Rust identifier for the override to parse properly. If the override parse fails the error
+/// is silently ignored (we just don't have a reliable way to tell the difference). **If you're
+/// getting a different type than expected, please check to see if your override syntax is correct
+/// before opening an issue.**
+///
+/// Wildcard overrides like in [query_as!] are also allowed, in which case the output type
+/// is left up to inference.
+///
+/// See [query!] for more information.
+///
+/// ```rust,ignore
+/// // let mut conn = <impl sqlx::Executor>;
+/// let count: i64 = sqlx::query_scalar!("select count(*) from accounts where active = $1", true)
+///     .fetch_one(&mut conn)
+///     .await?
+///     .unwrap_or(0); // `count(*)` is an expression and so is inferred to be nullable
+///
+/// // with a type override
+/// let count = sqlx::query_scalar!(r#"select count(*) as "count!" from accounts"#)
+///     .fetch_one(&mut conn)
+///     .await?;
+/// ```
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+macro_rules! query_scalar (
+    (url = $url:literal, $query:expr) => (
+        $crate::sqlx_macros::expand_query!(url = $url, scalar = _, source = $query)
+    );
+    (url = $url:literal, $query:expr, $($args:tt)*) => (
+        $crate::sqlx_macros::expand_query!(url = $url, scalar = _, source = $query, args = [$($args)*])
+    );
+    ($query:expr) => (
+        $crate::sqlx_macros::expand_query!(scalar = _, source = $query)
+    );
+    ($query:expr, $($args:tt)*) => (
+        $crate::sqlx_macros::expand_query!(scalar = _, source = $query, args = [$($args)*])
+    )
+);
+
+/// A variant of [query_scalar!] which takes a file path like [query_file!].
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+macro_rules! query_file_scalar (
+    ($path:literal) => (
+        $crate::sqlx_macros::expand_query!(scalar = _, source_file = $path)
+    );
+    ($path:literal, $($args:tt)*) => (
+        $crate::sqlx_macros::expand_query!(scalar = _, source_file = $path, args = [$($args)*])
+    )
+);
+
+/// A variant of [query_scalar!] which does not typecheck bind parameters and leaves the output type
+/// to inference. The query itself is still checked that it is syntactically and semantically
+/// valid for the database, that it only produces one column and that the number of bind parameters
+/// is correct.
+///
+/// For this macro variant the name of the column is irrelevant.
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+macro_rules! query_scalar_unchecked (
+    ($query:expr) => (
+        $crate::sqlx_macros::expand_query!(scalar = _, source = $query, checked = false)
+    );
+    ($query:expr, $($args:tt)*) => (
+        $crate::sqlx_macros::expand_query!(scalar = _, source = $query, args = [$($args)*], checked = false)
+    )
+);
+
+/// A variant of [query_file_scalar!] which does not typecheck bind parameters and leaves the output
+/// type to inference. The query itself is still checked that it is syntactically and
+/// semantically valid for the database, that it only produces one column and that the number of
+/// bind parameters is correct.
+///
+/// For this macro variant the name of the column is irrelevant.
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+macro_rules! query_file_scalar_unchecked (
+    ($path:literal) => (
+        $crate::sqlx_macros::expand_query!(scalar = _, source_file = $path, checked = false)
+    );
+    ($path:literal, $($args:tt)*) => (
+        $crate::sqlx_macros::expand_query!(scalar = _, source_file = $path, args = [$($args)*], checked = false)
+    )
+);
+
+/// Checks a script of several statements at compile time and expands to the script as a
+/// `&'static str`.
+///
+/// [query!] and the other query macros only accept a single statement, as only the first would
+/// be described otherwise. This macro splits the script on `;` and checks each statement
+/// against the database on its own, reporting which one failed. The script is then executed
+/// as a whole, without bind parameters:
+///
+/// ```rust,ignore
+/// conn.execute(sqlx::execute_many!(
+///     "UPDATE accounts SET locked = TRUE WHERE last_login < now() - interval '1 year';
+///      DELETE FROM sessions WHERE account_id IN (SELECT id FROM accounts WHERE locked);"
+/// ))
+/// .await?;
+/// ```
+///
+/// As every statement is checked against the database as it is now, a statement that uses an
+/// object created earlier in the same script (such as a table in a migration) fails to check;
+/// use [migrate!] or execute those with [query()][crate::query::query] instead.
+///
+/// Semicolons in string literals, quoted identifiers, comments and dollar-quoted strings are
+/// handled, but backslash escapes in MySQL string literals and compound statements with `;` in
+/// their body (e.g. a trigger) are not.
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+macro_rules! execute_many (
+    (url = $url:literal, $query:expr) => ({
+        $crate::sqlx_macros::expand_execute_many!(url = $url, source = $query)
+    });
+    ($query:expr) => ({
+        $crate::sqlx_macros::expand_execute_many!(source = $query)
+    })
+);
+
 /// Embeds migrations into the binary by expanding to a static instance of [Migrator][crate::migrate::Migrator].
 ///
 /// ```rust,ignore
@@ -610,6 +807,12 @@ macro_rules! query_file_as_unchecked (
 /// The directory must be relative to the project root (the directory containing `Cargo.toml`),
 /// unlike `include_str!()` which uses compiler internals to get the path of the file where it
 /// was invoked.
+///
+/// Each migration is embedded with `include_str!()`, so editing an existing migration will
+/// trigger a rebuild; adding a new file to the directory will not, so touch a source file (or
+/// run `cargo clean -p <your crate>`) after adding a migration.
+///
+/// Two migrations with the same version are rejected at compile time.
 #[cfg(feature = "migrate")]
 #[macro_export]
 macro_rules! migrate {
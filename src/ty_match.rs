@@ -37,6 +37,11 @@ impl<T, U> WrapSameExt for WrapSame<T, Option<U>> {
     type Wrapped = Option<T>;
 }
 
+// e.g. `&record.field` where the field is an `Option`
+impl<T, U> WrapSameExt for WrapSame<T, &'_ Option<U>> {
+    type Wrapped = Option<T>;
+}
+
 impl<T, U> WrapSameExt for &'_ WrapSame<T, U> {
     type Wrapped = T;
 }
@@ -73,6 +78,14 @@ impl<'a> MatchBorrowExt for MatchBorrow<Option<&'a [u8]>, Option<&'a Vec<u8>>> {
     type Matched = Option<&'a [u8]>;
 }
 
+impl<'a> MatchBorrowExt for MatchBorrow<Option<&'a str>, &'_ Option<String>> {
+    type Matched = Option<&'a str>;
+}
+
+impl<'a> MatchBorrowExt for MatchBorrow<Option<&'a [u8]>, &'_ Option<Vec<u8>>> {
+    type Matched = Option<&'a [u8]>;
+}
+
 impl<'a> MatchBorrowExt for MatchBorrow<&'a str, String> {
     type Matched = &'a str;
 }
@@ -142,6 +155,7 @@ fn test_wrap_same() {
         let _: i32 = WrapSame::<i32, _>::new(&0i32).wrap_same();
         let _: i32 = WrapSame::<i32, _>::new(&"hello, world!").wrap_same();
         let _: Option<i32> = WrapSame::<i32, _>::new(&Some(String::new())).wrap_same();
+        let _: Option<i32> = WrapSame::<i32, _>::new(&&Some(0i32)).wrap_same();
     }
 }
 
@@ -159,5 +173,11 @@ fn test_match_borrow() {
 
         let (_, match_borrow) = MatchBorrow::new(0i64, &0i64);
         let _: i64 = match_borrow.match_borrow();
+
+        let (_, match_borrow) = MatchBorrow::new(Some(""), &&Some(String::new()));
+        let _: Option<&str> = match_borrow.match_borrow();
+
+        let (_, match_borrow) = MatchBorrow::new(Some(0i64), &&Some(0i64));
+        let _: Option<i64> = match_borrow.match_borrow();
     }
 }
@@ -1,4 +1,4 @@
-use sqlx::any::AnyRow;
+use sqlx::any::{AnyKind, AnyRow};
 use sqlx::{Any, Connection, Executor, Row};
 use sqlx_test::new;
 
@@ -97,3 +97,56 @@ async fn it_can_fail_and_recover_with_pool() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_binds_a_typed_null() -> anyhow::Result<()> {
+    let mut conn = new::<Any>().await?;
+
+    #[allow(unreachable_patterns)]
+    let sql = match conn.kind() {
+        #[cfg(feature = "postgres")]
+        AnyKind::Postgres => "SELECT COALESCE($1, 42)",
+
+        // MSSQL has no typed NULLs
+        #[cfg(feature = "mssql")]
+        AnyKind::Mssql => return Ok(()),
+
+        _ => "SELECT COALESCE(?, 42)",
+    };
+
+    let value: i64 = sqlx::query_scalar(sql)
+        .bind(sqlx::types::Null::<i64>::new())
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 42);
+
+    Ok(())
+}
+
+#[cfg(all(feature = "testing", feature = "migrate"))]
+#[sqlx_macros::test(fixtures("fixture_rows"))]
+async fn it_applies_fixtures(pool: sqlx::AnyPool) -> anyhow::Result<()> {
+    let rows = pool.fetch_all("SELECT id FROM _sqlx_fixture_rows").await?;
+
+    assert_eq!(rows.len(), 2);
+
+    // each test gets its own database, so the fixture can be applied to every one of them
+    sqlx::query("INSERT INTO _sqlx_fixture_rows (id) VALUES (3)")
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(all(feature = "testing", feature = "migrate"))]
+#[sqlx_macros::test(fixtures("fixture_rows"))]
+async fn it_applies_fixtures_to_a_database_per_test(pool: sqlx::AnyPool) -> anyhow::Result<()> {
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM _sqlx_fixture_rows")
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(count, 2);
+
+    Ok(())
+}
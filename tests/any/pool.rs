@@ -1,9 +1,13 @@
+use futures::task::{waker, ArcWake};
 use sqlx::any::AnyPoolOptions;
+use sqlx::pool::CloseReason;
+use std::future::Future;
 use std::sync::{
-    atomic::{AtomicUsize, Ordering},
+    atomic::{AtomicBool, AtomicUsize, Ordering},
     Arc,
 };
-use std::time::Duration;
+use std::task::Context;
+use std::time::{Duration, Instant};
 
 #[sqlx_macros::test]
 async fn pool_should_invoke_after_connect() -> anyhow::Result<()> {
@@ -62,3 +66,158 @@ async fn pool_should_be_returned_failed_transactions() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn pool_should_invoke_event_hooks() -> anyhow::Result<()> {
+    let opened = Arc::new(AtomicUsize::new(0));
+    let closed = Arc::new(AtomicUsize::new(0));
+    let timed_out = Arc::new(AtomicUsize::new(0));
+
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .connect_timeout(Duration::from_secs(1))
+        .on_connect({
+            let opened = opened.clone();
+            move || {
+                opened.fetch_add(1, Ordering::SeqCst);
+            }
+        })
+        .on_close({
+            let closed = closed.clone();
+            move |reason| {
+                assert_eq!(reason, CloseReason::PoolClosed);
+                closed.fetch_add(1, Ordering::SeqCst);
+            }
+        })
+        .on_acquire_timeout({
+            let timed_out = timed_out.clone();
+            move || {
+                timed_out.fetch_add(1, Ordering::SeqCst);
+            }
+        })
+        .connect(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let conn = pool.acquire().await?;
+    assert!(pool.acquire().await.is_err());
+    drop(conn);
+
+    pool.close().await;
+
+    assert_eq!(opened.load(Ordering::SeqCst), 1);
+    assert_eq!(timed_out.load(Ordering::SeqCst), 1);
+    assert_eq!(closed.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn pool_should_close_idle_connections() -> anyhow::Result<()> {
+    let pool = AnyPoolOptions::new()
+        .max_connections(2)
+        .connect(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let a = pool.acquire().await?;
+    let b = pool.acquire().await?;
+    assert_eq!(pool.size(), 2);
+
+    drop(b);
+    wait_until(|| pool.num_idle() == 1).await?;
+
+    // a connection that is checked out is not closed
+    pool.close_idle().await;
+    assert_eq!(pool.size(), 1);
+
+    drop(a);
+    wait_until(|| pool.num_idle() == 1).await?;
+
+    // keep every connection
+    pool.retain(|meta| meta.age < Duration::from_secs(60)).await;
+    assert_eq!(pool.size(), 1);
+    assert_eq!(pool.num_idle(), 1);
+
+    pool.retain(|_| false).await;
+    assert_eq!(pool.size(), 0);
+
+    // the pool opens a new connection on demand
+    let _ = pool.acquire().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn pool_should_not_leak_when_acquire_is_dropped() -> anyhow::Result<()> {
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .connect(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    pool.close_idle().await;
+
+    // drop `acquire()` at every point while it is opening a connection
+    for i in 0..50 {
+        let _ = sqlx_rt::timeout(Duration::from_micros(i * 100), pool.acquire()).await;
+    }
+
+    // any slot that leaked would make this time out
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let _conn = pool.acquire_with_deadline(deadline).await?;
+    assert_eq!(pool.size(), 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn pool_should_pass_on_wakeups_of_dropped_acquires() -> anyhow::Result<()> {
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .connect(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let conn = pool.acquire().await?;
+
+    // both wait for `conn` to be returned
+    let woken = Arc::new(Woken(AtomicBool::new(false)));
+    let first_waker = waker(Arc::clone(&woken));
+
+    let mut first = Box::pin(pool.acquire());
+    assert!(first
+        .as_mut()
+        .poll(&mut Context::from_waker(&first_waker))
+        .is_pending());
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let mut second = Box::pin(pool.acquire_with_deadline(deadline));
+    assert!(futures::poll!(&mut second).is_pending());
+
+    // wakes `first`, which is dropped before it can take the connection
+    drop(conn);
+    wait_until(|| woken.0.load(Ordering::SeqCst)).await?;
+    drop(first);
+
+    // times out if the wakeup was lost with `first`
+    let _conn = second.await?;
+
+    Ok(())
+}
+
+struct Woken(AtomicBool);
+
+impl ArcWake for Woken {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+// a connection is returned to the pool from a task of its own when it has to be flushed first
+async fn wait_until(condition: impl Fn() -> bool) -> anyhow::Result<()> {
+    sqlx_rt::timeout(Duration::from_secs(5), async {
+        while !condition() {
+            sqlx_rt::yield_now().await;
+        }
+    })
+    .await?;
+
+    Ok(())
+}
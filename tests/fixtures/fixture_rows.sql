@@ -0,0 +1,2 @@
+CREATE TABLE _sqlx_fixture_rows (id INTEGER PRIMARY KEY);
+INSERT INTO _sqlx_fixture_rows (id) VALUES (1), (2);
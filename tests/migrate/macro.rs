@@ -1,4 +1,4 @@
-use sqlx::migrate::Migrator;
+use sqlx::migrate::{MigrationType, Migrator};
 use std::path::Path;
 
 static EMBEDDED: Migrator = sqlx::migrate!("tests/migrate/migrations");
@@ -7,12 +7,31 @@ static EMBEDDED: Migrator = sqlx::migrate!("tests/migrate/migrations");
 async fn same_output() -> anyhow::Result<()> {
     let runtime = Migrator::new(Path::new("tests/migrate/migrations")).await?;
 
+    assert_eq!(EMBEDDED.iter().len(), runtime.iter().len());
+
     for (e, r) in EMBEDDED.iter().zip(runtime.iter()) {
         assert_eq!(e.version, r.version);
         assert_eq!(e.description, r.description);
+        assert_eq!(e.migration_type, r.migration_type);
         assert_eq!(e.sql, r.sql);
         assert_eq!(e.checksum, r.checksum);
     }
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn reversible_pairs() -> anyhow::Result<()> {
+    let pair: Vec<_> = EMBEDDED
+        .iter()
+        .filter(|m| m.version == 20201015120000)
+        .collect();
+
+    assert_eq!(pair.len(), 2);
+    assert_eq!(pair[0].migration_type, MigrationType::ReversibleUp);
+    assert_eq!(pair[1].migration_type, MigrationType::ReversibleDown);
+    assert_eq!(pair[0].description, "tweet owner index");
+    assert_eq!(pair[1].description, "tweet owner index");
+
+    Ok(())
+}
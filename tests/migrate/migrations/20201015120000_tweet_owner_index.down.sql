@@ -0,0 +1 @@
+DROP INDEX tweet_owner_id;
@@ -0,0 +1 @@
+CREATE INDEX tweet_owner_id ON tweet (owner_id);
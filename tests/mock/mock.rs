@@ -0,0 +1,128 @@
+use sqlx::testing::{MockRow, MockValue};
+use sqlx::{Connection, Done, Executor, MockConnection, Row};
+
+#[sqlx_macros::test]
+async fn it_returns_expected_rows() -> anyhow::Result<()> {
+    let mut conn = MockConnection::new();
+
+    conn.expect("SELECT id, name FROM users WHERE active = ?")
+        .returning(vec![
+            MockRow::new().with("id", 1_i64).with("name", "alice"),
+            MockRow::new().with("id", 2_i64).with("name", "bob"),
+        ]);
+
+    let users: Vec<(i64, String)> = sqlx::query_as("SELECT id, name FROM users WHERE active = ?")
+        .bind(true)
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(users, vec![(1, "alice".to_owned()), (2, "bob".to_owned())]);
+    assert!(conn.is_satisfied());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_records_statements_and_binds() -> anyhow::Result<()> {
+    let mut conn = MockConnection::new();
+
+    conn.expect("UPDATE users SET name = ? WHERE id = ?")
+        .rows_affected(1);
+
+    let done = sqlx::query(
+        "UPDATE users
+         SET name = ?
+         WHERE id = ?",
+    )
+    .bind("carol")
+    .bind(Option::<i32>::None)
+    .execute(&mut conn)
+    .await?;
+
+    assert_eq!(done.rows_affected(), 1);
+    assert_eq!(
+        conn.executed()[0].arguments,
+        vec![MockValue::Text("carol".into()), MockValue::Null]
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_rejects_unexpected_statements() -> anyhow::Result<()> {
+    let mut conn = MockConnection::new();
+
+    conn.expect("SELECT 1");
+
+    let res = conn.execute("SELECT 2").await;
+
+    assert!(matches!(res, Err(sqlx::Error::Protocol(_))));
+    assert!(!conn.is_satisfied());
+    assert_eq!(conn.executed()[0].sql, "SELECT 2");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_records_transactions() -> anyhow::Result<()> {
+    let mut conn = MockConnection::new();
+
+    conn.expect("DELETE FROM users");
+
+    let mut tx = conn.begin().await?;
+    tx.execute("DELETE FROM users").await?;
+    tx.rollback().await?;
+
+    let executed: Vec<_> = conn.executed().iter().map(|e| e.sql.as_str()).collect();
+
+    assert_eq!(executed, ["BEGIN", "DELETE FROM users", "ROLLBACK"]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_records_named_savepoints() -> anyhow::Result<()> {
+    let mut conn = MockConnection::new();
+
+    let mut tx = conn.begin().await?;
+
+    // "first" stays in place when its guard is dropped
+    tx.savepoint("first").await?;
+    tx.savepoint("second").await?.rollback().await?;
+    tx.rollback_to("first").await?;
+    tx.release("first").await?;
+    tx.commit().await?;
+
+    let executed: Vec<_> = conn.executed().iter().map(|e| e.sql.as_str()).collect();
+
+    assert_eq!(
+        executed,
+        [
+            "BEGIN",
+            "SAVEPOINT first",
+            "SAVEPOINT second",
+            "ROLLBACK TO SAVEPOINT second",
+            "RELEASE SAVEPOINT second",
+            "ROLLBACK TO SAVEPOINT first",
+            "RELEASE SAVEPOINT first",
+            "COMMIT"
+        ]
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_decodes_by_name() -> anyhow::Result<()> {
+    let mut conn = MockConnection::new();
+
+    conn.expect("SELECT email FROM users")
+        .returning(vec![MockRow::new().with("email", Option::<&str>::None)]);
+
+    let row = conn.fetch_one("SELECT email FROM users").await?;
+    let email: Option<String> = row.try_get("email")?;
+
+    assert_eq!(email, None);
+
+    Ok(())
+}
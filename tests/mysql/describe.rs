@@ -1,5 +1,5 @@
 use sqlx::mysql::MySql;
-use sqlx::{Column, Executor, Type, TypeInfo};
+use sqlx::{Column, Connection, Executor, ServerFlavor, Type, TypeInfo};
 use sqlx_test::new;
 
 #[sqlx_macros::test]
@@ -69,3 +69,31 @@ async fn uses_alias_name() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_describes_returning_on_mariadb() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let returning = match conn.server_version() {
+        Some(version) => version.flavor == ServerFlavor::MariaDb && version.at_least(10, 5),
+        None => false,
+    };
+
+    if !returning {
+        // MySQL has no `RETURNING` clause
+        return Ok(());
+    }
+
+    let d = conn
+        .describe("INSERT INTO tweet (text) VALUES (?) RETURNING id, owner_id")
+        .await?;
+
+    assert_eq!(d.columns().len(), 2);
+    assert_eq!(d.columns()[0].name(), "id");
+    assert_eq!(d.columns()[1].name(), "owner_id");
+
+    assert_eq!(d.nullable(0), Some(false));
+    assert_eq!(d.nullable(1), Some(true));
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use sqlx::{Connection, MySql, MySqlConnection, Transaction};
+use sqlx::{Connection, Done, MySql, MySqlConnection, Transaction};
 use sqlx_test::new;
 
 #[sqlx_macros::test]
@@ -33,6 +33,45 @@ async fn macro_select_from_cte_bind() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_insert_exposes_last_insert_id() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+    let mut tx = conn.begin().await?;
+
+    // MySQL has no `RETURNING`; the id is reported by `execute()` instead
+    let done = sqlx::query!(
+        "INSERT INTO tweet (text, owner_id) VALUES (?, ?)",
+        "Hello, World",
+        1_i64
+    )
+    .execute(&mut tx)
+    .await?;
+
+    assert_eq!(done.rows_affected(), 1);
+
+    let tweet = sqlx::query!("SELECT text FROM tweet WHERE id = ?", done.last_insert_id())
+        .fetch_one(&mut tx)
+        .await?;
+
+    assert_eq!(tweet.text, "Hello, World");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_query_scalar() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    // the column is named `count(*)` which is not a valid identifier
+    let count = sqlx::query_scalar!("select count(*) from (select 1) foo")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
 #[derive(Debug)]
 struct RawAccount {
     r#type: i32,
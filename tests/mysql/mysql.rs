@@ -14,6 +14,36 @@ async fn it_connects() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_decodes_text_in_utf8mb4_collations() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE emoji (
+    general VARCHAR(20) CHARACTER SET utf8mb4 COLLATE utf8mb4_general_ci,
+    bin VARCHAR(20) CHARACTER SET utf8mb4 COLLATE utf8mb4_bin
+);
+        "#,
+    )
+    .await?;
+
+    sqlx::query("INSERT INTO emoji (general, bin) VALUES (?, ?)")
+        .bind("🦀 crab")
+        .bind("🐘 elephant")
+        .execute(&mut conn)
+        .await?;
+
+    let (general, bin): (String, String) = sqlx::query_as("SELECT general, bin FROM emoji")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(general, "🦀 crab");
+    assert_eq!(bin, "🐘 elephant");
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_maths() -> anyhow::Result<()> {
     let mut conn = new::<MySql>().await?;
@@ -387,3 +417,136 @@ async fn test_issue_622() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[derive(Debug, PartialEq, sqlx::Type)]
+#[sqlx(rename_all = "snake_case")]
+enum Shape {
+    Square,
+    RoundedSquare,
+}
+
+#[derive(Debug, PartialEq, sqlx::Type)]
+#[repr(i64)]
+enum Priority {
+    Low = 1,
+    High = 2,
+}
+
+#[sqlx_macros::test]
+async fn it_decodes_derived_enums_from_text_and_int_columns() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    // `Priority` is an `i64` but is stored in a narrower column
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE tasks (
+    shape VARCHAR(20) NOT NULL,
+    priority SMALLINT NOT NULL
+);
+    "#,
+    )
+    .await?;
+
+    sqlx::query("INSERT INTO tasks ( shape, priority ) VALUES ( 'rounded_square', 2 )")
+        .execute(&mut conn)
+        .await?;
+
+    let (shape, priority): (Shape, Priority) = sqlx::query_as("SELECT shape, priority FROM tasks")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(shape, Shape::RoundedSquare);
+    assert_eq!(priority, Priority::High);
+
+    Ok(())
+}
+
+#[cfg(feature = "migrate")]
+#[sqlx_macros::test]
+async fn it_creates_and_drops_databases_with_special_names() -> anyhow::Result<()> {
+    use sqlx::migrate::MigrateDatabase;
+
+    let mut url = url::Url::parse(&env::var("DATABASE_URL")?)?;
+    url.set_path("/sqlx%20create%60test");
+    let url = url.as_str();
+
+    MySql::drop_database(url).await?;
+    assert!(!MySql::database_exists(url).await?);
+
+    MySql::create_database(url).await?;
+    assert!(MySql::database_exists(url).await?);
+
+    let mut conn = MySqlConnection::connect(url).await?;
+    conn.ping().await?;
+    conn.close().await?;
+
+    MySql::drop_database(url).await?;
+    assert!(!MySql::database_exists(url).await?);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_explains_queries() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let plan = sqlx::query("SELECT * FROM tweet WHERE id = ?")
+        .bind(1_i64)
+        .explain(&mut conn)
+        .await?;
+
+    assert_eq!(plan.len(), 1);
+
+    let table: Option<String> = plan[0].try_get("table")?;
+    assert_eq!(table.as_deref(), Some("tweet"));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_reuse_a_connection_after_a_cancelled_query() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let cancelled = sqlx_rt::timeout(
+        std::time::Duration::from_millis(100),
+        conn.execute("SELECT SLEEP(1)"),
+    )
+    .await;
+
+    assert!(cancelled.is_err());
+
+    // the response to the cancelled query is discarded rather than read as ours
+    let value: i64 = sqlx::query_scalar("SELECT 42").fetch_one(&mut conn).await?;
+    assert_eq!(value, 42);
+
+    conn.ping().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_reuse_a_connection_after_a_cancelled_query_with_arguments() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    // cancel at later and later points, so that some attempts are cut off while the statement
+    // is still being prepared and others while it is being executed
+    for i in 0..50_i64 {
+        let _ = sqlx_rt::timeout(
+            std::time::Duration::from_micros(i as u64 * 100),
+            sqlx::query("SELECT ?, SLEEP(0.01)")
+                .bind(i)
+                .persistent(false)
+                .execute(&mut conn),
+        )
+        .await;
+
+        let value: i64 = sqlx::query_scalar("SELECT ?")
+            .bind(i)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(value, i);
+    }
+
+    Ok(())
+}
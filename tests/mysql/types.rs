@@ -21,6 +21,10 @@ test_type!(i32(MySql, "2141512" == 2141512_i32));
 test_type!(u64(MySql, "CAST(2141512 AS UNSIGNED)" == 2141512_u64));
 test_type!(i64(MySql, "2141512" == 2141512_i64));
 
+test_type!(non_zero_u32<std::num::NonZeroU32>(MySql,
+    "CAST(2141512 AS UNSIGNED)" == std::num::NonZeroU32::new(2141512).unwrap()
+));
+
 test_type!(f64(MySql, "3.14159265e0" == 3.14159265_f64));
 
 // NOTE: This behavior can be very surprising. MySQL implicitly widens FLOAT bind parameters
@@ -42,6 +46,12 @@ test_type!(bytes<Vec<u8>>(MySql,
         == vec![0_u8, 0, 0, 0, 0x52]
 ));
 
+test_type!(std_duration<std::time::Duration>(MySql,
+    "CAST('00:00:00' AS TIME)" == std::time::Duration::default(),
+    "CAST('01:02:03' AS TIME)" == std::time::Duration::from_secs(3_723),
+    "CAST('838:59:59.5' AS TIME(1))" == std::time::Duration::from_millis(3_020_399_500)
+));
+
 #[cfg(feature = "uuid")]
 test_type!(uuid<sqlx::types::Uuid>(MySql,
     "x'b731678f636f4135bc6f19440c13bd19'"
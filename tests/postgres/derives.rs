@@ -1,5 +1,5 @@
 use futures::TryStreamExt;
-use sqlx::{Connection, Executor, FromRow, Postgres};
+use sqlx::{Connection, Executor, FromRow, Postgres, Row};
 use sqlx_core::postgres::types::PgRange;
 use sqlx_test::{new, test_type};
 use std::fmt::Debug;
@@ -94,6 +94,15 @@ struct InventoryItem {
     price: Option<i64>,
 }
 
+// Enum type in a schema that is not on the search path
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename = "inventory.status")]
+#[sqlx(rename_all = "snake_case")]
+enum StockStatus {
+    InStock,
+    SoldOut,
+}
+
 // Custom range type
 #[derive(sqlx::Type, Debug, PartialEq)]
 #[sqlx(rename = "float_range")]
@@ -314,6 +323,100 @@ SELECT $1 = ROW('fuzzy dice', 42, 199)::inventory_item, $1
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_schema_qualified_type() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let rec: (bool, StockStatus) = sqlx::query_as(
+        "
+SELECT $1 = 'sold_out'::inventory.status, $1
+        ",
+    )
+    .bind(StockStatus::SoldOut)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(rec.0);
+    assert_eq!(rec.1, StockStatus::SoldOut);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_enum_array_type() -> anyhow::Result<()> {
+    #[derive(PartialEq, Debug, sqlx::Type)]
+    #[sqlx(rename = "status")]
+    #[sqlx(rename_all = "lowercase")]
+    enum Status {
+        New,
+        Open,
+        Closed,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let statuses = vec![Status::Open, Status::Closed, Status::New];
+
+    let rec: (bool, Vec<Status>) = sqlx::query_as(
+        "
+SELECT $1 = ARRAY['open', 'closed', 'new']::status[], $1
+        ",
+    )
+    .bind(&statuses)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(rec.0);
+    assert_eq!(rec.1, statuses);
+
+    // the text protocol does not send the type of the elements
+    let row = conn
+        .fetch_one("SELECT ARRAY['open', 'closed', 'new']::status[]")
+        .await?;
+
+    assert_eq!(row.try_get::<Vec<Status>, _>(0)?, statuses);
+
+    // the array type of a schema-qualified type is in the same schema
+    let rec: (bool, Vec<StockStatus>) = sqlx::query_as(
+        "
+SELECT $1 = ARRAY['sold_out', 'in_stock']::inventory.status[], $1
+        ",
+    )
+    .bind(vec![StockStatus::SoldOut, StockStatus::InStock])
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(rec.0);
+    assert_eq!(rec.1, [StockStatus::SoldOut, StockStatus::InStock]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_record_array_type() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let value = vec![InventoryItem {
+        name: "fuzzy dice".to_owned(),
+        supplier_id: Some(42),
+        price: Some(199),
+    }];
+
+    let rec: (bool, Vec<InventoryItem>) = sqlx::query_as(
+        "
+SELECT $1 = ARRAY[ROW('fuzzy dice', 42, 199)::inventory_item], $1
+        ",
+    )
+    .bind(&value)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(rec.0);
+    assert_eq!(rec.1, value);
+
+    Ok(())
+}
+
 #[cfg(feature = "macros")]
 #[sqlx_macros::test]
 async fn test_from_row() -> anyhow::Result<()> {
@@ -484,5 +587,60 @@ async fn test_default() -> anyhow::Result<()> {
     assert_eq!(has_default.not_default, 1);
     assert_eq!(has_default.default, None);
 
+    let has_default: HasDefault = sqlx::query_as(r#"SELECT 1 AS not_default, 2 AS default"#)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(has_default.default, Some(2));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_transparent_encode_by_value() -> anyhow::Result<()> {
+    use sqlx::encode::IsNull;
+    use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo};
+
+    // encodes as the name of the method that encoded it
+    struct EncodedBy;
+
+    impl sqlx::Type<Postgres> for EncodedBy {
+        fn type_info() -> PgTypeInfo {
+            <&str as sqlx::Type<Postgres>>::type_info()
+        }
+    }
+
+    impl sqlx::Encode<'_, Postgres> for EncodedBy {
+        fn encode(self, buf: &mut PgArgumentBuffer) -> IsNull {
+            <&str as sqlx::Encode<Postgres>>::encode("encode", buf)
+        }
+
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+            <&str as sqlx::Encode<Postgres>>::encode("encode_by_ref", buf)
+        }
+    }
+
+    #[derive(sqlx::Encode)]
+    #[sqlx(transparent)]
+    struct TransparentEncodedBy(EncodedBy);
+
+    impl sqlx::Type<Postgres> for TransparentEncodedBy {
+        fn type_info() -> PgTypeInfo {
+            <EncodedBy as sqlx::Type<Postgres>>::type_info()
+        }
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    // a value bound by value is encoded by value all the way down
+    let (by_value, by_ref): (String, String) = sqlx::query_as("SELECT $1::text, $2::text")
+        .bind(TransparentEncodedBy(EncodedBy))
+        .bind(&TransparentEncodedBy(EncodedBy))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(by_value, "encode");
+    assert_eq!(by_ref, "encode_by_ref");
+
     Ok(())
 }
@@ -93,3 +93,96 @@ async fn it_describes_composite() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_describes_types_outside_of_the_search_path_with_their_schema() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let d = conn
+        .describe("SELECT 'sold_out'::inventory.status, ROW('name',10,500)::inventory_item")
+        .await?;
+
+    assert_eq!(d.columns()[0].type_info().name(), "inventory.status");
+    assert_eq!(d.columns()[1].type_info().name(), "inventory_item");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_describes_domains_as_their_base_type() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let d = conn
+        .describe("SELECT $1::positive_int AS _1, $2::open_status AS _2")
+        .await?;
+
+    let params = d.parameters().unwrap().left().unwrap();
+
+    assert_eq!(params[0].name(), "INT4");
+    assert_eq!(params[1].name(), "status");
+
+    // a domain over an enum is resolved to the enum, with its variants
+    assert_eq!(
+        format!("{:?}", d.columns()[1].type_info().kind()),
+        r#"Enum(["new", "open", "closed"])"#
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_describes_composite_parameters() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let d = conn.describe("SELECT $1::inventory_item AS _1").await?;
+
+    let params = d.parameters().unwrap().left().unwrap();
+
+    assert_eq!(params[0].name(), "inventory_item");
+    assert_eq!(d.columns()[0].type_info().name(), "inventory_item");
+
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+#[sqlx_macros::test]
+async fn it_describes_nullability_from_the_plan() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE explain_authors (id INT4 PRIMARY KEY, name TEXT NOT NULL);
+CREATE TEMPORARY TABLE explain_posts (
+    id INT4 PRIMARY KEY,
+    author_id INT4 NOT NULL,
+    title TEXT
+);
+        "#,
+    )
+    .await?;
+
+    let d = conn
+        .describe(
+            "SELECT a.name, p.id, COALESCE(p.title, ''), count(*) OVER () \
+             FROM explain_authors a LEFT JOIN explain_posts p ON p.author_id = a.id",
+        )
+        .await?;
+
+    assert_eq!(d.nullable(0), Some(false));
+    assert_eq!(d.nullable(1), Some(true));
+    assert_eq!(d.nullable(2), Some(false));
+    assert_eq!(d.nullable(3), Some(false));
+
+    let d = conn
+        .describe(
+            "SELECT a.name, p.id \
+             FROM explain_authors a INNER JOIN explain_posts p ON p.author_id = a.id \
+             WHERE a.id = $1",
+        )
+        .await?;
+
+    assert_eq!(d.nullable(0), Some(false));
+    assert_eq!(d.nullable(1), Some(false));
+
+    Ok(())
+}
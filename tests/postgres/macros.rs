@@ -20,6 +20,81 @@ async fn test_query() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_query_scalar() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let id = sqlx::query_scalar!("select 1").fetch_one(&mut conn).await?;
+    // `select 1` infers to nullable in Postgres
+    assert_eq!(id, Some(1i32));
+
+    // invalid column names are ignored
+    let id = sqlx::query_scalar!(r#"select 1 as "&foo""#)
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(id, Some(1i32));
+
+    let id = sqlx::query_scalar!(r#"select 1 as "foo!""#)
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(id, 1i32);
+
+    let id = sqlx::query_scalar!(r#"select 1 as "foo?""#)
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(id, Some(1i32));
+
+    let id = sqlx::query_scalar!(r#"select 1 as "foo: MyInt4""#)
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(id, Some(MyInt4(1i32)));
+
+    let id = sqlx::query_scalar!(r#"select 1 as "foo?: MyInt4""#)
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(id, Some(MyInt4(1i32)));
+
+    let id = sqlx::query_scalar!(r#"select 1 as "foo!: MyInt4""#)
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(id, MyInt4(1i32));
+
+    let id: MyInt4 = sqlx::query_scalar!(r#"select 1 as "foo!: _""#)
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(id, MyInt4(1i32));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_query_concat() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let account = sqlx::query!(
+        "SELECT * from (VALUES (1, 'Herp Derpinson')) accounts(id, name) " + "where id = $1",
+        1i32
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(account.name.as_deref(), Some("Herp Derpinson"));
+
+    let account = sqlx::query!(
+        concat!(
+            "SELECT * from (VALUES (1, 'Herp Derpinson')) accounts(id, name) ",
+            "where id = $1"
+        ),
+        1i32
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(account.name.as_deref(), Some("Herp Derpinson"));
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn test_non_null() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -110,6 +185,38 @@ async fn test_query_file() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_query_as_returning() -> anyhow::Result<()> {
+    #[derive(Debug)]
+    struct Tweet {
+        id: i64,
+        text: String,
+        owner_id: Option<i64>,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+    let mut tx = conn.begin().await?;
+
+    let texts = vec!["first".to_owned(), "second".to_owned()];
+
+    let tweets = sqlx::query_as!(
+        Tweet,
+        "INSERT INTO tweet (text) SELECT * FROM UNNEST($1::text[]) RETURNING id, text, owner_id",
+        &texts[..]
+    )
+    .fetch_all(&mut tx)
+    .await?;
+
+    assert_eq!(tweets.len(), 2);
+    assert!(tweets.iter().all(|tweet| tweet.owner_id.is_none()));
+    assert!(tweets[0].id < tweets[1].id);
+    assert_eq!(tweets[1].text, "second");
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
 #[derive(Debug)]
 struct Account {
     id: i32,
@@ -200,6 +307,35 @@ async fn query_by_string() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn query_by_optional_fields() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    struct Account {
+        name: Option<String>,
+        age: Option<i32>,
+    }
+
+    let account = Account {
+        name: Some("Herp Derpinson".to_string()),
+        age: None,
+    };
+
+    // the same query binds either a value or NULL
+    let result = sqlx::query!(
+        "SELECT $1::text IS NULL as \"name_is_null!\", $2::int4 IS NULL as \"age_is_null!\"",
+        &account.name,
+        &account.age,
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(!result.name_is_null);
+    assert!(result.age_is_null);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 #[cfg(feature = "bigdecimal")]
 async fn query_by_bigdecimal() -> anyhow::Result<()> {
@@ -302,6 +438,48 @@ async fn test_array_from_slice() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_array_any() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let ids: Vec<i64> = vec![1, 3];
+
+    let rows = sqlx::query!(
+        "SELECT id FROM (VALUES (1::int8), (2), (3)) t(id) WHERE id = ANY($1) ORDER BY id",
+        &ids[..]
+    )
+    .fetch_all(&mut conn)
+    .await?;
+
+    let found: Vec<Option<i64>> = rows.into_iter().map(|row| row.id).collect();
+    assert_eq!(found, vec![Some(1), Some(3)]);
+
+    let names = sqlx::query_scalar!(
+        r#"SELECT array_agg(name) as "names!" FROM unnest($1::text[]) names(name)"#,
+        &["foo".to_owned(), "bar".to_owned()][..]
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(names, vec!["foo".to_owned(), "bar".to_owned()]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_domain() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // domains are checked as their base type
+    let value = sqlx::query_scalar!(r#"SELECT $1::positive_int as "value!""#, 5i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 5i32);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn fetch_is_usable_issue_224() -> anyhow::Result<()> {
     // ensures that the stream returned by `query::Map::fetch()` is usable with `TryStreamExt`
@@ -349,6 +527,92 @@ async fn test_column_override_nullable() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[derive(PartialEq, Eq, Debug, sqlx::Type)]
+#[sqlx(rename = "status")]
+#[sqlx(rename_all = "lowercase")]
+enum Status {
+    New,
+    Open,
+    Closed,
+}
+
+#[sqlx_macros::test]
+async fn test_query_as_unchecked_custom_type() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    struct Ticket {
+        id: i32,
+        status: Status,
+    }
+
+    // the unchecked macros decode a user-defined enum into the field without describing it
+    let ticket =
+        sqlx::query_as_unchecked!(Ticket, "select 1 as id, $1::status as status", Status::Open)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(ticket.id, 1);
+    assert_eq!(ticket.status, Status::Open);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_query_custom_type() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // `query!()` has no field to infer a user-defined type from
+    let record = sqlx::query!(r#"select $1::status as "status!: Status""#, Status::Open)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(record.status, Status::Open);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_query_as_custom_type() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    struct Ticket {
+        id: i32,
+        status: Option<Status>,
+    }
+
+    let ticket = sqlx::query_as!(
+        Ticket,
+        r#"select 1 as "id!", $1::status as status"#,
+        Status::Closed
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(ticket.id, 1);
+    assert_eq!(ticket.status, Some(Status::Closed));
+
+    let status: Status = sqlx::query_scalar!(r#"select $1::status as "status!""#, Status::New)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(status, Status::New);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_query_unchecked_type_override() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let record = sqlx::query_unchecked!(r#"select $1::status as "status: Status""#, Status::Closed)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(record.status, Some(Status::Closed));
+
+    Ok(())
+}
+
 async fn with_test_row<'a>(
     conn: &'a mut PgConnection,
 ) -> anyhow::Result<Transaction<'a, Postgres>> {
@@ -527,3 +791,31 @@ async fn test_bind_arg_override_wildcard() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn test_execute_many() -> anyhow::Result<()> {
+    use sqlx::{Done, Executor};
+
+    let mut conn = new::<Postgres>().await?;
+    let mut tx = conn.begin().await?;
+
+    let done = tx
+        .execute(sqlx::execute_many!(
+            "INSERT INTO tweet (text, owner_id) VALUES ('one; two', 1);
+             -- a comment; with a semicolon
+             UPDATE tweet SET text = 'three' WHERE text = 'one; two';"
+        ))
+        .await?;
+
+    assert_eq!(done.rows_affected(), 2);
+
+    let text = sqlx::query_scalar!("SELECT text FROM tweet WHERE text = 'three'")
+        .fetch_one(&mut tx)
+        .await?;
+
+    assert_eq!(text, "three");
+
+    tx.rollback().await?;
+
+    Ok(())
+}
@@ -35,6 +35,68 @@ async fn it_can_select_void() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_widens_narrower_numeric_columns_on_decode() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (a, b, c): (i64, i64, i32) = sqlx::query_as("SELECT 1::int2, 2::int4, 3::int2")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!((a, b, c), (1, 2, 3));
+
+    let value: f64 = sqlx::query_scalar("SELECT 1.5::float4")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 1.5);
+
+    // narrowing is still rejected
+    let res = sqlx::query_scalar::<_, i32>("SELECT 1::int8")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_binds_borrowed_and_shared_strings() -> anyhow::Result<()> {
+    use std::borrow::Cow;
+    use std::sync::Arc;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let owned = String::from("borrowed");
+    let shared: Arc<str> = Arc::from("shared");
+
+    let (a, b, c, d): (String, String, String, Option<String>) =
+        sqlx::query_as("SELECT $1, $2, $3, $4")
+            .bind(Cow::Borrowed(owned.as_str()))
+            .bind(Arc::clone(&shared))
+            .bind(Box::<str>::from("boxed"))
+            .bind(&Option::<String>::None)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(a, "borrowed");
+    assert_eq!(b, "shared");
+    assert_eq!(c, "boxed");
+    assert_eq!(d, None);
+
+    let row = conn.fetch_one("SELECT 'decoded'::text").await?;
+    let value: Cow<'_, str> = row.try_get(0)?;
+
+    assert!(matches!(value, Cow::Borrowed("decoded")));
+
+    let value: Arc<str> = row.try_get(0)?;
+
+    assert_eq!(&*value, "decoded");
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_pings() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -44,6 +106,169 @@ async fn it_pings() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_reports_the_server_version() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let version = conn.server_version().cloned().unwrap();
+
+    let version_num: String = sqlx::query_scalar("SHOW server_version_num")
+        .fetch_one(&mut conn)
+        .await?;
+
+    // e.g. 130002 for 13.2, or 90621 for 9.6.21
+    let version_num: u32 = version_num.parse()?;
+
+    assert_eq!(version.major, version_num / 10000);
+
+    if version.major < 10 {
+        assert_eq!(version.minor, version_num / 100 % 100);
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_applies_session_settings_from_options() -> anyhow::Result<()> {
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    let options = options
+        .search_path("pg_catalog")
+        .statement_timeout(Duration::from_secs(5));
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    let search_path: String = sqlx::query_scalar("SHOW search_path")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let statement_timeout: String = sqlx::query_scalar("SHOW statement_timeout")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(search_path, "pg_catalog");
+    assert_eq!(statement_timeout, "5s");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fetches_the_password_for_each_connection() -> anyhow::Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let url = url::Url::parse(&env::var("DATABASE_URL")?)?;
+    let password = url.password().unwrap_or_default().to_owned();
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let options: PgConnectOptions = url.as_str().parse()?;
+    let options = options.password("wrong").password_provider({
+        let calls = calls.clone();
+
+        move || {
+            calls.fetch_add(1, Ordering::SeqCst);
+
+            let password = password.clone();
+            Box::pin(async move { Ok(password) })
+        }
+    });
+
+    PgConnection::connect_with(&options).await?.close().await?;
+    PgConnection::connect_with(&options).await?.close().await?;
+
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    let options = options.password_provider(|| Box::pin(async { Err("vault is sealed".into()) }));
+
+    let err = PgConnection::connect_with(&options).await.unwrap_err();
+    assert!(err.to_string().contains("vault is sealed"));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reprepares_statements_after_schema_changes() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE reprepare_test (id INTEGER)")
+        .await?;
+
+    conn.execute("INSERT INTO reprepare_test (id) VALUES (1)")
+        .await?;
+
+    let select = "SELECT * FROM reprepare_test WHERE id = $1";
+
+    let row = sqlx::query(select).bind(1_i32).fetch_one(&mut conn).await?;
+    assert_eq!(row.columns().len(), 1);
+
+    // the cached statement now returns a different row type
+    conn.execute("ALTER TABLE reprepare_test ADD COLUMN name TEXT")
+        .await?;
+
+    let row = sqlx::query(select).bind(1_i32).fetch_one(&mut conn).await?;
+    assert_eq!(row.columns().len(), 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_does_not_reprepare_statements_inside_a_transaction() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE reprepare_tx_test (id INTEGER)")
+        .await?;
+
+    conn.execute("INSERT INTO reprepare_tx_test (id) VALUES (1)")
+        .await?;
+
+    let select = "SELECT * FROM reprepare_tx_test WHERE id = $1";
+
+    sqlx::query(select).bind(1_i32).fetch_one(&mut conn).await?;
+
+    let mut tx = conn.begin().await?;
+
+    sqlx::query("ALTER TABLE reprepare_tx_test ADD COLUMN name TEXT")
+        .execute(&mut tx)
+        .await?;
+
+    // the error has aborted the transaction, so it is returned instead of retried
+    let error = sqlx::query(select)
+        .bind(1_i32)
+        .fetch_one(&mut tx)
+        .await
+        .unwrap_err();
+
+    let error = error.as_database_error().unwrap();
+    assert_eq!(error.code().as_deref(), Some("0A000"));
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_pings_inside_a_transaction() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut tx = conn.begin().await?;
+
+    sqlx::query("CREATE TEMPORARY TABLE ping_test (id INTEGER)")
+        .execute(&mut tx)
+        .await?;
+
+    tx.ping().await?;
+
+    // the ping must not have ended the transaction
+    sqlx::query("INSERT INTO ping_test (id) VALUES (1)")
+        .execute(&mut tx)
+        .await?;
+
+    tx.rollback().await?;
+
+    conn.ping().await?;
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_maths() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -143,6 +368,103 @@ CREATE TEMPORARY TABLE json_stuff (obj json, obj2 jsonb);
     Ok(())
 }
 
+#[cfg(feature = "json")]
+#[sqlx_macros::test]
+async fn it_sends_and_receives_json_notifications() -> anyhow::Result<()> {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Event {
+        id: i64,
+        name: String,
+    }
+
+    let pool = sqlx_test::pool::<Postgres>().await?;
+    let mut listener = sqlx::postgres::PgListener::connect_with(&pool).await?;
+
+    listener.listen("json_events").await?;
+
+    let event = Event {
+        id: 1,
+        name: "created".to_owned(),
+    };
+
+    sqlx::postgres::notify(&pool, "json_events", &event).await?;
+
+    let notification = listener.recv().await?;
+
+    assert_eq!(notification.channel(), "json_events");
+    assert_eq!(notification.payload_json::<Event>()?, event);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_buffers_notifications_of_a_pooled_listener() -> anyhow::Result<()> {
+    use sqlx::postgres::{PgListener, PgListenerOverflow};
+
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let mut listener = PgListener::connect_with(&pool).await?;
+
+    listener.set_buffer_capacity(2, PgListenerOverflow::DropOldest);
+    listener.listen("buffered").await?;
+
+    // notifications for our own session arrive while the listener executes this query
+    sqlx::query(
+        "SELECT pg_notify('buffered', '1'), pg_notify('buffered', '2'), \
+         pg_notify('buffered', '3')",
+    )
+    .execute(&mut listener)
+    .await?;
+
+    assert_eq!(listener.recv().await?.payload(), "2");
+    assert_eq!(listener.recv().await?.payload(), "3");
+    assert_eq!(listener.dropped_notifications(), 1);
+
+    drop(listener);
+
+    // the connection goes back to the pool without its subscriptions
+    let channels: Vec<String> = sqlx::query_scalar("SELECT pg_listening_channels()")
+        .fetch_all(&pool)
+        .await?;
+
+    assert!(channels.is_empty());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_unlistens_a_dropped_listener_with_notifications_in_flight() -> anyhow::Result<()> {
+    use sqlx::postgres::PgListener;
+
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let mut listener = PgListener::connect_with(&pool).await?;
+    listener.listen("in_flight").await?;
+
+    // none of these are received before the listener is dropped
+    let mut other = new::<Postgres>().await?;
+
+    for _ in 0..10 {
+        other.execute("NOTIFY in_flight, 'pending'").await?;
+    }
+
+    drop(listener);
+
+    let channels: Vec<String> = sqlx::query_scalar("SELECT pg_listening_channels()")
+        .fetch_all(&pool)
+        .await?;
+
+    assert!(channels.is_empty());
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_works_with_cache_disabled() -> anyhow::Result<()> {
     setup_if_needed();
@@ -163,6 +485,89 @@ async fn it_works_with_cache_disabled() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_closes_statements_with_cache_disabled() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    let mut url = url::Url::parse(&env::var("DATABASE_URL")?)?;
+    url.query_pairs_mut()
+        .append_pair("statement-cache-capacity", "0");
+
+    let mut conn = PgConnection::connect(url.as_ref()).await?;
+
+    for index in 1..=10_i32 {
+        let _ = sqlx::query(&format!("SELECT $1 + {}", index))
+            .bind(index)
+            .fetch_one(&mut conn)
+            .await?;
+    }
+
+    // only the statement counting the prepared statements is left
+    let count: i64 = sqlx::query_scalar("SELECT count(*) FROM pg_prepared_statements")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_registers_user_defined_types() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    let mut conn = PgConnection::connect_with(&options.register_type("status")).await?;
+
+    // columns returned by unprepared statements resolve the registered type
+    let row = conn.fetch_one("SELECT 'open'::status").await?;
+
+    assert_eq!(row.column(0).type_info().name(), "status");
+
+    let info = conn.register_type("inventory_item").await?;
+
+    assert_eq!(info.name(), "inventory_item");
+
+    let res = conn.register_type("does_not_exist").await;
+
+    assert!(matches!(res, Err(sqlx::Error::Configuration(_))));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_collects_notices_per_statement() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let done = conn
+        .execute(
+            r#"
+DO $$
+BEGIN
+    RAISE WARNING 'first' USING HINT = 'a hint';
+    RAISE NOTICE 'second';
+END
+$$;
+            "#,
+        )
+        .await?;
+
+    let notices = done.notices();
+
+    assert_eq!(notices.len(), 2);
+    assert_eq!(notices[0].severity(), PgSeverity::Warning);
+    assert_eq!(notices[0].message(), "first");
+    assert_eq!(notices[0].hint(), Some("a hint"));
+    assert_eq!(notices[1].severity(), PgSeverity::Notice);
+    assert_eq!(notices[1].message(), "second");
+
+    let done = conn.execute("SELECT 1").await?;
+
+    assert!(done.notices().is_empty());
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_executes_with_pool() -> anyhow::Result<()> {
     let pool = sqlx_test::pool::<Postgres>().await?;
@@ -302,6 +707,70 @@ async fn it_can_query_all_scalar() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_copy_in_and_out() -> anyhow::Result<()> {
+    use futures::SinkExt;
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE copied (id INTEGER NOT NULL, name TEXT NOT NULL)")
+        .await?;
+
+    let mut copy = conn
+        .copy_in_raw("COPY copied (id, name) FROM STDIN")
+        .await?;
+
+    assert!(copy.is_textual());
+    assert_eq!(copy.num_columns(), 2);
+
+    copy.send_data("1\tone\n2\t").await?;
+    copy.send(&b"two\n"[..]).await?;
+
+    assert_eq!(copy.finish().await?, 2);
+
+    let data: Vec<_> = conn
+        .copy_out_raw("COPY (SELECT * FROM copied ORDER BY id) TO STDOUT")
+        .await?
+        .try_collect()
+        .await?;
+
+    assert_eq!(data.concat(), b"1\tone\n2\ttwo\n");
+
+    let copy = conn
+        .copy_in_raw("COPY copied (id, name) FROM STDIN")
+        .await?;
+    copy.abort("changed my mind").await?;
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM copied")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_use_the_connection_after_dropping_a_copy() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE copied (id INTEGER NOT NULL)")
+        .await?;
+
+    let mut copy = conn.copy_in_raw("COPY copied (id) FROM STDIN").await?;
+    copy.send_data("1\n").await?;
+    drop(copy);
+
+    // the copy is aborted and its error discarded
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM copied")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_work_with_transactions() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -417,6 +886,47 @@ async fn it_can_work_with_nested_transactions() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_work_with_named_savepoints() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut tx = conn.begin().await?;
+
+    tx.execute("CREATE TEMPORARY TABLE savepoint_users (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    tx.execute("INSERT INTO savepoint_users (id) VALUES (1)")
+        .await?;
+
+    let mut savepoint = tx.savepoint("second").await?;
+    assert_eq!(savepoint.name(), "second");
+
+    savepoint
+        .execute("INSERT INTO savepoint_users (id) VALUES (2)")
+        .await?;
+
+    // an error aborts the transaction until we roll back to the savepoint
+    assert!(savepoint
+        .execute("INSERT INTO savepoint_users (id) VALUES (2)")
+        .await
+        .is_err());
+
+    savepoint.rollback().await?;
+
+    tx.savepoint("third").await?;
+    tx.execute("INSERT INTO savepoint_users (id) VALUES (3)")
+        .await?;
+    tx.release("third").await?;
+
+    // a released savepoint can no longer be rolled back to
+    assert!(tx.rollback_to("third").await.is_err());
+
+    // names are interpolated into SQL and must be plain identifiers
+    assert!(tx.savepoint("x; DROP TABLE savepoint_users").await.is_err());
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_drop_multiple_transactions() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -680,6 +1190,44 @@ async fn it_can_handle_parameter_status_message_issue_484() -> anyhow::Result<()
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_streams_rows_returned_by_a_bulk_insert() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    let mut tx = conn.begin().await?;
+
+    let texts: Vec<String> = (0..5000).map(|i| format!("tweet #{}", i)).collect();
+
+    let ids: Vec<i64> =
+        sqlx::query("INSERT INTO tweet (text) SELECT * FROM UNNEST($1::text[]) RETURNING id")
+            .bind(&texts)
+            .fetch_all_scalar(&mut tx)
+            .await?;
+
+    assert_eq!(ids.len(), texts.len());
+
+    let mut rows =
+        sqlx::query("INSERT INTO tweet (text) SELECT * FROM UNNEST($1::text[]) RETURNING *")
+            .bind(&texts)
+            .fetch(&mut tx);
+
+    let mut count = 0;
+
+    while let Some(row) = rows.try_next().await? {
+        let text: &str = row.try_get("text")?;
+        assert!(text.starts_with("tweet #"));
+
+        count += 1;
+    }
+
+    drop(rows);
+
+    assert_eq!(count, texts.len());
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -710,6 +1258,46 @@ async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_execute_a_statement_prepared_on_another_connection() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    let mut other = new::<Postgres>().await?;
+
+    // temporary tables only exist on the connection that created them
+    conn.execute("CREATE TEMPORARY TABLE pipelined (id INT4 NOT NULL)")
+        .await?;
+
+    let statement = conn
+        .prepare("SELECT id FROM pipelined WHERE id = $1")
+        .await?;
+
+    // the statement is parsed on `other` in the same round-trip it is executed in
+    let result = statement
+        .query()
+        .bind(1_i32)
+        .fetch_optional(&mut other)
+        .await;
+
+    assert!(result.is_err());
+
+    other
+        .execute(
+            "CREATE TEMPORARY TABLE pipelined (id INT4 NOT NULL); \
+             INSERT INTO pipelined VALUES (1);",
+        )
+        .await?;
+
+    // a statement that failed to parse is not left behind in the cache
+    for _ in 0..2 {
+        let row = statement.query().bind(1_i32).fetch_one(&mut other).await?;
+        let id: i32 = row.try_get(0)?;
+
+        assert_eq!(id, 1);
+    }
+
+    Ok(())
+}
+
 // repro is more reliable with the basic scheduler used by `#[tokio::test]`
 #[cfg(feature = "_rt-tokio")]
 #[tokio::test]
@@ -763,3 +1351,281 @@ async fn test_issue_622() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg(feature = "migrate")]
+#[sqlx_macros::test]
+async fn it_creates_and_drops_databases_with_special_names() -> anyhow::Result<()> {
+    use sqlx::migrate::MigrateDatabase;
+
+    let mut url = url::Url::parse(&env::var("DATABASE_URL")?)?;
+    url.set_path("/sqlx%20create%22test");
+    let url = url.as_str();
+
+    Postgres::drop_database(url).await?;
+    assert!(!Postgres::database_exists(url).await?);
+
+    Postgres::create_database(url).await?;
+    assert!(Postgres::database_exists(url).await?);
+
+    let mut conn = PgConnection::connect(url).await?;
+    conn.ping().await?;
+    conn.close().await?;
+
+    Postgres::drop_database(url).await?;
+    assert!(!Postgres::database_exists(url).await?);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_sets_and_resets_timeouts() -> anyhow::Result<()> {
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+
+    conn.set_statement_timeout(Some(Duration::from_millis(1500)))
+        .await?;
+    conn.set_lock_timeout(Some(Duration::from_secs(2))).await?;
+
+    let timeout: String = sqlx::query_scalar("SHOW statement_timeout")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(timeout, "1500ms");
+
+    let timeout: String = sqlx::query_scalar("SHOW lock_timeout")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(timeout, "2s");
+
+    drop(conn);
+
+    // the session settings are reset before the connection goes back into the pool
+    let mut conn = pool.acquire().await?;
+
+    let timeout: String = sqlx::query_scalar("SHOW statement_timeout")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(timeout, "0");
+
+    let timeout: String = sqlx::query_scalar("SHOW lock_timeout")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(timeout, "0");
+
+    // inside of a transaction the settings only last until it ends
+    let mut tx = conn.begin().await?;
+
+    tx.set_idle_in_transaction_session_timeout(Some(Duration::from_secs(60)))
+        .await?;
+
+    let timeout: String = sqlx::query_scalar("SHOW idle_in_transaction_session_timeout")
+        .fetch_one(&mut tx)
+        .await?;
+    assert_eq!(timeout, "1min");
+
+    tx.commit().await?;
+
+    let timeout: String = sqlx::query_scalar("SHOW idle_in_transaction_session_timeout")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(timeout, "0");
+
+    // the statement timeout actually applies
+    conn.set_statement_timeout(Some(Duration::from_millis(50)))
+        .await?;
+
+    let res = sqlx::query("SELECT pg_sleep(1)").execute(&mut conn).await;
+    assert!(res.is_err());
+
+    conn.set_statement_timeout(None).await?;
+
+    sqlx::query("SELECT pg_sleep(0.1)")
+        .execute(&mut conn)
+        .await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_round_trips_quoted_identifiers_and_literals() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let table = Postgres::quote_identifier(r#"odd "table"; name"#);
+    let column = Postgres::quote_identifier("Mixed Case");
+    let value = r"it's a C:\path";
+
+    conn.execute(&*format!(
+        "CREATE TEMPORARY TABLE {} ({} TEXT DEFAULT {})",
+        table,
+        column,
+        Postgres::quote_literal(value)
+    ))
+    .await?;
+
+    conn.execute(&*format!("INSERT INTO {} DEFAULT VALUES", table))
+        .await?;
+
+    let stored: String = sqlx::query_scalar(&format!("SELECT {} FROM {}", column, table))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(stored, value);
+
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+#[sqlx_macros::test]
+async fn it_explains_queries() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let plan = sqlx::query("SELECT * FROM tweet WHERE id = $1")
+        .bind(1_i64)
+        .explain(&mut conn)
+        .await?;
+
+    assert!(plan["Plan"]["Node Type"].is_string());
+    assert!(plan.get("Execution Time").is_none());
+
+    let plan = sqlx::query("SELECT generate_series(1, $1)")
+        .bind(3_i32)
+        .explain_analyze(&mut conn)
+        .await?;
+
+    assert_eq!(plan["Plan"]["Actual Rows"], 3);
+    assert!(plan["Execution Time"].is_number());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_reuse_a_connection_after_a_cancelled_query() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let cancelled = sqlx_rt::timeout(
+        Duration::from_millis(100),
+        conn.execute("SELECT pg_sleep(1)"),
+    )
+    .await;
+
+    assert!(cancelled.is_err());
+
+    // the response to the cancelled query is discarded rather than read as ours
+    let value: i32 = sqlx::query_scalar("SELECT 42").fetch_one(&mut conn).await?;
+    assert_eq!(value, 42);
+
+    conn.ping().await?;
+
+    Ok(())
+}
+
+#[cfg(all(feature = "testing", feature = "migrate"))]
+#[sqlx_macros::test]
+async fn it_creates_and_drops_a_test_database() -> anyhow::Result<()> {
+    use sqlx::migrate::{MigrateDatabase, Migrator};
+    use sqlx::testing::TestPool;
+    use std::path::Path;
+
+    let migrator = Migrator::new(Path::new("tests/migrate/migrations")).await?;
+
+    let url = env::var("DATABASE_URL")?;
+    let pool = TestPool::<Postgres>::new(&url, &migrator).await?;
+    let other = TestPool::<Postgres>::new(&url, &migrator).await?;
+
+    assert_ne!(pool.url(), other.url());
+
+    // each test database starts out migrated and empty
+    sqlx::query("INSERT INTO tweet (id, text) VALUES (1, 'hello')")
+        .execute(&*pool)
+        .await?;
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tweet")
+        .fetch_one(&*other)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    let test_url = pool.url().to_owned();
+    assert!(Postgres::database_exists(&test_url).await?);
+
+    pool.close().await?;
+
+    assert!(!Postgres::database_exists(&test_url).await?);
+
+    // a test that fails without closing its pool still drops its database
+    let other_url = other.url().to_owned();
+    drop(other);
+
+    assert!(!Postgres::database_exists(&other_url).await?);
+
+    Ok(())
+}
+
+// terminates the backend with the given pid and waits until it has exited
+async fn terminate_backend(conn: &mut PgConnection, pid: i32) -> anyhow::Result<()> {
+    sqlx::query("SELECT pg_terminate_backend($1)")
+        .bind(pid)
+        .execute(&mut *conn)
+        .await?;
+
+    loop {
+        let alive: bool =
+            sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM pg_stat_activity WHERE pid = $1)")
+                .bind(pid)
+                .fetch_one(&mut *conn)
+                .await?;
+
+        if !alive {
+            return Ok(());
+        }
+    }
+}
+
+#[sqlx_macros::test]
+async fn it_reconnects_after_the_connection_is_killed() -> anyhow::Result<()> {
+    use sqlx::reconnect::ReconnectingConnection;
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+
+    // a recently used connection is only checked after an error
+    let mut conn =
+        ReconnectingConnection::<Postgres>::new(options).ping_after_idle(Duration::from_secs(60));
+
+    let pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(conn.acquire().await?)
+        .await?;
+
+    let mut other = new::<Postgres>().await?;
+
+    terminate_backend(&mut other, pid).await?;
+
+    let result = sqlx::query_scalar::<_, i32>("SELECT pg_backend_pid()")
+        .fetch_one(conn.acquire().await?)
+        .await;
+
+    assert!(conn.check_result(result).is_err());
+
+    let new_pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(conn.acquire().await?)
+        .await?;
+
+    assert_ne!(pid, new_pid);
+
+    // an idle connection is checked before it is handed out
+    let mut conn = conn.ping_after_idle(Duration::from_secs(0));
+
+    terminate_backend(&mut other, new_pid).await?;
+
+    let last_pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(conn.acquire().await?)
+        .await?;
+
+    assert_ne!(new_pid, last_pid);
+
+    conn.close().await?;
+
+    Ok(())
+}
@@ -18,8 +18,17 @@ CREATE TABLE tweet
     owner_id   BIGINT
 );
 
+-- https://www.postgresql.org/docs/current/sql-createdomain.html
+CREATE DOMAIN positive_int AS INT4 CHECK (VALUE > 0);
+CREATE DOMAIN open_status AS status CHECK (VALUE <> 'closed');
+
 CREATE TYPE float_range AS RANGE
 (
     subtype = float8,
     subtype_diff = float8mi
 );
+
+-- a user-defined type that is not on the search path
+CREATE SCHEMA inventory;
+
+CREATE TYPE inventory.status AS ENUM ('in_stock', 'sold_out');
@@ -65,6 +65,11 @@ test_type!(string_vec<Vec<String>>(Postgres,
         == vec!["Hello, World", "", "Goodbye"]
 ));
 
+test_type!(text_ip_addr<sqlx::types::Text<std::net::Ipv4Addr>>(Postgres,
+    "'127.0.0.1'::text" == sqlx::types::Text(std::net::Ipv4Addr::LOCALHOST),
+    "'10.0.0.1'::varchar" == sqlx::types::Text(std::net::Ipv4Addr::new(10, 0, 0, 1)),
+));
+
 test_type!(i8(
     Postgres,
     "0::\"char\"" == 0_i8,
@@ -94,13 +99,81 @@ test_type!(i32_vec<Vec<i32>>(Postgres,
 
 test_type!(i64(Postgres, "9358295312::bigint" == 9358295312_i64));
 
-test_type!(f32(Postgres, "9419.122::real" == 9419.122_f32));
+#[sqlx_macros::test]
+async fn it_binds_typed_nulls() -> anyhow::Result<()> {
+    use sqlx::types::Null;
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let (value, ty): (Option<i64>, String) = sqlx::query_as("SELECT $1, pg_typeof($1)::text")
+        .bind(Null::<i64>::new())
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, None);
+    assert_eq!(ty, "bigint");
+
+    let value: Option<String> = sqlx::query_scalar("SELECT COALESCE($1, $2)")
+        .bind(Null::<String>::new())
+        .bind(Null::<String>::new())
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, None);
+
+    Ok(())
+}
+
+test_type!(non_zero_i64<std::num::NonZeroI64>(Postgres,
+    "9358295312::bigint" == std::num::NonZeroI64::new(9358295312).unwrap()
+));
+
+#[sqlx_macros::test]
+async fn it_rejects_zero_as_non_zero() -> anyhow::Result<()> {
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let res: Result<std::num::NonZeroI32, _> = sqlx::query_scalar("SELECT 0::int4")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+
+    Ok(())
+}
+
+test_type!(f32(
+    Postgres,
+    "9419.122::real" == 9419.122_f32,
+    "'Infinity'::real" == f32::INFINITY,
+    "'-Infinity'::real" == f32::NEG_INFINITY
+));
 
 test_type!(f64(
     Postgres,
-    "939399419.1225182::double precision" == 939399419.1225182_f64
+    "939399419.1225182::double precision" == 939399419.1225182_f64,
+    "'Infinity'::double precision" == f64::INFINITY,
+    "'-Infinity'::double precision" == f64::NEG_INFINITY
 ));
 
+#[sqlx_macros::test]
+async fn it_decodes_nan_as_float() -> anyhow::Result<()> {
+    use sqlx::{Executor, Row};
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let value: f64 = sqlx::query_scalar("SELECT 'NaN'::float8")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(value.is_nan());
+
+    // the text protocol
+    let row = conn.fetch_one("SELECT 'NaN'::float4").await?;
+    assert!(row.try_get::<f32, _>(0)?.is_nan());
+
+    Ok(())
+}
+
 test_type!(f64_vec<Vec<f64>>(Postgres,
     "'{939399419.1225182,-12.0}'::float8[]" == vec![939399419.1225182_f64, -12.0]
 ));
@@ -139,6 +212,32 @@ test_type!(uuid_vec<Vec<sqlx::types::Uuid>>(Postgres,
         ]
 ));
 
+test_type!(ip_addr<std::net::IpAddr>(Postgres,
+    "'127.0.0.1'::inet" == "127.0.0.1".parse::<std::net::IpAddr>().unwrap(),
+    "'::ffff:1.2.3.0'::inet" == "::ffff:1.2.3.0".parse::<std::net::IpAddr>().unwrap(),
+));
+
+test_type!(ipv4_addr<std::net::Ipv4Addr>(Postgres,
+    "'8.8.8.8'::inet" == std::net::Ipv4Addr::new(8, 8, 8, 8),
+));
+
+test_type!(ipv6_addr<std::net::Ipv6Addr>(Postgres,
+    "'::1'::inet" == std::net::Ipv6Addr::LOCALHOST,
+));
+
+#[sqlx_macros::test]
+async fn it_rejects_masked_inet_as_ip_addr() -> anyhow::Result<()> {
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let res: Result<std::net::IpAddr, _> = sqlx::query_scalar("SELECT '10.0.0.0/8'::inet")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+
+    Ok(())
+}
+
 #[cfg(feature = "ipnetwork")]
 test_type!(ipnetwork<sqlx::types::ipnetwork::IpNetwork>(Postgres,
     "'127.0.0.1'::inet"
@@ -251,6 +350,40 @@ mod chrono {
             ]
     ));
 
+    #[sqlx_macros::test]
+    async fn it_decodes_timestamp_in_configured_zone() -> anyhow::Result<()> {
+        use sqlx::postgres::{PgConnectOptions, PgConnection, PgTimestampZone};
+        use sqlx::Connection;
+
+        let options: PgConnectOptions = dotenv::var("DATABASE_URL")?.parse()?;
+        let sql = "SELECT '2019-01-02 05:10:20'::timestamp";
+
+        // UTC by default
+        let mut conn = PgConnection::connect_with(&options).await?;
+        let utc: DateTime<Utc> = sqlx::query_scalar(sql).fetch_one(&mut conn).await?;
+        assert_eq!(utc, Utc.ymd(2019, 1, 2).and_hms(5, 10, 20));
+
+        let options = options.timestamp_zone(PgTimestampZone::FixedOffset(2 * 60 * 60));
+        let mut conn = PgConnection::connect_with(&options).await?;
+
+        let utc: DateTime<Utc> = sqlx::query_scalar(sql).fetch_one(&mut conn).await?;
+        assert_eq!(utc, Utc.ymd(2019, 1, 2).and_hms(3, 10, 20));
+
+        let utc: Vec<DateTime<Utc>> = sqlx::query_scalar("SELECT array[$1::timestamp]")
+            .bind(NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20))
+            .fetch_one(&mut conn)
+            .await?;
+        assert_eq!(utc, [Utc.ymd(2019, 1, 2).and_hms(3, 10, 20)]);
+
+        // TIMESTAMPTZ carries its own offset and is not affected
+        let utc: DateTime<Utc> = sqlx::query_scalar("SELECT '2019-01-02 05:10:20Z'::timestamptz")
+            .fetch_one(&mut conn)
+            .await?;
+        assert_eq!(utc, Utc.ymd(2019, 1, 2).and_hms(5, 10, 20));
+
+        Ok(())
+    }
+
     test_type!(chrono_time_tz<PgTimeTz>(Postgres,
         "TIMETZ '05:10:20.115100+00'" == PgTimeTz { time: NaiveTime::from_hms_micro(5, 10, 20, 115100), offset: FixedOffset::east(0) },
         "TIMETZ '05:10:20.115100+06:30'" == PgTimeTz { time: NaiveTime::from_hms_micro(5, 10, 20, 115100), offset: FixedOffset::east(60 * 60 * 6 + 1800) },
@@ -419,6 +552,30 @@ test_type!(decimal<sqlx::types::Decimal>(Postgres,
     "12345.6789::numeric" == sqlx::types::Decimal::from_str("12345.6789").unwrap(),
 ));
 
+#[cfg(feature = "decimal")]
+#[sqlx_macros::test]
+async fn it_rejects_nan_as_decimal() -> anyhow::Result<()> {
+    use sqlx::{Executor, Row};
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let res: Result<sqlx::types::Decimal, _> = sqlx::query_scalar("SELECT 'NaN'::numeric")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+
+    // the text protocol
+    let row = conn.fetch_one("SELECT 'NaN'::numeric").await?;
+    let err = row.try_get::<sqlx::types::Decimal, _>(0).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .contains("Decimal cannot represent the NUMERIC value NaN"));
+
+    Ok(())
+}
+
 const EXC2: Bound<i32> = Bound::Excluded(2);
 const EXC3: Bound<i32> = Bound::Excluded(3);
 const INC1: Bound<i32> = Bound::Included(1);
@@ -471,6 +628,13 @@ test_prepared_type!(interval<PgInterval>(
         },
 ));
 
+test_prepared_type!(std_duration<std::time::Duration>(
+    Postgres,
+    "INTERVAL '1h'" == std::time::Duration::from_secs(3_600),
+    "INTERVAL '1 day 2 seconds'" == std::time::Duration::from_secs(86_402),
+    "INTERVAL '03:10:20.116100'" == std::time::Duration::from_micros(11_420_116_100),
+));
+
 test_prepared_type!(money<PgMoney>(Postgres, "123.45::money" == PgMoney(12345)));
 
 test_prepared_type!(money_vec<Vec<PgMoney>>(Postgres,
@@ -1,8 +1,10 @@
 use futures::TryStreamExt;
+use sqlx::pool::ShardedPool;
 use sqlx::sqlite::SqlitePoolOptions;
 use sqlx::{
-    query, sqlite::Sqlite, sqlite::SqliteRow, Column, Connection, Done, Executor, Row,
-    SqliteConnection, SqlitePool, Statement, TypeInfo,
+    query, sqlite::Sqlite, sqlite::SqliteArguments, sqlite::SqliteRow, ArgumentCount, Arguments,
+    Column, Connection, Done, Executor, Row, ServerFlavor, SqliteConnection, SqlitePool, Statement,
+    StatementId, TypeInfo,
 };
 use sqlx_test::new;
 
@@ -202,6 +204,56 @@ async fn it_executes_with_pool() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_routes_and_broadcasts_to_shards() -> anyhow::Result<()> {
+    let mut shards = Vec::new();
+
+    for id in 0..3_i32 {
+        // every shard is a separate in-memory database
+        let pool: SqlitePool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await?;
+
+        pool.execute("CREATE TABLE shard (id INTEGER NOT NULL)")
+            .await?;
+
+        sqlx::query("INSERT INTO shard (id) VALUES (?)")
+            .bind(id)
+            .execute(&pool)
+            .await?;
+
+        shards.push(pool);
+    }
+
+    let pool = ShardedPool::new(shards, |tenant: &u64| (*tenant % 3) as usize);
+
+    assert_eq!(pool.route(&7), 1);
+
+    let id: i32 = sqlx::query_scalar("SELECT id FROM shard")
+        .fetch_one(pool.get(&5))
+        .await?;
+
+    assert_eq!(id, 2);
+
+    let ids = pool
+        .broadcast_all(|pool| sqlx::query_scalar::<_, i32>("SELECT id FROM shard").fetch_one(pool))
+        .await?;
+
+    assert_eq!(ids, vec![0, 1, 2]);
+
+    let mut rows: Vec<(usize, i32)> = pool
+        .broadcast(|pool| sqlx::query_scalar("SELECT id FROM shard").fetch(pool))
+        .try_collect()
+        .await?;
+
+    rows.sort();
+
+    assert_eq!(rows, vec![(0, 0), (1, 1), (2, 2)]);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_opens_in_memory() -> anyhow::Result<()> {
     // If the filename is ":memory:", then a private, temporary in-memory database
@@ -416,6 +468,519 @@ CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL COLLATE
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_reports_the_linked_version() -> anyhow::Result<()> {
+    let conn = new::<Sqlite>().await?;
+
+    let version = conn.server_version().unwrap();
+
+    assert_eq!(version.flavor, ServerFlavor::Sqlite);
+    assert_eq!(version.major, 3);
+
+    let (raw,): (String,) = sqlx::query_as("SELECT sqlite_version()")
+        .fetch_one(&mut new::<Sqlite>().await?)
+        .await?;
+
+    assert_eq!(version.raw, raw);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_tracks_the_transaction_status() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    assert!(!conn.is_in_transaction());
+
+    let mut tx = conn.begin().await?;
+    assert!(tx.is_in_transaction());
+    tx.rollback().await?;
+
+    assert!(!conn.is_in_transaction());
+
+    conn.execute("BEGIN").await?;
+    assert!(conn.is_in_transaction());
+
+    conn.execute("COMMIT").await?;
+    assert!(!conn.is_in_transaction());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_closes_pooled_connections_left_inside_a_transaction() -> anyhow::Result<()> {
+    let pool = SqlitePoolOptions::new()
+        .min_connections(0)
+        .connect("sqlite::memory:")
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+    conn.execute("BEGIN").await?;
+    drop(conn);
+
+    assert_eq!(pool.size(), 0);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_executes_generically_on_any_executor() -> anyhow::Result<()> {
+    async fn answer<'c, E>(executor: E) -> sqlx::Result<i32>
+    where
+        E: Executor<'c, Database = Sqlite>,
+    {
+        sqlx::query_scalar("SELECT 42").fetch_one(executor).await
+    }
+
+    let pool = sqlx_test::pool::<Sqlite>().await?;
+    assert_eq!(answer(&pool).await?, 42);
+
+    let mut pool_conn = pool.acquire().await?;
+    assert_eq!(answer(&mut pool_conn).await?, 42);
+
+    let mut tx = pool_conn.begin().await?;
+    assert_eq!(answer(&mut tx).await?, 42);
+    tx.rollback().await?;
+
+    let mut conn = new::<Sqlite>().await?;
+    assert_eq!(answer(&mut conn).await?, 42);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_runs_several_queries_on_any_acquire() -> anyhow::Result<()> {
+    use sqlx::Acquire;
+
+    async fn sum<'a, A>(conn: A) -> sqlx::Result<i32>
+    where
+        A: Acquire<'a, Database = Sqlite>,
+    {
+        let mut conn = conn.acquire().await?;
+
+        let a: i32 = sqlx::query_scalar("SELECT 20")
+            .fetch_one(&mut *conn)
+            .await?;
+        let b: i32 = sqlx::query_scalar("SELECT 22")
+            .fetch_one(&mut *conn)
+            .await?;
+
+        Ok(a + b)
+    }
+
+    let pool = sqlx_test::pool::<Sqlite>().await?;
+    assert_eq!(sum(&pool).await?, 42);
+
+    let mut conn = new::<Sqlite>().await?;
+    assert_eq!(sum(&mut conn).await?, 42);
+
+    let mut tx = conn.begin().await?;
+    assert_eq!(sum(&mut tx).await?, 42);
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_binds_dynamically_built_arguments() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let mut sql = String::from("SELECT 0");
+    let mut arguments = SqliteArguments::default();
+
+    for value in 1..=5_i32 {
+        arguments.add(value);
+        sql += &format!(" + ?{}", arguments.len());
+    }
+
+    assert_eq!(arguments.len(), 5);
+
+    let sum: i32 = sqlx::query_scalar_with(&sql, arguments)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(sum, 15);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_checks_column_types_before_fetching() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let statement = conn.prepare("SELECT id, text FROM tweet").await?;
+
+    statement.column(0).check_type::<i64>()?;
+    statement.column(1).check_type::<String>()?;
+
+    let err = statement.column(1).check_type::<i64>().unwrap_err();
+
+    assert!(matches!(err, sqlx::Error::ColumnDecode { .. }));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_sets_up_reconnecting_connections() -> anyhow::Result<()> {
+    use sqlx::reconnect::ReconnectingConnection;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let connects = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&connects);
+
+    let mut conn = ReconnectingConnection::<Sqlite>::new("sqlite::memory:".parse()?).after_connect(
+        move |conn| {
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            Box::pin(async move {
+                conn.execute("CREATE TABLE session (id INTEGER)").await?;
+
+                Ok(())
+            })
+        },
+    );
+
+    // the session set up by the callback is only created once while the connection is alive
+    for _ in 0..2 {
+        sqlx::query("INSERT INTO session (id) VALUES (1)")
+            .execute(conn.acquire().await?)
+            .await?;
+    }
+
+    assert_eq!(connects.load(Ordering::SeqCst), 1);
+
+    conn.close().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_inserts_in_bind_sized_chunks() -> anyhow::Result<()> {
+    use sqlx::Database;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE chunks (a INTEGER, b INTEGER, c INTEGER)")
+        .await?;
+
+    let items: Vec<i32> = (0..20_000).collect();
+    let mut statements = 0;
+
+    assert!(Sqlite::bind_chunks(&items, 0).is_err());
+
+    for chunk in Sqlite::bind_chunks(&items, 3)? {
+        let values = vec!["(?, ?, ?)"; chunk.len()].join(", ");
+        let sql = format!("INSERT INTO chunks (a, b, c) VALUES {}", values);
+
+        let mut query = sqlx::query(&sql);
+
+        for item in chunk {
+            query = query.bind(*item).bind(*item).bind(*item);
+        }
+
+        query.execute(&mut conn).await?;
+        statements += 1;
+    }
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM chunks")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 20_000);
+    assert_eq!(statements, 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reads_blobs_incrementally() -> anyhow::Result<()> {
+    use futures::io::{AsyncReadExt, AsyncSeekExt, SeekFrom};
+
+    // blobs are opened in the `main` database, so use a private in-memory database
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+
+    conn.execute("CREATE TABLE files (id INTEGER PRIMARY KEY, contents BLOB NOT NULL)")
+        .await?;
+
+    let contents: Vec<u8> = (0..10_000u32).map(|i| i as u8).collect();
+
+    let id = sqlx::query("INSERT INTO files (contents) VALUES (?)")
+        .bind(contents.clone())
+        .execute(&mut conn)
+        .await?
+        .last_insert_rowid();
+
+    let mut blob = conn.open_blob("files", "contents", id)?;
+
+    assert_eq!(blob.len(), contents.len());
+
+    let mut chunk = [0_u8; 16];
+    blob.seek(SeekFrom::Start(100)).await?;
+    blob.read_exact(&mut chunk).await?;
+
+    assert_eq!(&chunk[..], &contents[100..116]);
+
+    let mut buf = Vec::new();
+    blob.seek(SeekFrom::Start(0)).await?;
+    blob.read_to_end(&mut buf).await?;
+
+    assert_eq!(buf, contents);
+
+    drop(blob);
+
+    assert!(conn.open_blob("files", "contents", id + 1).is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_use_the_connection_after_dropping_a_blob_mid_read() -> anyhow::Result<()> {
+    use futures::io::AsyncReadExt;
+
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+
+    conn.execute("CREATE TABLE files (id INTEGER PRIMARY KEY, contents BLOB NOT NULL)")
+        .await?;
+
+    let id = sqlx::query("INSERT INTO files (contents) VALUES (zeroblob(1000000))")
+        .execute(&mut conn)
+        .await?
+        .last_insert_rowid();
+
+    for _ in 0..10 {
+        let mut blob = conn.open_blob("files", "contents", id)?;
+        let mut buf = vec![0_u8; 1_000_000];
+
+        // start a read on the worker, then drop the blob without waiting for it
+        let _ = futures::poll!(blob.read(&mut buf));
+        drop(blob);
+
+        let (len,): (i64,) = sqlx::query_as("SELECT length(contents) FROM files WHERE id = ?")
+            .bind(id)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(len, 1_000_000);
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_caches_query_results() -> anyhow::Result<()> {
+    use std::time::Duration;
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await?;
+
+    pool.execute("CREATE TABLE counters (value INTEGER NOT NULL)")
+        .await?;
+    pool.execute("INSERT INTO counters (value) VALUES (1)")
+        .await?;
+
+    let sql = "SELECT value FROM counters WHERE value > ? ORDER BY value";
+    let ttl = Duration::from_secs(60);
+
+    let first: Vec<(i64,)> = sqlx::query_as(sql)
+        .bind(0)
+        .cached(ttl)
+        .fetch_all(&pool)
+        .await?;
+
+    pool.execute("INSERT INTO counters (value) VALUES (2)")
+        .await?;
+
+    let cached: Vec<(i64,)> = sqlx::query_as(sql)
+        .bind(0)
+        .cached(ttl)
+        .fetch_all(&pool)
+        .await?;
+
+    assert_eq!(first, vec![(1,)]);
+    assert_eq!(cached, first);
+
+    // different arguments are cached separately
+    let other: Vec<(i64,)> = sqlx::query_as(sql)
+        .bind(1)
+        .cached(ttl)
+        .fetch_all(&pool)
+        .await?;
+
+    assert_eq!(other, vec![(2,)]);
+
+    pool.invalidate_cached(sql);
+
+    let fresh: Vec<(i64,)> = sqlx::query_as(sql)
+        .bind(0)
+        .cached(ttl)
+        .fetch_all(&pool)
+        .await?;
+
+    assert_eq!(fresh, vec![(1,), (2,)]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_evicts_and_expires_cached_query_results() -> anyhow::Result<()> {
+    use std::time::Duration;
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .result_cache_capacity(1)
+        .connect("sqlite::memory:")
+        .await?;
+
+    pool.execute("CREATE TABLE counters (value INTEGER NOT NULL)")
+        .await?;
+    pool.execute("INSERT INTO counters (value) VALUES (1)")
+        .await?;
+
+    let sql = "SELECT COUNT(*) FROM counters WHERE value > ?";
+    let ttl = Duration::from_secs(60);
+
+    let count: i64 = sqlx::query_scalar(sql)
+        .bind(0)
+        .cached(ttl)
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(count, 1);
+
+    pool.execute("INSERT INTO counters (value) VALUES (2)")
+        .await?;
+
+    // the cache holds a single result, so caching another one evicts the first
+    let count: i64 = sqlx::query_scalar(sql)
+        .bind(1)
+        .cached(ttl)
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(count, 1);
+
+    let count: i64 = sqlx::query_scalar(sql)
+        .bind(0)
+        .cached(ttl)
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(count, 2);
+
+    // an expired result is not returned
+    let ttl = Duration::from_secs(0);
+
+    let count: i64 = sqlx::query_scalar(sql)
+        .bind(1)
+        .cached(ttl)
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(count, 1);
+
+    pool.execute("INSERT INTO counters (value) VALUES (3)")
+        .await?;
+
+    let count: i64 = sqlx::query_scalar(sql)
+        .bind(1)
+        .cached(ttl)
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(count, 2);
+
+    Ok(())
+}
+
+#[test]
+fn it_executes_blocking() -> anyhow::Result<()> {
+    use sqlx::blocking::BlockingConnection;
+
+    let mut conn = BlockingConnection::<Sqlite>::connect("sqlite::memory:")?;
+
+    conn.execute("CREATE TABLE items (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")?;
+    conn.execute(sqlx::query("INSERT INTO items (name) VALUES (?)").bind("one"))?;
+
+    let row = conn.fetch_one("SELECT name FROM items")?;
+    let name: &str = row.try_get(0)?;
+
+    assert_eq!(name, "one");
+
+    let count: (i64,) =
+        conn.run(|conn| sqlx::query_as("SELECT COUNT(*) FROM items").fetch_one(conn))?;
+
+    assert_eq!(count.0, 1);
+
+    conn.close()?;
+
+    Ok(())
+}
+
+#[cfg(feature = "r2d2")]
+#[test]
+fn it_pools_blocking_connections_with_r2d2() -> anyhow::Result<()> {
+    use sqlx::blocking::r2d2::{r2d2, ConnectionManager};
+
+    let manager = ConnectionManager::<Sqlite>::from_url("sqlite::memory:")?;
+    let pool = r2d2::Pool::builder().max_size(2).build(manager)?;
+
+    let mut conn = pool.get()?;
+    let row = conn.fetch_one("SELECT 1 + 1")?;
+    let value: i32 = row.try_get(0)?;
+
+    assert_eq!(value, 2);
+
+    let _other = pool.get()?;
+
+    assert_eq!(pool.state().connections, 2);
+    assert_eq!(pool.state().idle_connections, 0);
+
+    drop(conn);
+
+    assert_eq!(pool.state().idle_connections, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_streams_typed_rows_from_query_as() -> anyhow::Result<()> {
+    use futures::StreamExt;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    let sql = "SELECT 1 UNION ALL SELECT 2 UNION ALL SELECT 3 \
+               UNION ALL SELECT 4 UNION ALL SELECT 5";
+
+    let all: Vec<(i32,)> = sqlx::query_as(sql).fetch(&mut conn).try_collect().await?;
+
+    assert_eq!(all, vec![(1,), (2,), (3,), (4,), (5,)]);
+
+    let batches: Vec<Vec<i32>> = sqlx::query_as::<_, (i32,)>(sql)
+        .fetch(&mut conn)
+        .chunks(2)
+        .map(|batch| {
+            batch
+                .into_iter()
+                .map(|row| row.map(|(v,)| v))
+                .collect::<Result<Vec<i32>, sqlx::Error>>()
+        })
+        .try_collect()
+        .await?;
+
+    assert_eq!(batches, vec![vec![1, 2], vec![3, 4], vec![5]]);
+
+    // dropping the stream part-way leaves the connection usable
+    {
+        let mut s = sqlx::query_as::<_, (i32,)>(sql).fetch(&mut conn);
+
+        assert_eq!(s.try_next().await?, Some((1,)));
+    }
+
+    let (one,): (i32,) = sqlx::query_as("SELECT 1").fetch_one(&mut conn).await?;
+
+    assert_eq!(one, 1);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_caches_statements() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
@@ -455,6 +1020,49 @@ async fn it_caches_statements() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_tracks_statistics() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    for i in 0..3 {
+        let val: i32 = sqlx::query_scalar("SELECT ? AS val")
+            .bind(i)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(i, val);
+    }
+
+    let statistics = conn.statistics();
+
+    assert_eq!(statistics.statements_executed, 3);
+    assert_eq!(statistics.rows_fetched, 3);
+    assert_eq!(statistics.statement_cache_misses, 1);
+    assert_eq!(statistics.statement_cache_hits, 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_identifies_statements() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    assert_eq!(conn.statistics().last_statement_id, None);
+
+    let statement = conn.prepare("SELECT ? AS val").await?;
+
+    let _: i32 = statement
+        .query_scalar()
+        .bind(1)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(conn.statistics().last_statement_id, Some(statement.id()));
+    assert_eq!(statement.id(), StatementId::of("SELECT ?  AS val"));
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
@@ -485,3 +1093,38 @@ async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_gets_a_default_for_null() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let row = conn
+        .fetch_one("SELECT NULL AS discount, 5 AS quantity, 'five' AS label")
+        .await?;
+
+    assert_eq!(row.try_get_or::<i32, _>("discount", 10)?, 10);
+    assert_eq!(row.try_get_or::<i32, _>("quantity", 10)?, 5);
+    assert_eq!(row.try_get_unchecked_or::<i32, _>("discount", 10)?, 10);
+
+    // a default does not cover a missing column or a value of the wrong type
+    assert!(row.try_get_or::<i32, _>("price", 10).is_err());
+    assert!(row.try_get_or::<i32, _>("label", 10).is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_probes_for_columns() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let row = conn.fetch_one("SELECT 1 AS a, 2 AS b, 3 AS b").await?;
+
+    assert_eq!(row.column_ordinal("a"), Some(0));
+    assert_eq!(row.column_ordinal("c"), None);
+
+    // agrees with indexing by name when a name is repeated
+    assert_eq!(row.column_ordinal("b"), Some(2));
+    assert_eq!(row.try_get::<i32, _>("b")?, 3);
+
+    Ok(())
+}
@@ -25,6 +25,10 @@ test_type!(str<String>(Sqlite,
     "''" == ""
 ));
 
+test_type!(text_ip_addr<sqlx::types::Text<std::net::Ipv4Addr>>(Sqlite,
+    "'127.0.0.1'" == sqlx::types::Text(std::net::Ipv4Addr::LOCALHOST)
+));
+
 test_type!(bytes<Vec<u8>>(Sqlite,
     "X'DEADBEEF'"
         == vec![0xDE_u8, 0xAD, 0xBE, 0xEF],
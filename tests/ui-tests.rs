@@ -42,5 +42,12 @@ fn ui_tests() {
         t.compile_fail("tests/ui/sqlite/*.rs");
     }
 
+    // a URL for a database whose feature is not enabled is reported at the query
+    if cfg!(not(feature = "mssql")) {
+        std::env::set_var("SQLX_UI_MSSQL_URL", "mssql://sa@localhost/sqlx");
+
+        t.compile_fail("tests/ui/missing-feature/mssql.rs");
+    }
+
     t.compile_fail("tests/ui/*.rs");
 }
@@ -0,0 +1,3 @@
+fn main() {
+    let _query = sqlx::query!(url = "env:SQLX_UI_MSSQL_URL", "SELECT 1");
+}
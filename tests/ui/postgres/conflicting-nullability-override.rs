@@ -0,0 +1,3 @@
+fn main() {
+    let _query = sqlx::query!(r#"select 1 as "id!?""#);
+}
@@ -0,0 +1,10 @@
+struct Record {
+    id: i32,
+}
+
+fn main() {
+    let _query = sqlx::query!("select 1 as id, 2 as id");
+
+    // the fields of the given struct are unique but the columns must be as well
+    let _query = sqlx::query_as!(Record, "select 1 as id, 2 as id");
+}
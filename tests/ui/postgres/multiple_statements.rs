@@ -0,0 +1,5 @@
+fn main() {
+    let _query = sqlx::query!("select 1 as one; select 2 as two");
+
+    let _script = sqlx::execute_many!("select 1; select * from this_table_does_not_exist");
+}
@@ -0,0 +1,4 @@
+fn main() {
+    // an enum can only be inferred from the struct field of `query_as!()`
+    let _ = sqlx::query!("select 'open'::status as status");
+}
@@ -0,0 +1,5 @@
+fn main() {
+    let _query = sqlx::query!("select $1::int4", 1i32, 2i32);
+
+    let _query = sqlx::query!("select $1::int4, $2::int4", 1i32);
+}